@@ -20,6 +20,10 @@ function preview(req: PreviewReq): ReqControl {
   return get("preview", req);
 }
 
+function sprites(commitCount: CommitCount): ReqControl {
+  return get("sprites", { commit_count: commitCount });
+}
+
 function upload(file: File): ReqControl {
   const controller = new AbortController();
   const formData = new FormData();
@@ -122,4 +126,4 @@ function errHandler(addToast: AddToast, setErr: Dispatch<string> = () => {}) {
   };
 }
 
-export default { preview, upload, errHandler, createRepo, getRepo, genDownloadUrl, download };
+export default { preview, sprites, upload, errHandler, createRepo, getRepo, genDownloadUrl, download };
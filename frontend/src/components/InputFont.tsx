@@ -7,6 +7,10 @@ function InputFont({ value, onChange }: SelectInputProp<Font>) {
       <legend className="fieldset-legend">Font</legend>
       <select className="select w-full" value={value} onChange={onChange}>
         <option value={Font.SubwayTracker}>Subway Tracker</option>
+        <option value={Font.Compact}>Compact</option>
+        <option value={Font.Bold}>Bold</option>
+        <option value={Font.Monospace}>Monospace</option>
+        <option value={Font.Icons}>Icons</option>
       </select>
     </fieldset>
   );
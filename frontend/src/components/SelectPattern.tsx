@@ -6,6 +6,7 @@ import type { TabId, TabSetting } from "../types.ts";
 import Daily from "./Daily.tsx";
 import Image from "./Image.tsx";
 import Random from "./Random.tsx";
+import Sprite from "./Sprite.tsx";
 import Text from "./Text.tsx";
 import TextFilePattern from "./TextFilePattern.tsx";
 
@@ -15,6 +16,7 @@ const TABS: Record<TabId, TabSetting> = {
   TextFilePattern: { tabLabel: "Text File Pattern", componentClass: TextFilePattern },
   Image: { tabLabel: "Image", componentClass: Image },
   Text: { tabLabel: "Text", componentClass: Text },
+  Sprite: { tabLabel: "Sprite", componentClass: Sprite },
 };
 
 function SelectPattern() {
@@ -26,6 +28,7 @@ function SelectPattern() {
     TextFilePattern: [],
     Image: [],
     Text: [],
+    Sprite: [],
   });
 
   const [handleTabClickFuncs, setTabDataFuncs] = useMemo(() => {
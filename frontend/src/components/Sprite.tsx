@@ -0,0 +1,75 @@
+import { useContext, useEffect, useState } from "react";
+
+import api from "../api.tsx";
+import { ToastContext } from "../context.tsx";
+import { useCommitCountInput, usePreviewData, useStartDateInput } from "../hooks.ts";
+import type { OnSelectChange, SelectPatternTabProp, SpriteResult } from "../types.ts";
+import InputCommitCount from "./InputCommitCount.tsx";
+import InputDate from "./InputDate.tsx";
+import PatternTab from "./PatternTab.tsx";
+
+function Sprite({ updatePreviewData }: SelectPatternTabProp) {
+  const { addToast } = useContext(ToastContext);
+
+  const [startDate, onStartDateChange, startDateErr] = useStartDateInput({ mustBeSunday: true });
+  const [commitCount, onCommitCountChange] = useCommitCountInput();
+  const [sprites, setSprites] = useState<SpriteResult[]>([]);
+  const [name, setName] = useState("");
+
+  const [data, setData, setDataAtIndex] = usePreviewData(updatePreviewData);
+
+  const [loading, setLoading] = useState(false);
+
+  useEffect(() => {
+    setLoading(true);
+
+    const [resPromise, controller] = api.sprites(commitCount);
+
+    resPromise
+      .then(async res => {
+        if (controller.signal.aborted) return;
+        setSprites((await res.json()) as SpriteResult[]);
+      })
+      .catch(api.errHandler(addToast))
+      .finally(() => setLoading(false));
+
+    return () => {
+      controller.abort("component dismount");
+      setLoading(false);
+    };
+  }, [commitCount]);
+
+  const sprite = sprites.find(sprite => sprite.name === name) || sprites[0];
+
+  useEffect(() => {
+    if (sprite) setData(sprite.data);
+  }, [sprite, setData]);
+
+  const onNameChange: OnSelectChange = e => setName(e.target.value);
+
+  return (
+    <PatternTab
+      title="Sprite Pattern"
+      subtitle="Draw a built-in sprite: hearts, arrows, Space Invaders, borders and more"
+      startDate={startDate}
+      data={data}
+      setDataAtIndex={setDataAtIndex}
+      loading={loading}
+    >
+      <InputDate legend="Start date" date={startDate} onChange={onStartDateChange} error={startDateErr} />
+      <InputCommitCount value={commitCount} onChange={onCommitCountChange} />
+      <fieldset className="fieldset col-span-2">
+        <legend className="fieldset-legend">Sprite</legend>
+        <select className="select w-full" value={sprite?.name ?? ""} onChange={onNameChange}>
+          {sprites.map(sprite => (
+            <option key={sprite.name} value={sprite.name}>
+              {sprite.description} ({sprite.weeks} weeks)
+            </option>
+          ))}
+        </select>
+      </fieldset>
+    </PatternTab>
+  );
+}
+
+export default Sprite;
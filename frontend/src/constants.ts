@@ -20,6 +20,10 @@ const ALL_COMMIT_COUNT = [...Object.values(CommitCount)];
 
 enum Font {
   SubwayTracker = "SubwayTracker",
+  Compact = "Compact",
+  Bold = "Bold",
+  Monospace = "Monospace",
+  Icons = "Icons",
 }
 
 const SUNDAY = 0;
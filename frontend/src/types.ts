@@ -22,6 +22,13 @@ type PreviewResult = {
   data: CommitCount[];
 };
 
+type SpriteResult = {
+  name: string;
+  description: string;
+  weeks: number;
+  data: CommitCount[];
+};
+
 type CreateRepoData = {
   name: string;
   username: string;
@@ -46,7 +53,7 @@ type UpdatePreviewData = (data: CommitCount[]) => void;
 type SelectPatternTabProp = { updatePreviewData: UpdatePreviewData };
 
 type TabComponent = (prop: SelectPatternTabProp & { key: string }) => JSX.Element;
-type TabId = "Daily" | "Random" | "TextFilePattern" | "Image" | "Text";
+type TabId = "Daily" | "Random" | "TextFilePattern" | "Image" | "Text" | "Sprite";
 type TabSetting = { tabLabel: string; componentClass: TabComponent; defaultChecked?: boolean };
 
 export type {
@@ -57,6 +64,7 @@ export type {
   OnButtonClick,
   FileUploadResult,
   PreviewResult,
+  SpriteResult,
   CreateRepoData,
   UpdatePreviewData,
   SelectPatternTabProp,
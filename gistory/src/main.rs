@@ -3,131 +3,530 @@ mod git;
 mod utils;
 mod visualizer;
 
-use std::path::PathBuf;
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 
-use clap::{ArgAction, ArgGroup, Command, arg, value_parser};
-use log::debug;
+use chrono::Datelike;
+use clap::builder::PossibleValuesParser;
+use clap::{Arg, ArgAction, ArgGroup, ArgMatches, Command, arg, value_parser};
+use log::{debug, warn};
 
-use crate::visualizer::CommitCount;
+fn grid_args() -> Vec<Arg> {
+    vec![
+        arg!(-s --"start-date" <START_DATE> "Start date. Format YYYY-mm-dd. Optional with --design, --pattern-file and --heatmap, where it overrides the start date of the file")
+            .required_unless_present_any(["design", "pattern-file", "heatmap", "year", "center"])
+            .value_parser(value_parser!(chrono::NaiveDate)),
+        arg!(-e --"end-date" <END_DATE> "End date. Format YYYY-mm-dd.").required(false)
+            .value_parser(value_parser!(chrono::NaiveDate))
+            .required_if_eq_any([
+                ("full", "true"),
+                ("random", "true")
+            ]),
+        arg!(-c --"commit-count" <COMMIT_COUNT> "Commit count").required(false)
+            .value_parser(value_parser!(visualizer::CommitCount))
+            .default_value("many"),
+        arg!(--"font" <FONT> "Font for text").required(false)
+            .value_parser(value_parser!(visualizer::Font))
+            .default_value("subway-tracker"),
+        arg!(--"font-file" <FONT_FILE> "Load the font for text from a BDF, PSF or text file. Glyphs taller than 7 pixels are shrunk. Text format: a [c] line followed by 7 lines of x and . for each character")
+            .value_parser(value_parser!(PathBuf))
+            .conflicts_with("font"),
+        arg!(--"fallback" <FALLBACK> "What to draw for characters missing from the font, after trying the same letter without accent or in the other case").required(false)
+            .value_parser(value_parser!(visualizer::font::Fallback))
+            .default_value("replace"),
+        arg!(--"letter-spacing" <COLUMNS> "Blank columns between characters").required(false)
+            .value_parser(value_parser!(usize))
+            .default_value("1")
+            .requires("text"),
+        arg!(--"word-spacing" <COLUMNS> "Blank columns between words, instead of the space character of the font")
+            .value_parser(value_parser!(usize))
+            .requires("text"),
+        arg!(--"kerning" "Remove the letter spacing between characters that don't touch, like T and o").action(ArgAction::SetTrue)
+            .requires("text"),
+        arg!(--"leading" <COLUMNS> "Blank columns before the text").required(false)
+            .value_parser(value_parser!(usize))
+            .default_value("0")
+            .requires("text"),
+        arg!(--"text-width" <WEEKS> "Number of weeks to align the text in")
+            .value_parser(value_parser!(usize))
+            .requires("text"),
+        arg!(--"align" <ALIGNMENT> "Alignment of the text within --text-width").required(false)
+            .value_parser(value_parser!(visualizer::text_options::Alignment))
+            .default_value("left")
+            .requires("text-width"),
+        arg!(--"shading" <SHADING> "Commit counts of the text: solid, an outline in --shade around it, or a gradient from --shade to --commit-count").required(false)
+            .value_parser(value_parser!(visualizer::text_options::Shading))
+            .default_value("solid")
+            .requires("text"),
+        arg!(--"shade" <COMMIT_COUNT> "Commit count of the outline or of the start of the gradient").required(false)
+            .value_parser(value_parser!(visualizer::CommitCount))
+            .default_value("few")
+            .requires("text"),
+        arg!(--"marquee" "Write the text across consecutive calendar years from the year of --start-date, wrapping between words").action(ArgAction::SetTrue)
+            .requires("text")
+            .conflicts_with_all(["year", "week", "center"]),
+        arg!(-f --"full" "Fill all days with the same number of commits").action(ArgAction::SetTrue),
+        arg!(-r --"random" "Fill all days with random number of commits").action(ArgAction::SetTrue),
+        arg!(--"weights" <WEIGHTS> "Relative chance of each commit count from zero to a-lot, with --random and --random-background. Format: 5 numbers, e.g. 50,30,10,5,5").required(false)
+            .value_parser(value_parser!(visualizer::fill::Weights))
+            .default_value("1,1,1,1,1"),
+        arg!(--"weekdays" <DAYS> "Days of the week filled by --full, --random and --random-background, the others get no commit. Format: all, weekdays, weekends, or day names like mon,wed,fri").required(false)
+            .value_parser(value_parser!(visualizer::fill::Weekdays))
+            .default_value("all"),
+        arg!(--"random-background" "Fill the days without commits of the pattern with random commits, until --end-date").action(ArgAction::SetTrue)
+            .requires("end-date")
+            .conflicts_with_all(["random", "generate", "simulate"]),
+        arg!(-g --"generate" <GENERATOR> "Fill all days with a procedural pattern")
+            .value_parser(value_parser!(visualizer::generator::Generator))
+            .requires("end-date"),
+        arg!(--"noise-scale" <WEEKS> "Size of the generated features in weeks: noise scale, wave period and gradient length. Default to 8, or to all weeks for the gradient")
+            .value_parser(value_parser!(f32))
+            .requires("generate"),
+        arg!(--"octaves" <OCTAVES> "Number of noise layers, each one twice as detailed as the previous one").required(false)
+            .value_parser(value_parser!(usize))
+            .default_value("3")
+            .requires("generate"),
+        arg!(--"density" <DENSITY> "Share of days alive in the first generation of the Game of Life").required(false)
+            .value_parser(value_parser!(f32))
+            .default_value("0.35")
+            .requires("generate"),
+        arg!(--"steps" <STEPS> "Number of generations of the Game of Life").required(false)
+            .value_parser(value_parser!(usize))
+            .default_value("10")
+            .requires("generate"),
+        arg!(--"rule" <RULE> "Wolfram code of the cellular automaton, like 30 or 110").required(false)
+            .value_parser(value_parser!(u8))
+            .default_value("30")
+            .requires("generate"),
+        arg!(--"amplitude" <DAYS> "Height of the sine wave in days, from its middle").required(false)
+            .value_parser(value_parser!(f32))
+            .default_value("3.0")
+            .requires("generate"),
+        arg!(--"simulate" <PROFILE> "Fill all days with the activity of a simulated developer, described by a TOML or JSON profile file")
+            .value_parser(value_parser!(PathBuf))
+            .requires("end-date"),
+        arg!(--"seed" <SEED> "Seed of the random patterns: --random, --random-background, --generate, --simulate and the random layers of --design. The same seed draws the same pattern. Default to a new seed, printed with --random, --random-background, --generate, --simulate and designs with random layers")
+            .value_parser(value_parser!(u64)),
+        arg!(-p --"pattern-file" <PATTERN> "Draw pattern from file. File format: up to 7 lines of 0->4, one per day of the week. 0, . and space mean no commit, 4 means lots of commits. Lines starting with # are comments, and the first lines can be headers: start_date, year, week and center like the options of the same names, and palette with 5 characters replacing 0->4, e.g. palette: .-+*@")
+            .value_parser(value_parser!(PathBuf)),
+        arg!(--"lenient" "Read the pattern file like before headers and comments: other characters than 0->4 mean no commit, empty lines and lines after the 7th are ignored").action(ArgAction::SetTrue)
+            .requires("pattern-file"),
+        arg!(-i --"image" <IMAGE> "Draw image. Image will be re-scaled to 7-pixel height and turned to grayscale")
+            .value_parser(value_parser!(PathBuf)),
+        arg!(--"dither" <DITHERING> "Dithering of the image").required(false)
+            .value_parser(value_parser!(visualizer::image_options::Dithering))
+            .default_value("none")
+            .requires("image"),
+        arg!(--"thresholds" <THRESHOLDS> "Brightness cut-offs between commit counts of the image, from light to dark. Format: auto or 0.2,0.4,0.6,0.8")
+            .value_parser(value_parser!(visualizer::image_options::Thresholds))
+            .requires("image"),
+        arg!(--"gamma" <GAMMA> "Gamma correction of the image, applied after contrast").required(false)
+            .value_parser(value_parser!(f32))
+            .default_value("1.0")
+            .requires("image"),
+        arg!(--"contrast" <CONTRAST> "Contrast of the image, 1.0 keeps the original contrast").required(false)
+            .value_parser(value_parser!(f32))
+            .default_value("1.0")
+            .requires("image"),
+        arg!(--"invert-image" "Give more commits to light pixels instead of dark pixels").action(ArgAction::SetTrue)
+            .requires("image"),
+        arg!(--"fit" <FIT_MODE> "How the image is scaled to 7 rows. Transparent pixels get no commit").required(false)
+            .value_parser(value_parser!(visualizer::image_options::FitMode))
+            .default_value("height")
+            .requires("image"),
+        arg!(--"fit-weeks" <WEEKS> "Number of weeks of the image for --fit width, crop and pad. Default to 53")
+            .value_parser(value_parser!(usize))
+            .requires("image"),
+        arg!(--"resampling" <FILTER> "Resampling filter of the image, nearest keeps pixel art crisp").required(false)
+            .value_parser(value_parser!(visualizer::image_options::Resampling))
+            .default_value("lanczos3")
+            .requires("image"),
+        arg!(--"animation" <LAYOUT> "Draw each frame of an animated GIF or PNG after the previous one, one calendar year per frame starting at the year of --start-date, or one block of --fit-weeks weeks per frame")
+            .value_parser(value_parser!(visualizer::animation::FrameLayout))
+            .requires("image")
+            .conflicts_with_all(["year", "week", "center"]),
+        arg!(-t --"text" <TEXT> "Print given text on grid"),
+        arg!(-x --"raw" <RAW_PATERN> "Raw commit count pattern with characters 0->4, one per day. | or spaces end a week early, e.g. 0440|044|4")
+            .value_parser(value_parser!(visualizer::raw_pattern::RawPattern)),
+        arg!(--"heatmap" <SERIES> "Draw a heatmap of a time series from a CSV file of date,value lines or a JSON array of {\"date\", \"value\"} objects. Start at the Sunday of the earliest date")
+            .value_parser(value_parser!(PathBuf)),
+        arg!(--"heatmap-scale" <SCALE> "How values of the heatmap are turned into commit counts").required(false)
+            .value_parser(value_parser!(visualizer::heatmap::Scale))
+            .default_value("linear")
+            .requires("heatmap"),
+        arg!(--"encode" <MESSAGE> "Hide a message of printable ASCII characters, one character per week from a Sunday start date as a 7-bit binary code between two full weeks. Read it back with the decode command"),
+        arg!(-d --"design" <DESIGN> "Load a design file (.json or .toml) created by the export command")
+            .value_parser(value_parser!(PathBuf)),
+        arg!(--"year" <YEAR> "Place the pattern at the first full week of the given calendar year")
+            .value_parser(value_parser!(i32))
+            .conflicts_with("center"),
+        arg!(--"week" <WEEK> "With --year, place the pattern at the given week of the year instead. Week 0 contains January 1st")
+            .value_parser(value_parser!(usize))
+            .requires("year"),
+        arg!(--"center" "Center the pattern in the last year shown on the profile, ending today").action(ArgAction::SetTrue),
+        arg!(--"zoom" <FACTOR> "Enlarge the pattern by an integer factor. Days past the 7th row are dropped")
+            .value_parser(value_parser!(usize)),
+        arg!(--"mirror" "Reverse the order of the weeks").action(ArgAction::SetTrue),
+        arg!(--"flip" "Reverse the days within each week").action(ArgAction::SetTrue),
+        arg!(--"invert" "Invert commit counts: zero <-> a-lot, few <-> many").action(ArgAction::SetTrue),
+        arg!(--"tile-until" <END_DATE> "Repeat the pattern until the given date. Format YYYY-mm-dd.")
+            .value_parser(value_parser!(chrono::NaiveDate)),
+        arg!(--"crop-from" <DATE> "Drop days before the given date. Format YYYY-mm-dd.")
+            .value_parser(value_parser!(chrono::NaiveDate)),
+        arg!(--"crop-to" <DATE> "Drop days after the given date. Format YYYY-mm-dd.")
+            .value_parser(value_parser!(chrono::NaiveDate)),
+        arg!(--"shift-weeks" <WEEKS> "Move the pattern by the given number of weeks, negative to move back")
+            .value_parser(value_parser!(i64))
+            .allow_negative_numbers(true),
+        arg!(--"shift-days" <DAYS> "Move the pattern by the given number of days, negative to move back")
+            .value_parser(value_parser!(i64))
+            .allow_negative_numbers(true),
+    ]
+}
 
-#[tokio::main]
-async fn main() -> error::Result<()> {
-    env_logger::init();
+/// Options of the created repo
+fn repo_args(cwd: &OsStr, local_tz: &str, default_username: &str, default_email: &str) -> Vec<Arg> {
+    vec![
+        arg!(-n --"repo-name" <REPOSITORY_NAME> "Repo name.").default_value("gistory"),
+        arg!(-w --"working-dir" <WORKING_DIR> "Working directory. Default to cwd.")
+            .value_parser(value_parser!(PathBuf))
+            .default_value(cwd.to_os_string()),
+        arg!(-u --"user-name" <USER_NAME> "Git username. Default to global git config.")
+            .default_value(default_username.to_string()),
+        arg!(-m --"email" <EMAIL> "Git user email. This must match GitHub email. Default to global git config.")
+            .default_value(default_email.to_string()),
+        arg!(-b --"branch" <BRANCH_NAME> "Branch name. Default to `master`")
+            .default_value("master"),
+        arg!(-z --"time-zone" <TIME_ZONE> "Time zone in format +-HHMM. Defaults to local timezone.")
+            .value_parser(value_parser!(chrono::FixedOffset))
+            .default_value(local_tz.to_string()),
+    ]
+}
 
-    let cwd = std::env::current_dir()?.into_os_string();
-    let local_tz = chrono::Local::now().format("%z").to_string();
-    let default_username = git::utils::get_global_config("user.name")?;
-    let default_email = git::utils::get_global_config("user.email")?;
+/// Options of the number of commits per level
+fn level_args() -> Vec<Arg> {
+    vec![
+        arg!(-l --"commit-levels" <COMMIT_LEVELS> "Number of commits for levels few,some,many,a-lot, at most 1000 a day. Format: 1,8,15,25. Default to 1,2,3,4")
+            .value_parser(value_parser!(visualizer::CommitLevels))
+            .conflicts_with("calibrate"),
+        arg!(--"calibrate" <COUNTS_FILE> "Compute commit levels from existing daily commit counts so colors match GitHub's quartiles. File format: one day per line, the last field is the commit count")
+            .value_parser(value_parser!(PathBuf)),
+    ]
+}
 
-    let matches = Command::new("gistory")
-        .version("0.1.0")
-        .about("A tool to draw on GitHub commit graph")
-        .arg(
-            arg!(-n --"repo-name" <REPOSITORY_NAME> "Repo name.").default_value("gistory")
-        )
-        .arg(
-            arg!(-w --"working-dir" <WORKING_DIR> "Working directory. Default to cwd.")
-                .value_parser(value_parser!(PathBuf))
-                .default_value(cwd)
-        )
-        .arg(
-            arg!(-u --"user-name" <USER_NAME> "Git username. Default to global git config.")
-                .default_value(default_username)
-        )
-        .arg(
-            arg!(-m --"email" <EMAIL> "Git user email. This must match GitHub email. Default to global git config.")
-                .default_value(default_email)
-        )
-        .arg(
-            arg!(-b --"branch" <BRANCH_NAME> "Branch name. Default to `master`")
-                .default_value("master")
-        )
-        .arg(
-            arg!(-z --"time-zone" <TIME_ZONE> "Time zone in format +-HHMM. Defaults to local timezone.")
-                .value_parser(value_parser!(chrono::FixedOffset))
-                .default_value(local_tz)
-        )
-        .arg(
-            arg!(-s --"start-date" <START_DATE> "Start date. Format YYYY-mm-dd.").required(true)
-                .value_parser(value_parser!(chrono::NaiveDate))
-        )
-        .arg(
-            arg!(-e --"end-date" <END_DATE> "End date. Format YYYY-mm-dd.").required(false)
-                .value_parser(value_parser!(chrono::NaiveDate))
-                .required_if_eq_any([
-                    ("full", "true"),
-                    ("random", "true")
-                ])
-        )
-        .arg(
-            arg!(-c --"commit-count" <COMMIT_COUNT> "Commit count").required(false)
-                .value_parser(value_parser!(visualizer::CommitCount))
-                .default_value("many")
-        )
-        .arg(
-            arg!(--"font" <FONT> "Font for text").required(false)
-                .value_parser(value_parser!(visualizer::Font))
-                .default_value("subway-tracker")
-        )
-        .arg(
-            arg!(-f --"full" "Fill all days with the same number of commits").action(ArgAction::SetTrue)
-        )
-        .arg(
-            arg!(-r --"random" "Fill all days with random number of commits").action(ArgAction::SetTrue)
-        )
-        .arg(
-            arg!(-p --"pattern-file" <PATTERN> "Draw pattern from file. File format: text file contains character from 0->4 on less than 7 lines. 0 means no commit, 4 means lots of commits")
-                .value_parser(value_parser!(PathBuf))
-        )
-        .arg(
-            arg!(-i --"image" <IMAGE> "Draw image. Image will be re-scaled to 7-pixel height and turned to grayscale")
-                .value_parser(value_parser!(PathBuf))
-        )
-        .arg(
-            arg!(-t --"text" <TEXT> "Print given text on grid")
-        )
-        .arg(
-            arg!(-x --"raw" <RAW_PATERN> "Raw commit count pattern with characters 0->4. Invalid characters are considered as 0")
-        )
-        .group(
-            ArgGroup::new("method").args(["full", "random", "pattern-file", "image", "text", "raw"]).required(true).multiple(false)
-        )
-        .get_matches();
+/// Options of the commits and of the preview
+fn commit_args() -> Vec<Arg> {
+    let mut args = level_args();
+    args.extend([
+        arg!(--"preview" "Print the grid to the terminal and exit without creating the repo").action(ArgAction::SetTrue)
+            .visible_alias("dry-run"),
+        arg!(--"color" <COLOR_MODE> "Color mode of the preview").required(false)
+            .value_parser(value_parser!(visualizer::preview::ColorMode))
+            .default_value("auto"),
+    ]);
+    args
+}
+
+fn method_group() -> ArgGroup {
+    ArgGroup::new("method").args(["full", "random", "generate", "simulate", "pattern-file", "image", "text", "raw", "encode", "heatmap", "design"]).required(true).multiple(false)
+}
+
+fn get_anchor(matches: &ArgMatches) -> Option<visualizer::Anchor> {
+    if let Some(year) = matches.get_one::<i32>("year") {
+        return Some(match matches.get_one::<usize>("week") {
+            Some(week) => visualizer::Anchor::Week {
+                year: *year,
+                week: *week,
+            },
+            None => visualizer::Anchor::Year(*year),
+        });
+    }
+    if matches.get_flag("center") {
+        let today = chrono::Local::now().date_naive();
+        return Some(visualizer::Anchor::Center { today });
+    }
+    None
+}
+
+fn transform_grid(
+    matches: &ArgMatches,
+    grid: &mut visualizer::CommitGrid,
+) -> error::Result<()> {
+    // transforms are applied in the same order as they are listed in the help
+    if let Some(factor) = matches.get_one::<usize>("zoom") {
+        grid.scale(*factor)?;
+    }
+    if matches.get_flag("mirror") {
+        grid.mirror();
+    }
+    if matches.get_flag("flip") {
+        grid.flip();
+    }
+    if matches.get_flag("invert") {
+        grid.invert();
+    }
+    if let Some(end_date) = matches.get_one::<chrono::NaiveDate>("tile-until") {
+        grid.tile(*end_date)?;
+    }
+    let crop_from = matches.get_one::<chrono::NaiveDate>("crop-from");
+    let crop_to = matches.get_one::<chrono::NaiveDate>("crop-to");
+    if crop_from.is_some() || crop_to.is_some() {
+        let from = crop_from.copied().unwrap_or(chrono::NaiveDate::MIN);
+        let to = crop_to.copied().unwrap_or(chrono::NaiveDate::MAX);
+        grid.crop(from, to)?;
+    }
+    let shift_weeks = matches.get_one::<i64>("shift-weeks").copied().unwrap_or(0);
+    let shift_days = matches.get_one::<i64>("shift-days").copied().unwrap_or(0);
+    if shift_weeks != 0 || shift_days != 0 {
+        grid.shift(shift_weeks, shift_days)?;
+    }
+    Ok(())
+}
+
+async fn create_grid(matches: &ArgMatches) -> error::Result<visualizer::CommitGrid> {
+    let (mut grid, anchor) = create_unplaced_grid(matches).await?;
+    transform_grid(matches, &mut grid)?;
+    if let Some(anchor) = anchor {
+        grid.place(anchor)?;
+        debug!("Placed grid at {anchor:?}: {grid:?}");
+    }
+    if matches.get_flag("random-background") {
+        let end_date = matches.get_one::<chrono::NaiveDate>("end-date").unwrap();
+        grid.fill_background(*end_date, &get_fill_options(matches), get_seed(matches))?;
+    }
+    Ok(grid)
+}
+
+/// Seed of the random patterns, printed when it isn't given so the same pattern can be drawn again.
+/// The same seed is used by all the random patterns of the grid
+fn get_seed(matches: &ArgMatches) -> u64 {
+    static GENERATED_SEED: OnceLock<u64> = OnceLock::new();
+    match matches.get_one::<u64>("seed") {
+        Some(seed) => *seed,
+        None => *GENERATED_SEED.get_or_init(|| {
+            let seed = visualizer::grid::random_seed();
+            eprintln!("Seed: {seed}");
+            seed
+        }),
+    }
+}
+
+fn get_fill_options(matches: &ArgMatches) -> visualizer::fill::FillOptions {
+    visualizer::fill::FillOptions {
+        weights: *matches.get_one::<visualizer::fill::Weights>("weights").unwrap(),
+        weekdays: *matches.get_one::<visualizer::fill::Weekdays>("weekdays").unwrap(),
+    }
+}
+
+/// The grid before transforms, and where to place it: from the options, or else from the pattern file
+async fn create_unplaced_grid(
+    matches: &ArgMatches,
+) -> error::Result<(visualizer::CommitGrid, Option<visualizer::Anchor>)> {
+    if let Some(design) = matches.get_one::<PathBuf>("design") {
+        // only print the seed of designs which use it
+        let base_dir = design.parent().unwrap_or(Path::new("."));
+        let seed = if visualizer::design::Design::read(design)
+            .await?
+            .has_random_layers(base_dir)
+            .await?
+        {
+            get_seed(matches)
+        } else {
+            0
+        };
+        let design_grid = visualizer::CommitGrid::load_with_seed(design, seed).await?;
+        let Some(start_date) = matches.get_one::<chrono::NaiveDate>("start-date") else {
+            debug!("Grid: {design_grid:?}");
+            return Ok((design_grid, get_anchor(matches)));
+        };
+        let mut grid = visualizer::CommitGrid::new(*start_date);
+        grid.set_data(design_grid.get_data().to_vec());
+        debug!("Grid: {grid:?}");
+        return Ok((grid, get_anchor(matches)));
+    }
+
+    if let Some(series) = matches.get_one::<PathBuf>("heatmap") {
+        let scale = matches
+            .get_one::<visualizer::heatmap::Scale>("heatmap-scale")
+            .unwrap();
+        let heatmap = visualizer::CommitGrid::from_series_file(series, *scale).await?;
+        let Some(start_date) = matches.get_one::<chrono::NaiveDate>("start-date") else {
+            debug!("Grid: {heatmap:?}");
+            return Ok((heatmap, get_anchor(matches)));
+        };
+        let mut grid = visualizer::CommitGrid::new(*start_date);
+        grid.set_data(heatmap.get_data().to_vec());
+        debug!("Grid: {grid:?}");
+        return Ok((grid, get_anchor(matches)));
+    }
+
+    if let Some(pattern_file) = matches.get_one::<PathBuf>("pattern-file") {
+        let pattern =
+            visualizer::pattern_file::PatternFile::read(pattern_file, matches.get_flag("lenient"))
+                .await?;
+        let anchor = get_anchor(matches).or(pattern.anchor);
+        let start_date = match matches.get_one::<chrono::NaiveDate>("start-date") {
+            Some(start_date) => *start_date,
+            None => match (pattern.start_date, anchor) {
+                (Some(start_date), _) => start_date,
+                (None, Some(anchor)) => anchor.window_start()?,
+                (None, None) => {
+                    return Err(error::Error::InvalidArg(
+                        "--start-date is required when the pattern file has no start_date, year or center header"
+                            .to_string(),
+                    ));
+                }
+            },
+        };
+        let mut grid = visualizer::CommitGrid::new(start_date);
+        grid.set_data(pattern.data);
+        debug!("Grid: {grid:?}");
+        return Ok((grid, anchor));
+    }
 
-    let start_date = matches.get_one::<chrono::NaiveDate>("start-date").unwrap();
-    let mut grid = visualizer::CommitGrid::new(*start_date);
+    let start_date = match matches.get_one::<chrono::NaiveDate>("start-date") {
+        Some(start_date) => *start_date,
+        // start date is only optional with an anchor, which will move the grid anyway
+        None => get_anchor(matches).unwrap().window_start()?,
+    };
+    let mut grid = visualizer::CommitGrid::new(start_date);
 
     if matches.get_flag("full") {
         let end_date = matches.get_one::<chrono::NaiveDate>("end-date").unwrap();
         let commit_count = matches
             .get_one::<visualizer::CommitCount>("commit-count")
             .unwrap();
-        grid.full(*commit_count, *end_date)?;
+        grid.full_with_options(*commit_count, *end_date, &get_fill_options(matches))?;
     } else if matches.get_flag("random") {
         let end_date = matches.get_one::<chrono::NaiveDate>("end-date").unwrap();
-        grid.random(*end_date)?;
-    } else if matches.contains_id("pattern-file") {
-        let pattern_file = matches.get_one::<PathBuf>("pattern-file").unwrap();
-        grid.read_pattern_file(pattern_file).await?;
+        grid.random_with_options(*end_date, &get_fill_options(matches), get_seed(matches))?;
+    } else if let Some(generator) = matches.get_one::<visualizer::generator::Generator>("generate") {
+        let end_date = matches.get_one::<chrono::NaiveDate>("end-date").unwrap();
+        let options = visualizer::generator::GeneratorOptions {
+            scale: matches.get_one::<f32>("noise-scale").copied(),
+            octaves: *matches.get_one::<usize>("octaves").unwrap(),
+            density: *matches.get_one::<f32>("density").unwrap(),
+            steps: *matches.get_one::<usize>("steps").unwrap(),
+            rule: *matches.get_one::<u8>("rule").unwrap(),
+            amplitude: *matches.get_one::<f32>("amplitude").unwrap(),
+        };
+        grid.generate(*generator, &options, *end_date, get_seed(matches))?;
+    } else if let Some(profile) = matches.get_one::<PathBuf>("simulate") {
+        let end_date = matches.get_one::<chrono::NaiveDate>("end-date").unwrap();
+        let profile = visualizer::activity::ActivityProfile::read(profile).await?;
+        grid.simulate(&profile, *end_date, get_seed(matches))?;
     } else if matches.contains_id("image") {
         let image = matches.get_one::<PathBuf>("image").unwrap();
-        grid.read_image_file(image).await?;
+        let options = visualizer::ImageOptions {
+            dithering: *matches
+                .get_one::<visualizer::image_options::Dithering>("dither")
+                .unwrap(),
+            thresholds: matches
+                .get_one::<visualizer::image_options::Thresholds>("thresholds")
+                .copied()
+                .unwrap_or_default(),
+            gamma: *matches.get_one::<f32>("gamma").unwrap(),
+            contrast: *matches.get_one::<f32>("contrast").unwrap(),
+            invert: matches.get_flag("invert-image"),
+            fit: *matches
+                .get_one::<visualizer::image_options::FitMode>("fit")
+                .unwrap(),
+            weeks: matches.get_one::<usize>("fit-weeks").copied(),
+            resampling: *matches
+                .get_one::<visualizer::image_options::Resampling>("resampling")
+                .unwrap(),
+        };
+        match matches.get_one::<visualizer::animation::FrameLayout>("animation") {
+            Some(layout) => grid.read_animation_file(image, &options, *layout).await?,
+            None => grid.read_image_file_with_options(image, &options).await?,
+        }
     } else if matches.contains_id("text") {
         let text = matches.get_one::<String>("text").unwrap();
-        let font = matches.get_one::<visualizer::Font>("font").unwrap();
         let commit_count = matches
             .get_one::<visualizer::CommitCount>("commit-count")
             .unwrap();
-        grid.show_text(text.clone(), *font, *commit_count)?;
+        let options = visualizer::TextOptions {
+            fallback: *matches
+                .get_one::<visualizer::font::Fallback>("fallback")
+                .unwrap(),
+            letter_spacing: *matches.get_one::<usize>("letter-spacing").unwrap(),
+            word_spacing: matches.get_one::<usize>("word-spacing").copied(),
+            kerning: matches.get_flag("kerning"),
+            leading: *matches.get_one::<usize>("leading").unwrap(),
+            width: matches.get_one::<usize>("text-width").copied(),
+            alignment: *matches
+                .get_one::<visualizer::text_options::Alignment>("align")
+                .unwrap(),
+            shading: *matches
+                .get_one::<visualizer::text_options::Shading>("shading")
+                .unwrap(),
+            shade: *matches.get_one::<visualizer::CommitCount>("shade").unwrap(),
+        };
+        let font_file = match matches.get_one::<PathBuf>("font-file") {
+            Some(font_file) => Some(visualizer::FontFile::read(font_file).await?),
+            None => None,
+        };
+        let font = *matches.get_one::<visualizer::Font>("font").unwrap();
+        if matches.get_flag("marquee") {
+            let years = match &font_file {
+                Some(font_file) => {
+                    grid.show_marquee_with_font_file(text, font_file, *commit_count, &options)?
+                }
+                None => grid.show_marquee(text, font, *commit_count, &options)?,
+            };
+            let first_year = start_date.year();
+            eprintln!(
+                "The text needs {years} year(s): {first_year} to {}",
+                first_year + years as i32 - 1
+            );
+        } else {
+            match &font_file {
+                Some(font_file) => {
+                    grid.show_text_with_font_file(text, font_file, *commit_count, &options)?
+                }
+                None => grid.show_text_with_options(text, font, *commit_count, &options)?,
+            }
+        }
+    } else if let Some(message) = matches.get_one::<String>("encode") {
+        let commit_count = matches
+            .get_one::<visualizer::CommitCount>("commit-count")
+            .unwrap();
+        grid.show_message(message, *commit_count)?;
     } else if matches.contains_id("raw") {
-        let raw_pattern = matches.get_one::<String>("raw").unwrap();
-        let commit_counts: Vec<CommitCount> = raw_pattern.chars().map(|c| c.into()).collect();
-        grid.set_data(commit_counts);
+        let raw_pattern = matches
+            .get_one::<visualizer::raw_pattern::RawPattern>("raw")
+            .unwrap();
+        grid.set_data(raw_pattern.clone().into_days());
     } else {
         unreachable!("No method flag provided");
     };
     debug!("Grid: {grid:?}");
 
+    Ok((grid, get_anchor(matches)))
+}
+
+async fn get_commit_levels(matches: &ArgMatches) -> error::Result<visualizer::CommitLevels> {
+    if let Some(counts_file) = matches.get_one::<PathBuf>("calibrate") {
+        visualizer::CommitLevels::calibrate_from_file(counts_file).await
+    } else {
+        Ok(matches
+            .get_one::<visualizer::CommitLevels>("commit-levels")
+            .copied()
+            .unwrap_or_default())
+    }
+}
+
+/// Print the grid with --preview, or else create the repo
+async fn write_grid(matches: &ArgMatches, grid: &visualizer::CommitGrid) -> error::Result<()> {
+    if matches.get_flag("preview") {
+        let color_mode = matches
+            .get_one::<visualizer::preview::ColorMode>("color")
+            .unwrap();
+        print!("{}", visualizer::preview::render(grid, *color_mode));
+        return Ok(());
+    }
+
     let working_dir = matches.get_one::<PathBuf>("working-dir").unwrap();
     let repo_name = matches.get_one::<String>("repo-name").unwrap();
     let repo_path = working_dir.join(repo_name);
@@ -137,6 +536,13 @@ async fn main() -> error::Result<()> {
     let user_name = matches.get_one::<String>("user-name").unwrap();
     let email = matches.get_one::<String>("email").unwrap();
 
+    let commit_levels = get_commit_levels(matches).await?;
+    debug!("Commit levels: {commit_levels:?}");
+    let stats = grid.stats(&commit_levels);
+    if stats.is_large() {
+        warn!("Creating {} commits, run the stats command to check the size of the repo", stats.total_commits);
+    }
+
     let mut repo = git::repo::Repo::new(
         repo_path,
         branch.clone(),
@@ -146,7 +552,187 @@ async fn main() -> error::Result<()> {
     );
     debug!("Repo: {repo:?}");
     repo.init().await?;
-    grid.populate_repo(&mut repo).await?;
+    grid.populate_repo_with_levels(&mut repo, &commit_levels)
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() -> error::Result<()> {
+    env_logger::init();
+
+    let cwd = std::env::current_dir()?.into_os_string();
+    let local_tz = chrono::Local::now().format("%z").to_string();
+    let default_username = git::utils::get_global_config("user.name")?;
+    let default_email = git::utils::get_global_config("user.email")?;
+
+    let matches = Command::new("gistory")
+        .version("0.1.0")
+        .about("A tool to draw on GitHub commit graph")
+        .args_conflicts_with_subcommands(true)
+        .subcommand_negates_reqs(true)
+        .args(repo_args(&cwd, &local_tz, &default_username, &default_email))
+        .args(grid_args())
+        .args(commit_args())
+        .group(method_group())
+        .subcommand(
+            Command::new("render")
+                .about("Render the grid to an SVG or PNG image, like GitHub's contribution graph")
+                .arg(
+                    arg!(-o --"output" <OUTPUT> "Output file. Format is detected from the extension: .svg or .png").required(true)
+                        .value_parser(value_parser!(PathBuf))
+                )
+                .arg(
+                    arg!(--"theme" <THEME> "Color theme").required(false)
+                        .value_parser(value_parser!(visualizer::render::Theme))
+                        .default_value("light")
+                )
+                .arg(
+                    arg!(--"scale" <SCALE> "Size multiplier of PNG images").required(false)
+                        .value_parser(value_parser!(u32).range(1..=16))
+                        .default_value("2")
+                )
+                .args(grid_args())
+                .group(method_group())
+        )
+        .subcommand(
+            Command::new("export")
+                .about("Save the grid to a design file (.json or .toml) or a pattern file (.txt)")
+                .arg(
+                    arg!(-o --"output" <OUTPUT> "Output file. Format is detected from the extension: .json, .toml or .txt").required(true)
+                        .value_parser(value_parser!(PathBuf))
+                )
+                .arg(arg!(--"title" <TITLE> "Title of the design"))
+                .arg(arg!(--"author" <AUTHOR> "Author of the design"))
+                .arg(arg!(--"description" <DESCRIPTION> "Description of the design"))
+                .args(grid_args())
+                .group(method_group())
+        )
+        .subcommand(
+            Command::new("decode")
+                .about("Print the messages hidden with --encode in a grid, e.g. from a design or a pattern file")
+                .args(grid_args())
+                .group(method_group())
+        )
+        .subcommand(
+            Command::new("sprite")
+                .about("List the built-in sprites, preview one, or draw it at a date")
+                .arg(
+                    arg!([NAME] "Sprite to draw. List the sprites when missing")
+                        .value_parser(PossibleValuesParser::new(visualizer::sprite::names()))
+                )
+                .arg(
+                    arg!(--"at" <DATE> "Date of the top left day of the sprite, a Sunday. Format YYYY-mm-dd. Only preview the sprite when missing")
+                        .value_parser(value_parser!(chrono::NaiveDate))
+                        .requires("NAME")
+                )
+                .arg(
+                    arg!(-c --"commit-count" <COMMIT_COUNT> "Commit count").required(false)
+                        .value_parser(value_parser!(visualizer::CommitCount))
+                        .default_value("many")
+                )
+                .args(repo_args(&cwd, &local_tz, &default_username, &default_email))
+                .args(commit_args())
+        )
+        .subcommand(
+            Command::new("stats")
+                .about("Print statistics of the grid and estimate the number of commits and size of the repo")
+                .args(grid_args())
+                .args(level_args())
+                .group(method_group())
+        )
+        .get_matches();
+
+    if let Some(("render", sub_matches)) = matches.subcommand() {
+        let grid = create_grid(sub_matches).await?;
+        let output = sub_matches.get_one::<PathBuf>("output").unwrap();
+        let theme = sub_matches
+            .get_one::<visualizer::render::Theme>("theme")
+            .unwrap();
+        let scale = sub_matches.get_one::<u32>("scale").unwrap();
+        visualizer::render::save(&grid, *theme, *scale, output).await?;
+        debug!("Rendered to {}", output.display());
+        return Ok(());
+    }
+
+    if let Some(("export", sub_matches)) = matches.subcommand() {
+        let grid = create_grid(sub_matches).await?;
+        let output = sub_matches.get_one::<PathBuf>("output").unwrap();
+        if output.extension().is_some_and(|ext| ext == "txt") {
+            grid.write_pattern_file(output).await?;
+        } else {
+            let metadata = visualizer::DesignMetadata {
+                title: sub_matches.get_one::<String>("title").cloned(),
+                author: sub_matches.get_one::<String>("author").cloned(),
+                description: sub_matches.get_one::<String>("description").cloned(),
+            };
+            visualizer::Design::from_grid(&grid, metadata)
+                .write(output)
+                .await?;
+        }
+        debug!("Exported to {}", output.display());
+        return Ok(());
+    }
+
+    if let Some(("decode", sub_matches)) = matches.subcommand() {
+        let grid = create_grid(sub_matches).await?;
+        let messages = visualizer::binary_code::decode(&grid);
+        if messages.is_empty() {
+            return Err(error::Error::InvalidData(
+                "No message found in the grid".to_string(),
+            ));
+        }
+        for message in messages {
+            println!("{message}");
+        }
+        return Ok(());
+    }
+
+    if let Some(("sprite", sub_matches)) = matches.subcommand() {
+        let Some(name) = sub_matches.get_one::<String>("NAME") else {
+            for name in visualizer::sprite::names() {
+                let sprite = visualizer::sprite::get(name).unwrap();
+                println!(
+                    "{name:<12} {:>2}x{}  {}",
+                    sprite.width(),
+                    sprite.height(),
+                    sprite.description
+                );
+            }
+            return Ok(());
+        };
+        let commit_count = sub_matches
+            .get_one::<visualizer::CommitCount>("commit-count")
+            .unwrap();
+        let Some(at) = sub_matches.get_one::<chrono::NaiveDate>("at") else {
+            // without a date, show the sprite from the current week
+            let today = chrono::Local::now().date_naive();
+            let sunday = today - chrono::TimeDelta::days(today.weekday().num_days_from_sunday() as i64);
+            let mut grid = visualizer::CommitGrid::new(sunday);
+            grid.show_sprite(name, *commit_count)?;
+            let color_mode = sub_matches
+                .get_one::<visualizer::preview::ColorMode>("color")
+                .unwrap();
+            print!("{}", visualizer::preview::render(&grid, *color_mode));
+            return Ok(());
+        };
+        let mut grid = visualizer::CommitGrid::new(*at);
+        grid.show_sprite(name, *commit_count)?;
+        write_grid(sub_matches, &grid).await?;
+        debug!("Drew sprite {name} at {at}");
+        return Ok(());
+    }
+
+    if let Some(("stats", sub_matches)) = matches.subcommand() {
+        let grid = create_grid(sub_matches).await?;
+        let commit_levels = get_commit_levels(sub_matches).await?;
+        print!("{}", grid.stats(&commit_levels));
+        return Ok(());
+    }
+
+    let grid = create_grid(&matches).await?;
+    write_grid(&matches, &grid).await?;
 
     debug!("Done");
     Ok(())
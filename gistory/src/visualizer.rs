@@ -1,6 +1,40 @@
+pub mod activity;
+pub mod anchor;
+pub mod animation;
+pub mod binary_code;
+pub mod calendar;
+pub mod calibration;
+pub mod composition;
+pub mod design;
+pub mod fill;
 pub mod font;
+mod font_bold;
+mod font_compact;
+mod font_fallback;
+pub mod font_file;
+mod font_icons;
+mod font_monospace;
 mod font_subway_tracker;
+pub mod generator;
 pub mod grid;
+pub mod heatmap;
+pub mod image_options;
+pub mod marquee;
+pub mod pattern_file;
+pub mod preview;
+pub mod raw_pattern;
+pub mod render;
+pub mod sprite;
+mod sprite_library;
+pub mod stats;
+pub mod text_options;
+pub mod transform;
 
+pub use anchor::Anchor;
+pub use calibration::CommitLevels;
+pub use design::{Design, DesignMetadata};
 pub use font::Font;
+pub use font_file::FontFile;
 pub use grid::{CommitCount, CommitGrid};
+pub use image_options::ImageOptions;
+pub use text_options::TextOptions;
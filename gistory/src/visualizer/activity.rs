@@ -0,0 +1,276 @@
+use std::path::Path;
+use std::str::FromStr;
+
+use chrono::{Datelike, NaiveDate, TimeDelta, Weekday};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use tokio::fs;
+
+use crate::error::{Error, Result};
+use crate::visualizer::calendar::DAYS_PER_WEEK;
+use crate::visualizer::design::DesignFormat;
+use crate::visualizer::heatmap::quartile_levels;
+use crate::visualizer::CommitGrid;
+
+/// A day off, every year on the same date or only once
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum Holiday {
+    /// Format MM-DD, e.g. 12-25
+    Yearly { month: u32, day: u32 },
+    /// Format YYYY-MM-DD
+    Once(NaiveDate),
+}
+
+impl FromStr for Holiday {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        if let Ok(date) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+            return Ok(Holiday::Once(date));
+        }
+        let invalid = || {
+            Error::InvalidData(format!(
+                "Invalid holiday {s:?}, expected MM-DD or YYYY-MM-DD"
+            ))
+        };
+        let (month, day) = s.split_once('-').ok_or_else(invalid)?;
+        let (month, day) = (
+            month.parse::<u32>().map_err(|_| invalid())?,
+            day.parse::<u32>().map_err(|_| invalid())?,
+        );
+        // 2000 is a leap year, so February 29th is accepted
+        NaiveDate::from_ymd_opt(2000, month, day).ok_or_else(invalid)?;
+        Ok(Holiday::Yearly { month, day })
+    }
+}
+
+impl TryFrom<String> for Holiday {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self> {
+        value.parse()
+    }
+}
+
+impl From<Holiday> for String {
+    fn from(value: Holiday) -> Self {
+        match value {
+            Holiday::Yearly { month, day } => format!("{month:02}-{day:02}"),
+            Holiday::Once(date) => date.format("%Y-%m-%d").to_string(),
+        }
+    }
+}
+
+impl Holiday {
+    fn matches(&self, date: NaiveDate) -> bool {
+        match self {
+            Holiday::Yearly { month, day } => date.month() == *month && date.day() == *day,
+            Holiday::Once(holiday) => *holiday == date,
+        }
+    }
+}
+
+/// Days away from the keyboard, both ends included
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Deserialize, Serialize)]
+pub struct Vacation {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+}
+
+/// How a simulated developer commits. Read from a TOML or JSON file, all fields are optional.
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ActivityProfile {
+    /// Average number of commits of an active working day
+    pub weekday_commits: f32,
+    /// Average number of commits of an active weekend day
+    pub weekend_commits: f32,
+    /// Days of the week with `weekend_commits`, e.g. ["Sat", "Sun"]
+    pub weekend: Vec<Weekday>,
+    /// Chance of a day without any commit
+    pub idle_chance: f32,
+    /// Chance of a day being active or idle like the day before, from 0 to 1.
+    /// Higher values make longer streaks and gaps
+    pub streakiness: f32,
+    /// Number of weeks over which activity grows from nothing to its full rate
+    pub ramp_up_weeks: usize,
+    pub vacations: Vec<Vacation>,
+    pub holidays: Vec<Holiday>,
+    pub releases: Vec<NaiveDate>,
+    /// Number of days before each release, release day included, with more commits
+    pub release_crunch_days: usize,
+    /// Commits are multiplied by this factor during the crunch before a release
+    pub release_burst: f32,
+}
+
+impl Default for ActivityProfile {
+    fn default() -> Self {
+        Self {
+            weekday_commits: 4.0,
+            weekend_commits: 1.0,
+            weekend: vec![Weekday::Sat, Weekday::Sun],
+            idle_chance: 0.2,
+            streakiness: 0.6,
+            ramp_up_weeks: 0,
+            vacations: vec![],
+            holidays: vec![],
+            releases: vec![],
+            release_crunch_days: 5,
+            release_burst: 2.5,
+        }
+    }
+}
+
+/// Highest mean number of commits a day, release bursts included.
+/// The Poisson sampling takes longer the larger the mean, and loses precision well above it
+const MAX_DAILY_COMMITS: f32 = 100.0;
+
+/// Number of events of a Poisson distribution with the given mean, using Knuth's algorithm
+fn poisson(rng: &mut impl Rng, mean: f32) -> usize {
+    let limit = (-mean.max(0.0) as f64).exp();
+    let mut product: f64 = rng.random();
+    let mut events = 0;
+    while product > limit {
+        events += 1;
+        product *= rng.random::<f64>();
+    }
+    events
+}
+
+impl ActivityProfile {
+    pub fn validate(&self) -> Result<()> {
+        let chances = [
+            ("idle_chance", self.idle_chance),
+            ("streakiness", self.streakiness),
+        ];
+        for (name, chance) in chances {
+            if !(0.0..=1.0).contains(&chance) {
+                return Err(Error::InvalidData(format!(
+                    "{name} must be between 0 and 1, got {chance}"
+                )));
+            }
+        }
+        let rates = [
+            ("weekday_commits", self.weekday_commits),
+            ("weekend_commits", self.weekend_commits),
+            ("release_burst", self.release_burst),
+        ];
+        for (name, rate) in rates {
+            if !(0.0..=MAX_DAILY_COMMITS).contains(&rate) {
+                return Err(Error::InvalidData(format!(
+                    "{name} must be between 0 and {MAX_DAILY_COMMITS}, got {rate}"
+                )));
+            }
+        }
+        let busiest_day = self.weekday_commits.max(self.weekend_commits);
+        if !self.releases.is_empty() && busiest_day * self.release_burst > MAX_DAILY_COMMITS {
+            return Err(Error::InvalidData(format!(
+                "Commits during a release crunch must be at most {MAX_DAILY_COMMITS} a day, got {busiest_day} times a release_burst of {}",
+                self.release_burst
+            )));
+        }
+        if let Some(vacation) = self.vacations.iter().find(|v| v.start > v.end) {
+            return Err(Error::InvalidData(format!(
+                "Vacation starting {} ends before it starts",
+                vacation.start
+            )));
+        }
+        Ok(())
+    }
+
+    pub fn parse(content: &str, format: DesignFormat) -> Result<Self> {
+        let profile: Self = match format {
+            DesignFormat::Json => serde_json::from_str(content)
+                .map_err(|e| Error::InvalidData(format!("Invalid activity profile: {e}")))?,
+            DesignFormat::Toml => toml::from_str(content)
+                .map_err(|e| Error::InvalidData(format!("Invalid activity profile: {e}")))?,
+        };
+        profile.validate()?;
+        Ok(profile)
+    }
+
+    pub async fn read(path: impl AsRef<Path>) -> Result<Self> {
+        let content = fs::read_to_string(path).await?;
+        Self::parse(&content, DesignFormat::detect(&content))
+    }
+
+    fn is_day_off(&self, date: NaiveDate) -> bool {
+        self.holidays.iter().any(|holiday| holiday.matches(date))
+            || self
+                .vacations
+                .iter()
+                .any(|vacation| vacation.start <= date && date <= vacation.end)
+    }
+
+    fn is_crunch(&self, date: NaiveDate) -> bool {
+        self.releases.iter().any(|release| {
+            let days_before = (*release - date).num_days();
+            days_before >= 0 && (days_before as usize) < self.release_crunch_days
+        })
+    }
+
+    /// Number of commits of each day from `start_date` to `end_date`
+    pub fn simulate(&self, start_date: NaiveDate, end_date: NaiveDate, seed: u64) -> Vec<usize> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let days = (end_date - start_date).num_days() + 1;
+        let mut active = true;
+
+        (0..days.max(0))
+            .map(|i| {
+                let date = start_date + TimeDelta::days(i);
+                if self.is_day_off(date) {
+                    active = false;
+                    return 0;
+                }
+
+                let crunch = self.is_crunch(date);
+                if !crunch && !rng.random_bool(self.streakiness as f64) {
+                    active = !rng.random_bool(self.idle_chance as f64);
+                }
+                if !active && !crunch {
+                    return 0;
+                }
+                active = true;
+
+                let mut mean = if self.weekend.contains(&date.weekday()) {
+                    self.weekend_commits
+                } else {
+                    self.weekday_commits
+                };
+                if self.ramp_up_weeks > 0 {
+                    let weeks = (i as usize / DAYS_PER_WEEK + 1) as f32;
+                    mean *= (weeks / self.ramp_up_weeks as f32).min(1.0);
+                }
+                if crunch {
+                    mean *= self.release_burst;
+                }
+                poisson(&mut rng, mean)
+            })
+            .collect()
+    }
+}
+
+impl CommitGrid {
+    /// Fill the days from the start date to `end_date` with the activity of a simulated developer.
+    /// The same profile and seed always give the same activity.
+    pub fn simulate(
+        &mut self,
+        profile: &ActivityProfile,
+        end_date: NaiveDate,
+        seed: u64,
+    ) -> Result<()> {
+        if self.get_start_date().gt(&end_date) {
+            return Err(Error::InvalidArg(
+                "end_date must be >= start_date".to_string(),
+            ));
+        }
+        profile.validate()?;
+
+        let commits = profile.simulate(self.get_start_date(), end_date, seed);
+        let commits: Vec<f64> = commits.iter().map(|c| *c as f64).collect();
+        self.set_data(quartile_levels(&commits));
+        Ok(())
+    }
+}
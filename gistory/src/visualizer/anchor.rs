@@ -0,0 +1,143 @@
+use chrono::{Datelike, NaiveDate, TimeDelta};
+use log::warn;
+
+use crate::error::{Error, Result};
+use crate::visualizer::calendar::DAYS_PER_WEEK;
+use crate::visualizer::{CommitCount, CommitGrid};
+
+/// Number of columns GitHub shows on a profile, either for the last year or for a calendar year
+pub const VISIBLE_WEEKS: usize = 53;
+
+/// Where to place a pattern on the GitHub profile.
+/// The first 7 days of the pattern always become the first column, from Sunday to Saturday.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Anchor {
+    /// Start at the first full week of the calendar year
+    Year(i32),
+    /// Start at the given week of the calendar year. Week 0 is the week containing January 1st
+    Week { year: i32, week: usize },
+    /// Center the pattern in the last year view, whose last column contains `today`
+    Center { today: NaiveDate },
+}
+
+struct Window {
+    origin: NaiveDate, // where the first day of the pattern lands
+    first_day: NaiveDate,
+    last_day: NaiveDate,
+}
+
+fn sunday_before(date: NaiveDate) -> NaiveDate {
+    date - TimeDelta::days(date.weekday().num_days_from_sunday() as i64)
+}
+
+fn year_bounds(year: i32) -> Result<(NaiveDate, NaiveDate)> {
+    let first_day = NaiveDate::from_ymd_opt(year, 1, 1);
+    let last_day = NaiveDate::from_ymd_opt(year, 12, 31);
+    match (first_day, last_day) {
+        (Some(first_day), Some(last_day)) => Ok((first_day, last_day)),
+        _ => Err(Error::InvalidArg(format!("Invalid year {year}"))),
+    }
+}
+
+impl Anchor {
+    /// The Sunday of the first column of the visible window
+    pub fn window_start(&self) -> Result<NaiveDate> {
+        match self {
+            Anchor::Year(year) | Anchor::Week { year, .. } => {
+                let (first_day, _) = year_bounds(*year)?;
+                Ok(sunday_before(first_day))
+            }
+            Anchor::Center { today } => {
+                Ok(sunday_before(*today) - TimeDelta::weeks(VISIBLE_WEEKS as i64 - 1))
+            }
+        }
+    }
+
+    /// Number of complete columns between where the pattern starts and the end of the visible window
+    pub fn full_weeks(&self) -> Result<usize> {
+        let window = self.window(VISIBLE_WEEKS)?;
+        let days = (window.last_day - window.origin).num_days() + 1;
+        Ok(days.max(0) as usize / DAYS_PER_WEEK)
+    }
+
+    fn window(&self, pattern_weeks: usize) -> Result<Window> {
+        let window_start = self.window_start()?;
+        match self {
+            Anchor::Year(year) => {
+                let (first_day, last_day) = year_bounds(*year)?;
+                let origin = if first_day == window_start {
+                    first_day
+                } else {
+                    window_start + TimeDelta::weeks(1)
+                };
+                Ok(Window {
+                    origin,
+                    first_day,
+                    last_day,
+                })
+            }
+            Anchor::Week { year, week } => {
+                if *week >= VISIBLE_WEEKS {
+                    return Err(Error::InvalidArg(format!(
+                        "week must be < {VISIBLE_WEEKS}, got {week}"
+                    )));
+                }
+                let (first_day, last_day) = year_bounds(*year)?;
+                Ok(Window {
+                    origin: window_start + TimeDelta::weeks(*week as i64),
+                    first_day,
+                    last_day,
+                })
+            }
+            Anchor::Center { today } => {
+                let offset = VISIBLE_WEEKS.saturating_sub(pattern_weeks) / 2;
+                Ok(Window {
+                    origin: window_start + TimeDelta::weeks(offset as i64),
+                    first_day: window_start,
+                    last_day: *today,
+                })
+            }
+        }
+    }
+}
+
+impl CommitGrid {
+    /// Move the grid to the given anchor. Days falling outside the visible window are dropped.
+    pub fn place(&mut self, anchor: Anchor) -> Result<()> {
+        let data = self.get_data();
+        let pattern_weeks = data.len().div_ceil(DAYS_PER_WEEK);
+        let window = anchor.window(pattern_weeks)?;
+
+        let mut placed = Vec::with_capacity(data.len());
+        let mut dropped = 0;
+        for (i, commit_count) in data.iter().enumerate() {
+            let date = window.origin + TimeDelta::days(i as i64);
+            if date > window.last_day {
+                dropped += data[i..]
+                    .iter()
+                    .filter(|c| **c != CommitCount::Zero)
+                    .count();
+                break;
+            }
+            if date < window.first_day {
+                if *commit_count != CommitCount::Zero {
+                    dropped += 1;
+                }
+                placed.push(CommitCount::Zero);
+            } else {
+                placed.push(*commit_count);
+            }
+        }
+
+        if dropped > 0 {
+            warn!(
+                "{dropped} day(s) of the pattern fall outside {} - {} and were dropped",
+                window.first_day, window.last_day
+            );
+        }
+
+        *self = CommitGrid::new(window.origin);
+        self.set_data(placed);
+        Ok(())
+    }
+}
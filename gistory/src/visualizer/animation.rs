@@ -0,0 +1,117 @@
+use std::io::Cursor;
+use std::path::Path;
+
+use chrono::{Datelike, TimeDelta};
+use image::codecs::gif::GifDecoder;
+use image::codecs::png::PngDecoder;
+use image::{AnimationDecoder, DynamicImage, ImageFormat};
+use log::warn;
+use serde::{Deserialize, Serialize};
+use tokio::fs;
+
+use crate::error::{Error, Result};
+use crate::visualizer::anchor::{Anchor, VISIBLE_WEEKS};
+use crate::visualizer::calendar::DAYS_PER_WEEK;
+use crate::visualizer::composition::BlendMode;
+use crate::visualizer::image_options::ImageOptions;
+use crate::visualizer::{CommitCount, CommitGrid};
+
+/// Where the frames of an animated image are drawn
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, clap::ValueEnum, Deserialize, Serialize)]
+pub enum FrameLayout {
+    /// One frame per calendar year, starting at the year of start_date.
+    /// Clicking through the years on the profile plays the animation.
+    #[default]
+    Years,
+    /// One frame per block of weeks, starting at start_date.
+    /// The block size is `ImageOptions::weeks`, default to 53.
+    Weeks,
+}
+
+fn load_frames(buffer: &[u8]) -> Result<Vec<DynamicImage>> {
+    let to_error = |e: image::ImageError| Error::InvalidData(format!("Failed to load image: {e}"));
+    let frames = match image::guess_format(buffer).map_err(to_error)? {
+        ImageFormat::Gif => GifDecoder::new(Cursor::new(buffer))
+            .map_err(to_error)?
+            .into_frames()
+            .collect_frames()
+            .map_err(to_error)?,
+        ImageFormat::Png => {
+            let decoder = PngDecoder::new(Cursor::new(buffer)).map_err(to_error)?;
+            if !decoder.is_apng().map_err(to_error)? {
+                return Ok(vec![image::load_from_memory(buffer).map_err(to_error)?]);
+            }
+            decoder
+                .apng()
+                .map_err(to_error)?
+                .into_frames()
+                .collect_frames()
+                .map_err(to_error)?
+        }
+        // Still images are animations of a single frame
+        _ => return Ok(vec![image::load_from_memory(buffer).map_err(to_error)?]),
+    };
+    // Frames are already composited over the previous ones by the decoders
+    Ok(frames
+        .into_iter()
+        .map(|frame| DynamicImage::ImageRgba8(frame.into_buffer()))
+        .collect())
+}
+
+impl CommitGrid {
+    /// Draw each frame of an animated GIF or PNG after the previous one, laid out by `layout`.
+    /// Frame timing is ignored.
+    pub async fn read_animation_file(
+        &mut self,
+        path: impl AsRef<Path>,
+        options: &ImageOptions,
+        layout: FrameLayout,
+    ) -> Result<()> {
+        let buffer = fs::read(path).await?;
+        let frames = load_frames(&buffer)?;
+        if frames.is_empty() {
+            return Err(Error::InvalidData("Image has no frame".to_string()));
+        }
+
+        let start_date = self.get_start_date();
+        let mut animation: Option<CommitGrid> = None;
+        for (i, frame) in frames.iter().enumerate() {
+            let mut frame_grid = CommitGrid::new(start_date);
+            frame_grid.set_data(options.convert(frame)?);
+
+            match layout {
+                FrameLayout::Years => {
+                    frame_grid.place(Anchor::Year(start_date.year() + i as i32))?
+                }
+                FrameLayout::Weeks => {
+                    let block = options.weeks.unwrap_or(VISIBLE_WEEKS) * DAYS_PER_WEEK;
+                    let mut data = frame_grid.get_data().to_vec();
+                    if data.len() > block {
+                        let dropped = data[block..]
+                            .iter()
+                            .filter(|c| **c != CommitCount::Zero)
+                            .count();
+                        if dropped > 0 {
+                            warn!(
+                                "{dropped} day(s) of frame {i} go past its block and were dropped"
+                            );
+                        }
+                        data.truncate(block);
+                    }
+                    frame_grid = CommitGrid::new(start_date + TimeDelta::days((i * block) as i64));
+                    frame_grid.set_data(data);
+                }
+            }
+
+            let animation =
+                animation.get_or_insert_with(|| CommitGrid::new(frame_grid.get_start_date()));
+            let offset = (frame_grid.get_start_date() - animation.get_start_date()).num_days();
+            animation.blend(&frame_grid, offset as usize, BlendMode::Overwrite);
+        }
+
+        if let Some(animation) = animation {
+            *self = animation;
+        }
+        Ok(())
+    }
+}
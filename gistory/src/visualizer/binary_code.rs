@@ -0,0 +1,188 @@
+use chrono::{Datelike, Weekday};
+
+use crate::error::{Error, Result};
+use crate::visualizer::anchor::VISIBLE_WEEKS;
+use crate::visualizer::calendar::{Calendar, DAYS_PER_WEEK};
+use crate::visualizer::{CommitCount, CommitGrid};
+
+/// A week with commits every day, which no printable character gives
+const MARKER: u8 = 0x7F;
+
+/// Longest message fitting in the visible weeks of a profile with its two markers
+pub const MAX_MESSAGE_LEN: usize = VISIBLE_WEEKS - 2;
+
+fn is_printable(code: u8) -> bool {
+    (0x20..0x7F).contains(&code)
+}
+
+/// Encode a message of printable ASCII characters, one character per week.
+/// Bit 6 of the character code is the Sunday and bit 0 the Saturday, a day with commits being a 1.
+/// The message is written between two full weeks, so it can be found again in a calendar.
+pub fn encode(message: &str, commit_count: CommitCount) -> Result<Vec<CommitCount>> {
+    if commit_count == CommitCount::Zero {
+        return Err(Error::InvalidArg(
+            "A message can't be written with zero commits".to_string(),
+        ));
+    }
+    if message.is_empty() {
+        return Err(Error::InvalidArg("Message is empty".to_string()));
+    }
+    let invalid: Vec<String> = message
+        .chars()
+        .enumerate()
+        .filter(|(_, ch)| !ch.is_ascii() || !is_printable(*ch as u8))
+        .map(|(i, ch)| format!("{ch:?} at position {}", i + 1))
+        .collect();
+    if !invalid.is_empty() {
+        return Err(Error::InvalidArg(format!(
+            "Message can only contain printable ASCII characters: {}",
+            invalid.join(", ")
+        )));
+    }
+    if message.len() > MAX_MESSAGE_LEN {
+        return Err(Error::InvalidArg(format!(
+            "Message has {} characters, expected at most {MAX_MESSAGE_LEN}",
+            message.len()
+        )));
+    }
+
+    let codes = std::iter::once(MARKER)
+        .chain(message.bytes())
+        .chain(std::iter::once(MARKER));
+    Ok(codes
+        .flat_map(|code| {
+            (0..DAYS_PER_WEEK).map(move |day| {
+                if code & 1 << (DAYS_PER_WEEK - 1 - day) != 0 {
+                    commit_count
+                } else {
+                    CommitCount::Zero
+                }
+            })
+        })
+        .collect())
+}
+
+/// Find the messages written by `encode` in a grid, whatever its start date.
+/// Each week of the calendar is a character, so messages must have been written from a Sunday.
+/// Any commit count other than Zero is a 1.
+pub fn decode(grid: &CommitGrid) -> Vec<String> {
+    let calendar = Calendar::from_grid(grid);
+    let codes: Vec<u8> = (0..calendar.weeks())
+        .map(|week| {
+            (0..DAYS_PER_WEEK).fold(0, |code, day| {
+                let bit = calendar
+                    .get(week, day)
+                    .is_some_and(|commit_count| commit_count != CommitCount::Zero);
+                code << 1 | bit as u8
+            })
+        })
+        .collect();
+
+    let mut messages = Vec::new();
+    let mut week = 0;
+    while week < codes.len() {
+        if codes[week] != MARKER {
+            week += 1;
+            continue;
+        }
+        let length = codes[week + 1..]
+            .iter()
+            .take_while(|code| is_printable(**code))
+            .count();
+        let end = week + 1 + length;
+        if length > 0 && codes.get(end) == Some(&MARKER) {
+            messages.push(
+                codes[week + 1..end]
+                    .iter()
+                    .map(|code| *code as char)
+                    .collect(),
+            );
+            week = end + 1;
+        } else {
+            // the end of something else, or the start of the next message
+            week += 1;
+        }
+    }
+    messages
+}
+
+impl CommitGrid {
+    /// Write a hidden message from the start date, which must be a Sunday for `decode` to find it, see `encode`
+    pub fn show_message(&mut self, message: &str, commit_count: CommitCount) -> Result<()> {
+        let start_date = self.get_start_date();
+        if start_date.weekday() != Weekday::Sun {
+            return Err(Error::InvalidArg(format!(
+                "{start_date} is not a Sunday, a message is written one character per week from a Sunday"
+            )));
+        }
+        self.set_data(encode(message, commit_count)?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    fn grid_at(date: &str) -> CommitGrid {
+        CommitGrid::new(NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap())
+    }
+
+    fn encode_error(message: &str, commit_count: CommitCount) -> String {
+        match encode(message, commit_count) {
+            Err(Error::InvalidArg(message)) => message,
+            other => panic!("expected an InvalidArg error for {message:?}, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn decodes_encoded_messages() {
+        let message = "Hi there! ~{0}";
+        let mut grid = grid_at("2025-01-05");
+        grid.show_message(message, CommitCount::Many).unwrap();
+        assert_eq!(grid.get_data().len(), (message.len() + 2) * DAYS_PER_WEEK);
+        assert_eq!(decode(&grid), [message]);
+
+        // messages separated by other weeks, written with any commit count
+        let mut data = encode("abc", CommitCount::Some).unwrap();
+        data.extend([CommitCount::Zero; DAYS_PER_WEEK]);
+        data.extend(encode("longest message", CommitCount::Few).unwrap());
+        grid.set_data(data);
+        assert_eq!(decode(&grid), ["abc", "longest message"]);
+    }
+
+    #[test]
+    fn rejects_messages_not_starting_on_sunday() {
+        let mut grid = grid_at("2025-01-07");
+        match grid.show_message("Hi", CommitCount::Many) {
+            Err(Error::InvalidArg(message)) => assert_eq!(
+                message,
+                "2025-01-07 is not a Sunday, a message is written one character per week from a Sunday"
+            ),
+            other => panic!("expected an InvalidArg error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn reports_invalid_messages() {
+        assert_eq!(encode_error("", CommitCount::Many), "Message is empty");
+        assert_eq!(
+            encode_error("Hi", CommitCount::Zero),
+            "A message can't be written with zero commits"
+        );
+        assert_eq!(
+            encode_error("caf\u{e9}\n", CommitCount::Many),
+            "Message can only contain printable ASCII characters: '\u{e9}' at position 4, '\\n' at position 5"
+        );
+        assert!(encode(&"x".repeat(MAX_MESSAGE_LEN), CommitCount::Many).is_ok());
+        assert_eq!(
+            encode_error(&"x".repeat(MAX_MESSAGE_LEN + 1), CommitCount::Many),
+            format!(
+                "Message has {} characters, expected at most {MAX_MESSAGE_LEN}",
+                MAX_MESSAGE_LEN + 1
+            )
+        );
+    }
+}
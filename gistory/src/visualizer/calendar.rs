@@ -0,0 +1,75 @@
+use chrono::{Datelike, Month, NaiveDate, TimeDelta};
+
+use crate::visualizer::{CommitCount, CommitGrid};
+
+pub const DAYS_PER_WEEK: usize = 7;
+pub const WEEKDAY_LABELS: [&str; DAYS_PER_WEEK] = ["", "Mon", "", "Wed", "", "Fri", ""];
+
+/// A `CommitGrid` laid out the way GitHub draws its contribution graph:
+/// one column per week starting on Sunday, one row per weekday.
+/// Days outside the grid's range are `None`.
+pub struct Calendar {
+    first_sunday: NaiveDate,
+    cells: Vec<Option<CommitCount>>, // store cells by column. cells[0] is first_sunday, cells[1] is the Monday after, etc
+}
+
+impl Calendar {
+    pub fn from_grid(grid: &CommitGrid) -> Self {
+        let start_date = grid.get_start_date();
+        let offset = start_date.weekday().num_days_from_sunday() as usize;
+        let first_sunday = start_date - TimeDelta::days(offset as i64);
+
+        let data = grid.get_data();
+        let weeks = (offset + data.len()).div_ceil(DAYS_PER_WEEK);
+
+        let mut cells = vec![None; weeks * DAYS_PER_WEEK];
+        for (i, commit_count) in data.iter().enumerate() {
+            cells[offset + i] = Some(*commit_count);
+        }
+
+        Self {
+            first_sunday,
+            cells,
+        }
+    }
+
+    pub fn weeks(&self) -> usize {
+        self.cells.len() / DAYS_PER_WEEK
+    }
+
+    pub fn get(&self, week: usize, weekday: usize) -> Option<CommitCount> {
+        self.cells
+            .get(week * DAYS_PER_WEEK + weekday)
+            .copied()
+            .flatten()
+    }
+
+    pub fn week_start(&self, week: usize) -> NaiveDate {
+        self.first_sunday + TimeDelta::weeks(week as i64)
+    }
+
+    pub fn month_labels(&self, min_gap: usize) -> Vec<(usize, &'static str)> {
+        // like GitHub, a month is labeled above the first week starting in that month,
+        // and a label is dropped when it would collide with the next one
+        let mut labels: Vec<(usize, &'static str)> = Vec::new();
+        let mut previous_month = None;
+
+        for week in 0..self.weeks() {
+            let month = self.week_start(week).month();
+            if previous_month == Some(month) {
+                continue;
+            }
+            previous_month = Some(month);
+
+            if let Some((last_week, _)) = labels.last()
+                && week - last_week < min_gap
+            {
+                labels.pop();
+            }
+            let name = Month::try_from(month as u8).unwrap().name();
+            labels.push((week, &name[..3]));
+        }
+
+        labels
+    }
+}
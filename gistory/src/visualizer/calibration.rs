@@ -0,0 +1,142 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use tokio::fs;
+
+use crate::error::{Error, Result};
+use crate::visualizer::CommitCount;
+
+/// Most commits a level can create on a single day
+pub const MAX_COMMITS_PER_DAY: usize = 1000;
+
+/// Number of commits created for each non-zero `CommitCount` level.
+///
+/// GitHub colours each cell by comparing the day's commit count against the quartiles of the
+/// user's own activity, so the default 1-4 commits only produces the intended shades for
+/// accounts with almost no history. Use `CommitLevels::calibrate` to derive levels from real data.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Deserialize, Serialize)]
+pub struct CommitLevels {
+    pub few: usize,
+    pub some: usize,
+    pub many: usize,
+    pub a_lot: usize,
+}
+
+impl Default for CommitLevels {
+    fn default() -> Self {
+        Self {
+            few: CommitCount::Few.value(),
+            some: CommitCount::Some.value(),
+            many: CommitCount::Many.value(),
+            a_lot: CommitCount::ALot.value(),
+        }
+    }
+}
+
+impl CommitLevels {
+    pub fn new(few: usize, some: usize, many: usize, a_lot: usize) -> Result<Self> {
+        let levels = Self {
+            few,
+            some,
+            many,
+            a_lot,
+        };
+        levels.validate()?;
+        Ok(levels)
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if self.few == 0
+            || self.few >= self.some
+            || self.some >= self.many
+            || self.many >= self.a_lot
+        {
+            return Err(Error::InvalidArg(format!(
+                "Commit levels must be strictly increasing and > 0, got {self:?}"
+            )));
+        }
+        if self.a_lot > MAX_COMMITS_PER_DAY {
+            return Err(Error::InvalidArg(format!(
+                "Commit levels must be at most {MAX_COMMITS_PER_DAY} commits per day, got {self:?}"
+            )));
+        }
+        Ok(())
+    }
+
+    pub fn commits_for(&self, commit_count: CommitCount) -> usize {
+        match commit_count {
+            CommitCount::Zero => 0,
+            CommitCount::Few => self.few,
+            CommitCount::Some => self.some,
+            CommitCount::Many => self.many,
+            CommitCount::ALot => self.a_lot,
+        }
+    }
+
+    pub fn calibrate(daily_counts: &[usize]) -> Result<Self> {
+        // GitHub splits the non-zero daily counts into quartiles and assigns a shade to each one.
+        // To land in the n-th shade, a day must have more commits than the (n-1)-th quartile.
+        // The top level uses the user's maximum so that it never shifts the existing quartiles down,
+        // up to the most commits a level can create.
+        let mut counts: Vec<usize> = daily_counts.iter().copied().filter(|c| *c > 0).collect();
+        if counts.is_empty() {
+            return Err(Error::InvalidData(
+                "Cannot calibrate commit levels without any activity".to_string(),
+            ));
+        }
+        counts.sort_unstable();
+
+        let quartile = |q: usize| counts[(counts.len() - 1) * q / 4];
+        let max = *counts.last().unwrap();
+
+        let few = 1;
+        let some = (quartile(1) + 1).max(few + 1);
+        let many = (quartile(2) + 1).max(some + 1);
+        let a_lot = (quartile(3) + 1)
+            .max(max.min(MAX_COMMITS_PER_DAY))
+            .max(many + 1);
+
+        Self::new(few, some, many, a_lot)
+    }
+
+    pub async fn calibrate_from_file(path: impl AsRef<Path>) -> Result<Self> {
+        // file format: one day per line, the last comma or whitespace separated field is the
+        // commit count of that day, e.g. "12" or "2025-01-05,12". Empty lines are ignored.
+        let content = fs::read_to_string(path).await?;
+        let mut daily_counts = Vec::new();
+
+        for (i, line) in content.lines().enumerate() {
+            let Some(field) = line
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .rfind(|f| !f.is_empty())
+            else {
+                continue;
+            };
+            let count = field.parse::<usize>().map_err(|_| {
+                Error::InvalidData(format!("Invalid commit count {field:?} on line {}", i + 1))
+            })?;
+            daily_counts.push(count);
+        }
+
+        Self::calibrate(&daily_counts)
+    }
+}
+
+impl std::str::FromStr for CommitLevels {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        // format: FEW,SOME,MANY,A_LOT e.g. "1,8,15,25"
+        let values = s
+            .split(',')
+            .map(|v| v.trim().parse::<usize>())
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|e| Error::InvalidArg(format!("Invalid commit levels {s:?}: {e}")))?;
+        let [few, some, many, a_lot] = values[..] else {
+            return Err(Error::InvalidArg(format!(
+                "Commit levels must have exactly 4 values, got {s:?}"
+            )));
+        };
+        Self::new(few, some, many, a_lot)
+    }
+}
@@ -0,0 +1,267 @@
+use std::path::{Path, PathBuf};
+
+use chrono::TimeDelta;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Error, Result};
+use crate::visualizer::anchor::VISIBLE_WEEKS;
+use crate::visualizer::calendar::DAYS_PER_WEEK;
+use crate::visualizer::{CommitCount, CommitGrid, Font, TextOptions, design};
+
+/// Longest layer, and furthest offset of a layer from the start date: 10 years of days
+pub const MAX_LAYER_DAYS: usize = 10 * VISIBLE_WEEKS * DAYS_PER_WEEK;
+
+/// Most designs included in each other through design layers
+pub const MAX_DESIGN_DEPTH: usize = 8;
+
+/// How the cells of a layer are combined with the cells below it
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, clap::ValueEnum, Deserialize, Serialize)]
+pub enum BlendMode {
+    /// Keep the higher commit count
+    #[default]
+    Max,
+    /// Sum the commit counts, clamped at ALot
+    Add,
+    /// Replace the cells below, including with Zero
+    Overwrite,
+    /// Keep the cells below only where the layer is not Zero
+    Mask,
+}
+
+impl BlendMode {
+    pub fn blend(self, below: CommitCount, above: CommitCount) -> CommitCount {
+        match self {
+            BlendMode::Max => CommitCount::ALL[(below as usize).max(above as usize)],
+            BlendMode::Add => {
+                let sum = (below as usize + above as usize).min(CommitCount::ALot as usize);
+                CommitCount::ALL[sum]
+            }
+            BlendMode::Overwrite => above,
+            BlendMode::Mask => {
+                if above == CommitCount::Zero {
+                    CommitCount::Zero
+                } else {
+                    below
+                }
+            }
+        }
+    }
+}
+
+/// Where the cells of a layer come from.
+/// File paths are relative to the directory of the design file.
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+#[serde(tag = "type")]
+pub enum LayerSource {
+    /// Same format as `Design::data`: one string of 0 to 4 per 7 days
+    Data {
+        data: Vec<String>,
+    },
+    Full {
+        commit_count: CommitCount,
+        days: usize,
+    },
+    Random {
+        days: usize,
+        /// Default to a seed derived from the seed of the design
+        #[serde(default)]
+        seed: Option<u64>,
+    },
+    Text {
+        text: String,
+        #[serde(default = "default_font")]
+        font: Font,
+        commit_count: CommitCount,
+        #[serde(default, flatten)]
+        options: TextOptions,
+    },
+    /// The outline of a rectangle spanning all 7 days of `weeks` weeks
+    Frame {
+        weeks: usize,
+        commit_count: CommitCount,
+    },
+    PatternFile {
+        path: PathBuf,
+        /// Read the original format, see `PatternFile`
+        #[serde(default)]
+        lenient: bool,
+    },
+    Image {
+        path: PathBuf,
+    },
+    Design {
+        path: PathBuf,
+    },
+}
+
+fn default_font() -> Font {
+    Font::SubwayTracker
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+pub struct Layer {
+    pub source: LayerSource,
+    /// Offset from the start date of the composition
+    #[serde(default)]
+    pub week: usize,
+    #[serde(default)]
+    pub day: usize,
+    #[serde(default)]
+    pub mode: BlendMode,
+}
+
+impl Layer {
+    pub fn offset(&self) -> usize {
+        self.week * DAYS_PER_WEEK + self.day
+    }
+
+    /// Check that the layer stays within `MAX_LAYER_DAYS`, since designs can come from anywhere
+    pub fn validate(&self) -> Result<()> {
+        let too_long = |name: &str, days: Option<usize>| match days {
+            Some(days) if days <= MAX_LAYER_DAYS => Ok(()),
+            _ => Err(Error::InvalidData(format!(
+                "Layer {name} is too large, layers are limited to {MAX_LAYER_DAYS} days"
+            ))),
+        };
+
+        let offset = self
+            .week
+            .checked_mul(DAYS_PER_WEEK)
+            .and_then(|days| days.checked_add(self.day));
+        too_long("offset", offset)?;
+        match &self.source {
+            LayerSource::Full { days, .. } | LayerSource::Random { days, .. } => {
+                too_long("days", Some(*days))
+            }
+            LayerSource::Frame { weeks, .. } => too_long("weeks", weeks.checked_mul(DAYS_PER_WEEK)),
+            LayerSource::Text { options, .. } => {
+                options.validate()?;
+                let columns = [
+                    Some(options.letter_spacing),
+                    options.word_spacing,
+                    Some(options.leading),
+                    options.width,
+                ];
+                columns.into_iter().flatten().try_for_each(|weeks| {
+                    too_long("text spacing", weeks.checked_mul(DAYS_PER_WEEK))
+                })
+            }
+            LayerSource::Data { .. }
+            | LayerSource::PatternFile { .. }
+            | LayerSource::Image { .. }
+            | LayerSource::Design { .. } => Ok(()),
+        }
+    }
+}
+
+fn resolve(path: &Path, base_dir: Option<&Path>) -> Result<PathBuf> {
+    match base_dir {
+        Some(base_dir) => Ok(base_dir.join(path)),
+        None => Err(Error::InvalidData(format!(
+            "Layer file {} is not allowed here",
+            path.display()
+        ))),
+    }
+}
+
+impl LayerSource {
+    /// Create the grid of this layer. Files are only allowed when `base_dir` is given.
+    /// `parents` are the canonical paths of the designs being loaded, see `CommitGrid::load_nested`
+    pub async fn to_grid(
+        &self,
+        start_date: chrono::NaiveDate,
+        base_dir: Option<&Path>,
+        seed: u64,
+        parents: &[PathBuf],
+    ) -> Result<CommitGrid> {
+        let mut grid = CommitGrid::new(start_date);
+        let end_date = |days: usize| {
+            start_date
+                .checked_add_signed(TimeDelta::days(days.max(1) as i64 - 1))
+                .ok_or_else(|| {
+                    Error::InvalidData(format!(
+                        "Layer of {days} days starting {start_date} is out of range"
+                    ))
+                })
+        };
+
+        match self {
+            LayerSource::Data { data } => grid.set_data(design::parse_data(data)?),
+            LayerSource::Full { commit_count, days } => {
+                grid.full(*commit_count, end_date(*days)?)?
+            }
+            LayerSource::Random {
+                days,
+                seed: layer_seed,
+            } => grid.random_with_seed(end_date(*days)?, layer_seed.unwrap_or(seed))?,
+            LayerSource::Text {
+                text,
+                font,
+                commit_count,
+                options,
+            } => grid.show_text_with_options(text, *font, *commit_count, options)?,
+            LayerSource::Frame {
+                weeks,
+                commit_count,
+            } => {
+                let mut data = vec![CommitCount::Zero; weeks * DAYS_PER_WEEK];
+                for (i, cell) in data.iter_mut().enumerate() {
+                    let (week, day) = (i / DAYS_PER_WEEK, i % DAYS_PER_WEEK);
+                    if week == 0 || week + 1 == *weeks || day == 0 || day + 1 == DAYS_PER_WEEK {
+                        *cell = *commit_count;
+                    }
+                }
+                grid.set_data(data);
+            }
+            LayerSource::PatternFile { path, lenient } => {
+                grid.read_pattern_file(resolve(path, base_dir)?, *lenient)
+                    .await?
+            }
+            LayerSource::Image { path } => grid.read_image_file(resolve(path, base_dir)?).await?,
+            LayerSource::Design { path } => {
+                let path = resolve(path, base_dir)?;
+                grid = Box::pin(CommitGrid::load_nested(&path, seed, parents)).await?;
+            }
+        }
+
+        Ok(grid)
+    }
+}
+
+impl CommitGrid {
+    /// Blend the data of `layer` into this grid, starting `offset` days after start_date.
+    /// The grid is extended with Zero if the layer goes past its end.
+    pub fn blend(&mut self, layer: &CommitGrid, offset: usize, mode: BlendMode) {
+        let mut data = self.get_data().to_vec();
+        let layer_data = layer.get_data();
+        if data.len() < offset + layer_data.len() {
+            data.resize(offset + layer_data.len(), CommitCount::Zero);
+        }
+        for (i, above) in layer_data.iter().enumerate() {
+            data[offset + i] = mode.blend(data[offset + i], *above);
+        }
+        self.set_data(data);
+    }
+
+    /// Blend the given layers into this grid, from bottom to top.
+    /// Each layer gets its own seed derived from `seed`.
+    /// `parents` are the canonical paths of the designs being loaded, see `CommitGrid::load_nested`
+    pub async fn compose(
+        &mut self,
+        layers: &[Layer],
+        base_dir: Option<&Path>,
+        seed: u64,
+        parents: &[PathBuf],
+    ) -> Result<()> {
+        for (i, layer) in layers.iter().enumerate() {
+            layer.validate()?;
+            let layer_seed = seed.wrapping_add(i as u64);
+            let layer_grid = layer
+                .source
+                .to_grid(self.get_start_date(), base_dir, layer_seed, parents)
+                .await?;
+            self.blend(&layer_grid, layer.offset(), layer.mode);
+        }
+        Ok(())
+    }
+}
@@ -1,45 +1,416 @@
-def convert_font_file(input_file="font.txt", output_file="font_output.txt"):
-    try:
-        with open(input_file, 'r') as f:
-            content = f.read()
+"""Generate the built-in font tables from their glyph files.
 
-        # Split the content into character blocks
-        # Assuming each character block is separated by blank lines
-        blocks = [block.strip() for block in content.split('\n\n') if block.strip()]
+    python3 convert_font.py
 
-        output = []
+Glyph files are blocks separated by blank lines: the character, or its code point like U+00A0
+for characters that can't be seen, followed by its 7 rows of `x` and `.`:
 
-        for block in blocks:
-            lines = block.strip().split('\n')
+    A
+    .xxx.
+    x...x
+    x...x
+    xxxxx
+    x...x
+    x...x
+    x...x
 
-            # Assuming the first line contains the character
-            # For example: "A:" or just "A"
-            char_line = lines[0].strip()
+- font.txt is Subway Tracker. Its letters with accents (Latin-1, Latin Extended-A/B and
+  Latin Extended Additional) are made of the base letter and the accent, see `compose`.
+- font_compact.txt is Compact. Lowercase letters are drawn as uppercase, accented letters
+  are made like Subway Tracker ones, with a single row for the accent.
+- font_icons.txt is Icons.
+- Bold and Monospace are made from Subway Tracker.
 
-            # Try to get the character, assuming it's the first non-whitespace character
-            char = char_line[0]
-            if char in ("'", "\\"):
-                char = f"\\{char}"
+font_fallback.rs, the similar characters of missing ones and the compositions of letters with
+combining accents, is generated from the Unicode database of Python.
+"""
 
-            # Get the data lines (skip the first line which contains the character)
-            data_lines = [line.strip() for line in lines[1:] if line.strip()]
+import os
+import unicodedata
 
-            # Format the output in Rust format
-            rust_block = f"b'{char}' => Char {{\n    data: [\n"
-            for data_line in data_lines:
-                rust_block += f'        "{data_line}",\n'
-            rust_block += "    ],\n},"
+DIR = os.path.dirname(os.path.abspath(__file__))
+HEIGHT = 7
+REPLACEMENT_CHAR = '\ufffd'
+NBSP = '\u00a0'
+SOFT_HYPHEN = '\u00ad'
 
-            output.append(rust_block)
+# letters with accents are looked up in these ranges
+ACCENTED_RANGES = list(range(0xC0, 0x250)) + list(range(0x1E00, 0x1F00))
 
-        # Write the output to a file
-        with open(output_file, 'w') as f:
-            f.write('\n\n'.join(output))
 
-        print(f"Conversion completed. Output written to {output_file}")
+# ---------------------------------------------------------------- glyph files
 
-    except FileNotFoundError:
-        print(f"Error: File '{input_file}' not found.")
 
-if __name__ == "__main__":
-    convert_font_file()
\ No newline at end of file
+def read_glyphs(name):
+    with open(os.path.join(DIR, name), encoding='utf-8') as f:
+        content = f.read()
+
+    glyphs = {}
+    for block in content.split('\n\n'):
+        lines = [line.strip() for line in block.split('\n') if line.strip()]
+        if not lines:
+            continue
+        char = lines[0]
+        if char.startswith('U+') and len(char) > 2:
+            char = chr(int(char[2:], 16))
+        if len(char) != 1:
+            raise ValueError(f'{name}: invalid character line {lines[0]!r}')
+        rows = lines[1:]
+        check_glyph(name, char, rows)
+        glyphs[char] = rows
+    return glyphs
+
+
+def check_glyph(name, char, rows):
+    if len(rows) != HEIGHT:
+        raise ValueError(f'{name}: {char!r} has {len(rows)} rows, expected {HEIGHT}')
+    if any(len(row) != len(rows[0]) for row in rows):
+        raise ValueError(f'{name}: {char!r} has rows of different widths')
+    if any(set(row) - set('x.') for row in rows):
+        raise ValueError(f'{name}: {char!r} has characters other than x and .')
+
+
+# ---------------------------------------------------------------- rust output
+
+
+def rust_char(c):
+    if c == '\\':
+        return "'\\\\'"
+    if c == "'":
+        return "'\\''"
+    category = unicodedata.category(c)
+    if (category.startswith('Z') and c != ' ') or category.startswith('C') or c == REPLACEMENT_CHAR:
+        return "'\\u{%x}'" % ord(c)
+    return "'%s'" % c
+
+
+def rust_str(s):
+    return '"' + ''.join('\\u{%x}' % ord(c) if unicodedata.combining(c) else c for c in s) + '"'
+
+
+def order_key(c):
+    # ASCII first, like the original tables
+    return (0 if c < '\x80' else 1, ord(c))
+
+
+def write_font(name, glyphs):
+    output = [
+        'use phf::phf_map;',
+        '',
+        'use crate::visualizer::font::Char;',
+        '',
+        'pub const CHARS_MAPPING: phf::Map<char, Char> = phf_map! {',
+        '',
+    ]
+    for char in sorted(glyphs, key=order_key):
+        rows = glyphs[char]
+        check_glyph(name, char, rows)
+        output.append('%s => Char {' % rust_char(char))
+        output.append('    data: [')
+        output.extend('        "%s",' % row for row in rows)
+        output.append('    ],')
+        output.append('},')
+        output.append('')
+    output[-1] = '};'
+
+    with open(os.path.join(DIR, name), 'w', encoding='utf-8') as f:
+        f.write('\n'.join(output) + '\n')
+    print(f'{name}: {len(glyphs)} glyphs')
+
+
+# ---------------------------------------------------------------- accents
+
+
+def center(pattern, width):
+    w = len(pattern[0])
+    if w >= width:
+        return pattern
+    left = (width - w) // 2
+    return ['.' * left + row + '.' * (width - w - left) for row in pattern]
+
+
+def diaeresis(width):
+    if width <= 2:
+        return ['x.x']
+    if width % 2:
+        return ['x.x'] if width <= 4 else ['.x.x.']
+    return ['x..x']
+
+
+# marks above, by combining character: (2-row pattern or None, 1-row pattern)
+TOP = {
+    '\u0300': (['x.', '.x'], ['x.']),  # grave
+    '\u0301': (['.x', 'x.'], ['.x']),  # acute
+    '\u0302': (['.x.', 'x.x'], ['x.x']),  # circumflex
+    '\u0303': (['.x.x', 'x.x.'], ['xx.x']),  # tilde
+    '\u0304': (None, ['xxx']),  # macron
+    '\u0306': (['x..x', '.xx.'], ['x..x']),  # breve
+    '\u0307': (None, ['x']),  # dot above
+    '\u0308': (None, None),  # diaeresis, depends on the width, see `diaeresis`
+    '\u030a': (['xx', 'xx'], ['xx']),  # ring above
+    '\u030b': (['.x.x', 'x.x.'], ['x.x']),  # double acute
+    '\u030c': (['x.x', '.x.'], ['x.x']),  # caron
+}
+# marks below, by combining character
+BOTTOM = {
+    '\u0323': ['x'],  # dot below
+    '\u0326': ['x'],  # comma below
+    '\u0327': ['.x', 'x.'],  # cedilla
+    '\u0328': ['x.', '.x'],  # ogonek
+    '\u0331': ['xxx'],  # macron below
+}
+# 1-row marks below, when there is no room for 2 rows
+BOTTOM_SMALL = {'\u0327': ['x'], '\u0328': ['.x']}
+
+
+def is_blank(row):
+    return 'x' not in row
+
+
+def hamming(a, b):
+    return sum(1 for x, y in zip(a, b) if x != y)
+
+
+def shrink(body, height, lossy):
+    """Remove duplicate adjacent rows until body fits in height rows. None if impossible.
+    When lossy, remove the inner row closest to one of its neighbours if there is no duplicate."""
+    body = list(body)
+    if height < 3 and len(body) > height:
+        return None
+    while len(body) > height:
+        runs = [i for i in range(len(body) - 1) if body[i] == body[i + 1] and not is_blank(body[i])]
+        if not runs and lossy:
+            inner = range(1, len(body) - 1)
+            closest = min(
+                inner,
+                key=lambda i: min(hamming(body[i], body[i - 1]), hamming(body[i], body[i + 1])),
+            )
+            del body[closest]
+            continue
+        if not runs:
+            return None
+        # remove from the longest run first
+        longest = max(runs, key=lambda i: sum(1 for row in body if row == body[i]))
+        del body[longest]
+    return body
+
+
+def compose(rows, mark, compact):
+    """Glyph of the letter `rows` with the accent `mark`, squeezing the letter to make room.
+    The letter is only squeezed by removing duplicate rows if possible, and any row otherwise.
+    None if the accent isn't supported or doesn't fit."""
+    return compose_with(rows, mark, compact, False) or compose_with(rows, mark, compact, True)
+
+
+def compose_with(rows, mark, compact, lossy):
+    width = len(rows[0])
+    top = next(i for i, row in enumerate(rows) if not is_blank(row))
+    bottom = max(i for i, row in enumerate(rows) if not is_blank(row))
+    body = rows[top:bottom + 1]
+
+    if mark in TOP:
+        two_rows, one_row = TOP[mark]
+        if mark == '\u0308':
+            two_rows, one_row = None, diaeresis(width)
+        if mark == '\u0303' and width < 4:
+            one_row = ['xx.']
+        # (pattern, blank rows between the accent and the letter), from the most to the least readable
+        options = [] if compact or lossy else [(two_rows, 1), (two_rows, 0), (one_row, 1)]
+        options += [(one_row, 0)]
+        for pattern, gap in options:
+            if pattern is None:
+                continue
+            fitted = shrink(body, bottom + 1 - len(pattern) - gap, lossy)
+            if fitted is None:
+                continue
+            w = max(width, len(pattern[0]))
+            stack = center(pattern, w) + ['.' * w] * gap + center(fitted, w)
+            # the letter keeps its baseline
+            return ['.' * w] * (bottom + 1 - len(stack)) + stack + ['.' * w] * (HEIGHT - 1 - bottom)
+        return None
+
+    if mark in BOTTOM:
+        options = [] if compact or lossy else [BOTTOM[mark]]
+        options += [BOTTOM_SMALL.get(mark, BOTTOM[mark][:1])]
+        for pattern in options:
+            fitted = shrink(body, HEIGHT - len(pattern), lossy)
+            if fitted is None:
+                continue
+            w = max(width, len(pattern[0]))
+            if mark == '\u0328':  # the ogonek hangs on the right
+                pattern = ['.' * (w - len(row)) + row for row in pattern]
+            else:
+                pattern = center(pattern, w)
+            stack = center(fitted, w) + pattern
+            # the letter keeps its top row if there is room below it, otherwise it moves up
+            start = min(top, HEIGHT - len(stack))
+            return ['.' * w] * start + stack + ['.' * w] * (HEIGHT - start - len(stack))
+    return None
+
+
+def decompose(c):
+    decomposition = unicodedata.decomposition(c)
+    if not decomposition or decomposition.startswith('<'):
+        return None
+    return [chr(int(code, 16)) for code in decomposition.split()]
+
+
+def full_decompose(c):
+    parts = decompose(c)
+    if parts is None:
+        return [c]
+    return full_decompose(parts[0]) + parts[1:]
+
+
+def add_accented(glyphs, compact, dotless):
+    """Add the letters made of a glyph and a single accent. `dotless` are the letters
+    drawn instead of i and j under an accent above"""
+    for code in ACCENTED_RANGES:
+        c = chr(code)
+        if c in glyphs:
+            continue
+        parts = full_decompose(c)
+        if len(parts) != 2:
+            continue
+        base, mark = parts
+        if mark in TOP and base in dotless:
+            base = dotless[base]
+        if base not in glyphs:
+            continue
+        composed = compose(glyphs[base], mark, compact)
+        if composed is not None:
+            glyphs[c] = composed
+
+
+# ---------------------------------------------------------------- fonts
+
+
+def subway_tracker():
+    glyphs = read_glyphs('font.txt')
+    add_accented(glyphs, False, {'i': 'ı', 'j': 'ȷ'})
+    return glyphs
+
+
+def bold(subway):
+    """Subway Tracker overlaid with itself shifted one column right"""
+    glyphs = {}
+    for c, rows in subway.items():
+        if c in (' ', NBSP):
+            glyphs[c] = ['..'] * HEIGHT
+            continue
+        glyphs[c] = [
+            ''.join(
+                'x' if (i < len(row) and row[i] == 'x') or (i > 0 and row[i - 1] == 'x') else '.'
+                for i in range(len(row) + 1)
+            )
+            for row in rows
+        ]
+    return glyphs
+
+
+MONOSPACE_WIDTH = 5
+# characters of Subway Tracker too wide for Monospace, but worth redrawing
+MONOSPACE_REDESIGNS = {
+    '%': ['xx...', 'xx..x', '...x.', '..x..', '.x...', 'x..xx', '...xx'],
+    '~': ['.....', '.....', '.x...', 'x.x.x', '...x.', '.....', '.....'],
+}
+
+
+def monospace(subway):
+    """Subway Tracker centered in 5 columns. Wider characters are left out, and drawn with the fallback"""
+    glyphs = {}
+    for c, rows in subway.items():
+        if c in MONOSPACE_REDESIGNS:
+            glyphs[c] = MONOSPACE_REDESIGNS[c]
+            continue
+        w = len(rows[0])
+        if w > MONOSPACE_WIDTH:
+            continue
+        left = (MONOSPACE_WIDTH - w) // 2
+        glyphs[c] = ['.' * left + row + '.' * (MONOSPACE_WIDTH - w - left) for row in rows]
+    return glyphs
+
+
+def compact():
+    glyphs = read_glyphs('font_compact.txt')
+    for c in 'abcdefghijklmnopqrstuvwxyz':
+        glyphs[c] = glyphs[c.upper()]
+    add_accented(glyphs, True, {})
+    return glyphs
+
+
+# ---------------------------------------------------------------- fallback
+
+# similar characters that the Unicode decompositions don't give
+SIMILAR_CHARS = {
+    'Æ': 'A', 'æ': 'a', 'Ø': 'O', 'ø': 'o', 'ß': 's', 'Ð': 'D', 'ð': 'd', 'Þ': 'P', 'þ': 'p',
+    'Œ': 'O', 'œ': 'o', 'Ł': 'L', 'ł': 'l', 'Đ': 'D', 'đ': 'd', 'Ħ': 'H', 'ħ': 'h', 'ı': 'i',
+    'ȷ': 'j', 'ĸ': 'k', 'Ŋ': 'N', 'ŋ': 'n', 'Ŧ': 'T', 'ŧ': 't', 'ſ': 's', 'Ĳ': 'I', 'ĳ': 'i',
+    'Ŀ': 'L', 'ŀ': 'l', 'ŉ': 'n', '¡': '!', '¿': '?', '«': '<', '»': '>', '×': 'x', '÷': '/',
+    '·': '.', '¦': '|', '¬': '-', 'µ': 'u', '¢': 'c', '£': 'L', '¥': 'Y', '©': 'C', '®': 'R',
+    '°': 'o', '±': '+', '§': 'S', '¶': 'P', '¤': 'o', '¨': '"', '´': "'", '¸': ',', '¯': '-',
+    NBSP: ' ', SOFT_HYPHEN: '-',
+    '‐': '-', '‑': '-', '‒': '-', '–': '-', '—': '-', '―': '-',
+    '‘': "'", '’': "'", '‚': ',', '‛': "'", '“': '"', '”': '"',
+    '„': '"', '‟': '"', '•': '·', '…': '.', '′': "'", '″': '"',
+    '‹': '<', '›': '>', '€': 'E', '™': 'T',
+}
+
+
+def write_fallback():
+    similar = {}
+    for code in list(range(0xA0, 0x250)) + list(range(0x1E00, 0x1F00)):
+        c = chr(code)
+        if c in SIMILAR_CHARS:
+            continue
+        decomposition = unicodedata.decomposition(c)
+        if not decomposition:
+            continue
+        if decomposition.startswith('<'):
+            parts = decomposition.split()[1:]
+            if len(parts) != 1:
+                continue
+            target = chr(int(parts[0], 16))
+        else:
+            target = chr(int(decomposition.split()[0], 16))
+        if target != c and not unicodedata.combining(target):
+            similar[c] = target
+    similar.update(SIMILAR_CHARS)
+
+    compositions = {}
+    for code in ACCENTED_RANGES:
+        c = chr(code)
+        parts = decompose(c)
+        if parts and len(parts) == 2 and unicodedata.combining(parts[1]):
+            compositions[parts[0] + parts[1]] = c
+
+    output = [
+        'use phf::phf_map;',
+        '',
+        '/// Similar character to draw when a character is missing from a font,',
+        '/// usually the same letter without accent',
+        'pub const SIMILAR_CHARS: phf::Map<char, char> = phf_map! {',
+    ]
+    for c in sorted(similar, key=ord):
+        output.append('    %s => %s,' % (rust_char(c), rust_char(similar[c])))
+    output.append('};')
+    output.append('')
+    output.append('/// Characters made of a letter followed by a combining accent')
+    output.append("pub const COMPOSITIONS: phf::Map<&'static str, char> = phf_map! {")
+    for key in sorted(compositions, key=lambda key: ord(compositions[key])):
+        output.append('    %s => %s,' % (rust_str(key), rust_char(compositions[key])))
+    output.append('};')
+
+    with open(os.path.join(DIR, 'font_fallback.rs'), 'w', encoding='utf-8') as f:
+        f.write('\n'.join(output) + '\n')
+    print(f'font_fallback.rs: {len(similar)} similar characters, {len(compositions)} compositions')
+
+
+if __name__ == '__main__':
+    subway = subway_tracker()
+    write_font('font_subway_tracker.rs', subway)
+    write_font('font_bold.rs', bold(subway))
+    write_font('font_monospace.rs', monospace(subway))
+    write_font('font_compact.rs', compact())
+    write_font('font_icons.rs', read_glyphs('font_icons.txt'))
+    write_fallback()
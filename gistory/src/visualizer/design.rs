@@ -0,0 +1,256 @@
+use std::path::{Path, PathBuf};
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use tokio::fs;
+
+use crate::error::{Error, Result};
+use crate::visualizer::calendar::DAYS_PER_WEEK;
+use crate::visualizer::composition::{Layer, LayerSource, MAX_DESIGN_DEPTH};
+use crate::visualizer::{CommitCount, CommitGrid};
+
+// Version 2 adds layers
+pub const DESIGN_VERSION: u32 = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DesignFormat {
+    Json,
+    Toml,
+}
+
+impl DesignFormat {
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self> {
+        let extension = path
+            .as_ref()
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext.to_ascii_lowercase());
+        match extension.as_deref() {
+            Some("json") => Ok(DesignFormat::Json),
+            Some("toml") => Ok(DesignFormat::Toml),
+            _ => Err(Error::InvalidArg(
+                "Design file must have extension .json or .toml".to_string(),
+            )),
+        }
+    }
+
+    pub fn detect(content: &str) -> Self {
+        // a JSON design is always an object, while a TOML design starts with a key or a comment
+        if content.trim_start().starts_with('{') {
+            DesignFormat::Json
+        } else {
+            DesignFormat::Toml
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default, Deserialize, Serialize)]
+pub struct DesignMetadata {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl DesignMetadata {
+    pub fn is_empty(&self) -> bool {
+        self.title.is_none() && self.author.is_none() && self.description.is_none()
+    }
+}
+
+/// A portable, versioned representation of a `CommitGrid`.
+///
+/// `data` holds one string per 7 days starting at `start_date`,
+/// each character being a commit count from 0 to 4.
+/// `layers` are then blended on top of `data`, from bottom to top, e.g.
+///
+/// ```json
+/// {
+///   "version": 2,
+///   "start_date": "2025-01-05",
+///   "metadata": { "title": "Hello" },
+///   "data": ["0344430", "0003000", "0344430"],
+///   "layers": [
+///     { "source": { "type": "Text", "text": "HI", "commit_count": "ALot" }, "week": 4 }
+///   ]
+/// }
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize)]
+pub struct Design {
+    pub version: u32,
+    pub start_date: NaiveDate,
+    #[serde(default, skip_serializing_if = "DesignMetadata::is_empty")]
+    pub metadata: DesignMetadata,
+    #[serde(default)]
+    pub data: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub layers: Vec<Layer>,
+}
+
+/// Commit counts of `data`, one string per 7 days. Only the last string can be shorter
+pub fn parse_data(data: &[String]) -> Result<Vec<CommitCount>> {
+    let mut commit_counts = Vec::with_capacity(data.len() * DAYS_PER_WEEK);
+    for (i, days) in data.iter().enumerate() {
+        let length = days.chars().count();
+        let is_last = i + 1 == data.len();
+        if length > DAYS_PER_WEEK || (length < DAYS_PER_WEEK && !is_last) {
+            return Err(Error::InvalidData(format!(
+                "data[{i}] has {length} days, expected {DAYS_PER_WEEK}{}",
+                if is_last { " or less" } else { "" }
+            )));
+        }
+        for (j, ch) in days.chars().enumerate() {
+            let Some(commit_count) = CommitCount::from_digit(ch) else {
+                return Err(Error::InvalidData(format!(
+                    "Invalid commit count {ch:?} at data[{i}][{j}], expected 0 to 4"
+                )));
+            };
+            commit_counts.push(commit_count);
+        }
+    }
+    Ok(commit_counts)
+}
+
+impl Design {
+    pub fn from_grid(grid: &CommitGrid, metadata: DesignMetadata) -> Self {
+        let data = grid
+            .get_data()
+            .chunks(DAYS_PER_WEEK)
+            .map(|days| days.iter().map(|c| c.to_digit()).collect())
+            .collect();
+        Self {
+            version: DESIGN_VERSION,
+            start_date: grid.get_start_date(),
+            metadata,
+            data,
+            layers: vec![],
+        }
+    }
+
+    /// Whether the grid of this design depends on the seed given to `to_grid`: random layers without
+    /// their own seed, in this design or in the designs it includes, whose paths are relative to `base_dir`
+    pub async fn has_random_layers(&self, base_dir: &Path) -> Result<bool> {
+        self.has_nested_random_layers(base_dir, 0).await
+    }
+
+    async fn has_nested_random_layers(&self, base_dir: &Path, depth: usize) -> Result<bool> {
+        for layer in &self.layers {
+            match &layer.source {
+                LayerSource::Random { seed: None, .. } => return Ok(true),
+                // deeper designs fail to load, see `CommitGrid::load_nested`
+                LayerSource::Design { path } if depth < MAX_DESIGN_DEPTH => {
+                    let path = base_dir.join(path);
+                    let design = Design::read(&path).await?;
+                    let base_dir = path.parent().unwrap_or(Path::new("."));
+                    if Box::pin(design.has_nested_random_layers(base_dir, depth + 1)).await? {
+                        return Ok(true);
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(false)
+    }
+
+    /// Create the grid of this design. Layers reading files are only allowed when `base_dir` is given,
+    /// and their paths are relative to it. Random layers without their own seed are derived from `seed`.
+    /// `parents` are the canonical paths of the designs including this one, see `CommitGrid::load_nested`
+    pub async fn to_grid(
+        &self,
+        base_dir: Option<&Path>,
+        seed: u64,
+        parents: &[PathBuf],
+    ) -> Result<CommitGrid> {
+        if self.version > DESIGN_VERSION {
+            return Err(Error::InvalidData(format!(
+                "Unsupported design version {}, latest supported version is {DESIGN_VERSION}",
+                self.version
+            )));
+        }
+
+        let mut grid = CommitGrid::new(self.start_date);
+        grid.set_data(parse_data(&self.data)?);
+        grid.compose(&self.layers, base_dir, seed, parents).await?;
+        Ok(grid)
+    }
+
+    pub fn parse(content: &str, format: DesignFormat) -> Result<Self> {
+        let design: Self = match format {
+            DesignFormat::Json => serde_json::from_str(content)
+                .map_err(|e| Error::InvalidData(format!("Invalid design file: {e}")))?,
+            DesignFormat::Toml => toml::from_str(content)
+                .map_err(|e| Error::InvalidData(format!("Invalid design file: {e}")))?,
+        };
+        design.layers.iter().try_for_each(Layer::validate)?;
+        Ok(design)
+    }
+
+    pub fn serialize(&self, format: DesignFormat) -> Result<String> {
+        match format {
+            DesignFormat::Json => serde_json::to_string_pretty(self)
+                .map(|json| json + "\n")
+                .map_err(|e| Error::InvalidData(format!("Cannot serialize design: {e}"))),
+            DesignFormat::Toml => toml::to_string_pretty(self)
+                .map_err(|e| Error::InvalidData(format!("Cannot serialize design: {e}"))),
+        }
+    }
+
+    pub async fn read(path: impl AsRef<Path>) -> Result<Self> {
+        let format = DesignFormat::from_path(&path)?;
+        let content = fs::read_to_string(path).await?;
+        Self::parse(&content, format)
+    }
+
+    pub async fn write(&self, path: impl AsRef<Path>) -> Result<()> {
+        let format = DesignFormat::from_path(&path)?;
+        let content = self.serialize(format)?;
+        fs::write(path, content).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data(weeks: &[&str]) -> Vec<String> {
+        weeks.iter().map(|days| days.to_string()).collect()
+    }
+
+    fn parse_error(weeks: &[&str]) -> String {
+        match parse_data(&data(weeks)) {
+            Err(Error::InvalidData(message)) => message,
+            other => panic!("expected an InvalidData error for {weeks:?}, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parses_weeks_and_a_shorter_last_week() {
+        let commit_counts = parse_data(&data(&["0123401", "43"])).unwrap();
+        let digits: String = commit_counts.iter().map(|c| c.to_digit()).collect();
+        assert_eq!(digits, "012340143");
+        assert!(parse_data(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn reports_weeks_of_the_wrong_length() {
+        assert_eq!(
+            parse_error(&["012340", "0000000"]),
+            "data[0] has 6 days, expected 7"
+        );
+        assert_eq!(
+            parse_error(&["0000000", "01234012", "0"]),
+            "data[1] has 8 days, expected 7"
+        );
+        assert_eq!(
+            parse_error(&["0000000", "01234012"]),
+            "data[1] has 8 days, expected 7 or less"
+        );
+        assert_eq!(
+            parse_error(&["01x3401"]),
+            "Invalid commit count 'x' at data[0][2], expected 0 to 4"
+        );
+    }
+}
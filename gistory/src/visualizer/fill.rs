@@ -0,0 +1,286 @@
+use std::str::FromStr;
+
+use chrono::{Datelike, NaiveDate, TimeDelta, Weekday};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Error, Result};
+use crate::visualizer::{CommitCount, CommitGrid};
+
+/// Relative chance of each commit count in random fills, from Zero to ALot.
+/// Format: 5 numbers, e.g. 50,30,10,5,5
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Weights([u32; 5]);
+
+impl Default for Weights {
+    fn default() -> Self {
+        Weights([1; 5])
+    }
+}
+
+impl FromStr for Weights {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let values = s
+            .split(',')
+            .map(|v| v.trim().parse::<u32>())
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|e| Error::InvalidArg(format!("Invalid weights {s:?}: {e}")))?;
+        let Ok(weights) = <[u32; 5]>::try_from(values) else {
+            return Err(Error::InvalidArg(format!(
+                "Weights must have exactly 5 values, got {s:?}"
+            )));
+        };
+        if weights.iter().all(|w| *w == 0) {
+            return Err(Error::InvalidArg(format!(
+                "At least one weight must be > 0, got {s:?}"
+            )));
+        }
+        // pick draws a number below the total
+        if weights
+            .iter()
+            .try_fold(0u32, |total, w| total.checked_add(*w))
+            .is_none()
+        {
+            return Err(Error::InvalidArg(format!(
+                "Weights must add up to at most {}, got {s:?}",
+                u32::MAX
+            )));
+        }
+        Ok(Weights(weights))
+    }
+}
+
+impl TryFrom<String> for Weights {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self> {
+        value.parse()
+    }
+}
+
+impl From<Weights> for String {
+    fn from(value: Weights) -> Self {
+        value.0.map(|w| w.to_string()).join(",")
+    }
+}
+
+impl Weights {
+    fn pick(&self, rng: &mut impl Rng) -> CommitCount {
+        let total: u32 = self.0.iter().sum();
+        let mut value = rng.random_range(0..total);
+        for (commit_count, weight) in CommitCount::ALL.iter().zip(self.0) {
+            if value < weight {
+                return *commit_count;
+            }
+            value -= weight;
+        }
+        unreachable!("value is below the total of the weights")
+    }
+}
+
+/// Days of the week a fill is restricted to.
+/// Format: all, weekdays, weekends, or day names like mon,wed,fri
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Weekdays(u8);
+
+impl Default for Weekdays {
+    fn default() -> Self {
+        Weekdays(0b111_1111)
+    }
+}
+
+impl FromStr for Weekdays {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let days: Vec<Weekday> = match s.trim().to_ascii_lowercase().as_str() {
+            "all" => return Ok(Weekdays::default()),
+            "weekdays" => vec![
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri,
+            ],
+            "weekends" => vec![Weekday::Sat, Weekday::Sun],
+            _ => s
+                .split(',')
+                .map(|day| day.trim().parse::<Weekday>())
+                .collect::<std::result::Result<_, _>>()
+                .map_err(|_| Error::InvalidArg(format!("Invalid weekdays {s:?}")))?,
+        };
+        Ok(Weekdays(days.iter().fold(0, |mask, day| {
+            mask | 1 << day.num_days_from_sunday()
+        })))
+    }
+}
+
+impl TryFrom<String> for Weekdays {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self> {
+        value.parse()
+    }
+}
+
+impl From<Weekdays> for String {
+    fn from(value: Weekdays) -> Self {
+        if value == Weekdays::default() {
+            return "all".to_string();
+        }
+        let mut day = Weekday::Sun;
+        let mut days = Vec::new();
+        for i in 0..7 {
+            if value.0 & 1 << i != 0 {
+                days.push(day.to_string().to_ascii_lowercase());
+            }
+            day = day.succ();
+        }
+        days.join(",")
+    }
+}
+
+impl Weekdays {
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.0 & 1 << date.weekday().num_days_from_sunday() != 0
+    }
+}
+
+/// Options of the full and random fills
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct FillOptions {
+    /// Only used by random fills
+    pub weights: Weights,
+    pub weekdays: Weekdays,
+}
+
+impl CommitGrid {
+    fn fill(
+        &mut self,
+        end_date: NaiveDate,
+        weekdays: Weekdays,
+        mut pick: impl FnMut() -> CommitCount,
+    ) -> Result<()> {
+        if self.get_start_date().gt(&end_date) {
+            return Err(Error::InvalidArg(
+                "end_date must be >= start_date".to_string(),
+            ));
+        }
+        let days = (end_date - self.get_start_date()).num_days();
+        let data = (0..=days)
+            .map(|i| {
+                let date = self.get_start_date() + TimeDelta::days(i);
+                if weekdays.contains(date) {
+                    pick()
+                } else {
+                    CommitCount::Zero
+                }
+            })
+            .collect();
+        self.set_data(data);
+        Ok(())
+    }
+
+    /// Same as `full`, only filling the days of `options.weekdays`
+    pub fn full_with_options(
+        &mut self,
+        commit_count: CommitCount,
+        end_date: NaiveDate,
+        options: &FillOptions,
+    ) -> Result<()> {
+        self.fill(end_date, options.weekdays, || commit_count)
+    }
+
+    /// Same as `random_with_seed`, with commit counts picked by `options.weights`
+    /// and only on the days of `options.weekdays`
+    pub fn random_with_options(
+        &mut self,
+        end_date: NaiveDate,
+        options: &FillOptions,
+        seed: u64,
+    ) -> Result<()> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        self.fill(end_date, options.weekdays, || {
+            options.weights.pick(&mut rng)
+        })
+    }
+
+    /// Random-fill the Zero days of this grid, up to `end_date`, keeping the others as they are
+    pub fn fill_background(
+        &mut self,
+        end_date: NaiveDate,
+        options: &FillOptions,
+        seed: u64,
+    ) -> Result<()> {
+        let mut background = CommitGrid::new(self.get_start_date());
+        background.random_with_options(end_date, options, seed)?;
+
+        let mut data = background.get_data().to_vec();
+        let foreground = self.get_data();
+        if data.len() < foreground.len() {
+            data.resize(foreground.len(), CommitCount::Zero);
+        }
+        for (cell, above) in data.iter_mut().zip(foreground) {
+            if *above != CommitCount::Zero {
+                *cell = *above;
+            }
+        }
+        self.set_data(data);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn weights_error(s: &str) -> String {
+        match s.parse::<Weights>() {
+            Err(Error::InvalidArg(message)) => message,
+            other => panic!("expected an InvalidArg error for {s:?}, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parses_weights() {
+        assert_eq!(
+            "50, 30,10,5,5".parse::<Weights>().unwrap(),
+            Weights([50, 30, 10, 5, 5])
+        );
+        assert_eq!(
+            "4294967291,1,1,1,1".parse::<Weights>().unwrap(),
+            Weights([u32::MAX - 4, 1, 1, 1, 1])
+        );
+    }
+
+    #[test]
+    fn reports_invalid_weights() {
+        assert_eq!(
+            weights_error("1,2,3"),
+            "Weights must have exactly 5 values, got \"1,2,3\""
+        );
+        assert_eq!(
+            weights_error("0,0,0,0,0"),
+            "At least one weight must be > 0, got \"0,0,0,0,0\""
+        );
+        assert_eq!(
+            weights_error("4294967295,1,0,0,0"),
+            "Weights must add up to at most 4294967295, got \"4294967295,1,0,0,0\""
+        );
+    }
+
+    #[test]
+    fn picks_only_weighted_commit_counts() {
+        let weights: Weights = "0,0,4294967295,0,0".parse().unwrap();
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..100 {
+            assert_eq!(weights.pick(&mut rng), CommitCount::Some);
+        }
+    }
+}
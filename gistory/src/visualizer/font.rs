@@ -1,9 +1,20 @@
-use serde::Deserialize;
+use log::warn;
+use serde::{Deserialize, Serialize};
 
+use super::font_bold::CHARS_MAPPING as BOLD_MAPPING;
+use super::font_compact::CHARS_MAPPING as COMPACT_MAPPING;
+use super::font_fallback::{COMPOSITIONS, SIMILAR_CHARS};
+use super::font_icons::CHARS_MAPPING as ICONS_MAPPING;
+use super::font_monospace::CHARS_MAPPING as MONOSPACE_MAPPING;
 use super::font_subway_tracker::CHARS_MAPPING as SUBWAY_TRACKER_MAPPING;
+use crate::error::{Error, Result};
+use crate::visualizer::text_options::Glyph;
 
 pub const CHAR_HEIGHT: usize = 7;
+/// Glyph drawn for characters missing from a font, a hollow box in the built-in fonts
+pub const REPLACEMENT_CHAR: char = '\u{FFFD}';
 
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum Pixel {
     On,
     Off,
@@ -37,23 +48,205 @@ impl Char {
     }
 }
 
-#[derive(Debug, Clone, Copy, clap::ValueEnum, Deserialize)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, clap::ValueEnum, Deserialize, Serialize)]
 pub enum Font {
     SubwayTracker,
+    /// 3x5 letters centered in the 7 days, lowercase letters are drawn as uppercase
+    Compact,
+    /// Subway Tracker with strokes 2 pixels wide
+    Bold,
+    /// Subway Tracker with all characters 5 pixels wide
+    Monospace,
+    /// Pixel-art icons: h heart, s star, y smiley, f frown, i invader, g ghost, p pac-man,
+    /// t tree, n note, c coffee, l lightning, d diamond, k skull, a/b/u/v arrows
+    /// right/left/up/down, m mountain, o ring, x cross
+    Icons,
 }
 
 impl Font {
-    pub fn get_char(&self, ch: u8) -> Option<&Char> {
+    fn mapping(&self) -> &'static phf::Map<char, Char> {
         match self {
-            Font::SubwayTracker => SUBWAY_TRACKER_MAPPING.get(&ch),
+            Font::SubwayTracker => &SUBWAY_TRACKER_MAPPING,
+            Font::Compact => &COMPACT_MAPPING,
+            Font::Bold => &BOLD_MAPPING,
+            Font::Monospace => &MONOSPACE_MAPPING,
+            Font::Icons => &ICONS_MAPPING,
         }
     }
 
+    pub fn get_char(&self, ch: char) -> Option<&Char> {
+        self.mapping().get(&ch)
+    }
+
     pub fn supported_chars(&self) -> String {
-        let map = match self {
-            Font::SubwayTracker => SUBWAY_TRACKER_MAPPING,
+        let mut chars: Vec<char> = self
+            .mapping()
+            .keys()
+            .copied()
+            .filter(|c| !c.is_whitespace() && *c != REPLACEMENT_CHAR && !c.is_control())
+            .collect();
+        chars.sort();
+        format!("<space> {}", chars.into_iter().collect::<String>())
+    }
+
+    /// Glyphs of the text, with missing characters handled by `fallback`
+    pub(crate) fn glyphs(&self, text: &str, fallback: Fallback) -> Result<Vec<Glyph>> {
+        lookup_glyphs(
+            text,
+            fallback,
+            |ch| {
+                self.get_char(ch).map(|c| Glyph {
+                    pixels: c.pixels_by_column(),
+                    is_space: ch.is_whitespace(),
+                })
+            },
+            || self.supported_chars(),
+        )
+    }
+}
+
+/// What to draw for characters missing from a font.
+/// A similar character is tried first: the other case, or the same letter without accent.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, clap::ValueEnum, Deserialize, Serialize)]
+pub enum Fallback {
+    /// Draw the replacement glyph
+    #[default]
+    Replace,
+    /// Leave the character out
+    Skip,
+    /// Fail
+    Error,
+}
+
+fn is_combining(ch: char) -> bool {
+    matches!(ch, '\u{300}'..='\u{36F}')
+}
+
+/// Split text into characters, merging a letter with the combining accents following it.
+/// Accents that can't be merged are dropped.
+fn compose(text: &str) -> Vec<char> {
+    let mut chars: Vec<char> = Vec::with_capacity(text.len());
+    for ch in text.chars() {
+        if !is_combining(ch) {
+            chars.push(ch);
+            continue;
+        }
+        if let Some(last) = chars.last_mut()
+            && let Some(composed) = COMPOSITIONS.get(format!("{last}{ch}").as_str())
+        {
+            *last = *composed;
+        }
+    }
+    chars
+}
+
+fn find_similar<G>(ch: char, get_glyph: &impl Fn(char) -> Option<G>) -> Option<G> {
+    let mut candidate = ch;
+    // a few steps are enough to go from any character to its base letter
+    for _ in 0..4 {
+        if let Some(glyph) = get_glyph(candidate) {
+            return Some(glyph);
+        }
+        let other_case: Vec<char> = if candidate.is_uppercase() {
+            candidate.to_lowercase().collect()
+        } else {
+            candidate.to_uppercase().collect()
         };
-        let chars: Vec<u8> = map.keys().copied().collect();
-        format!("<space> {}", String::from_utf8(chars).unwrap())
+        if let [other_case] = other_case[..]
+            && let Some(glyph) = get_glyph(other_case)
+        {
+            return Some(glyph);
+        }
+        match SIMILAR_CHARS.get(&candidate) {
+            Some(similar) => candidate = *similar,
+            None => break,
+        }
+    }
+    None
+}
+
+/// Find the glyph of each character of `text`, applying the fallback policy to missing characters
+pub(crate) fn lookup_glyphs<G>(
+    text: &str,
+    fallback: Fallback,
+    get_glyph: impl Fn(char) -> Option<G>,
+    supported_chars: impl FnOnce() -> String,
+) -> Result<Vec<G>> {
+    let mut glyphs = Vec::with_capacity(text.len());
+    let mut missing: Vec<char> = Vec::new();
+
+    for ch in compose(text) {
+        if let Some(glyph) = find_similar(ch, &get_glyph) {
+            glyphs.push(glyph);
+            continue;
+        }
+        if !missing.contains(&ch) {
+            missing.push(ch);
+        }
+        if fallback == Fallback::Replace
+            && let Some(glyph) = get_glyph(REPLACEMENT_CHAR).or_else(|| get_glyph('?'))
+        {
+            glyphs.push(glyph);
+        }
+    }
+
+    if !missing.is_empty() {
+        let missing = missing
+            .iter()
+            .map(|c| format!("{c:?}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        match fallback {
+            Fallback::Replace => warn!("Font has no glyph for {missing}, they were replaced"),
+            Fallback::Skip => warn!("Font has no glyph for {missing}, they were skipped"),
+            Fallback::Error => {
+                return Err(Error::InvalidData(format!(
+                    "Font has no glyph for {missing}. Text only accepts {}",
+                    supported_chars()
+                )));
+            }
+        }
+    }
+    Ok(glyphs)
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::ValueEnum;
+
+    use super::*;
+
+    /// Characters listed by `supported_chars`, `<space>` being the space character
+    fn listed_chars(font: Font) -> Vec<char> {
+        let supported = font.supported_chars();
+        let chars = supported
+            .strip_prefix("<space> ")
+            .expect("supported chars start with <space>");
+        std::iter::once(' ').chain(chars.chars()).collect()
+    }
+
+    #[test]
+    fn supported_chars_have_valid_glyphs() {
+        for font in Font::value_variants() {
+            for ch in listed_chars(*font).into_iter().chain([REPLACEMENT_CHAR]) {
+                let Some(glyph) = font.get_char(ch) else {
+                    panic!("{font:?} has no glyph for {ch:?}");
+                };
+                let width = glyph.width();
+                assert!(width > 0, "{font:?} {ch:?} is empty");
+                for (i, row) in glyph.data.iter().enumerate() {
+                    assert_eq!(
+                        row.len(),
+                        width,
+                        "{font:?} {ch:?}: row {i} is {row:?}, expected {width} pixels"
+                    );
+                    assert!(
+                        row.bytes().all(|pixel| pixel == b'x' || pixel == b'.'),
+                        "{font:?} {ch:?}: row {i} is {row:?}, expected only x and ."
+                    );
+                }
+                assert_eq!(glyph.pixels_by_column().len(), width * CHAR_HEIGHT);
+            }
+        }
     }
 }
@@ -0,0 +1,5622 @@
+use phf::phf_map;
+
+use crate::visualizer::font::Char;
+
+pub const CHARS_MAPPING: phf::Map<char, Char> = phf_map! {
+
+' ' => Char {
+    data: [
+        "..",
+        "..",
+        "..",
+        "..",
+        "..",
+        "..",
+        "..",
+    ],
+},
+
+'!' => Char {
+    data: [
+        "xx",
+        "xx",
+        "xx",
+        "xx",
+        "xx",
+        "..",
+        "xx",
+    ],
+},
+
+'"' => Char {
+    data: [
+        "xxxx",
+        "xxxx",
+        "....",
+        "....",
+        "....",
+        "....",
+        "....",
+    ],
+},
+
+'#' => Char {
+    data: [
+        "......",
+        ".xxxx.",
+        "xxxxxx",
+        ".xxxx.",
+        "xxxxxx",
+        ".xxxx.",
+        "......",
+    ],
+},
+
+'$' => Char {
+    data: [
+        ".xxxx.",
+        "xxxxxx",
+        "xxxx..",
+        ".xxxx.",
+        "..xxxx",
+        "xxxxxx",
+        ".xxxx.",
+    ],
+},
+
+'%' => Char {
+    data: [
+        ".xx..xx.",
+        "xxxx.xx.",
+        ".xx.xx..",
+        "...xx...",
+        "..xx.xx.",
+        ".xx.xxxx",
+        ".xx..xx.",
+    ],
+},
+
+'&' => Char {
+    data: [
+        ".xxx..",
+        "xx.xx.",
+        "xxxx..",
+        ".xx...",
+        "xxxxxx",
+        "xx.xx.",
+        ".xxxxx",
+    ],
+},
+
+'\'' => Char {
+    data: [
+        "xx",
+        "xx",
+        "..",
+        "..",
+        "..",
+        "..",
+        "..",
+    ],
+},
+
+'(' => Char {
+    data: [
+        ".xx",
+        "xx.",
+        "xx.",
+        "xx.",
+        "xx.",
+        "xx.",
+        ".xx",
+    ],
+},
+
+')' => Char {
+    data: [
+        "xx.",
+        ".xx",
+        ".xx",
+        ".xx",
+        ".xx",
+        ".xx",
+        "xx.",
+    ],
+},
+
+'*' => Char {
+    data: [
+        "..xx..",
+        "xxxxxx",
+        ".xxxx.",
+        "..xx..",
+        ".xxxx.",
+        "xxxxxx",
+        "..xx..",
+    ],
+},
+
+'+' => Char {
+    data: [
+        "......",
+        "..xx..",
+        "..xx..",
+        "xxxxxx",
+        "..xx..",
+        "..xx..",
+        "......",
+    ],
+},
+
+',' => Char {
+    data: [
+        "...",
+        "...",
+        "...",
+        "...",
+        "...",
+        ".xx",
+        "xxx",
+    ],
+},
+
+'-' => Char {
+    data: [
+        "....",
+        "....",
+        "....",
+        "xxxx",
+        "....",
+        "....",
+        "....",
+    ],
+},
+
+'.' => Char {
+    data: [
+        "..",
+        "..",
+        "..",
+        "..",
+        "..",
+        "..",
+        "xx",
+    ],
+},
+
+'/' => Char {
+    data: [
+        "....xx",
+        "....xx",
+        "...xx.",
+        "..xx..",
+        ".xx...",
+        "xx....",
+        "xx....",
+    ],
+},
+
+'0' => Char {
+    data: [
+        ".xxxx.",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'1' => Char {
+    data: [
+        "..xx..",
+        "xxxx..",
+        "..xx..",
+        "..xx..",
+        "..xx..",
+        "..xx..",
+        "xxxxxx",
+    ],
+},
+
+'2' => Char {
+    data: [
+        ".xxxx.",
+        "xx..xx",
+        "....xx",
+        "...xx.",
+        "..xx..",
+        ".xx...",
+        "xxxxxx",
+    ],
+},
+
+'3' => Char {
+    data: [
+        ".xxxx.",
+        "xx..xx",
+        "....xx",
+        "..xxx.",
+        "....xx",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'4' => Char {
+    data: [
+        "....xx",
+        "...xxx",
+        "..xxxx",
+        ".xx.xx",
+        "xxxxxx",
+        "....xx",
+        "....xx",
+    ],
+},
+
+'5' => Char {
+    data: [
+        "xxxxxx",
+        "xx....",
+        "xx....",
+        "xxxxx.",
+        "....xx",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'6' => Char {
+    data: [
+        ".xxxx.",
+        "xx..xx",
+        "xx....",
+        "xxxxx.",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'7' => Char {
+    data: [
+        "xxxxxx",
+        "....xx",
+        "...xx.",
+        "..xx..",
+        ".xx...",
+        ".xx...",
+        ".xx...",
+    ],
+},
+
+'8' => Char {
+    data: [
+        ".xxxx.",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'9' => Char {
+    data: [
+        ".xxxx.",
+        "xx..xx",
+        "xx..xx",
+        ".xxxxx",
+        "....xx",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+':' => Char {
+    data: [
+        "..",
+        "..",
+        "..",
+        "xx",
+        "..",
+        "..",
+        "xx",
+    ],
+},
+
+';' => Char {
+    data: [
+        "...",
+        "...",
+        "...",
+        ".xx",
+        "...",
+        ".xx",
+        "xxx",
+    ],
+},
+
+'<' => Char {
+    data: [
+        "...xx",
+        "..xx.",
+        ".xx..",
+        "xx...",
+        ".xx..",
+        "..xx.",
+        "...xx",
+    ],
+},
+
+'=' => Char {
+    data: [
+        "......",
+        "......",
+        "xxxxxx",
+        "......",
+        "xxxxxx",
+        "......",
+        "......",
+    ],
+},
+
+'>' => Char {
+    data: [
+        "xx...",
+        ".xx..",
+        "..xx.",
+        "...xx",
+        "..xx.",
+        ".xx..",
+        "xx...",
+    ],
+},
+
+'?' => Char {
+    data: [
+        ".xxxx.",
+        "xx..xx",
+        "....xx",
+        "..xxx.",
+        ".xx...",
+        "......",
+        ".xx...",
+    ],
+},
+
+'@' => Char {
+    data: [
+        ".xxxx.",
+        "xx..xx",
+        "xx.xxx",
+        "xxxxxx",
+        "xxxxxx",
+        "xx.xx.",
+        ".xx...",
+    ],
+},
+
+'A' => Char {
+    data: [
+        ".xxxx.",
+        "xx..xx",
+        "xx..xx",
+        "xxxxxx",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+    ],
+},
+
+'B' => Char {
+    data: [
+        "xxxxx.",
+        "xx..xx",
+        "xx..xx",
+        "xxxxx.",
+        "xx..xx",
+        "xx..xx",
+        "xxxxx.",
+    ],
+},
+
+'C' => Char {
+    data: [
+        ".xxxx.",
+        "xx..xx",
+        "xx....",
+        "xx....",
+        "xx....",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'D' => Char {
+    data: [
+        "xxxxx.",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        "xxxxx.",
+    ],
+},
+
+'E' => Char {
+    data: [
+        "xxxxxx",
+        "xx....",
+        "xx....",
+        "xxxxx.",
+        "xx....",
+        "xx....",
+        "xxxxxx",
+    ],
+},
+
+'F' => Char {
+    data: [
+        "xxxxxx",
+        "xx....",
+        "xx....",
+        "xxxxx.",
+        "xx....",
+        "xx....",
+        "xx....",
+    ],
+},
+
+'G' => Char {
+    data: [
+        ".xxxx.",
+        "xx..xx",
+        "xx....",
+        "xxxxxx",
+        "xx..xx",
+        "xx..xx",
+        ".xxxxx",
+    ],
+},
+
+'H' => Char {
+    data: [
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        "xxxxxx",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+    ],
+},
+
+'I' => Char {
+    data: [
+        ".xxxx.",
+        "..xx..",
+        "..xx..",
+        "..xx..",
+        "..xx..",
+        "..xx..",
+        "xxxxxx",
+    ],
+},
+
+'J' => Char {
+    data: [
+        ".xxxxx",
+        "....xx",
+        "....xx",
+        "....xx",
+        "....xx",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'K' => Char {
+    data: [
+        "xx..xx",
+        "xx.xx.",
+        "xxxx..",
+        "xxx...",
+        "xxxx..",
+        "xx.xx.",
+        "xx..xx",
+    ],
+},
+
+'L' => Char {
+    data: [
+        "xx....",
+        "xx....",
+        "xx....",
+        "xx....",
+        "xx....",
+        "xx....",
+        "xxxxxx",
+    ],
+},
+
+'M' => Char {
+    data: [
+        "xxxxxx",
+        "xxxxxx",
+        "xxxxxx",
+        "xxxxxx",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+    ],
+},
+
+'N' => Char {
+    data: [
+        "xx..xx",
+        "xx..xx",
+        "xxx.xx",
+        "xxxxxx",
+        "xx.xxx",
+        "xx..xx",
+        "xx..xx",
+    ],
+},
+
+'O' => Char {
+    data: [
+        ".xxxx.",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'P' => Char {
+    data: [
+        "xxxxx.",
+        "xx..xx",
+        "xx..xx",
+        "xxxxx.",
+        "xx....",
+        "xx....",
+        "xx....",
+    ],
+},
+
+'Q' => Char {
+    data: [
+        ".xxxx.",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        "xxxxxx",
+        "xx.xx.",
+        ".xxxxx",
+    ],
+},
+
+'R' => Char {
+    data: [
+        "xxxxx.",
+        "xx..xx",
+        "xx..xx",
+        "xxxxx.",
+        "xxxx..",
+        "xx.xx.",
+        "xx..xx",
+    ],
+},
+
+'S' => Char {
+    data: [
+        ".xxxx.",
+        "xx..xx",
+        "xx....",
+        ".xxxx.",
+        "....xx",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'T' => Char {
+    data: [
+        "xxxxxx",
+        "..xx..",
+        "..xx..",
+        "..xx..",
+        "..xx..",
+        "..xx..",
+        "..xx..",
+    ],
+},
+
+'U' => Char {
+    data: [
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'V' => Char {
+    data: [
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+        "..xx..",
+    ],
+},
+
+'W' => Char {
+    data: [
+        "xx..xx",
+        "xx..xx",
+        "xxxxxx",
+        "xxxxxx",
+        "xxxxxx",
+        "xxxxxx",
+        ".xxxx.",
+    ],
+},
+
+'X' => Char {
+    data: [
+        "xx..xx",
+        ".xxxx.",
+        "..xx..",
+        "..xx..",
+        "..xx..",
+        ".xxxx.",
+        "xx..xx",
+    ],
+},
+
+'Y' => Char {
+    data: [
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+        "..xx..",
+        "..xx..",
+        "..xx..",
+    ],
+},
+
+'Z' => Char {
+    data: [
+        "xxxxxx",
+        "....xx",
+        "...xx.",
+        "..xx..",
+        ".xx...",
+        "xx....",
+        "xxxxxx",
+    ],
+},
+
+'[' => Char {
+    data: [
+        "xxx",
+        "xx.",
+        "xx.",
+        "xx.",
+        "xx.",
+        "xx.",
+        "xxx",
+    ],
+},
+
+'\\' => Char {
+    data: [
+        "xx....",
+        "xx....",
+        ".xx...",
+        "..xx..",
+        "...xx.",
+        "....xx",
+        "....xx",
+    ],
+},
+
+']' => Char {
+    data: [
+        "xxx",
+        ".xx",
+        ".xx",
+        ".xx",
+        ".xx",
+        ".xx",
+        "xxx",
+    ],
+},
+
+'^' => Char {
+    data: [
+        "..xx..",
+        ".xxxx.",
+        "xx..xx",
+        "......",
+        "......",
+        "......",
+        "......",
+    ],
+},
+
+'_' => Char {
+    data: [
+        ".....",
+        ".....",
+        ".....",
+        ".....",
+        ".....",
+        ".....",
+        "xxxxx",
+    ],
+},
+
+'`' => Char {
+    data: [
+        "xx..",
+        ".xx.",
+        "..xx",
+        "....",
+        "....",
+        "....",
+        "....",
+    ],
+},
+
+'a' => Char {
+    data: [
+        ".....",
+        ".xxx.",
+        "...xx",
+        ".xxxx",
+        "xx.xx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'b' => Char {
+    data: [
+        "xx...",
+        "xx...",
+        "xxxx.",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'c' => Char {
+    data: [
+        ".....",
+        ".xxx.",
+        "xx.xx",
+        "xx...",
+        "xx...",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'d' => Char {
+    data: [
+        "...xx",
+        "...xx",
+        ".xxxx",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'e' => Char {
+    data: [
+        ".....",
+        ".xxx.",
+        "xx.xx",
+        "xx.xx",
+        "xxxxx",
+        "xx...",
+        ".xxx.",
+    ],
+},
+
+'f' => Char {
+    data: [
+        ".xxx",
+        "xx..",
+        "xx..",
+        "xxx.",
+        "xx..",
+        "xx..",
+        "xx..",
+    ],
+},
+
+'g' => Char {
+    data: [
+        ".....",
+        ".xxx.",
+        "xx.xx",
+        "xx.xx",
+        ".xxxx",
+        "...xx",
+        ".xxx.",
+    ],
+},
+
+'h' => Char {
+    data: [
+        "xx...",
+        "xx...",
+        "xxxx.",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+    ],
+},
+
+'i' => Char {
+    data: [
+        ".xx..",
+        ".....",
+        "xxx..",
+        ".xx..",
+        ".xx..",
+        ".xx..",
+        "..xx.",
+    ],
+},
+
+'j' => Char {
+    data: [
+        "...xx",
+        ".....",
+        "..xxx",
+        "...xx",
+        "...xx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'k' => Char {
+    data: [
+        "xx...",
+        "xx...",
+        "xx.xx",
+        "xxxx.",
+        "xxx..",
+        "xxxx.",
+        "xx.xx",
+    ],
+},
+
+'l' => Char {
+    data: [
+        "xxx.",
+        ".xx.",
+        ".xx.",
+        ".xx.",
+        ".xx.",
+        ".xx.",
+        "..xx",
+    ],
+},
+
+'m' => Char {
+    data: [
+        "......",
+        ".xxxx.",
+        "xxxxxx",
+        "xxxxxx",
+        "xxxxxx",
+        "xxxxxx",
+        "xxxxxx",
+    ],
+},
+
+'n' => Char {
+    data: [
+        ".....",
+        ".xxx.",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+    ],
+},
+
+'o' => Char {
+    data: [
+        ".....",
+        ".xxx.",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'p' => Char {
+    data: [
+        ".....",
+        ".xxx.",
+        "xx.xx",
+        "xx.xx",
+        "xxxx.",
+        "xx...",
+        "xx...",
+    ],
+},
+
+'q' => Char {
+    data: [
+        ".....",
+        ".xxx.",
+        "xx.xx",
+        "xx.xx",
+        ".xxxx",
+        "...xx",
+        "...xx",
+    ],
+},
+
+'r' => Char {
+    data: [
+        "....",
+        ".xxx",
+        "xx..",
+        "xx..",
+        "xx..",
+        "xx..",
+        "xx..",
+    ],
+},
+
+'s' => Char {
+    data: [
+        ".....",
+        ".xxx.",
+        "xx.xx",
+        ".xx..",
+        "..xx.",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'t' => Char {
+    data: [
+        "....",
+        "xx..",
+        "xxxx",
+        "xx..",
+        "xx..",
+        "xx..",
+        ".xxx",
+    ],
+},
+
+'u' => Char {
+    data: [
+        ".....",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'v' => Char {
+    data: [
+        "......",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+        "..xx..",
+    ],
+},
+
+'w' => Char {
+    data: [
+        "......",
+        "xx..xx",
+        "xxxxxx",
+        "xxxxxx",
+        "xxxxxx",
+        "xxxxxx",
+        ".xxxx.",
+    ],
+},
+
+'x' => Char {
+    data: [
+        "......",
+        "xx..xx",
+        ".xxxx.",
+        "..xx..",
+        "..xx..",
+        ".xxxx.",
+        "xx..xx",
+    ],
+},
+
+'y' => Char {
+    data: [
+        ".....",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        ".xxxx",
+        "...xx",
+        ".xxx.",
+    ],
+},
+
+'z' => Char {
+    data: [
+        ".....",
+        "xxxxx",
+        "...xx",
+        "..xx.",
+        ".xx..",
+        "xx...",
+        "xxxxx",
+    ],
+},
+
+'{' => Char {
+    data: [
+        ".xxx",
+        ".xx.",
+        ".xx.",
+        "xxx.",
+        ".xx.",
+        ".xx.",
+        ".xxx",
+    ],
+},
+
+'|' => Char {
+    data: [
+        "xx",
+        "xx",
+        "xx",
+        "xx",
+        "xx",
+        "xx",
+        "xx",
+    ],
+},
+
+'}' => Char {
+    data: [
+        "xxx.",
+        ".xx.",
+        ".xx.",
+        ".xxx",
+        ".xx.",
+        ".xx.",
+        "xxx.",
+    ],
+},
+
+'~' => Char {
+    data: [
+        "........",
+        "........",
+        ".xxx....",
+        "xx.xx.xx",
+        "....xxx.",
+        "........",
+        "........",
+    ],
+},
+
+'\u{a0}' => Char {
+    data: [
+        "..",
+        "..",
+        "..",
+        "..",
+        "..",
+        "..",
+        "..",
+    ],
+},
+
+'¡' => Char {
+    data: [
+        "xx",
+        "..",
+        "xx",
+        "xx",
+        "xx",
+        "xx",
+        "xx",
+    ],
+},
+
+'¢' => Char {
+    data: [
+        "..xx.",
+        ".xxxx",
+        "xxxx.",
+        "xxxx.",
+        "xxxx.",
+        ".xxxx",
+        "..xx.",
+    ],
+},
+
+'£' => Char {
+    data: [
+        "..xxx.",
+        ".xx.xx",
+        ".xx...",
+        "xxxxx.",
+        ".xx...",
+        ".xx...",
+        "xxxxxx",
+    ],
+},
+
+'¤' => Char {
+    data: [
+        "......",
+        "xx..xx",
+        ".xxxx.",
+        ".xxxx.",
+        ".xxxx.",
+        "xx..xx",
+        "......",
+    ],
+},
+
+'¥' => Char {
+    data: [
+        "xx..xx",
+        ".xxxx.",
+        "..xx..",
+        "xxxxxx",
+        "..xx..",
+        "xxxxxx",
+        "..xx..",
+    ],
+},
+
+'¦' => Char {
+    data: [
+        "xx",
+        "xx",
+        "xx",
+        "..",
+        "xx",
+        "xx",
+        "xx",
+    ],
+},
+
+'§' => Char {
+    data: [
+        ".xxxx",
+        "xx...",
+        ".xxx.",
+        "xx.xx",
+        ".xxx.",
+        "...xx",
+        "xxxx.",
+    ],
+},
+
+'¨' => Char {
+    data: [
+        "xxxx",
+        "....",
+        "....",
+        "....",
+        "....",
+        "....",
+        "....",
+    ],
+},
+
+'©' => Char {
+    data: [
+        ".xxxxxx.",
+        "xx....xx",
+        "xx.xxxxx",
+        "xxxx..xx",
+        "xx.xxxxx",
+        "xx....xx",
+        ".xxxxxx.",
+    ],
+},
+
+'«' => Char {
+    data: [
+        ".......",
+        ".......",
+        "..xx.xx",
+        ".xx.xx.",
+        "xx.xx..",
+        ".xx.xx.",
+        "..xx.xx",
+    ],
+},
+
+'¬' => Char {
+    data: [
+        "......",
+        "......",
+        "......",
+        "xxxxxx",
+        "....xx",
+        "......",
+        "......",
+    ],
+},
+
+'\u{ad}' => Char {
+    data: [
+        "....",
+        "....",
+        "....",
+        "xxxx",
+        "....",
+        "....",
+        "....",
+    ],
+},
+
+'®' => Char {
+    data: [
+        ".xxxxxx.",
+        "xxxxx.xx",
+        "xxxxxxxx",
+        "xxxxx.xx",
+        "xxxxxxxx",
+        "xx....xx",
+        ".xxxxxx.",
+    ],
+},
+
+'¯' => Char {
+    data: [
+        "xxxx",
+        "....",
+        "....",
+        "....",
+        "....",
+        "....",
+        "....",
+    ],
+},
+
+'°' => Char {
+    data: [
+        ".xx.",
+        "xxxx",
+        ".xx.",
+        "....",
+        "....",
+        "....",
+        "....",
+    ],
+},
+
+'±' => Char {
+    data: [
+        "......",
+        "..xx..",
+        "..xx..",
+        "xxxxxx",
+        "..xx..",
+        "......",
+        "xxxxxx",
+    ],
+},
+
+'´' => Char {
+    data: [
+        ".xx",
+        "xx.",
+        "...",
+        "...",
+        "...",
+        "...",
+        "...",
+    ],
+},
+
+'µ' => Char {
+    data: [
+        ".....",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        "xxxx.",
+        "xx...",
+        "xx...",
+    ],
+},
+
+'¶' => Char {
+    data: [
+        ".xxxxx",
+        "xxxxxx",
+        "xxxxxx",
+        ".xxxxx",
+        "..xxxx",
+        "..xxxx",
+        "..xxxx",
+    ],
+},
+
+'·' => Char {
+    data: [
+        "..",
+        "..",
+        "..",
+        "xx",
+        "..",
+        "..",
+        "..",
+    ],
+},
+
+'»' => Char {
+    data: [
+        ".......",
+        ".......",
+        "xx.xx..",
+        ".xx.xx.",
+        "..xx.xx",
+        ".xx.xx.",
+        "xx.xx..",
+    ],
+},
+
+'¿' => Char {
+    data: [
+        ".xx...",
+        "......",
+        "...xx.",
+        ".xxx..",
+        "xx....",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'À' => Char {
+    data: [
+        ".xx...",
+        "..xx..",
+        "......",
+        ".xxxx.",
+        "xx..xx",
+        "xxxxxx",
+        "xx..xx",
+    ],
+},
+
+'Á' => Char {
+    data: [
+        "..xx..",
+        ".xx...",
+        "......",
+        ".xxxx.",
+        "xx..xx",
+        "xxxxxx",
+        "xx..xx",
+    ],
+},
+
+'Â' => Char {
+    data: [
+        "..xx..",
+        ".xxxx.",
+        "......",
+        ".xxxx.",
+        "xx..xx",
+        "xxxxxx",
+        "xx..xx",
+    ],
+},
+
+'Ã' => Char {
+    data: [
+        ".xxxx.",
+        "xxxx..",
+        "......",
+        ".xxxx.",
+        "xx..xx",
+        "xxxxxx",
+        "xx..xx",
+    ],
+},
+
+'Ä' => Char {
+    data: [
+        ".xxxx.",
+        "......",
+        ".xxxx.",
+        "xx..xx",
+        "xxxxxx",
+        "xx..xx",
+        "xx..xx",
+    ],
+},
+
+'Å' => Char {
+    data: [
+        ".xxx..",
+        ".xxx..",
+        "......",
+        ".xxxx.",
+        "xx..xx",
+        "xxxxxx",
+        "xx..xx",
+    ],
+},
+
+'Æ' => Char {
+    data: [
+        ".xxxxxxx",
+        "xx.xx...",
+        "xx.xx...",
+        "xxxxxxx.",
+        "xx.xx...",
+        "xx.xx...",
+        "xx.xxxxx",
+    ],
+},
+
+'Ç' => Char {
+    data: [
+        ".xxxx.",
+        "xx..xx",
+        "xx....",
+        "xx..xx",
+        ".xxxx.",
+        "..xx..",
+        ".xx...",
+    ],
+},
+
+'È' => Char {
+    data: [
+        ".xx...",
+        "..xx..",
+        "xxxxxx",
+        "xx....",
+        "xxxxx.",
+        "xx....",
+        "xxxxxx",
+    ],
+},
+
+'É' => Char {
+    data: [
+        "..xx..",
+        ".xx...",
+        "xxxxxx",
+        "xx....",
+        "xxxxx.",
+        "xx....",
+        "xxxxxx",
+    ],
+},
+
+'Ê' => Char {
+    data: [
+        "..xx..",
+        ".xxxx.",
+        "xxxxxx",
+        "xx....",
+        "xxxxx.",
+        "xx....",
+        "xxxxxx",
+    ],
+},
+
+'Ë' => Char {
+    data: [
+        ".xxxx.",
+        "......",
+        "xxxxxx",
+        "xx....",
+        "xxxxx.",
+        "xx....",
+        "xxxxxx",
+    ],
+},
+
+'Ì' => Char {
+    data: [
+        ".xx...",
+        "..xx..",
+        "......",
+        ".xxxx.",
+        "..xx..",
+        "..xx..",
+        "xxxxxx",
+    ],
+},
+
+'Í' => Char {
+    data: [
+        "..xx..",
+        ".xx...",
+        "......",
+        ".xxxx.",
+        "..xx..",
+        "..xx..",
+        "xxxxxx",
+    ],
+},
+
+'Î' => Char {
+    data: [
+        "..xx..",
+        ".xxxx.",
+        "......",
+        ".xxxx.",
+        "..xx..",
+        "..xx..",
+        "xxxxxx",
+    ],
+},
+
+'Ï' => Char {
+    data: [
+        ".xxxx.",
+        "......",
+        ".xxxx.",
+        "..xx..",
+        "..xx..",
+        "..xx..",
+        "xxxxxx",
+    ],
+},
+
+'Ð' => Char {
+    data: [
+        "xxxxx.",
+        ".xx.xx",
+        ".xx.xx",
+        "xxxxxx",
+        ".xx.xx",
+        ".xx.xx",
+        "xxxxx.",
+    ],
+},
+
+'Ñ' => Char {
+    data: [
+        ".xxxx.",
+        "xxxx..",
+        "xx..xx",
+        "xxx.xx",
+        "xxxxxx",
+        "xx.xxx",
+        "xx..xx",
+    ],
+},
+
+'Ò' => Char {
+    data: [
+        ".xx...",
+        "..xx..",
+        "......",
+        ".xxxx.",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'Ó' => Char {
+    data: [
+        "..xx..",
+        ".xx...",
+        "......",
+        ".xxxx.",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'Ô' => Char {
+    data: [
+        "..xx..",
+        ".xxxx.",
+        "......",
+        ".xxxx.",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'Õ' => Char {
+    data: [
+        ".xxxx.",
+        "xxxx..",
+        "......",
+        ".xxxx.",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'Ö' => Char {
+    data: [
+        ".xxxx.",
+        "......",
+        ".xxxx.",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'×' => Char {
+    data: [
+        "......",
+        "......",
+        "xx..xx",
+        ".xxxx.",
+        "..xx..",
+        ".xxxx.",
+        "xx..xx",
+    ],
+},
+
+'Ø' => Char {
+    data: [
+        ".xxxx.",
+        "xx..xx",
+        "xx.xxx",
+        "xxxxxx",
+        "xxx.xx",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'Ù' => Char {
+    data: [
+        ".xx...",
+        "..xx..",
+        "......",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'Ú' => Char {
+    data: [
+        "..xx..",
+        ".xx...",
+        "......",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'Û' => Char {
+    data: [
+        "..xx..",
+        ".xxxx.",
+        "......",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'Ü' => Char {
+    data: [
+        ".xxxx.",
+        "......",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'Ý' => Char {
+    data: [
+        "..xx..",
+        ".xx...",
+        "......",
+        "xx..xx",
+        ".xxxx.",
+        "..xx..",
+        "..xx..",
+    ],
+},
+
+'Þ' => Char {
+    data: [
+        "xx....",
+        "xxxxx.",
+        "xx..xx",
+        "xx..xx",
+        "xxxxx.",
+        "xx....",
+        "xx....",
+    ],
+},
+
+'ß' => Char {
+    data: [
+        ".xxx.",
+        "xx.xx",
+        "xxxx.",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        "xxxx.",
+    ],
+},
+
+'à' => Char {
+    data: [
+        ".xx..",
+        "..xx.",
+        ".xxx.",
+        "...xx",
+        ".xxxx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'á' => Char {
+    data: [
+        "..xx.",
+        ".xx..",
+        ".xxx.",
+        "...xx",
+        ".xxxx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'â' => Char {
+    data: [
+        ".xx..",
+        "xxxx.",
+        ".xxx.",
+        "...xx",
+        ".xxxx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'ã' => Char {
+    data: [
+        ".xxxx",
+        "xxxx.",
+        ".xxx.",
+        "...xx",
+        ".xxxx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'ä' => Char {
+    data: [
+        "xx.xx",
+        ".....",
+        ".xxx.",
+        "...xx",
+        ".xxxx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'å' => Char {
+    data: [
+        ".xxx.",
+        ".xxx.",
+        ".xxx.",
+        "...xx",
+        ".xxxx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'æ' => Char {
+    data: [
+        "........",
+        ".xxxxxx.",
+        "...xx.xx",
+        ".xxxxxxx",
+        "xx.xx...",
+        "xx.xx.xx",
+        ".xxxxxx.",
+    ],
+},
+
+'ç' => Char {
+    data: [
+        ".xxx.",
+        "xx.xx",
+        "xx...",
+        "xx.xx",
+        ".xxx.",
+        "..xx.",
+        ".xx..",
+    ],
+},
+
+'è' => Char {
+    data: [
+        ".xx..",
+        "..xx.",
+        ".xxx.",
+        "xx.xx",
+        "xxxxx",
+        "xx...",
+        ".xxx.",
+    ],
+},
+
+'é' => Char {
+    data: [
+        "..xx.",
+        ".xx..",
+        ".xxx.",
+        "xx.xx",
+        "xxxxx",
+        "xx...",
+        ".xxx.",
+    ],
+},
+
+'ê' => Char {
+    data: [
+        ".xx..",
+        "xxxx.",
+        ".xxx.",
+        "xx.xx",
+        "xxxxx",
+        "xx...",
+        ".xxx.",
+    ],
+},
+
+'ë' => Char {
+    data: [
+        "xx.xx",
+        ".....",
+        ".xxx.",
+        "xx.xx",
+        "xxxxx",
+        "xx...",
+        ".xxx.",
+    ],
+},
+
+'ì' => Char {
+    data: [
+        ".xx..",
+        "..xx.",
+        ".....",
+        "xxx..",
+        ".xx..",
+        ".xx..",
+        "..xx.",
+    ],
+},
+
+'í' => Char {
+    data: [
+        "..xx.",
+        ".xx..",
+        ".....",
+        "xxx..",
+        ".xx..",
+        ".xx..",
+        "..xx.",
+    ],
+},
+
+'î' => Char {
+    data: [
+        ".xx..",
+        "xxxx.",
+        ".....",
+        "xxx..",
+        ".xx..",
+        ".xx..",
+        "..xx.",
+    ],
+},
+
+'ï' => Char {
+    data: [
+        "xx.xx",
+        ".....",
+        "xxx..",
+        ".xx..",
+        ".xx..",
+        ".xx..",
+        "..xx.",
+    ],
+},
+
+'ð' => Char {
+    data: [
+        ".xxxx",
+        "..xx.",
+        ".xxxx",
+        ".xxxx",
+        "xx.xx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'ñ' => Char {
+    data: [
+        ".xxxx",
+        "xxxx.",
+        ".....",
+        ".xxx.",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+    ],
+},
+
+'ò' => Char {
+    data: [
+        ".xx..",
+        "..xx.",
+        ".....",
+        ".xxx.",
+        "xx.xx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'ó' => Char {
+    data: [
+        "..xx.",
+        ".xx..",
+        ".....",
+        ".xxx.",
+        "xx.xx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'ô' => Char {
+    data: [
+        ".xx..",
+        "xxxx.",
+        ".....",
+        ".xxx.",
+        "xx.xx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'õ' => Char {
+    data: [
+        ".xxxx",
+        "xxxx.",
+        ".....",
+        ".xxx.",
+        "xx.xx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'ö' => Char {
+    data: [
+        "xx.xx",
+        ".....",
+        ".xxx.",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'÷' => Char {
+    data: [
+        "......",
+        "..xx..",
+        "......",
+        "xxxxxx",
+        "......",
+        "..xx..",
+        "......",
+    ],
+},
+
+'ø' => Char {
+    data: [
+        "......",
+        "....xx",
+        ".xxxx.",
+        "xxxxxx",
+        "xxx.xx",
+        ".xxxx.",
+        "xx....",
+    ],
+},
+
+'ù' => Char {
+    data: [
+        ".xx..",
+        "..xx.",
+        ".....",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'ú' => Char {
+    data: [
+        "..xx.",
+        ".xx..",
+        ".....",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'û' => Char {
+    data: [
+        ".xx..",
+        "xxxx.",
+        ".....",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'ü' => Char {
+    data: [
+        "xx.xx",
+        ".....",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'ý' => Char {
+    data: [
+        "..xx.",
+        ".xx..",
+        ".....",
+        "xx.xx",
+        ".xxxx",
+        "...xx",
+        ".xxx.",
+    ],
+},
+
+'þ' => Char {
+    data: [
+        "xx...",
+        "xxxx.",
+        "xx.xx",
+        "xx.xx",
+        "xxxx.",
+        "xx...",
+        "xx...",
+    ],
+},
+
+'ÿ' => Char {
+    data: [
+        "xx.xx",
+        ".....",
+        "xx.xx",
+        "xx.xx",
+        ".xxxx",
+        "...xx",
+        ".xxx.",
+    ],
+},
+
+'Ā' => Char {
+    data: [
+        ".xxxx.",
+        "......",
+        ".xxxx.",
+        "xx..xx",
+        "xxxxxx",
+        "xx..xx",
+        "xx..xx",
+    ],
+},
+
+'ā' => Char {
+    data: [
+        "xxxx.",
+        ".....",
+        ".xxx.",
+        "...xx",
+        ".xxxx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'Ă' => Char {
+    data: [
+        "xx.xx.",
+        ".xxx..",
+        "......",
+        ".xxxx.",
+        "xx..xx",
+        "xxxxxx",
+        "xx..xx",
+    ],
+},
+
+'ă' => Char {
+    data: [
+        "xx.xx",
+        ".xxx.",
+        ".xxx.",
+        "...xx",
+        ".xxxx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'Ą' => Char {
+    data: [
+        ".xxxx.",
+        "xx..xx",
+        "xxxxxx",
+        "xx..xx",
+        "xx..xx",
+        "...xx.",
+        "....xx",
+    ],
+},
+
+'ą' => Char {
+    data: [
+        ".xxx.",
+        "...xx",
+        ".xxxx",
+        "xx.xx",
+        ".xxx.",
+        "..xx.",
+        "...xx",
+    ],
+},
+
+'Ć' => Char {
+    data: [
+        "..xx..",
+        ".xx...",
+        ".xxxx.",
+        "xx..xx",
+        "xx....",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'ć' => Char {
+    data: [
+        "..xx.",
+        ".xx..",
+        ".xxx.",
+        "xx.xx",
+        "xx...",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'Ĉ' => Char {
+    data: [
+        "..xx..",
+        ".xxxx.",
+        ".xxxx.",
+        "xx..xx",
+        "xx....",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'ĉ' => Char {
+    data: [
+        ".xx..",
+        "xxxx.",
+        ".xxx.",
+        "xx.xx",
+        "xx...",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'Ċ' => Char {
+    data: [
+        "..xx..",
+        "......",
+        ".xxxx.",
+        "xx..xx",
+        "xx....",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'ċ' => Char {
+    data: [
+        ".xx..",
+        ".....",
+        ".xxx.",
+        "xx.xx",
+        "xx...",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'Č' => Char {
+    data: [
+        ".xxxx.",
+        "..xx..",
+        ".xxxx.",
+        "xx..xx",
+        "xx....",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'č' => Char {
+    data: [
+        "xxxx.",
+        ".xx..",
+        ".xxx.",
+        "xx.xx",
+        "xx...",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'Ď' => Char {
+    data: [
+        ".xxxx.",
+        "..xx..",
+        "......",
+        "xxxxx.",
+        "xx..xx",
+        "xx..xx",
+        "xxxxx.",
+    ],
+},
+
+'ď' => Char {
+    data: [
+        "xxxx.",
+        ".xx..",
+        ".....",
+        "...xx",
+        ".xxxx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'Đ' => Char {
+    data: [
+        "xxxxx.",
+        ".xx.xx",
+        ".xx.xx",
+        "xxxxxx",
+        ".xx.xx",
+        ".xx.xx",
+        "xxxxx.",
+    ],
+},
+
+'đ' => Char {
+    data: [
+        "...xx.",
+        "..xxxx",
+        ".xxxx.",
+        "xx.xx.",
+        "xx.xx.",
+        "xx.xx.",
+        ".xxxx.",
+    ],
+},
+
+'Ē' => Char {
+    data: [
+        ".xxxx.",
+        "......",
+        "xxxxxx",
+        "xx....",
+        "xxxxx.",
+        "xx....",
+        "xxxxxx",
+    ],
+},
+
+'ē' => Char {
+    data: [
+        "xxxx.",
+        ".....",
+        ".xxx.",
+        "xx.xx",
+        "xxxxx",
+        "xx...",
+        ".xxx.",
+    ],
+},
+
+'Ĕ' => Char {
+    data: [
+        "xx.xx.",
+        ".xxx..",
+        "xxxxxx",
+        "xx....",
+        "xxxxx.",
+        "xx....",
+        "xxxxxx",
+    ],
+},
+
+'ĕ' => Char {
+    data: [
+        "xx.xx",
+        ".xxx.",
+        ".xxx.",
+        "xx.xx",
+        "xxxxx",
+        "xx...",
+        ".xxx.",
+    ],
+},
+
+'Ė' => Char {
+    data: [
+        "..xx..",
+        "......",
+        "xxxxxx",
+        "xx....",
+        "xxxxx.",
+        "xx....",
+        "xxxxxx",
+    ],
+},
+
+'ė' => Char {
+    data: [
+        ".xx..",
+        ".....",
+        ".xxx.",
+        "xx.xx",
+        "xxxxx",
+        "xx...",
+        ".xxx.",
+    ],
+},
+
+'Ę' => Char {
+    data: [
+        "xxxxxx",
+        "xx....",
+        "xxxxx.",
+        "xx....",
+        "xxxxxx",
+        "...xx.",
+        "....xx",
+    ],
+},
+
+'ę' => Char {
+    data: [
+        ".xxx.",
+        "xx.xx",
+        "xxxxx",
+        "xx...",
+        ".xxx.",
+        "..xx.",
+        "...xx",
+    ],
+},
+
+'Ě' => Char {
+    data: [
+        ".xxxx.",
+        "..xx..",
+        "xxxxxx",
+        "xx....",
+        "xxxxx.",
+        "xx....",
+        "xxxxxx",
+    ],
+},
+
+'ě' => Char {
+    data: [
+        "xxxx.",
+        ".xx..",
+        ".xxx.",
+        "xx.xx",
+        "xxxxx",
+        "xx...",
+        ".xxx.",
+    ],
+},
+
+'Ĝ' => Char {
+    data: [
+        ".xxxx.",
+        ".xxxx.",
+        "xx..xx",
+        "xx....",
+        "xxxxxx",
+        "xx..xx",
+        ".xxxxx",
+    ],
+},
+
+'ĝ' => Char {
+    data: [
+        ".xx..",
+        "xxxx.",
+        ".xxx.",
+        "xx.xx",
+        ".xxxx",
+        "...xx",
+        ".xxx.",
+    ],
+},
+
+'Ğ' => Char {
+    data: [
+        "xx.xx.",
+        ".xxxx.",
+        "xx..xx",
+        "xx....",
+        "xxxxxx",
+        "xx..xx",
+        ".xxxxx",
+    ],
+},
+
+'ğ' => Char {
+    data: [
+        "xx.xx",
+        ".xxx.",
+        ".xxx.",
+        "xx.xx",
+        ".xxxx",
+        "...xx",
+        ".xxx.",
+    ],
+},
+
+'Ġ' => Char {
+    data: [
+        "..xx..",
+        ".xxxx.",
+        "xx..xx",
+        "xx....",
+        "xxxxxx",
+        "xx..xx",
+        ".xxxxx",
+    ],
+},
+
+'ġ' => Char {
+    data: [
+        ".xx..",
+        ".....",
+        ".xxx.",
+        "xx.xx",
+        ".xxxx",
+        "...xx",
+        ".xxx.",
+    ],
+},
+
+'Ģ' => Char {
+    data: [
+        ".xxxx.",
+        "xx..xx",
+        "xx....",
+        "xxxxxx",
+        "xx..xx",
+        ".xxxxx",
+        "..xx..",
+    ],
+},
+
+'ģ' => Char {
+    data: [
+        ".xxx.",
+        "xx.xx",
+        ".xxxx",
+        "...xx",
+        ".xxx.",
+        "..xx.",
+        ".xx..",
+    ],
+},
+
+'Ĥ' => Char {
+    data: [
+        "..xx..",
+        ".xxxx.",
+        "......",
+        "xx..xx",
+        "xxxxxx",
+        "xx..xx",
+        "xx..xx",
+    ],
+},
+
+'ĥ' => Char {
+    data: [
+        ".xx..",
+        "xxxx.",
+        ".....",
+        "xx...",
+        "xxxx.",
+        "xx.xx",
+        "xx.xx",
+    ],
+},
+
+'Ħ' => Char {
+    data: [
+        "xx..xx",
+        "xxxxxx",
+        "xx..xx",
+        "xxxxxx",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+    ],
+},
+
+'ħ' => Char {
+    data: [
+        ".xx...",
+        "xxxx..",
+        ".xx...",
+        ".xxxx.",
+        ".xx.xx",
+        ".xx.xx",
+        ".xx.xx",
+    ],
+},
+
+'Ĩ' => Char {
+    data: [
+        ".xxxx.",
+        "xxxx..",
+        "......",
+        ".xxxx.",
+        "..xx..",
+        "..xx..",
+        "xxxxxx",
+    ],
+},
+
+'ĩ' => Char {
+    data: [
+        ".xxxx",
+        "xxxx.",
+        ".....",
+        "xxx..",
+        ".xx..",
+        ".xx..",
+        "..xx.",
+    ],
+},
+
+'Ī' => Char {
+    data: [
+        ".xxxx.",
+        "......",
+        ".xxxx.",
+        "..xx..",
+        "..xx..",
+        "..xx..",
+        "xxxxxx",
+    ],
+},
+
+'ī' => Char {
+    data: [
+        "xxxx.",
+        ".....",
+        "xxx..",
+        ".xx..",
+        ".xx..",
+        ".xx..",
+        "..xx.",
+    ],
+},
+
+'Ĭ' => Char {
+    data: [
+        "xx.xx.",
+        ".xxx..",
+        "......",
+        ".xxxx.",
+        "..xx..",
+        "..xx..",
+        "xxxxxx",
+    ],
+},
+
+'ĭ' => Char {
+    data: [
+        "xx.xx",
+        ".xxx.",
+        ".....",
+        "xxx..",
+        ".xx..",
+        ".xx..",
+        "..xx.",
+    ],
+},
+
+'Į' => Char {
+    data: [
+        ".xxxx.",
+        "..xx..",
+        "..xx..",
+        "..xx..",
+        "xxxxxx",
+        "...xx.",
+        "....xx",
+    ],
+},
+
+'į' => Char {
+    data: [
+        ".xx..",
+        ".....",
+        "xxx..",
+        ".xx..",
+        "..xx.",
+        "..xx.",
+        "...xx",
+    ],
+},
+
+'İ' => Char {
+    data: [
+        "..xx..",
+        "......",
+        ".xxxx.",
+        "..xx..",
+        "..xx..",
+        "..xx..",
+        "xxxxxx",
+    ],
+},
+
+'ı' => Char {
+    data: [
+        ".....",
+        ".....",
+        "xxx..",
+        ".xx..",
+        ".xx..",
+        ".xx..",
+        "..xx.",
+    ],
+},
+
+'Ĳ' => Char {
+    data: [
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        "xxxxxx",
+        "xx.xx.",
+    ],
+},
+
+'ĳ' => Char {
+    data: [
+        "xx.xx",
+        ".....",
+        "xxxxx",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        "xxxx.",
+    ],
+},
+
+'Ĵ' => Char {
+    data: [
+        "..xx..",
+        ".xxxx.",
+        "......",
+        ".xxxxx",
+        "....xx",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'ĵ' => Char {
+    data: [
+        ".xx..",
+        "xxxx.",
+        ".....",
+        "..xxx",
+        "...xx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'Ķ' => Char {
+    data: [
+        "xx..xx",
+        "xxxx..",
+        "xxx...",
+        "xxxx..",
+        "xx.xx.",
+        "xx..xx",
+        "..xx..",
+    ],
+},
+
+'ķ' => Char {
+    data: [
+        "xx...",
+        "xx.xx",
+        "xxxx.",
+        "xxx..",
+        "xxxx.",
+        "xx.xx",
+        ".xx..",
+    ],
+},
+
+'ĸ' => Char {
+    data: [
+        ".....",
+        ".....",
+        "xx.xx",
+        "xxxx.",
+        "xxx..",
+        "xxxx.",
+        "xx.xx",
+    ],
+},
+
+'Ĺ' => Char {
+    data: [
+        "..xx..",
+        ".xx...",
+        "......",
+        "xx....",
+        "xx....",
+        "xx....",
+        "xxxxxx",
+    ],
+},
+
+'ĺ' => Char {
+    data: [
+        ".xx.",
+        "xx..",
+        "....",
+        "xxx.",
+        ".xx.",
+        ".xx.",
+        "..xx",
+    ],
+},
+
+'Ļ' => Char {
+    data: [
+        "xx....",
+        "xx....",
+        "xx....",
+        "xx....",
+        "xxxxxx",
+        "..xx..",
+        ".xx...",
+    ],
+},
+
+'ļ' => Char {
+    data: [
+        "xxx.",
+        ".xx.",
+        ".xx.",
+        ".xx.",
+        "..xx",
+        ".xx.",
+        "xx..",
+    ],
+},
+
+'Ľ' => Char {
+    data: [
+        ".xxxx.",
+        "..xx..",
+        "......",
+        "xx....",
+        "xx....",
+        "xx....",
+        "xxxxxx",
+    ],
+},
+
+'ľ' => Char {
+    data: [
+        "xxxx",
+        ".xx.",
+        "....",
+        "xxx.",
+        ".xx.",
+        ".xx.",
+        "..xx",
+    ],
+},
+
+'Ŀ' => Char {
+    data: [
+        "xx....",
+        "xx....",
+        "xx....",
+        "xx.xx.",
+        "xx....",
+        "xx....",
+        "xxxxxx",
+    ],
+},
+
+'ŀ' => Char {
+    data: [
+        "xxx...",
+        ".xx...",
+        ".xx...",
+        ".xxxx.",
+        ".xx...",
+        ".xx...",
+        "..xx..",
+    ],
+},
+
+'Ł' => Char {
+    data: [
+        ".xx...",
+        ".xx...",
+        ".xxxx.",
+        ".xxx..",
+        "xxx...",
+        ".xx...",
+        ".xxxxx",
+    ],
+},
+
+'ł' => Char {
+    data: [
+        ".xx..",
+        ".xx..",
+        ".xxx.",
+        "xxx..",
+        ".xx..",
+        ".xx..",
+        "..xx.",
+    ],
+},
+
+'Ń' => Char {
+    data: [
+        "..xx..",
+        ".xx...",
+        "xx..xx",
+        "xxx.xx",
+        "xxxxxx",
+        "xx.xxx",
+        "xx..xx",
+    ],
+},
+
+'ń' => Char {
+    data: [
+        "..xx.",
+        ".xx..",
+        ".....",
+        ".xxx.",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+    ],
+},
+
+'Ņ' => Char {
+    data: [
+        "xx..xx",
+        "xxx.xx",
+        "xxxxxx",
+        "xx.xxx",
+        "xx..xx",
+        "..xx..",
+        ".xx...",
+    ],
+},
+
+'ņ' => Char {
+    data: [
+        ".xxx.",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        "..xx.",
+        ".xx..",
+    ],
+},
+
+'Ň' => Char {
+    data: [
+        ".xxxx.",
+        "..xx..",
+        "xx..xx",
+        "xxx.xx",
+        "xxxxxx",
+        "xx.xxx",
+        "xx..xx",
+    ],
+},
+
+'ň' => Char {
+    data: [
+        "xxxx.",
+        ".xx..",
+        ".....",
+        ".xxx.",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+    ],
+},
+
+'ŉ' => Char {
+    data: [
+        "xx....",
+        "xx....",
+        "..xxx.",
+        ".xx.xx",
+        ".xx.xx",
+        ".xx.xx",
+        ".xx.xx",
+    ],
+},
+
+'Ŋ' => Char {
+    data: [
+        "xx..xx",
+        "xxx.xx",
+        "xxxxxx",
+        "xx.xxx",
+        "xx..xx",
+        "xx..xx",
+        "xxxxx.",
+    ],
+},
+
+'ŋ' => Char {
+    data: [
+        ".....",
+        ".xxx.",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        "xxxx.",
+    ],
+},
+
+'Ō' => Char {
+    data: [
+        ".xxxx.",
+        "......",
+        ".xxxx.",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'ō' => Char {
+    data: [
+        "xxxx.",
+        ".....",
+        ".xxx.",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'Ŏ' => Char {
+    data: [
+        "xx.xx.",
+        ".xxx..",
+        "......",
+        ".xxxx.",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'ŏ' => Char {
+    data: [
+        "xx.xx",
+        ".xxx.",
+        ".....",
+        ".xxx.",
+        "xx.xx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'Ő' => Char {
+    data: [
+        ".xxxx.",
+        "xxxx..",
+        "......",
+        ".xxxx.",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'ő' => Char {
+    data: [
+        ".xxxx",
+        "xxxx.",
+        ".....",
+        ".xxx.",
+        "xx.xx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'Œ' => Char {
+    data: [
+        ".xxxxxxx",
+        "xx.xx...",
+        "xx.xx...",
+        "xx.xxxx.",
+        "xx.xx...",
+        "xx.xx...",
+        ".xxxxxxx",
+    ],
+},
+
+'œ' => Char {
+    data: [
+        "........",
+        ".xxxxxx.",
+        "xx.xx.xx",
+        "xx.xxxxx",
+        "xx.xx...",
+        "xx.xx.xx",
+        ".xxxxxx.",
+    ],
+},
+
+'Ŕ' => Char {
+    data: [
+        "..xx..",
+        "xxxxx.",
+        "xx..xx",
+        "xxxxx.",
+        "xxxx..",
+        "xx.xx.",
+        "xx..xx",
+    ],
+},
+
+'ŕ' => Char {
+    data: [
+        ".xx.",
+        "xx..",
+        "....",
+        ".xxx",
+        "xx..",
+        "xx..",
+        "xx..",
+    ],
+},
+
+'Ŗ' => Char {
+    data: [
+        "xxxxx.",
+        "xx..xx",
+        "xxxxx.",
+        "xxxx..",
+        "xx.xx.",
+        "xx..xx",
+        "..xx..",
+    ],
+},
+
+'ŗ' => Char {
+    data: [
+        ".xxx",
+        "xx..",
+        "xx..",
+        "xx..",
+        "xx..",
+        ".xx.",
+        "xx..",
+    ],
+},
+
+'Ř' => Char {
+    data: [
+        ".xxxx.",
+        "xxxxx.",
+        "xx..xx",
+        "xxxxx.",
+        "xxxx..",
+        "xx.xx.",
+        "xx..xx",
+    ],
+},
+
+'ř' => Char {
+    data: [
+        "xxxx",
+        ".xx.",
+        "....",
+        ".xxx",
+        "xx..",
+        "xx..",
+        "xx..",
+    ],
+},
+
+'Ś' => Char {
+    data: [
+        "..xx..",
+        ".xxxx.",
+        "xx....",
+        ".xxxx.",
+        "....xx",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'ś' => Char {
+    data: [
+        "..xx.",
+        ".xxx.",
+        "xx.xx",
+        ".xx..",
+        "..xx.",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'Ŝ' => Char {
+    data: [
+        ".xxxx.",
+        ".xxxx.",
+        "xx....",
+        ".xxxx.",
+        "....xx",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'ŝ' => Char {
+    data: [
+        "xxxx.",
+        ".xxx.",
+        "xx.xx",
+        ".xx..",
+        "..xx.",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'Ş' => Char {
+    data: [
+        ".xxxx.",
+        "xx....",
+        ".xxxx.",
+        "....xx",
+        "xx..xx",
+        ".xxxx.",
+        "..xx..",
+    ],
+},
+
+'ş' => Char {
+    data: [
+        ".xxx.",
+        "xx.xx",
+        ".xx..",
+        "..xx.",
+        "xx.xx",
+        ".xxx.",
+        ".xx..",
+    ],
+},
+
+'Š' => Char {
+    data: [
+        ".xxxx.",
+        ".xxxx.",
+        "xx....",
+        ".xxxx.",
+        "....xx",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'š' => Char {
+    data: [
+        "xxxx.",
+        ".xxx.",
+        "xx.xx",
+        ".xx..",
+        "..xx.",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'Ţ' => Char {
+    data: [
+        "xxxxxx",
+        "..xx..",
+        "..xx..",
+        "..xx..",
+        "..xx..",
+        "..xx..",
+        ".xx...",
+    ],
+},
+
+'ţ' => Char {
+    data: [
+        "xx..",
+        "xxxx",
+        "xx..",
+        "xx..",
+        ".xxx",
+        ".xx.",
+        "xx..",
+    ],
+},
+
+'Ť' => Char {
+    data: [
+        ".xxxx.",
+        "..xx..",
+        "......",
+        "xxxxxx",
+        "..xx..",
+        "..xx..",
+        "..xx..",
+    ],
+},
+
+'ť' => Char {
+    data: [
+        "xxxx",
+        ".xx.",
+        "....",
+        "xx..",
+        "xxxx",
+        "xx..",
+        ".xxx",
+    ],
+},
+
+'Ŧ' => Char {
+    data: [
+        "xxxxxx",
+        "..xx..",
+        "..xx..",
+        ".xxxx.",
+        "..xx..",
+        "..xx..",
+        "..xx..",
+    ],
+},
+
+'ŧ' => Char {
+    data: [
+        "....",
+        "xx..",
+        "xxxx",
+        "xx..",
+        "xxxx",
+        "xx..",
+        ".xxx",
+    ],
+},
+
+'Ũ' => Char {
+    data: [
+        ".xxxx.",
+        "xxxx..",
+        "......",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'ũ' => Char {
+    data: [
+        ".xxxx",
+        "xxxx.",
+        ".....",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'Ū' => Char {
+    data: [
+        ".xxxx.",
+        "......",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'ū' => Char {
+    data: [
+        "xxxx.",
+        ".....",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'Ŭ' => Char {
+    data: [
+        "xx.xx.",
+        ".xxx..",
+        "......",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'ŭ' => Char {
+    data: [
+        "xx.xx",
+        ".xxx.",
+        ".....",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'Ů' => Char {
+    data: [
+        ".xxx..",
+        ".xxx..",
+        "......",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'ů' => Char {
+    data: [
+        ".xxx.",
+        ".xxx.",
+        ".....",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'Ű' => Char {
+    data: [
+        ".xxxx.",
+        "xxxx..",
+        "......",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'ű' => Char {
+    data: [
+        ".xxxx",
+        "xxxx.",
+        ".....",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'Ų' => Char {
+    data: [
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+        "...xx.",
+        "....xx",
+    ],
+},
+
+'ų' => Char {
+    data: [
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        ".xxx.",
+        "..xx.",
+        "...xx",
+    ],
+},
+
+'Ŵ' => Char {
+    data: [
+        "..xx..",
+        ".xxxx.",
+        "......",
+        "xx..xx",
+        "xxxxxx",
+        "xxxxxx",
+        ".xxxx.",
+    ],
+},
+
+'ŵ' => Char {
+    data: [
+        "..xx..",
+        ".xxxx.",
+        "......",
+        "xx..xx",
+        "xxxxxx",
+        "xxxxxx",
+        ".xxxx.",
+    ],
+},
+
+'Ŷ' => Char {
+    data: [
+        "..xx..",
+        ".xxxx.",
+        "......",
+        "xx..xx",
+        ".xxxx.",
+        "..xx..",
+        "..xx..",
+    ],
+},
+
+'ŷ' => Char {
+    data: [
+        ".xx..",
+        "xxxx.",
+        ".....",
+        "xx.xx",
+        ".xxxx",
+        "...xx",
+        ".xxx.",
+    ],
+},
+
+'Ÿ' => Char {
+    data: [
+        ".xxxx.",
+        "......",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+        "..xx..",
+        "..xx..",
+    ],
+},
+
+'Ź' => Char {
+    data: [
+        "..xx..",
+        "xxxxxx",
+        "...xx.",
+        "..xx..",
+        ".xx...",
+        "xx....",
+        "xxxxxx",
+    ],
+},
+
+'ź' => Char {
+    data: [
+        "..xx.",
+        "xxxxx",
+        "...xx",
+        "..xx.",
+        ".xx..",
+        "xx...",
+        "xxxxx",
+    ],
+},
+
+'Ż' => Char {
+    data: [
+        "..xx..",
+        "xxxxxx",
+        "...xx.",
+        "..xx..",
+        ".xx...",
+        "xx....",
+        "xxxxxx",
+    ],
+},
+
+'ż' => Char {
+    data: [
+        ".xx..",
+        "xxxxx",
+        "...xx",
+        "..xx.",
+        ".xx..",
+        "xx...",
+        "xxxxx",
+    ],
+},
+
+'Ž' => Char {
+    data: [
+        ".xxxx.",
+        "xxxxxx",
+        "...xx.",
+        "..xx..",
+        ".xx...",
+        "xx....",
+        "xxxxxx",
+    ],
+},
+
+'ž' => Char {
+    data: [
+        "xxxx.",
+        "xxxxx",
+        "...xx",
+        "..xx.",
+        ".xx..",
+        "xx...",
+        "xxxxx",
+    ],
+},
+
+'ſ' => Char {
+    data: [
+        ".xxx",
+        "xx..",
+        "xx..",
+        "xx..",
+        "xx..",
+        "xx..",
+        "xx..",
+    ],
+},
+
+'Ǎ' => Char {
+    data: [
+        ".xxxx.",
+        "..xx..",
+        "......",
+        ".xxxx.",
+        "xx..xx",
+        "xxxxxx",
+        "xx..xx",
+    ],
+},
+
+'ǎ' => Char {
+    data: [
+        "xxxx.",
+        ".xx..",
+        ".xxx.",
+        "...xx",
+        ".xxxx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'Ǐ' => Char {
+    data: [
+        ".xxxx.",
+        "..xx..",
+        "......",
+        ".xxxx.",
+        "..xx..",
+        "..xx..",
+        "xxxxxx",
+    ],
+},
+
+'ǐ' => Char {
+    data: [
+        "xxxx.",
+        ".xx..",
+        ".....",
+        "xxx..",
+        ".xx..",
+        ".xx..",
+        "..xx.",
+    ],
+},
+
+'Ǒ' => Char {
+    data: [
+        ".xxxx.",
+        "..xx..",
+        "......",
+        ".xxxx.",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'ǒ' => Char {
+    data: [
+        "xxxx.",
+        ".xx..",
+        ".....",
+        ".xxx.",
+        "xx.xx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'Ǔ' => Char {
+    data: [
+        ".xxxx.",
+        "..xx..",
+        "......",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'ǔ' => Char {
+    data: [
+        "xxxx.",
+        ".xx..",
+        ".....",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'Ǣ' => Char {
+    data: [
+        "..xxxx..",
+        "........",
+        ".xxxxxxx",
+        "xx.xx...",
+        "xxxxxxx.",
+        "xx.xx...",
+        "xx.xxxxx",
+    ],
+},
+
+'ǣ' => Char {
+    data: [
+        "..xxxx..",
+        ".xxxxxx.",
+        "...xx.xx",
+        ".xxxxxxx",
+        "xx.xx...",
+        "xx.xx.xx",
+        ".xxxxxx.",
+    ],
+},
+
+'Ǧ' => Char {
+    data: [
+        ".xxxx.",
+        ".xxxx.",
+        "xx..xx",
+        "xx....",
+        "xxxxxx",
+        "xx..xx",
+        ".xxxxx",
+    ],
+},
+
+'ǧ' => Char {
+    data: [
+        "xxxx.",
+        ".xx..",
+        ".xxx.",
+        "xx.xx",
+        ".xxxx",
+        "...xx",
+        ".xxx.",
+    ],
+},
+
+'Ǩ' => Char {
+    data: [
+        ".xxxx.",
+        "xx..xx",
+        "xxxx..",
+        "xxx...",
+        "xxxx..",
+        "xx.xx.",
+        "xx..xx",
+    ],
+},
+
+'ǩ' => Char {
+    data: [
+        "xxxx.",
+        "xx...",
+        "xx.xx",
+        "xxxx.",
+        "xxx..",
+        "xxxx.",
+        "xx.xx",
+    ],
+},
+
+'Ǫ' => Char {
+    data: [
+        ".xxxx.",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+        "...xx.",
+        "....xx",
+    ],
+},
+
+'ǫ' => Char {
+    data: [
+        ".xxx.",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        ".xxx.",
+        "..xx.",
+        "...xx",
+    ],
+},
+
+'ǰ' => Char {
+    data: [
+        "xxxx.",
+        ".xx..",
+        ".....",
+        "..xxx",
+        "...xx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'Ǵ' => Char {
+    data: [
+        "..xx..",
+        ".xxxx.",
+        "xx..xx",
+        "xx....",
+        "xxxxxx",
+        "xx..xx",
+        ".xxxxx",
+    ],
+},
+
+'ǵ' => Char {
+    data: [
+        "..xx.",
+        ".xx..",
+        ".xxx.",
+        "xx.xx",
+        ".xxxx",
+        "...xx",
+        ".xxx.",
+    ],
+},
+
+'Ǹ' => Char {
+    data: [
+        ".xx...",
+        "..xx..",
+        "xx..xx",
+        "xxx.xx",
+        "xxxxxx",
+        "xx.xxx",
+        "xx..xx",
+    ],
+},
+
+'ǹ' => Char {
+    data: [
+        ".xx..",
+        "..xx.",
+        ".....",
+        ".xxx.",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+    ],
+},
+
+'Ǽ' => Char {
+    data: [
+        "...xx...",
+        "..xx....",
+        ".xxxxxxx",
+        "xx.xx...",
+        "xxxxxxx.",
+        "xx.xx...",
+        "xx.xxxxx",
+    ],
+},
+
+'ǽ' => Char {
+    data: [
+        "...xx...",
+        ".xxxxxx.",
+        "...xx.xx",
+        ".xxxxxxx",
+        "xx.xx...",
+        "xx.xx.xx",
+        ".xxxxxx.",
+    ],
+},
+
+'Ǿ' => Char {
+    data: [
+        "..xx..",
+        ".xxxx.",
+        "xx.xxx",
+        "xxxxxx",
+        "xxx.xx",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'ǿ' => Char {
+    data: [
+        "..xx..",
+        "....xx",
+        ".xxxx.",
+        "xxxxxx",
+        "xxx.xx",
+        ".xxxx.",
+        "xx....",
+    ],
+},
+
+'Ș' => Char {
+    data: [
+        ".xxxx.",
+        "xx....",
+        ".xxxx.",
+        "....xx",
+        "xx..xx",
+        ".xxxx.",
+        "..xx..",
+    ],
+},
+
+'ș' => Char {
+    data: [
+        ".xxx.",
+        "xx.xx",
+        ".xx..",
+        "..xx.",
+        "xx.xx",
+        ".xxx.",
+        ".xx..",
+    ],
+},
+
+'Ț' => Char {
+    data: [
+        "xxxxxx",
+        "..xx..",
+        "..xx..",
+        "..xx..",
+        "..xx..",
+        "..xx..",
+        "..xx..",
+    ],
+},
+
+'ț' => Char {
+    data: [
+        "xx..",
+        "xxxx",
+        "xx..",
+        "xx..",
+        "xx..",
+        ".xxx",
+        ".xx.",
+    ],
+},
+
+'Ȟ' => Char {
+    data: [
+        ".xxxx.",
+        "..xx..",
+        "......",
+        "xx..xx",
+        "xxxxxx",
+        "xx..xx",
+        "xx..xx",
+    ],
+},
+
+'ȟ' => Char {
+    data: [
+        "xxxx.",
+        ".xx..",
+        ".....",
+        "xx...",
+        "xxxx.",
+        "xx.xx",
+        "xx.xx",
+    ],
+},
+
+'Ȧ' => Char {
+    data: [
+        "..xx..",
+        "......",
+        ".xxxx.",
+        "xx..xx",
+        "xxxxxx",
+        "xx..xx",
+        "xx..xx",
+    ],
+},
+
+'ȧ' => Char {
+    data: [
+        ".xx..",
+        ".....",
+        ".xxx.",
+        "...xx",
+        ".xxxx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'Ȩ' => Char {
+    data: [
+        "xxxxxx",
+        "xx....",
+        "xxxxx.",
+        "xx....",
+        "xxxxxx",
+        "..xx..",
+        ".xx...",
+    ],
+},
+
+'ȩ' => Char {
+    data: [
+        ".xxx.",
+        "xx.xx",
+        "xxxxx",
+        "xx...",
+        ".xxx.",
+        "..xx.",
+        ".xx..",
+    ],
+},
+
+'Ȯ' => Char {
+    data: [
+        "..xx..",
+        "......",
+        ".xxxx.",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'ȯ' => Char {
+    data: [
+        ".xx..",
+        ".....",
+        ".xxx.",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'Ȳ' => Char {
+    data: [
+        ".xxxx.",
+        "......",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+        "..xx..",
+        "..xx..",
+    ],
+},
+
+'ȳ' => Char {
+    data: [
+        "xxxx.",
+        ".....",
+        "xx.xx",
+        "xx.xx",
+        ".xxxx",
+        "...xx",
+        ".xxx.",
+    ],
+},
+
+'ȷ' => Char {
+    data: [
+        ".....",
+        ".....",
+        "..xxx",
+        "...xx",
+        "...xx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'Ḃ' => Char {
+    data: [
+        "..xx..",
+        "......",
+        "xxxxx.",
+        "xx..xx",
+        "xxxxx.",
+        "xx..xx",
+        "xxxxx.",
+    ],
+},
+
+'ḃ' => Char {
+    data: [
+        ".xx..",
+        ".....",
+        "xx...",
+        "xxxx.",
+        "xx.xx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'Ḅ' => Char {
+    data: [
+        "xxxxx.",
+        "xx..xx",
+        "xxxxx.",
+        "xx..xx",
+        "xx..xx",
+        "xxxxx.",
+        "..xx..",
+    ],
+},
+
+'ḅ' => Char {
+    data: [
+        "xx...",
+        "xx...",
+        "xxxx.",
+        "xx.xx",
+        "xx.xx",
+        ".xxx.",
+        ".xx..",
+    ],
+},
+
+'Ḇ' => Char {
+    data: [
+        "xxxxx.",
+        "xx..xx",
+        "xxxxx.",
+        "xx..xx",
+        "xx..xx",
+        "xxxxx.",
+        ".xxxx.",
+    ],
+},
+
+'ḇ' => Char {
+    data: [
+        "xx...",
+        "xx...",
+        "xxxx.",
+        "xx.xx",
+        "xx.xx",
+        ".xxx.",
+        "xxxx.",
+    ],
+},
+
+'Ḋ' => Char {
+    data: [
+        "..xx..",
+        "......",
+        "xxxxx.",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        "xxxxx.",
+    ],
+},
+
+'ḋ' => Char {
+    data: [
+        ".xx..",
+        ".....",
+        "...xx",
+        ".xxxx",
+        "xx.xx",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'Ḍ' => Char {
+    data: [
+        "xxxxx.",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        "xxxxx.",
+        "..xx..",
+    ],
+},
+
+'ḍ' => Char {
+    data: [
+        "...xx",
+        "...xx",
+        ".xxxx",
+        "xx.xx",
+        "xx.xx",
+        ".xxx.",
+        ".xx..",
+    ],
+},
+
+'Ḏ' => Char {
+    data: [
+        "xxxxx.",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        "xxxxx.",
+        ".xxxx.",
+    ],
+},
+
+'ḏ' => Char {
+    data: [
+        "...xx",
+        "...xx",
+        ".xxxx",
+        "xx.xx",
+        "xx.xx",
+        ".xxx.",
+        "xxxx.",
+    ],
+},
+
+'Ḑ' => Char {
+    data: [
+        "xxxxx.",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        "xxxxx.",
+        "..xx..",
+        ".xx...",
+    ],
+},
+
+'ḑ' => Char {
+    data: [
+        "...xx",
+        ".xxxx",
+        "xx.xx",
+        "xx.xx",
+        ".xxx.",
+        "..xx.",
+        ".xx..",
+    ],
+},
+
+'Ḟ' => Char {
+    data: [
+        "..xx..",
+        "......",
+        "xxxxxx",
+        "xx....",
+        "xxxxx.",
+        "xx....",
+        "xx....",
+    ],
+},
+
+'ḟ' => Char {
+    data: [
+        ".xx.",
+        "....",
+        ".xxx",
+        "xx..",
+        "xxx.",
+        "xx..",
+        "xx..",
+    ],
+},
+
+'Ḡ' => Char {
+    data: [
+        ".xxxx.",
+        ".xxxx.",
+        "xx..xx",
+        "xx....",
+        "xxxxxx",
+        "xx..xx",
+        ".xxxxx",
+    ],
+},
+
+'ḡ' => Char {
+    data: [
+        "xxxx.",
+        ".....",
+        ".xxx.",
+        "xx.xx",
+        ".xxxx",
+        "...xx",
+        ".xxx.",
+    ],
+},
+
+'Ḣ' => Char {
+    data: [
+        "..xx..",
+        "......",
+        "xx..xx",
+        "xxxxxx",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+    ],
+},
+
+'ḣ' => Char {
+    data: [
+        ".xx..",
+        ".....",
+        "xx...",
+        "xx...",
+        "xxxx.",
+        "xx.xx",
+        "xx.xx",
+    ],
+},
+
+'Ḥ' => Char {
+    data: [
+        "xx..xx",
+        "xx..xx",
+        "xxxxxx",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        "..xx..",
+    ],
+},
+
+'ḥ' => Char {
+    data: [
+        "xx...",
+        "xx...",
+        "xxxx.",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        ".xx..",
+    ],
+},
+
+'Ḧ' => Char {
+    data: [
+        ".xxxx.",
+        "......",
+        "xx..xx",
+        "xxxxxx",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+    ],
+},
+
+'ḧ' => Char {
+    data: [
+        "xx.xx",
+        ".....",
+        "xx...",
+        "xx...",
+        "xxxx.",
+        "xx.xx",
+        "xx.xx",
+    ],
+},
+
+'Ḩ' => Char {
+    data: [
+        "xx..xx",
+        "xxxxxx",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        "..xx..",
+        ".xx...",
+    ],
+},
+
+'ḩ' => Char {
+    data: [
+        "xx...",
+        "xx...",
+        "xxxx.",
+        "xx.xx",
+        "xx.xx",
+        "..xx.",
+        ".xx..",
+    ],
+},
+
+'Ḱ' => Char {
+    data: [
+        "..xx..",
+        "xx..xx",
+        "xxxx..",
+        "xxx...",
+        "xxxx..",
+        "xx.xx.",
+        "xx..xx",
+    ],
+},
+
+'ḱ' => Char {
+    data: [
+        "..xx.",
+        "xx...",
+        "xx.xx",
+        "xxxx.",
+        "xxx..",
+        "xxxx.",
+        "xx.xx",
+    ],
+},
+
+'Ḳ' => Char {
+    data: [
+        "xx..xx",
+        "xxxx..",
+        "xxx...",
+        "xxxx..",
+        "xx.xx.",
+        "xx..xx",
+        "..xx..",
+    ],
+},
+
+'ḳ' => Char {
+    data: [
+        "xx...",
+        "xx.xx",
+        "xxxx.",
+        "xxx..",
+        "xxxx.",
+        "xx.xx",
+        ".xx..",
+    ],
+},
+
+'Ḵ' => Char {
+    data: [
+        "xx..xx",
+        "xxxx..",
+        "xxx...",
+        "xxxx..",
+        "xx.xx.",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'ḵ' => Char {
+    data: [
+        "xx...",
+        "xx.xx",
+        "xxxx.",
+        "xxx..",
+        "xxxx.",
+        "xx.xx",
+        "xxxx.",
+    ],
+},
+
+'Ḷ' => Char {
+    data: [
+        "xx....",
+        "xx....",
+        "xx....",
+        "xx....",
+        "xx....",
+        "xxxxxx",
+        "..xx..",
+    ],
+},
+
+'ḷ' => Char {
+    data: [
+        "xxx.",
+        ".xx.",
+        ".xx.",
+        ".xx.",
+        ".xx.",
+        "..xx",
+        ".xx.",
+    ],
+},
+
+'Ḻ' => Char {
+    data: [
+        "xx....",
+        "xx....",
+        "xx....",
+        "xx....",
+        "xx....",
+        "xxxxxx",
+        ".xxxx.",
+    ],
+},
+
+'ḻ' => Char {
+    data: [
+        "xxx.",
+        ".xx.",
+        ".xx.",
+        ".xx.",
+        ".xx.",
+        "..xx",
+        "xxxx",
+    ],
+},
+
+'Ḿ' => Char {
+    data: [
+        "..xx..",
+        ".xx...",
+        "......",
+        "xxxxxx",
+        "xxxxxx",
+        "xx..xx",
+        "xx..xx",
+    ],
+},
+
+'ḿ' => Char {
+    data: [
+        "..xx..",
+        ".xx...",
+        "......",
+        ".xxxx.",
+        "xxxxxx",
+        "xxxxxx",
+        "xxxxxx",
+    ],
+},
+
+'Ṁ' => Char {
+    data: [
+        "..xx..",
+        "......",
+        "xxxxxx",
+        "xxxxxx",
+        "xxxxxx",
+        "xx..xx",
+        "xx..xx",
+    ],
+},
+
+'ṁ' => Char {
+    data: [
+        "..xx..",
+        "......",
+        ".xxxx.",
+        "xxxxxx",
+        "xxxxxx",
+        "xxxxxx",
+        "xxxxxx",
+    ],
+},
+
+'Ṃ' => Char {
+    data: [
+        "xxxxxx",
+        "xxxxxx",
+        "xxxxxx",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        "..xx..",
+    ],
+},
+
+'ṃ' => Char {
+    data: [
+        ".xxxx.",
+        "xxxxxx",
+        "xxxxxx",
+        "xxxxxx",
+        "xxxxxx",
+        "xxxxxx",
+        "..xx..",
+    ],
+},
+
+'Ṅ' => Char {
+    data: [
+        "..xx..",
+        "......",
+        "xx..xx",
+        "xxx.xx",
+        "xxxxxx",
+        "xx.xxx",
+        "xx..xx",
+    ],
+},
+
+'ṅ' => Char {
+    data: [
+        ".xx..",
+        ".....",
+        ".xxx.",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+    ],
+},
+
+'Ṇ' => Char {
+    data: [
+        "xx..xx",
+        "xxx.xx",
+        "xxxxxx",
+        "xx.xxx",
+        "xx..xx",
+        "xx..xx",
+        "..xx..",
+    ],
+},
+
+'ṇ' => Char {
+    data: [
+        ".xxx.",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        ".xx..",
+    ],
+},
+
+'Ṉ' => Char {
+    data: [
+        "xx..xx",
+        "xxx.xx",
+        "xxxxxx",
+        "xx.xxx",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'ṉ' => Char {
+    data: [
+        ".xxx.",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        "xxxx.",
+    ],
+},
+
+'Ṕ' => Char {
+    data: [
+        "..xx..",
+        ".xx...",
+        "......",
+        "xxxxx.",
+        "xx..xx",
+        "xxxxx.",
+        "xx....",
+    ],
+},
+
+'ṕ' => Char {
+    data: [
+        "..xx.",
+        ".xx..",
+        ".....",
+        ".xxx.",
+        "xx.xx",
+        "xxxx.",
+        "xx...",
+    ],
+},
+
+'Ṗ' => Char {
+    data: [
+        "..xx..",
+        "......",
+        "xxxxx.",
+        "xx..xx",
+        "xxxxx.",
+        "xx....",
+        "xx....",
+    ],
+},
+
+'ṗ' => Char {
+    data: [
+        ".xx..",
+        ".....",
+        ".xxx.",
+        "xx.xx",
+        "xxxx.",
+        "xx...",
+        "xx...",
+    ],
+},
+
+'Ṙ' => Char {
+    data: [
+        "..xx..",
+        "xxxxx.",
+        "xx..xx",
+        "xxxxx.",
+        "xxxx..",
+        "xx.xx.",
+        "xx..xx",
+    ],
+},
+
+'ṙ' => Char {
+    data: [
+        ".xx.",
+        "....",
+        ".xxx",
+        "xx..",
+        "xx..",
+        "xx..",
+        "xx..",
+    ],
+},
+
+'Ṛ' => Char {
+    data: [
+        "xxxxx.",
+        "xx..xx",
+        "xxxxx.",
+        "xxxx..",
+        "xx.xx.",
+        "xx..xx",
+        "..xx..",
+    ],
+},
+
+'ṛ' => Char {
+    data: [
+        ".xxx",
+        "xx..",
+        "xx..",
+        "xx..",
+        "xx..",
+        "xx..",
+        ".xx.",
+    ],
+},
+
+'Ṟ' => Char {
+    data: [
+        "xxxxx.",
+        "xx..xx",
+        "xxxxx.",
+        "xxxx..",
+        "xx.xx.",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'ṟ' => Char {
+    data: [
+        ".xxx",
+        "xx..",
+        "xx..",
+        "xx..",
+        "xx..",
+        "xx..",
+        "xxxx",
+    ],
+},
+
+'Ṡ' => Char {
+    data: [
+        "..xx..",
+        ".xxxx.",
+        "xx....",
+        ".xxxx.",
+        "....xx",
+        "xx..xx",
+        ".xxxx.",
+    ],
+},
+
+'ṡ' => Char {
+    data: [
+        ".xx..",
+        ".xxx.",
+        "xx.xx",
+        ".xx..",
+        "..xx.",
+        "xx.xx",
+        ".xxx.",
+    ],
+},
+
+'Ṣ' => Char {
+    data: [
+        ".xxxx.",
+        "xx....",
+        ".xxxx.",
+        "....xx",
+        "xx..xx",
+        ".xxxx.",
+        "..xx..",
+    ],
+},
+
+'ṣ' => Char {
+    data: [
+        ".xxx.",
+        "xx.xx",
+        ".xx..",
+        "..xx.",
+        "xx.xx",
+        ".xxx.",
+        ".xx..",
+    ],
+},
+
+'Ṫ' => Char {
+    data: [
+        "..xx..",
+        "......",
+        "xxxxxx",
+        "..xx..",
+        "..xx..",
+        "..xx..",
+        "..xx..",
+    ],
+},
+
+'ṫ' => Char {
+    data: [
+        ".xx.",
+        "....",
+        "xx..",
+        "xxxx",
+        "xx..",
+        "xx..",
+        ".xxx",
+    ],
+},
+
+'Ṭ' => Char {
+    data: [
+        "xxxxxx",
+        "..xx..",
+        "..xx..",
+        "..xx..",
+        "..xx..",
+        "..xx..",
+        "..xx..",
+    ],
+},
+
+'ṭ' => Char {
+    data: [
+        "xx..",
+        "xxxx",
+        "xx..",
+        "xx..",
+        "xx..",
+        ".xxx",
+        ".xx.",
+    ],
+},
+
+'Ṯ' => Char {
+    data: [
+        "xxxxxx",
+        "..xx..",
+        "..xx..",
+        "..xx..",
+        "..xx..",
+        "..xx..",
+        ".xxxx.",
+    ],
+},
+
+'ṯ' => Char {
+    data: [
+        "xx..",
+        "xxxx",
+        "xx..",
+        "xx..",
+        "xx..",
+        ".xxx",
+        "xxxx",
+    ],
+},
+
+'Ṽ' => Char {
+    data: [
+        ".xxxx.",
+        "xxxx..",
+        "......",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+        "..xx..",
+    ],
+},
+
+'ṽ' => Char {
+    data: [
+        ".xxxx.",
+        "xxxx..",
+        "......",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+        "..xx..",
+    ],
+},
+
+'Ṿ' => Char {
+    data: [
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+        "..xx..",
+        "..xx..",
+    ],
+},
+
+'ṿ' => Char {
+    data: [
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+        "..xx..",
+        "..xx..",
+    ],
+},
+
+'Ẁ' => Char {
+    data: [
+        ".xx...",
+        "..xx..",
+        "......",
+        "xx..xx",
+        "xxxxxx",
+        "xxxxxx",
+        ".xxxx.",
+    ],
+},
+
+'ẁ' => Char {
+    data: [
+        ".xx...",
+        "..xx..",
+        "......",
+        "xx..xx",
+        "xxxxxx",
+        "xxxxxx",
+        ".xxxx.",
+    ],
+},
+
+'Ẃ' => Char {
+    data: [
+        "..xx..",
+        ".xx...",
+        "......",
+        "xx..xx",
+        "xxxxxx",
+        "xxxxxx",
+        ".xxxx.",
+    ],
+},
+
+'ẃ' => Char {
+    data: [
+        "..xx..",
+        ".xx...",
+        "......",
+        "xx..xx",
+        "xxxxxx",
+        "xxxxxx",
+        ".xxxx.",
+    ],
+},
+
+'Ẅ' => Char {
+    data: [
+        ".xxxx.",
+        "......",
+        "xx..xx",
+        "xx..xx",
+        "xxxxxx",
+        "xxxxxx",
+        ".xxxx.",
+    ],
+},
+
+'ẅ' => Char {
+    data: [
+        ".xxxx.",
+        "......",
+        "xx..xx",
+        "xxxxxx",
+        "xxxxxx",
+        "xxxxxx",
+        ".xxxx.",
+    ],
+},
+
+'Ẇ' => Char {
+    data: [
+        "..xx..",
+        "......",
+        "xx..xx",
+        "xx..xx",
+        "xxxxxx",
+        "xxxxxx",
+        ".xxxx.",
+    ],
+},
+
+'ẇ' => Char {
+    data: [
+        "..xx..",
+        "......",
+        "xx..xx",
+        "xxxxxx",
+        "xxxxxx",
+        "xxxxxx",
+        ".xxxx.",
+    ],
+},
+
+'Ẉ' => Char {
+    data: [
+        "xx..xx",
+        "xx..xx",
+        "xxxxxx",
+        "xxxxxx",
+        "xxxxxx",
+        ".xxxx.",
+        "..xx..",
+    ],
+},
+
+'ẉ' => Char {
+    data: [
+        "xx..xx",
+        "xxxxxx",
+        "xxxxxx",
+        "xxxxxx",
+        "xxxxxx",
+        ".xxxx.",
+        "..xx..",
+    ],
+},
+
+'Ẋ' => Char {
+    data: [
+        "..xx..",
+        "......",
+        "xx..xx",
+        ".xxxx.",
+        "..xx..",
+        ".xxxx.",
+        "xx..xx",
+    ],
+},
+
+'ẋ' => Char {
+    data: [
+        "..xx..",
+        "......",
+        "xx..xx",
+        ".xxxx.",
+        "..xx..",
+        ".xxxx.",
+        "xx..xx",
+    ],
+},
+
+'Ẍ' => Char {
+    data: [
+        ".xxxx.",
+        "......",
+        "xx..xx",
+        ".xxxx.",
+        "..xx..",
+        ".xxxx.",
+        "xx..xx",
+    ],
+},
+
+'ẍ' => Char {
+    data: [
+        ".xxxx.",
+        "......",
+        "xx..xx",
+        ".xxxx.",
+        "..xx..",
+        ".xxxx.",
+        "xx..xx",
+    ],
+},
+
+'Ẏ' => Char {
+    data: [
+        "..xx..",
+        "......",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+        "..xx..",
+        "..xx..",
+    ],
+},
+
+'ẏ' => Char {
+    data: [
+        ".xx..",
+        ".....",
+        "xx.xx",
+        "xx.xx",
+        ".xxxx",
+        "...xx",
+        ".xxx.",
+    ],
+},
+
+'Ẑ' => Char {
+    data: [
+        ".xxxx.",
+        "xxxxxx",
+        "...xx.",
+        "..xx..",
+        ".xx...",
+        "xx....",
+        "xxxxxx",
+    ],
+},
+
+'ẑ' => Char {
+    data: [
+        "xxxx.",
+        "xxxxx",
+        "...xx",
+        "..xx.",
+        ".xx..",
+        "xx...",
+        "xxxxx",
+    ],
+},
+
+'Ẓ' => Char {
+    data: [
+        "xxxxxx",
+        "...xx.",
+        "..xx..",
+        ".xx...",
+        "xx....",
+        "xxxxxx",
+        "..xx..",
+    ],
+},
+
+'ẓ' => Char {
+    data: [
+        "xxxxx",
+        "...xx",
+        "..xx.",
+        ".xx..",
+        "xx...",
+        "xxxxx",
+        ".xx..",
+    ],
+},
+
+'Ẕ' => Char {
+    data: [
+        "xxxxxx",
+        "...xx.",
+        "..xx..",
+        ".xx...",
+        "xx....",
+        "xxxxxx",
+        ".xxxx.",
+    ],
+},
+
+'ẕ' => Char {
+    data: [
+        "xxxxx",
+        "...xx",
+        "..xx.",
+        ".xx..",
+        "xx...",
+        "xxxxx",
+        "xxxx.",
+    ],
+},
+
+'ẖ' => Char {
+    data: [
+        "xx...",
+        "xx...",
+        "xxxx.",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        "xxxx.",
+    ],
+},
+
+'ẗ' => Char {
+    data: [
+        "xxxx",
+        "....",
+        "xx..",
+        "xxxx",
+        "xx..",
+        "xx..",
+        ".xxx",
+    ],
+},
+
+'ẘ' => Char {
+    data: [
+        ".xxx..",
+        ".xxx..",
+        "......",
+        "xx..xx",
+        "xxxxxx",
+        "xxxxxx",
+        ".xxxx.",
+    ],
+},
+
+'ẙ' => Char {
+    data: [
+        ".xxx.",
+        ".xxx.",
+        ".....",
+        "xx.xx",
+        ".xxxx",
+        "...xx",
+        ".xxx.",
+    ],
+},
+
+'ẛ' => Char {
+    data: [
+        ".xx.",
+        "....",
+        ".xxx",
+        "xx..",
+        "xx..",
+        "xx..",
+        "xx..",
+    ],
+},
+
+'Ạ' => Char {
+    data: [
+        ".xxxx.",
+        "xx..xx",
+        "xxxxxx",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        "..xx..",
+    ],
+},
+
+'ạ' => Char {
+    data: [
+        ".xxx.",
+        "...xx",
+        ".xxxx",
+        "xx.xx",
+        "xx.xx",
+        ".xxx.",
+        ".xx..",
+    ],
+},
+
+'Ẹ' => Char {
+    data: [
+        "xxxxxx",
+        "xx....",
+        "xxxxx.",
+        "xx....",
+        "xx....",
+        "xxxxxx",
+        "..xx..",
+    ],
+},
+
+'ẹ' => Char {
+    data: [
+        ".xxx.",
+        "xx.xx",
+        "xx.xx",
+        "xxxxx",
+        "xx...",
+        ".xxx.",
+        ".xx..",
+    ],
+},
+
+'Ẽ' => Char {
+    data: [
+        ".xxxx.",
+        "xxxx..",
+        "xxxxxx",
+        "xx....",
+        "xxxxx.",
+        "xx....",
+        "xxxxxx",
+    ],
+},
+
+'ẽ' => Char {
+    data: [
+        ".xxxx",
+        "xxxx.",
+        ".xxx.",
+        "xx.xx",
+        "xxxxx",
+        "xx...",
+        ".xxx.",
+    ],
+},
+
+'Ị' => Char {
+    data: [
+        ".xxxx.",
+        "..xx..",
+        "..xx..",
+        "..xx..",
+        "..xx..",
+        "xxxxxx",
+        "..xx..",
+    ],
+},
+
+'ị' => Char {
+    data: [
+        ".xx..",
+        ".....",
+        "xxx..",
+        ".xx..",
+        ".xx..",
+        "..xx.",
+        ".xx..",
+    ],
+},
+
+'Ọ' => Char {
+    data: [
+        ".xxxx.",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+        "..xx..",
+    ],
+},
+
+'ọ' => Char {
+    data: [
+        ".xxx.",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        ".xxx.",
+        ".xx..",
+    ],
+},
+
+'Ụ' => Char {
+    data: [
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+        "..xx..",
+    ],
+},
+
+'ụ' => Char {
+    data: [
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        ".xxx.",
+        ".xx..",
+    ],
+},
+
+'Ỳ' => Char {
+    data: [
+        ".xx...",
+        "..xx..",
+        "......",
+        "xx..xx",
+        ".xxxx.",
+        "..xx..",
+        "..xx..",
+    ],
+},
+
+'ỳ' => Char {
+    data: [
+        ".xx..",
+        "..xx.",
+        ".....",
+        "xx.xx",
+        ".xxxx",
+        "...xx",
+        ".xxx.",
+    ],
+},
+
+'Ỵ' => Char {
+    data: [
+        "xx..xx",
+        "xx..xx",
+        ".xxxx.",
+        "..xx..",
+        "..xx..",
+        "..xx..",
+        "..xx..",
+    ],
+},
+
+'ỵ' => Char {
+    data: [
+        "xx.xx",
+        "xx.xx",
+        "xx.xx",
+        ".xxxx",
+        "...xx",
+        ".xxx.",
+        ".xx..",
+    ],
+},
+
+'Ỹ' => Char {
+    data: [
+        ".xxxx.",
+        "xxxx..",
+        "......",
+        "xx..xx",
+        ".xxxx.",
+        "..xx..",
+        "..xx..",
+    ],
+},
+
+'ỹ' => Char {
+    data: [
+        ".xxxx",
+        "xxxx.",
+        ".....",
+        "xx.xx",
+        ".xxxx",
+        "...xx",
+        ".xxx.",
+    ],
+},
+
+'\u{fffd}' => Char {
+    data: [
+        "xxxxxx",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        "xx..xx",
+        "xxxxxx",
+    ],
+},
+};
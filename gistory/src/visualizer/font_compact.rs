@@ -0,0 +1,4902 @@
+use phf::phf_map;
+
+use crate::visualizer::font::Char;
+
+pub const CHARS_MAPPING: phf::Map<char, Char> = phf_map! {
+
+' ' => Char {
+    data: [
+        ".",
+        ".",
+        ".",
+        ".",
+        ".",
+        ".",
+        ".",
+    ],
+},
+
+'!' => Char {
+    data: [
+        ".",
+        "x",
+        "x",
+        "x",
+        ".",
+        "x",
+        ".",
+    ],
+},
+
+'"' => Char {
+    data: [
+        "...",
+        "x.x",
+        "x.x",
+        "...",
+        "...",
+        "...",
+        "...",
+    ],
+},
+
+'#' => Char {
+    data: [
+        "...",
+        "x.x",
+        "xxx",
+        "x.x",
+        "xxx",
+        "x.x",
+        "...",
+    ],
+},
+
+'$' => Char {
+    data: [
+        "...",
+        ".xx",
+        "xx.",
+        ".x.",
+        ".xx",
+        "xx.",
+        "...",
+    ],
+},
+
+'%' => Char {
+    data: [
+        "...",
+        "x.x",
+        "..x",
+        ".x.",
+        "x..",
+        "x.x",
+        "...",
+    ],
+},
+
+'&' => Char {
+    data: [
+        "...",
+        ".x.",
+        "x.x",
+        ".x.",
+        "x.x",
+        ".xx",
+        "...",
+    ],
+},
+
+'\'' => Char {
+    data: [
+        ".",
+        "x",
+        "x",
+        ".",
+        ".",
+        ".",
+        ".",
+    ],
+},
+
+'(' => Char {
+    data: [
+        "..",
+        ".x",
+        "x.",
+        "x.",
+        "x.",
+        ".x",
+        "..",
+    ],
+},
+
+')' => Char {
+    data: [
+        "..",
+        "x.",
+        ".x",
+        ".x",
+        ".x",
+        "x.",
+        "..",
+    ],
+},
+
+'*' => Char {
+    data: [
+        "...",
+        "x.x",
+        ".x.",
+        "x.x",
+        "...",
+        "...",
+        "...",
+    ],
+},
+
+'+' => Char {
+    data: [
+        "...",
+        "...",
+        ".x.",
+        "xxx",
+        ".x.",
+        "...",
+        "...",
+    ],
+},
+
+',' => Char {
+    data: [
+        "..",
+        "..",
+        "..",
+        "..",
+        ".x",
+        "x.",
+        "..",
+    ],
+},
+
+'-' => Char {
+    data: [
+        "...",
+        "...",
+        "...",
+        "xxx",
+        "...",
+        "...",
+        "...",
+    ],
+},
+
+'.' => Char {
+    data: [
+        ".",
+        ".",
+        ".",
+        ".",
+        ".",
+        "x",
+        ".",
+    ],
+},
+
+'/' => Char {
+    data: [
+        "...",
+        "..x",
+        "..x",
+        ".x.",
+        "x..",
+        "x..",
+        "...",
+    ],
+},
+
+'0' => Char {
+    data: [
+        "...",
+        "xxx",
+        "x.x",
+        "x.x",
+        "x.x",
+        "xxx",
+        "...",
+    ],
+},
+
+'1' => Char {
+    data: [
+        "...",
+        ".x.",
+        "xx.",
+        ".x.",
+        ".x.",
+        "xxx",
+        "...",
+    ],
+},
+
+'2' => Char {
+    data: [
+        "...",
+        "xx.",
+        "..x",
+        ".x.",
+        "x..",
+        "xxx",
+        "...",
+    ],
+},
+
+'3' => Char {
+    data: [
+        "...",
+        "xx.",
+        "..x",
+        ".x.",
+        "..x",
+        "xx.",
+        "...",
+    ],
+},
+
+'4' => Char {
+    data: [
+        "...",
+        "x.x",
+        "x.x",
+        "xxx",
+        "..x",
+        "..x",
+        "...",
+    ],
+},
+
+'5' => Char {
+    data: [
+        "...",
+        "xxx",
+        "x..",
+        "xx.",
+        "..x",
+        "xx.",
+        "...",
+    ],
+},
+
+'6' => Char {
+    data: [
+        "...",
+        ".xx",
+        "x..",
+        "xxx",
+        "x.x",
+        "xxx",
+        "...",
+    ],
+},
+
+'7' => Char {
+    data: [
+        "...",
+        "xxx",
+        "..x",
+        ".x.",
+        ".x.",
+        ".x.",
+        "...",
+    ],
+},
+
+'8' => Char {
+    data: [
+        "...",
+        "xxx",
+        "x.x",
+        "xxx",
+        "x.x",
+        "xxx",
+        "...",
+    ],
+},
+
+'9' => Char {
+    data: [
+        "...",
+        "xxx",
+        "x.x",
+        "xxx",
+        "..x",
+        "xx.",
+        "...",
+    ],
+},
+
+':' => Char {
+    data: [
+        ".",
+        ".",
+        "x",
+        ".",
+        "x",
+        ".",
+        ".",
+    ],
+},
+
+';' => Char {
+    data: [
+        "..",
+        "..",
+        ".x",
+        "..",
+        ".x",
+        "x.",
+        "..",
+    ],
+},
+
+'<' => Char {
+    data: [
+        "...",
+        "..x",
+        ".x.",
+        "x..",
+        ".x.",
+        "..x",
+        "...",
+    ],
+},
+
+'=' => Char {
+    data: [
+        "...",
+        "...",
+        "xxx",
+        "...",
+        "xxx",
+        "...",
+        "...",
+    ],
+},
+
+'>' => Char {
+    data: [
+        "...",
+        "x..",
+        ".x.",
+        "..x",
+        ".x.",
+        "x..",
+        "...",
+    ],
+},
+
+'?' => Char {
+    data: [
+        "...",
+        "xx.",
+        "..x",
+        ".x.",
+        "...",
+        ".x.",
+        "...",
+    ],
+},
+
+'@' => Char {
+    data: [
+        "...",
+        ".x.",
+        "x.x",
+        "x.x",
+        "x..",
+        ".xx",
+        "...",
+    ],
+},
+
+'A' => Char {
+    data: [
+        "...",
+        ".x.",
+        "x.x",
+        "xxx",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'B' => Char {
+    data: [
+        "...",
+        "xx.",
+        "x.x",
+        "xx.",
+        "x.x",
+        "xx.",
+        "...",
+    ],
+},
+
+'C' => Char {
+    data: [
+        "...",
+        ".xx",
+        "x..",
+        "x..",
+        "x..",
+        ".xx",
+        "...",
+    ],
+},
+
+'D' => Char {
+    data: [
+        "...",
+        "xx.",
+        "x.x",
+        "x.x",
+        "x.x",
+        "xx.",
+        "...",
+    ],
+},
+
+'E' => Char {
+    data: [
+        "...",
+        "xxx",
+        "x..",
+        "xx.",
+        "x..",
+        "xxx",
+        "...",
+    ],
+},
+
+'F' => Char {
+    data: [
+        "...",
+        "xxx",
+        "x..",
+        "xx.",
+        "x..",
+        "x..",
+        "...",
+    ],
+},
+
+'G' => Char {
+    data: [
+        "...",
+        ".xx",
+        "x..",
+        "x.x",
+        "x.x",
+        ".xx",
+        "...",
+    ],
+},
+
+'H' => Char {
+    data: [
+        "...",
+        "x.x",
+        "x.x",
+        "xxx",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'I' => Char {
+    data: [
+        "...",
+        "xxx",
+        ".x.",
+        ".x.",
+        ".x.",
+        "xxx",
+        "...",
+    ],
+},
+
+'J' => Char {
+    data: [
+        "...",
+        "..x",
+        "..x",
+        "..x",
+        "x.x",
+        ".x.",
+        "...",
+    ],
+},
+
+'K' => Char {
+    data: [
+        "...",
+        "x.x",
+        "x.x",
+        "xx.",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'L' => Char {
+    data: [
+        "...",
+        "x..",
+        "x..",
+        "x..",
+        "x..",
+        "xxx",
+        "...",
+    ],
+},
+
+'M' => Char {
+    data: [
+        "...",
+        "x.x",
+        "xxx",
+        "xxx",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'N' => Char {
+    data: [
+        "...",
+        "xx.",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'O' => Char {
+    data: [
+        "...",
+        ".x.",
+        "x.x",
+        "x.x",
+        "x.x",
+        ".x.",
+        "...",
+    ],
+},
+
+'P' => Char {
+    data: [
+        "...",
+        "xx.",
+        "x.x",
+        "xx.",
+        "x..",
+        "x..",
+        "...",
+    ],
+},
+
+'Q' => Char {
+    data: [
+        "...",
+        ".x.",
+        "x.x",
+        "x.x",
+        "xx.",
+        ".xx",
+        "...",
+    ],
+},
+
+'R' => Char {
+    data: [
+        "...",
+        "xx.",
+        "x.x",
+        "xx.",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'S' => Char {
+    data: [
+        "...",
+        ".xx",
+        "x..",
+        ".x.",
+        "..x",
+        "xx.",
+        "...",
+    ],
+},
+
+'T' => Char {
+    data: [
+        "...",
+        "xxx",
+        ".x.",
+        ".x.",
+        ".x.",
+        ".x.",
+        "...",
+    ],
+},
+
+'U' => Char {
+    data: [
+        "...",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "xxx",
+        "...",
+    ],
+},
+
+'V' => Char {
+    data: [
+        "...",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        ".x.",
+        "...",
+    ],
+},
+
+'W' => Char {
+    data: [
+        "...",
+        "x.x",
+        "x.x",
+        "xxx",
+        "xxx",
+        "x.x",
+        "...",
+    ],
+},
+
+'X' => Char {
+    data: [
+        "...",
+        "x.x",
+        "x.x",
+        ".x.",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'Y' => Char {
+    data: [
+        "...",
+        "x.x",
+        "x.x",
+        ".x.",
+        ".x.",
+        ".x.",
+        "...",
+    ],
+},
+
+'Z' => Char {
+    data: [
+        "...",
+        "xxx",
+        "..x",
+        ".x.",
+        "x..",
+        "xxx",
+        "...",
+    ],
+},
+
+'[' => Char {
+    data: [
+        "..",
+        "xx",
+        "x.",
+        "x.",
+        "x.",
+        "xx",
+        "..",
+    ],
+},
+
+'\\' => Char {
+    data: [
+        "...",
+        "x..",
+        "x..",
+        ".x.",
+        "..x",
+        "..x",
+        "...",
+    ],
+},
+
+']' => Char {
+    data: [
+        "..",
+        "xx",
+        ".x",
+        ".x",
+        ".x",
+        "xx",
+        "..",
+    ],
+},
+
+'^' => Char {
+    data: [
+        "...",
+        ".x.",
+        "x.x",
+        "...",
+        "...",
+        "...",
+        "...",
+    ],
+},
+
+'_' => Char {
+    data: [
+        "...",
+        "...",
+        "...",
+        "...",
+        "...",
+        "xxx",
+        "...",
+    ],
+},
+
+'`' => Char {
+    data: [
+        "..",
+        "x.",
+        ".x",
+        "..",
+        "..",
+        "..",
+        "..",
+    ],
+},
+
+'a' => Char {
+    data: [
+        "...",
+        ".x.",
+        "x.x",
+        "xxx",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'b' => Char {
+    data: [
+        "...",
+        "xx.",
+        "x.x",
+        "xx.",
+        "x.x",
+        "xx.",
+        "...",
+    ],
+},
+
+'c' => Char {
+    data: [
+        "...",
+        ".xx",
+        "x..",
+        "x..",
+        "x..",
+        ".xx",
+        "...",
+    ],
+},
+
+'d' => Char {
+    data: [
+        "...",
+        "xx.",
+        "x.x",
+        "x.x",
+        "x.x",
+        "xx.",
+        "...",
+    ],
+},
+
+'e' => Char {
+    data: [
+        "...",
+        "xxx",
+        "x..",
+        "xx.",
+        "x..",
+        "xxx",
+        "...",
+    ],
+},
+
+'f' => Char {
+    data: [
+        "...",
+        "xxx",
+        "x..",
+        "xx.",
+        "x..",
+        "x..",
+        "...",
+    ],
+},
+
+'g' => Char {
+    data: [
+        "...",
+        ".xx",
+        "x..",
+        "x.x",
+        "x.x",
+        ".xx",
+        "...",
+    ],
+},
+
+'h' => Char {
+    data: [
+        "...",
+        "x.x",
+        "x.x",
+        "xxx",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'i' => Char {
+    data: [
+        "...",
+        "xxx",
+        ".x.",
+        ".x.",
+        ".x.",
+        "xxx",
+        "...",
+    ],
+},
+
+'j' => Char {
+    data: [
+        "...",
+        "..x",
+        "..x",
+        "..x",
+        "x.x",
+        ".x.",
+        "...",
+    ],
+},
+
+'k' => Char {
+    data: [
+        "...",
+        "x.x",
+        "x.x",
+        "xx.",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'l' => Char {
+    data: [
+        "...",
+        "x..",
+        "x..",
+        "x..",
+        "x..",
+        "xxx",
+        "...",
+    ],
+},
+
+'m' => Char {
+    data: [
+        "...",
+        "x.x",
+        "xxx",
+        "xxx",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'n' => Char {
+    data: [
+        "...",
+        "xx.",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'o' => Char {
+    data: [
+        "...",
+        ".x.",
+        "x.x",
+        "x.x",
+        "x.x",
+        ".x.",
+        "...",
+    ],
+},
+
+'p' => Char {
+    data: [
+        "...",
+        "xx.",
+        "x.x",
+        "xx.",
+        "x..",
+        "x..",
+        "...",
+    ],
+},
+
+'q' => Char {
+    data: [
+        "...",
+        ".x.",
+        "x.x",
+        "x.x",
+        "xx.",
+        ".xx",
+        "...",
+    ],
+},
+
+'r' => Char {
+    data: [
+        "...",
+        "xx.",
+        "x.x",
+        "xx.",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'s' => Char {
+    data: [
+        "...",
+        ".xx",
+        "x..",
+        ".x.",
+        "..x",
+        "xx.",
+        "...",
+    ],
+},
+
+'t' => Char {
+    data: [
+        "...",
+        "xxx",
+        ".x.",
+        ".x.",
+        ".x.",
+        ".x.",
+        "...",
+    ],
+},
+
+'u' => Char {
+    data: [
+        "...",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "xxx",
+        "...",
+    ],
+},
+
+'v' => Char {
+    data: [
+        "...",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        ".x.",
+        "...",
+    ],
+},
+
+'w' => Char {
+    data: [
+        "...",
+        "x.x",
+        "x.x",
+        "xxx",
+        "xxx",
+        "x.x",
+        "...",
+    ],
+},
+
+'x' => Char {
+    data: [
+        "...",
+        "x.x",
+        "x.x",
+        ".x.",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'y' => Char {
+    data: [
+        "...",
+        "x.x",
+        "x.x",
+        ".x.",
+        ".x.",
+        ".x.",
+        "...",
+    ],
+},
+
+'z' => Char {
+    data: [
+        "...",
+        "xxx",
+        "..x",
+        ".x.",
+        "x..",
+        "xxx",
+        "...",
+    ],
+},
+
+'{' => Char {
+    data: [
+        "...",
+        ".xx",
+        ".x.",
+        "xx.",
+        ".x.",
+        ".xx",
+        "...",
+    ],
+},
+
+'|' => Char {
+    data: [
+        ".",
+        "x",
+        "x",
+        "x",
+        "x",
+        "x",
+        ".",
+    ],
+},
+
+'}' => Char {
+    data: [
+        "...",
+        "xx.",
+        ".x.",
+        ".xx",
+        ".x.",
+        "xx.",
+        "...",
+    ],
+},
+
+'~' => Char {
+    data: [
+        "...",
+        "...",
+        "xx.",
+        ".xx",
+        "...",
+        "...",
+        "...",
+    ],
+},
+
+'\u{a0}' => Char {
+    data: [
+        ".",
+        ".",
+        ".",
+        ".",
+        ".",
+        ".",
+        ".",
+    ],
+},
+
+'\u{ad}' => Char {
+    data: [
+        "...",
+        "...",
+        "...",
+        "xxx",
+        "...",
+        "...",
+        "...",
+    ],
+},
+
+'À' => Char {
+    data: [
+        "x..",
+        ".x.",
+        "x.x",
+        "xxx",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'Á' => Char {
+    data: [
+        ".x.",
+        ".x.",
+        "x.x",
+        "xxx",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'Â' => Char {
+    data: [
+        "x.x",
+        ".x.",
+        "x.x",
+        "xxx",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'Ã' => Char {
+    data: [
+        "xx.",
+        ".x.",
+        "x.x",
+        "xxx",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'Ä' => Char {
+    data: [
+        "x.x",
+        ".x.",
+        "x.x",
+        "xxx",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'Å' => Char {
+    data: [
+        "xx.",
+        ".x.",
+        "x.x",
+        "xxx",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'Ç' => Char {
+    data: [
+        "...",
+        ".xx",
+        "x..",
+        "x..",
+        "x..",
+        ".xx",
+        ".x.",
+    ],
+},
+
+'È' => Char {
+    data: [
+        "x..",
+        "xxx",
+        "x..",
+        "xx.",
+        "x..",
+        "xxx",
+        "...",
+    ],
+},
+
+'É' => Char {
+    data: [
+        ".x.",
+        "xxx",
+        "x..",
+        "xx.",
+        "x..",
+        "xxx",
+        "...",
+    ],
+},
+
+'Ê' => Char {
+    data: [
+        "x.x",
+        "xxx",
+        "x..",
+        "xx.",
+        "x..",
+        "xxx",
+        "...",
+    ],
+},
+
+'Ë' => Char {
+    data: [
+        "x.x",
+        "xxx",
+        "x..",
+        "xx.",
+        "x..",
+        "xxx",
+        "...",
+    ],
+},
+
+'Ì' => Char {
+    data: [
+        "x..",
+        "xxx",
+        ".x.",
+        ".x.",
+        ".x.",
+        "xxx",
+        "...",
+    ],
+},
+
+'Í' => Char {
+    data: [
+        ".x.",
+        "xxx",
+        ".x.",
+        ".x.",
+        ".x.",
+        "xxx",
+        "...",
+    ],
+},
+
+'Î' => Char {
+    data: [
+        "x.x",
+        "xxx",
+        ".x.",
+        ".x.",
+        ".x.",
+        "xxx",
+        "...",
+    ],
+},
+
+'Ï' => Char {
+    data: [
+        "x.x",
+        "xxx",
+        ".x.",
+        ".x.",
+        ".x.",
+        "xxx",
+        "...",
+    ],
+},
+
+'Ñ' => Char {
+    data: [
+        "xx.",
+        "xx.",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'Ò' => Char {
+    data: [
+        "x..",
+        ".x.",
+        "x.x",
+        "x.x",
+        "x.x",
+        ".x.",
+        "...",
+    ],
+},
+
+'Ó' => Char {
+    data: [
+        ".x.",
+        ".x.",
+        "x.x",
+        "x.x",
+        "x.x",
+        ".x.",
+        "...",
+    ],
+},
+
+'Ô' => Char {
+    data: [
+        "x.x",
+        ".x.",
+        "x.x",
+        "x.x",
+        "x.x",
+        ".x.",
+        "...",
+    ],
+},
+
+'Õ' => Char {
+    data: [
+        "xx.",
+        ".x.",
+        "x.x",
+        "x.x",
+        "x.x",
+        ".x.",
+        "...",
+    ],
+},
+
+'Ö' => Char {
+    data: [
+        "x.x",
+        ".x.",
+        "x.x",
+        "x.x",
+        "x.x",
+        ".x.",
+        "...",
+    ],
+},
+
+'Ù' => Char {
+    data: [
+        "x..",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "xxx",
+        "...",
+    ],
+},
+
+'Ú' => Char {
+    data: [
+        ".x.",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "xxx",
+        "...",
+    ],
+},
+
+'Û' => Char {
+    data: [
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "xxx",
+        "...",
+    ],
+},
+
+'Ü' => Char {
+    data: [
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "xxx",
+        "...",
+    ],
+},
+
+'Ý' => Char {
+    data: [
+        ".x.",
+        "x.x",
+        "x.x",
+        ".x.",
+        ".x.",
+        ".x.",
+        "...",
+    ],
+},
+
+'à' => Char {
+    data: [
+        "x..",
+        ".x.",
+        "x.x",
+        "xxx",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'á' => Char {
+    data: [
+        ".x.",
+        ".x.",
+        "x.x",
+        "xxx",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'â' => Char {
+    data: [
+        "x.x",
+        ".x.",
+        "x.x",
+        "xxx",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'ã' => Char {
+    data: [
+        "xx.",
+        ".x.",
+        "x.x",
+        "xxx",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'ä' => Char {
+    data: [
+        "x.x",
+        ".x.",
+        "x.x",
+        "xxx",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'å' => Char {
+    data: [
+        "xx.",
+        ".x.",
+        "x.x",
+        "xxx",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'ç' => Char {
+    data: [
+        "...",
+        ".xx",
+        "x..",
+        "x..",
+        "x..",
+        ".xx",
+        ".x.",
+    ],
+},
+
+'è' => Char {
+    data: [
+        "x..",
+        "xxx",
+        "x..",
+        "xx.",
+        "x..",
+        "xxx",
+        "...",
+    ],
+},
+
+'é' => Char {
+    data: [
+        ".x.",
+        "xxx",
+        "x..",
+        "xx.",
+        "x..",
+        "xxx",
+        "...",
+    ],
+},
+
+'ê' => Char {
+    data: [
+        "x.x",
+        "xxx",
+        "x..",
+        "xx.",
+        "x..",
+        "xxx",
+        "...",
+    ],
+},
+
+'ë' => Char {
+    data: [
+        "x.x",
+        "xxx",
+        "x..",
+        "xx.",
+        "x..",
+        "xxx",
+        "...",
+    ],
+},
+
+'ì' => Char {
+    data: [
+        "x..",
+        "xxx",
+        ".x.",
+        ".x.",
+        ".x.",
+        "xxx",
+        "...",
+    ],
+},
+
+'í' => Char {
+    data: [
+        ".x.",
+        "xxx",
+        ".x.",
+        ".x.",
+        ".x.",
+        "xxx",
+        "...",
+    ],
+},
+
+'î' => Char {
+    data: [
+        "x.x",
+        "xxx",
+        ".x.",
+        ".x.",
+        ".x.",
+        "xxx",
+        "...",
+    ],
+},
+
+'ï' => Char {
+    data: [
+        "x.x",
+        "xxx",
+        ".x.",
+        ".x.",
+        ".x.",
+        "xxx",
+        "...",
+    ],
+},
+
+'ñ' => Char {
+    data: [
+        "xx.",
+        "xx.",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'ò' => Char {
+    data: [
+        "x..",
+        ".x.",
+        "x.x",
+        "x.x",
+        "x.x",
+        ".x.",
+        "...",
+    ],
+},
+
+'ó' => Char {
+    data: [
+        ".x.",
+        ".x.",
+        "x.x",
+        "x.x",
+        "x.x",
+        ".x.",
+        "...",
+    ],
+},
+
+'ô' => Char {
+    data: [
+        "x.x",
+        ".x.",
+        "x.x",
+        "x.x",
+        "x.x",
+        ".x.",
+        "...",
+    ],
+},
+
+'õ' => Char {
+    data: [
+        "xx.",
+        ".x.",
+        "x.x",
+        "x.x",
+        "x.x",
+        ".x.",
+        "...",
+    ],
+},
+
+'ö' => Char {
+    data: [
+        "x.x",
+        ".x.",
+        "x.x",
+        "x.x",
+        "x.x",
+        ".x.",
+        "...",
+    ],
+},
+
+'ù' => Char {
+    data: [
+        "x..",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "xxx",
+        "...",
+    ],
+},
+
+'ú' => Char {
+    data: [
+        ".x.",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "xxx",
+        "...",
+    ],
+},
+
+'û' => Char {
+    data: [
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "xxx",
+        "...",
+    ],
+},
+
+'ü' => Char {
+    data: [
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "xxx",
+        "...",
+    ],
+},
+
+'ý' => Char {
+    data: [
+        ".x.",
+        "x.x",
+        "x.x",
+        ".x.",
+        ".x.",
+        ".x.",
+        "...",
+    ],
+},
+
+'ÿ' => Char {
+    data: [
+        "x.x",
+        "x.x",
+        "x.x",
+        ".x.",
+        ".x.",
+        ".x.",
+        "...",
+    ],
+},
+
+'Ā' => Char {
+    data: [
+        "xxx",
+        ".x.",
+        "x.x",
+        "xxx",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'ā' => Char {
+    data: [
+        "xxx",
+        ".x.",
+        "x.x",
+        "xxx",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'Ă' => Char {
+    data: [
+        "x..x",
+        ".x..",
+        "x.x.",
+        "xxx.",
+        "x.x.",
+        "x.x.",
+        "....",
+    ],
+},
+
+'ă' => Char {
+    data: [
+        "x..x",
+        ".x..",
+        "x.x.",
+        "xxx.",
+        "x.x.",
+        "x.x.",
+        "....",
+    ],
+},
+
+'Ą' => Char {
+    data: [
+        "...",
+        ".x.",
+        "x.x",
+        "xxx",
+        "x.x",
+        "x.x",
+        "..x",
+    ],
+},
+
+'ą' => Char {
+    data: [
+        "...",
+        ".x.",
+        "x.x",
+        "xxx",
+        "x.x",
+        "x.x",
+        "..x",
+    ],
+},
+
+'Ć' => Char {
+    data: [
+        ".x.",
+        ".xx",
+        "x..",
+        "x..",
+        "x..",
+        ".xx",
+        "...",
+    ],
+},
+
+'ć' => Char {
+    data: [
+        ".x.",
+        ".xx",
+        "x..",
+        "x..",
+        "x..",
+        ".xx",
+        "...",
+    ],
+},
+
+'Ĉ' => Char {
+    data: [
+        "x.x",
+        ".xx",
+        "x..",
+        "x..",
+        "x..",
+        ".xx",
+        "...",
+    ],
+},
+
+'ĉ' => Char {
+    data: [
+        "x.x",
+        ".xx",
+        "x..",
+        "x..",
+        "x..",
+        ".xx",
+        "...",
+    ],
+},
+
+'Ċ' => Char {
+    data: [
+        ".x.",
+        ".xx",
+        "x..",
+        "x..",
+        "x..",
+        ".xx",
+        "...",
+    ],
+},
+
+'ċ' => Char {
+    data: [
+        ".x.",
+        ".xx",
+        "x..",
+        "x..",
+        "x..",
+        ".xx",
+        "...",
+    ],
+},
+
+'Č' => Char {
+    data: [
+        "x.x",
+        ".xx",
+        "x..",
+        "x..",
+        "x..",
+        ".xx",
+        "...",
+    ],
+},
+
+'č' => Char {
+    data: [
+        "x.x",
+        ".xx",
+        "x..",
+        "x..",
+        "x..",
+        ".xx",
+        "...",
+    ],
+},
+
+'Ď' => Char {
+    data: [
+        "x.x",
+        "xx.",
+        "x.x",
+        "x.x",
+        "x.x",
+        "xx.",
+        "...",
+    ],
+},
+
+'ď' => Char {
+    data: [
+        "x.x",
+        "xx.",
+        "x.x",
+        "x.x",
+        "x.x",
+        "xx.",
+        "...",
+    ],
+},
+
+'Ē' => Char {
+    data: [
+        "xxx",
+        "xxx",
+        "x..",
+        "xx.",
+        "x..",
+        "xxx",
+        "...",
+    ],
+},
+
+'ē' => Char {
+    data: [
+        "xxx",
+        "xxx",
+        "x..",
+        "xx.",
+        "x..",
+        "xxx",
+        "...",
+    ],
+},
+
+'Ĕ' => Char {
+    data: [
+        "x..x",
+        "xxx.",
+        "x...",
+        "xx..",
+        "x...",
+        "xxx.",
+        "....",
+    ],
+},
+
+'ĕ' => Char {
+    data: [
+        "x..x",
+        "xxx.",
+        "x...",
+        "xx..",
+        "x...",
+        "xxx.",
+        "....",
+    ],
+},
+
+'Ė' => Char {
+    data: [
+        ".x.",
+        "xxx",
+        "x..",
+        "xx.",
+        "x..",
+        "xxx",
+        "...",
+    ],
+},
+
+'ė' => Char {
+    data: [
+        ".x.",
+        "xxx",
+        "x..",
+        "xx.",
+        "x..",
+        "xxx",
+        "...",
+    ],
+},
+
+'Ę' => Char {
+    data: [
+        "...",
+        "xxx",
+        "x..",
+        "xx.",
+        "x..",
+        "xxx",
+        "..x",
+    ],
+},
+
+'ę' => Char {
+    data: [
+        "...",
+        "xxx",
+        "x..",
+        "xx.",
+        "x..",
+        "xxx",
+        "..x",
+    ],
+},
+
+'Ě' => Char {
+    data: [
+        "x.x",
+        "xxx",
+        "x..",
+        "xx.",
+        "x..",
+        "xxx",
+        "...",
+    ],
+},
+
+'ě' => Char {
+    data: [
+        "x.x",
+        "xxx",
+        "x..",
+        "xx.",
+        "x..",
+        "xxx",
+        "...",
+    ],
+},
+
+'Ĝ' => Char {
+    data: [
+        "x.x",
+        ".xx",
+        "x..",
+        "x.x",
+        "x.x",
+        ".xx",
+        "...",
+    ],
+},
+
+'ĝ' => Char {
+    data: [
+        "x.x",
+        ".xx",
+        "x..",
+        "x.x",
+        "x.x",
+        ".xx",
+        "...",
+    ],
+},
+
+'Ğ' => Char {
+    data: [
+        "x..x",
+        ".xx.",
+        "x...",
+        "x.x.",
+        "x.x.",
+        ".xx.",
+        "....",
+    ],
+},
+
+'ğ' => Char {
+    data: [
+        "x..x",
+        ".xx.",
+        "x...",
+        "x.x.",
+        "x.x.",
+        ".xx.",
+        "....",
+    ],
+},
+
+'Ġ' => Char {
+    data: [
+        ".x.",
+        ".xx",
+        "x..",
+        "x.x",
+        "x.x",
+        ".xx",
+        "...",
+    ],
+},
+
+'ġ' => Char {
+    data: [
+        ".x.",
+        ".xx",
+        "x..",
+        "x.x",
+        "x.x",
+        ".xx",
+        "...",
+    ],
+},
+
+'Ģ' => Char {
+    data: [
+        "...",
+        ".xx",
+        "x..",
+        "x.x",
+        "x.x",
+        ".xx",
+        ".x.",
+    ],
+},
+
+'ģ' => Char {
+    data: [
+        "...",
+        ".xx",
+        "x..",
+        "x.x",
+        "x.x",
+        ".xx",
+        ".x.",
+    ],
+},
+
+'Ĥ' => Char {
+    data: [
+        "x.x",
+        "x.x",
+        "x.x",
+        "xxx",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'ĥ' => Char {
+    data: [
+        "x.x",
+        "x.x",
+        "x.x",
+        "xxx",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'Ĩ' => Char {
+    data: [
+        "xx.",
+        "xxx",
+        ".x.",
+        ".x.",
+        ".x.",
+        "xxx",
+        "...",
+    ],
+},
+
+'ĩ' => Char {
+    data: [
+        "xx.",
+        "xxx",
+        ".x.",
+        ".x.",
+        ".x.",
+        "xxx",
+        "...",
+    ],
+},
+
+'Ī' => Char {
+    data: [
+        "xxx",
+        "xxx",
+        ".x.",
+        ".x.",
+        ".x.",
+        "xxx",
+        "...",
+    ],
+},
+
+'ī' => Char {
+    data: [
+        "xxx",
+        "xxx",
+        ".x.",
+        ".x.",
+        ".x.",
+        "xxx",
+        "...",
+    ],
+},
+
+'Ĭ' => Char {
+    data: [
+        "x..x",
+        "xxx.",
+        ".x..",
+        ".x..",
+        ".x..",
+        "xxx.",
+        "....",
+    ],
+},
+
+'ĭ' => Char {
+    data: [
+        "x..x",
+        "xxx.",
+        ".x..",
+        ".x..",
+        ".x..",
+        "xxx.",
+        "....",
+    ],
+},
+
+'Į' => Char {
+    data: [
+        "...",
+        "xxx",
+        ".x.",
+        ".x.",
+        ".x.",
+        "xxx",
+        "..x",
+    ],
+},
+
+'į' => Char {
+    data: [
+        "...",
+        "xxx",
+        ".x.",
+        ".x.",
+        ".x.",
+        "xxx",
+        "..x",
+    ],
+},
+
+'İ' => Char {
+    data: [
+        ".x.",
+        "xxx",
+        ".x.",
+        ".x.",
+        ".x.",
+        "xxx",
+        "...",
+    ],
+},
+
+'Ĵ' => Char {
+    data: [
+        "x.x",
+        "..x",
+        "..x",
+        "..x",
+        "x.x",
+        ".x.",
+        "...",
+    ],
+},
+
+'ĵ' => Char {
+    data: [
+        "x.x",
+        "..x",
+        "..x",
+        "..x",
+        "x.x",
+        ".x.",
+        "...",
+    ],
+},
+
+'Ķ' => Char {
+    data: [
+        "...",
+        "x.x",
+        "x.x",
+        "xx.",
+        "x.x",
+        "x.x",
+        ".x.",
+    ],
+},
+
+'ķ' => Char {
+    data: [
+        "...",
+        "x.x",
+        "x.x",
+        "xx.",
+        "x.x",
+        "x.x",
+        ".x.",
+    ],
+},
+
+'Ĺ' => Char {
+    data: [
+        ".x.",
+        "x..",
+        "x..",
+        "x..",
+        "x..",
+        "xxx",
+        "...",
+    ],
+},
+
+'ĺ' => Char {
+    data: [
+        ".x.",
+        "x..",
+        "x..",
+        "x..",
+        "x..",
+        "xxx",
+        "...",
+    ],
+},
+
+'Ļ' => Char {
+    data: [
+        "...",
+        "x..",
+        "x..",
+        "x..",
+        "x..",
+        "xxx",
+        ".x.",
+    ],
+},
+
+'ļ' => Char {
+    data: [
+        "...",
+        "x..",
+        "x..",
+        "x..",
+        "x..",
+        "xxx",
+        ".x.",
+    ],
+},
+
+'Ľ' => Char {
+    data: [
+        "x.x",
+        "x..",
+        "x..",
+        "x..",
+        "x..",
+        "xxx",
+        "...",
+    ],
+},
+
+'ľ' => Char {
+    data: [
+        "x.x",
+        "x..",
+        "x..",
+        "x..",
+        "x..",
+        "xxx",
+        "...",
+    ],
+},
+
+'Ń' => Char {
+    data: [
+        ".x.",
+        "xx.",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'ń' => Char {
+    data: [
+        ".x.",
+        "xx.",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'Ņ' => Char {
+    data: [
+        "...",
+        "xx.",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        ".x.",
+    ],
+},
+
+'ņ' => Char {
+    data: [
+        "...",
+        "xx.",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        ".x.",
+    ],
+},
+
+'Ň' => Char {
+    data: [
+        "x.x",
+        "xx.",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'ň' => Char {
+    data: [
+        "x.x",
+        "xx.",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'Ō' => Char {
+    data: [
+        "xxx",
+        ".x.",
+        "x.x",
+        "x.x",
+        "x.x",
+        ".x.",
+        "...",
+    ],
+},
+
+'ō' => Char {
+    data: [
+        "xxx",
+        ".x.",
+        "x.x",
+        "x.x",
+        "x.x",
+        ".x.",
+        "...",
+    ],
+},
+
+'Ŏ' => Char {
+    data: [
+        "x..x",
+        ".x..",
+        "x.x.",
+        "x.x.",
+        "x.x.",
+        ".x..",
+        "....",
+    ],
+},
+
+'ŏ' => Char {
+    data: [
+        "x..x",
+        ".x..",
+        "x.x.",
+        "x.x.",
+        "x.x.",
+        ".x..",
+        "....",
+    ],
+},
+
+'Ő' => Char {
+    data: [
+        "x.x",
+        ".x.",
+        "x.x",
+        "x.x",
+        "x.x",
+        ".x.",
+        "...",
+    ],
+},
+
+'ő' => Char {
+    data: [
+        "x.x",
+        ".x.",
+        "x.x",
+        "x.x",
+        "x.x",
+        ".x.",
+        "...",
+    ],
+},
+
+'Ŕ' => Char {
+    data: [
+        ".x.",
+        "xx.",
+        "x.x",
+        "xx.",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'ŕ' => Char {
+    data: [
+        ".x.",
+        "xx.",
+        "x.x",
+        "xx.",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'Ŗ' => Char {
+    data: [
+        "...",
+        "xx.",
+        "x.x",
+        "xx.",
+        "x.x",
+        "x.x",
+        ".x.",
+    ],
+},
+
+'ŗ' => Char {
+    data: [
+        "...",
+        "xx.",
+        "x.x",
+        "xx.",
+        "x.x",
+        "x.x",
+        ".x.",
+    ],
+},
+
+'Ř' => Char {
+    data: [
+        "x.x",
+        "xx.",
+        "x.x",
+        "xx.",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'ř' => Char {
+    data: [
+        "x.x",
+        "xx.",
+        "x.x",
+        "xx.",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'Ś' => Char {
+    data: [
+        ".x.",
+        ".xx",
+        "x..",
+        ".x.",
+        "..x",
+        "xx.",
+        "...",
+    ],
+},
+
+'ś' => Char {
+    data: [
+        ".x.",
+        ".xx",
+        "x..",
+        ".x.",
+        "..x",
+        "xx.",
+        "...",
+    ],
+},
+
+'Ŝ' => Char {
+    data: [
+        "x.x",
+        ".xx",
+        "x..",
+        ".x.",
+        "..x",
+        "xx.",
+        "...",
+    ],
+},
+
+'ŝ' => Char {
+    data: [
+        "x.x",
+        ".xx",
+        "x..",
+        ".x.",
+        "..x",
+        "xx.",
+        "...",
+    ],
+},
+
+'Ş' => Char {
+    data: [
+        "...",
+        ".xx",
+        "x..",
+        ".x.",
+        "..x",
+        "xx.",
+        ".x.",
+    ],
+},
+
+'ş' => Char {
+    data: [
+        "...",
+        ".xx",
+        "x..",
+        ".x.",
+        "..x",
+        "xx.",
+        ".x.",
+    ],
+},
+
+'Š' => Char {
+    data: [
+        "x.x",
+        ".xx",
+        "x..",
+        ".x.",
+        "..x",
+        "xx.",
+        "...",
+    ],
+},
+
+'š' => Char {
+    data: [
+        "x.x",
+        ".xx",
+        "x..",
+        ".x.",
+        "..x",
+        "xx.",
+        "...",
+    ],
+},
+
+'Ţ' => Char {
+    data: [
+        "...",
+        "xxx",
+        ".x.",
+        ".x.",
+        ".x.",
+        ".x.",
+        ".x.",
+    ],
+},
+
+'ţ' => Char {
+    data: [
+        "...",
+        "xxx",
+        ".x.",
+        ".x.",
+        ".x.",
+        ".x.",
+        ".x.",
+    ],
+},
+
+'Ť' => Char {
+    data: [
+        "x.x",
+        "xxx",
+        ".x.",
+        ".x.",
+        ".x.",
+        ".x.",
+        "...",
+    ],
+},
+
+'ť' => Char {
+    data: [
+        "x.x",
+        "xxx",
+        ".x.",
+        ".x.",
+        ".x.",
+        ".x.",
+        "...",
+    ],
+},
+
+'Ũ' => Char {
+    data: [
+        "xx.",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "xxx",
+        "...",
+    ],
+},
+
+'ũ' => Char {
+    data: [
+        "xx.",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "xxx",
+        "...",
+    ],
+},
+
+'Ū' => Char {
+    data: [
+        "xxx",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "xxx",
+        "...",
+    ],
+},
+
+'ū' => Char {
+    data: [
+        "xxx",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "xxx",
+        "...",
+    ],
+},
+
+'Ŭ' => Char {
+    data: [
+        "x..x",
+        "x.x.",
+        "x.x.",
+        "x.x.",
+        "x.x.",
+        "xxx.",
+        "....",
+    ],
+},
+
+'ŭ' => Char {
+    data: [
+        "x..x",
+        "x.x.",
+        "x.x.",
+        "x.x.",
+        "x.x.",
+        "xxx.",
+        "....",
+    ],
+},
+
+'Ů' => Char {
+    data: [
+        "xx.",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "xxx",
+        "...",
+    ],
+},
+
+'ů' => Char {
+    data: [
+        "xx.",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "xxx",
+        "...",
+    ],
+},
+
+'Ű' => Char {
+    data: [
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "xxx",
+        "...",
+    ],
+},
+
+'ű' => Char {
+    data: [
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "xxx",
+        "...",
+    ],
+},
+
+'Ų' => Char {
+    data: [
+        "...",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "xxx",
+        "..x",
+    ],
+},
+
+'ų' => Char {
+    data: [
+        "...",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "xxx",
+        "..x",
+    ],
+},
+
+'Ŵ' => Char {
+    data: [
+        "x.x",
+        "x.x",
+        "x.x",
+        "xxx",
+        "xxx",
+        "x.x",
+        "...",
+    ],
+},
+
+'ŵ' => Char {
+    data: [
+        "x.x",
+        "x.x",
+        "x.x",
+        "xxx",
+        "xxx",
+        "x.x",
+        "...",
+    ],
+},
+
+'Ŷ' => Char {
+    data: [
+        "x.x",
+        "x.x",
+        "x.x",
+        ".x.",
+        ".x.",
+        ".x.",
+        "...",
+    ],
+},
+
+'ŷ' => Char {
+    data: [
+        "x.x",
+        "x.x",
+        "x.x",
+        ".x.",
+        ".x.",
+        ".x.",
+        "...",
+    ],
+},
+
+'Ÿ' => Char {
+    data: [
+        "x.x",
+        "x.x",
+        "x.x",
+        ".x.",
+        ".x.",
+        ".x.",
+        "...",
+    ],
+},
+
+'Ź' => Char {
+    data: [
+        ".x.",
+        "xxx",
+        "..x",
+        ".x.",
+        "x..",
+        "xxx",
+        "...",
+    ],
+},
+
+'ź' => Char {
+    data: [
+        ".x.",
+        "xxx",
+        "..x",
+        ".x.",
+        "x..",
+        "xxx",
+        "...",
+    ],
+},
+
+'Ż' => Char {
+    data: [
+        ".x.",
+        "xxx",
+        "..x",
+        ".x.",
+        "x..",
+        "xxx",
+        "...",
+    ],
+},
+
+'ż' => Char {
+    data: [
+        ".x.",
+        "xxx",
+        "..x",
+        ".x.",
+        "x..",
+        "xxx",
+        "...",
+    ],
+},
+
+'Ž' => Char {
+    data: [
+        "x.x",
+        "xxx",
+        "..x",
+        ".x.",
+        "x..",
+        "xxx",
+        "...",
+    ],
+},
+
+'ž' => Char {
+    data: [
+        "x.x",
+        "xxx",
+        "..x",
+        ".x.",
+        "x..",
+        "xxx",
+        "...",
+    ],
+},
+
+'Ǎ' => Char {
+    data: [
+        "x.x",
+        ".x.",
+        "x.x",
+        "xxx",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'ǎ' => Char {
+    data: [
+        "x.x",
+        ".x.",
+        "x.x",
+        "xxx",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'Ǐ' => Char {
+    data: [
+        "x.x",
+        "xxx",
+        ".x.",
+        ".x.",
+        ".x.",
+        "xxx",
+        "...",
+    ],
+},
+
+'ǐ' => Char {
+    data: [
+        "x.x",
+        "xxx",
+        ".x.",
+        ".x.",
+        ".x.",
+        "xxx",
+        "...",
+    ],
+},
+
+'Ǒ' => Char {
+    data: [
+        "x.x",
+        ".x.",
+        "x.x",
+        "x.x",
+        "x.x",
+        ".x.",
+        "...",
+    ],
+},
+
+'ǒ' => Char {
+    data: [
+        "x.x",
+        ".x.",
+        "x.x",
+        "x.x",
+        "x.x",
+        ".x.",
+        "...",
+    ],
+},
+
+'Ǔ' => Char {
+    data: [
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "xxx",
+        "...",
+    ],
+},
+
+'ǔ' => Char {
+    data: [
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "xxx",
+        "...",
+    ],
+},
+
+'Ǧ' => Char {
+    data: [
+        "x.x",
+        ".xx",
+        "x..",
+        "x.x",
+        "x.x",
+        ".xx",
+        "...",
+    ],
+},
+
+'ǧ' => Char {
+    data: [
+        "x.x",
+        ".xx",
+        "x..",
+        "x.x",
+        "x.x",
+        ".xx",
+        "...",
+    ],
+},
+
+'Ǩ' => Char {
+    data: [
+        "x.x",
+        "x.x",
+        "x.x",
+        "xx.",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'ǩ' => Char {
+    data: [
+        "x.x",
+        "x.x",
+        "x.x",
+        "xx.",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'Ǫ' => Char {
+    data: [
+        "...",
+        ".x.",
+        "x.x",
+        "x.x",
+        "x.x",
+        ".x.",
+        "..x",
+    ],
+},
+
+'ǫ' => Char {
+    data: [
+        "...",
+        ".x.",
+        "x.x",
+        "x.x",
+        "x.x",
+        ".x.",
+        "..x",
+    ],
+},
+
+'ǰ' => Char {
+    data: [
+        "x.x",
+        "..x",
+        "..x",
+        "..x",
+        "x.x",
+        ".x.",
+        "...",
+    ],
+},
+
+'Ǵ' => Char {
+    data: [
+        ".x.",
+        ".xx",
+        "x..",
+        "x.x",
+        "x.x",
+        ".xx",
+        "...",
+    ],
+},
+
+'ǵ' => Char {
+    data: [
+        ".x.",
+        ".xx",
+        "x..",
+        "x.x",
+        "x.x",
+        ".xx",
+        "...",
+    ],
+},
+
+'Ǹ' => Char {
+    data: [
+        "x..",
+        "xx.",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'ǹ' => Char {
+    data: [
+        "x..",
+        "xx.",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'Ș' => Char {
+    data: [
+        "...",
+        ".xx",
+        "x..",
+        ".x.",
+        "..x",
+        "xx.",
+        ".x.",
+    ],
+},
+
+'ș' => Char {
+    data: [
+        "...",
+        ".xx",
+        "x..",
+        ".x.",
+        "..x",
+        "xx.",
+        ".x.",
+    ],
+},
+
+'Ț' => Char {
+    data: [
+        "...",
+        "xxx",
+        ".x.",
+        ".x.",
+        ".x.",
+        ".x.",
+        ".x.",
+    ],
+},
+
+'ț' => Char {
+    data: [
+        "...",
+        "xxx",
+        ".x.",
+        ".x.",
+        ".x.",
+        ".x.",
+        ".x.",
+    ],
+},
+
+'Ȟ' => Char {
+    data: [
+        "x.x",
+        "x.x",
+        "x.x",
+        "xxx",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'ȟ' => Char {
+    data: [
+        "x.x",
+        "x.x",
+        "x.x",
+        "xxx",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'Ȧ' => Char {
+    data: [
+        ".x.",
+        ".x.",
+        "x.x",
+        "xxx",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'ȧ' => Char {
+    data: [
+        ".x.",
+        ".x.",
+        "x.x",
+        "xxx",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'Ȩ' => Char {
+    data: [
+        "...",
+        "xxx",
+        "x..",
+        "xx.",
+        "x..",
+        "xxx",
+        ".x.",
+    ],
+},
+
+'ȩ' => Char {
+    data: [
+        "...",
+        "xxx",
+        "x..",
+        "xx.",
+        "x..",
+        "xxx",
+        ".x.",
+    ],
+},
+
+'Ȯ' => Char {
+    data: [
+        ".x.",
+        ".x.",
+        "x.x",
+        "x.x",
+        "x.x",
+        ".x.",
+        "...",
+    ],
+},
+
+'ȯ' => Char {
+    data: [
+        ".x.",
+        ".x.",
+        "x.x",
+        "x.x",
+        "x.x",
+        ".x.",
+        "...",
+    ],
+},
+
+'Ȳ' => Char {
+    data: [
+        "xxx",
+        "x.x",
+        "x.x",
+        ".x.",
+        ".x.",
+        ".x.",
+        "...",
+    ],
+},
+
+'ȳ' => Char {
+    data: [
+        "xxx",
+        "x.x",
+        "x.x",
+        ".x.",
+        ".x.",
+        ".x.",
+        "...",
+    ],
+},
+
+'Ḃ' => Char {
+    data: [
+        ".x.",
+        "xx.",
+        "x.x",
+        "xx.",
+        "x.x",
+        "xx.",
+        "...",
+    ],
+},
+
+'ḃ' => Char {
+    data: [
+        ".x.",
+        "xx.",
+        "x.x",
+        "xx.",
+        "x.x",
+        "xx.",
+        "...",
+    ],
+},
+
+'Ḅ' => Char {
+    data: [
+        "...",
+        "xx.",
+        "x.x",
+        "xx.",
+        "x.x",
+        "xx.",
+        ".x.",
+    ],
+},
+
+'ḅ' => Char {
+    data: [
+        "...",
+        "xx.",
+        "x.x",
+        "xx.",
+        "x.x",
+        "xx.",
+        ".x.",
+    ],
+},
+
+'Ḇ' => Char {
+    data: [
+        "...",
+        "xx.",
+        "x.x",
+        "xx.",
+        "x.x",
+        "xx.",
+        "xxx",
+    ],
+},
+
+'ḇ' => Char {
+    data: [
+        "...",
+        "xx.",
+        "x.x",
+        "xx.",
+        "x.x",
+        "xx.",
+        "xxx",
+    ],
+},
+
+'Ḋ' => Char {
+    data: [
+        ".x.",
+        "xx.",
+        "x.x",
+        "x.x",
+        "x.x",
+        "xx.",
+        "...",
+    ],
+},
+
+'ḋ' => Char {
+    data: [
+        ".x.",
+        "xx.",
+        "x.x",
+        "x.x",
+        "x.x",
+        "xx.",
+        "...",
+    ],
+},
+
+'Ḍ' => Char {
+    data: [
+        "...",
+        "xx.",
+        "x.x",
+        "x.x",
+        "x.x",
+        "xx.",
+        ".x.",
+    ],
+},
+
+'ḍ' => Char {
+    data: [
+        "...",
+        "xx.",
+        "x.x",
+        "x.x",
+        "x.x",
+        "xx.",
+        ".x.",
+    ],
+},
+
+'Ḏ' => Char {
+    data: [
+        "...",
+        "xx.",
+        "x.x",
+        "x.x",
+        "x.x",
+        "xx.",
+        "xxx",
+    ],
+},
+
+'ḏ' => Char {
+    data: [
+        "...",
+        "xx.",
+        "x.x",
+        "x.x",
+        "x.x",
+        "xx.",
+        "xxx",
+    ],
+},
+
+'Ḑ' => Char {
+    data: [
+        "...",
+        "xx.",
+        "x.x",
+        "x.x",
+        "x.x",
+        "xx.",
+        ".x.",
+    ],
+},
+
+'ḑ' => Char {
+    data: [
+        "...",
+        "xx.",
+        "x.x",
+        "x.x",
+        "x.x",
+        "xx.",
+        ".x.",
+    ],
+},
+
+'Ḟ' => Char {
+    data: [
+        ".x.",
+        "xxx",
+        "x..",
+        "xx.",
+        "x..",
+        "x..",
+        "...",
+    ],
+},
+
+'ḟ' => Char {
+    data: [
+        ".x.",
+        "xxx",
+        "x..",
+        "xx.",
+        "x..",
+        "x..",
+        "...",
+    ],
+},
+
+'Ḡ' => Char {
+    data: [
+        "xxx",
+        ".xx",
+        "x..",
+        "x.x",
+        "x.x",
+        ".xx",
+        "...",
+    ],
+},
+
+'ḡ' => Char {
+    data: [
+        "xxx",
+        ".xx",
+        "x..",
+        "x.x",
+        "x.x",
+        ".xx",
+        "...",
+    ],
+},
+
+'Ḣ' => Char {
+    data: [
+        ".x.",
+        "x.x",
+        "x.x",
+        "xxx",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'ḣ' => Char {
+    data: [
+        ".x.",
+        "x.x",
+        "x.x",
+        "xxx",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'Ḥ' => Char {
+    data: [
+        "...",
+        "x.x",
+        "x.x",
+        "xxx",
+        "x.x",
+        "x.x",
+        ".x.",
+    ],
+},
+
+'ḥ' => Char {
+    data: [
+        "...",
+        "x.x",
+        "x.x",
+        "xxx",
+        "x.x",
+        "x.x",
+        ".x.",
+    ],
+},
+
+'Ḧ' => Char {
+    data: [
+        "x.x",
+        "x.x",
+        "x.x",
+        "xxx",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'ḧ' => Char {
+    data: [
+        "x.x",
+        "x.x",
+        "x.x",
+        "xxx",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'Ḩ' => Char {
+    data: [
+        "...",
+        "x.x",
+        "x.x",
+        "xxx",
+        "x.x",
+        "x.x",
+        ".x.",
+    ],
+},
+
+'ḩ' => Char {
+    data: [
+        "...",
+        "x.x",
+        "x.x",
+        "xxx",
+        "x.x",
+        "x.x",
+        ".x.",
+    ],
+},
+
+'Ḱ' => Char {
+    data: [
+        ".x.",
+        "x.x",
+        "x.x",
+        "xx.",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'ḱ' => Char {
+    data: [
+        ".x.",
+        "x.x",
+        "x.x",
+        "xx.",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'Ḳ' => Char {
+    data: [
+        "...",
+        "x.x",
+        "x.x",
+        "xx.",
+        "x.x",
+        "x.x",
+        ".x.",
+    ],
+},
+
+'ḳ' => Char {
+    data: [
+        "...",
+        "x.x",
+        "x.x",
+        "xx.",
+        "x.x",
+        "x.x",
+        ".x.",
+    ],
+},
+
+'Ḵ' => Char {
+    data: [
+        "...",
+        "x.x",
+        "x.x",
+        "xx.",
+        "x.x",
+        "x.x",
+        "xxx",
+    ],
+},
+
+'ḵ' => Char {
+    data: [
+        "...",
+        "x.x",
+        "x.x",
+        "xx.",
+        "x.x",
+        "x.x",
+        "xxx",
+    ],
+},
+
+'Ḷ' => Char {
+    data: [
+        "...",
+        "x..",
+        "x..",
+        "x..",
+        "x..",
+        "xxx",
+        ".x.",
+    ],
+},
+
+'ḷ' => Char {
+    data: [
+        "...",
+        "x..",
+        "x..",
+        "x..",
+        "x..",
+        "xxx",
+        ".x.",
+    ],
+},
+
+'Ḻ' => Char {
+    data: [
+        "...",
+        "x..",
+        "x..",
+        "x..",
+        "x..",
+        "xxx",
+        "xxx",
+    ],
+},
+
+'ḻ' => Char {
+    data: [
+        "...",
+        "x..",
+        "x..",
+        "x..",
+        "x..",
+        "xxx",
+        "xxx",
+    ],
+},
+
+'Ḿ' => Char {
+    data: [
+        ".x.",
+        "x.x",
+        "xxx",
+        "xxx",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'ḿ' => Char {
+    data: [
+        ".x.",
+        "x.x",
+        "xxx",
+        "xxx",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'Ṁ' => Char {
+    data: [
+        ".x.",
+        "x.x",
+        "xxx",
+        "xxx",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'ṁ' => Char {
+    data: [
+        ".x.",
+        "x.x",
+        "xxx",
+        "xxx",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'Ṃ' => Char {
+    data: [
+        "...",
+        "x.x",
+        "xxx",
+        "xxx",
+        "x.x",
+        "x.x",
+        ".x.",
+    ],
+},
+
+'ṃ' => Char {
+    data: [
+        "...",
+        "x.x",
+        "xxx",
+        "xxx",
+        "x.x",
+        "x.x",
+        ".x.",
+    ],
+},
+
+'Ṅ' => Char {
+    data: [
+        ".x.",
+        "xx.",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'ṅ' => Char {
+    data: [
+        ".x.",
+        "xx.",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'Ṇ' => Char {
+    data: [
+        "...",
+        "xx.",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        ".x.",
+    ],
+},
+
+'ṇ' => Char {
+    data: [
+        "...",
+        "xx.",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        ".x.",
+    ],
+},
+
+'Ṉ' => Char {
+    data: [
+        "...",
+        "xx.",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "xxx",
+    ],
+},
+
+'ṉ' => Char {
+    data: [
+        "...",
+        "xx.",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "xxx",
+    ],
+},
+
+'Ṕ' => Char {
+    data: [
+        ".x.",
+        "xx.",
+        "x.x",
+        "xx.",
+        "x..",
+        "x..",
+        "...",
+    ],
+},
+
+'ṕ' => Char {
+    data: [
+        ".x.",
+        "xx.",
+        "x.x",
+        "xx.",
+        "x..",
+        "x..",
+        "...",
+    ],
+},
+
+'Ṗ' => Char {
+    data: [
+        ".x.",
+        "xx.",
+        "x.x",
+        "xx.",
+        "x..",
+        "x..",
+        "...",
+    ],
+},
+
+'ṗ' => Char {
+    data: [
+        ".x.",
+        "xx.",
+        "x.x",
+        "xx.",
+        "x..",
+        "x..",
+        "...",
+    ],
+},
+
+'Ṙ' => Char {
+    data: [
+        ".x.",
+        "xx.",
+        "x.x",
+        "xx.",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'ṙ' => Char {
+    data: [
+        ".x.",
+        "xx.",
+        "x.x",
+        "xx.",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'Ṛ' => Char {
+    data: [
+        "...",
+        "xx.",
+        "x.x",
+        "xx.",
+        "x.x",
+        "x.x",
+        ".x.",
+    ],
+},
+
+'ṛ' => Char {
+    data: [
+        "...",
+        "xx.",
+        "x.x",
+        "xx.",
+        "x.x",
+        "x.x",
+        ".x.",
+    ],
+},
+
+'Ṟ' => Char {
+    data: [
+        "...",
+        "xx.",
+        "x.x",
+        "xx.",
+        "x.x",
+        "x.x",
+        "xxx",
+    ],
+},
+
+'ṟ' => Char {
+    data: [
+        "...",
+        "xx.",
+        "x.x",
+        "xx.",
+        "x.x",
+        "x.x",
+        "xxx",
+    ],
+},
+
+'Ṡ' => Char {
+    data: [
+        ".x.",
+        ".xx",
+        "x..",
+        ".x.",
+        "..x",
+        "xx.",
+        "...",
+    ],
+},
+
+'ṡ' => Char {
+    data: [
+        ".x.",
+        ".xx",
+        "x..",
+        ".x.",
+        "..x",
+        "xx.",
+        "...",
+    ],
+},
+
+'Ṣ' => Char {
+    data: [
+        "...",
+        ".xx",
+        "x..",
+        ".x.",
+        "..x",
+        "xx.",
+        ".x.",
+    ],
+},
+
+'ṣ' => Char {
+    data: [
+        "...",
+        ".xx",
+        "x..",
+        ".x.",
+        "..x",
+        "xx.",
+        ".x.",
+    ],
+},
+
+'Ṫ' => Char {
+    data: [
+        ".x.",
+        "xxx",
+        ".x.",
+        ".x.",
+        ".x.",
+        ".x.",
+        "...",
+    ],
+},
+
+'ṫ' => Char {
+    data: [
+        ".x.",
+        "xxx",
+        ".x.",
+        ".x.",
+        ".x.",
+        ".x.",
+        "...",
+    ],
+},
+
+'Ṭ' => Char {
+    data: [
+        "...",
+        "xxx",
+        ".x.",
+        ".x.",
+        ".x.",
+        ".x.",
+        ".x.",
+    ],
+},
+
+'ṭ' => Char {
+    data: [
+        "...",
+        "xxx",
+        ".x.",
+        ".x.",
+        ".x.",
+        ".x.",
+        ".x.",
+    ],
+},
+
+'Ṯ' => Char {
+    data: [
+        "...",
+        "xxx",
+        ".x.",
+        ".x.",
+        ".x.",
+        ".x.",
+        "xxx",
+    ],
+},
+
+'ṯ' => Char {
+    data: [
+        "...",
+        "xxx",
+        ".x.",
+        ".x.",
+        ".x.",
+        ".x.",
+        "xxx",
+    ],
+},
+
+'Ṽ' => Char {
+    data: [
+        "xx.",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        ".x.",
+        "...",
+    ],
+},
+
+'ṽ' => Char {
+    data: [
+        "xx.",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        ".x.",
+        "...",
+    ],
+},
+
+'Ṿ' => Char {
+    data: [
+        "...",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        ".x.",
+        ".x.",
+    ],
+},
+
+'ṿ' => Char {
+    data: [
+        "...",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        ".x.",
+        ".x.",
+    ],
+},
+
+'Ẁ' => Char {
+    data: [
+        "x..",
+        "x.x",
+        "x.x",
+        "xxx",
+        "xxx",
+        "x.x",
+        "...",
+    ],
+},
+
+'ẁ' => Char {
+    data: [
+        "x..",
+        "x.x",
+        "x.x",
+        "xxx",
+        "xxx",
+        "x.x",
+        "...",
+    ],
+},
+
+'Ẃ' => Char {
+    data: [
+        ".x.",
+        "x.x",
+        "x.x",
+        "xxx",
+        "xxx",
+        "x.x",
+        "...",
+    ],
+},
+
+'ẃ' => Char {
+    data: [
+        ".x.",
+        "x.x",
+        "x.x",
+        "xxx",
+        "xxx",
+        "x.x",
+        "...",
+    ],
+},
+
+'Ẅ' => Char {
+    data: [
+        "x.x",
+        "x.x",
+        "x.x",
+        "xxx",
+        "xxx",
+        "x.x",
+        "...",
+    ],
+},
+
+'ẅ' => Char {
+    data: [
+        "x.x",
+        "x.x",
+        "x.x",
+        "xxx",
+        "xxx",
+        "x.x",
+        "...",
+    ],
+},
+
+'Ẇ' => Char {
+    data: [
+        ".x.",
+        "x.x",
+        "x.x",
+        "xxx",
+        "xxx",
+        "x.x",
+        "...",
+    ],
+},
+
+'ẇ' => Char {
+    data: [
+        ".x.",
+        "x.x",
+        "x.x",
+        "xxx",
+        "xxx",
+        "x.x",
+        "...",
+    ],
+},
+
+'Ẉ' => Char {
+    data: [
+        "...",
+        "x.x",
+        "x.x",
+        "xxx",
+        "xxx",
+        "x.x",
+        ".x.",
+    ],
+},
+
+'ẉ' => Char {
+    data: [
+        "...",
+        "x.x",
+        "x.x",
+        "xxx",
+        "xxx",
+        "x.x",
+        ".x.",
+    ],
+},
+
+'Ẋ' => Char {
+    data: [
+        ".x.",
+        "x.x",
+        "x.x",
+        ".x.",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'ẋ' => Char {
+    data: [
+        ".x.",
+        "x.x",
+        "x.x",
+        ".x.",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'Ẍ' => Char {
+    data: [
+        "x.x",
+        "x.x",
+        "x.x",
+        ".x.",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'ẍ' => Char {
+    data: [
+        "x.x",
+        "x.x",
+        "x.x",
+        ".x.",
+        "x.x",
+        "x.x",
+        "...",
+    ],
+},
+
+'Ẏ' => Char {
+    data: [
+        ".x.",
+        "x.x",
+        "x.x",
+        ".x.",
+        ".x.",
+        ".x.",
+        "...",
+    ],
+},
+
+'ẏ' => Char {
+    data: [
+        ".x.",
+        "x.x",
+        "x.x",
+        ".x.",
+        ".x.",
+        ".x.",
+        "...",
+    ],
+},
+
+'Ẑ' => Char {
+    data: [
+        "x.x",
+        "xxx",
+        "..x",
+        ".x.",
+        "x..",
+        "xxx",
+        "...",
+    ],
+},
+
+'ẑ' => Char {
+    data: [
+        "x.x",
+        "xxx",
+        "..x",
+        ".x.",
+        "x..",
+        "xxx",
+        "...",
+    ],
+},
+
+'Ẓ' => Char {
+    data: [
+        "...",
+        "xxx",
+        "..x",
+        ".x.",
+        "x..",
+        "xxx",
+        ".x.",
+    ],
+},
+
+'ẓ' => Char {
+    data: [
+        "...",
+        "xxx",
+        "..x",
+        ".x.",
+        "x..",
+        "xxx",
+        ".x.",
+    ],
+},
+
+'Ẕ' => Char {
+    data: [
+        "...",
+        "xxx",
+        "..x",
+        ".x.",
+        "x..",
+        "xxx",
+        "xxx",
+    ],
+},
+
+'ẕ' => Char {
+    data: [
+        "...",
+        "xxx",
+        "..x",
+        ".x.",
+        "x..",
+        "xxx",
+        "xxx",
+    ],
+},
+
+'ẖ' => Char {
+    data: [
+        "...",
+        "x.x",
+        "x.x",
+        "xxx",
+        "x.x",
+        "x.x",
+        "xxx",
+    ],
+},
+
+'ẗ' => Char {
+    data: [
+        "x.x",
+        "xxx",
+        ".x.",
+        ".x.",
+        ".x.",
+        ".x.",
+        "...",
+    ],
+},
+
+'ẘ' => Char {
+    data: [
+        "xx.",
+        "x.x",
+        "x.x",
+        "xxx",
+        "xxx",
+        "x.x",
+        "...",
+    ],
+},
+
+'ẙ' => Char {
+    data: [
+        "xx.",
+        "x.x",
+        "x.x",
+        ".x.",
+        ".x.",
+        ".x.",
+        "...",
+    ],
+},
+
+'Ạ' => Char {
+    data: [
+        "...",
+        ".x.",
+        "x.x",
+        "xxx",
+        "x.x",
+        "x.x",
+        ".x.",
+    ],
+},
+
+'ạ' => Char {
+    data: [
+        "...",
+        ".x.",
+        "x.x",
+        "xxx",
+        "x.x",
+        "x.x",
+        ".x.",
+    ],
+},
+
+'Ẹ' => Char {
+    data: [
+        "...",
+        "xxx",
+        "x..",
+        "xx.",
+        "x..",
+        "xxx",
+        ".x.",
+    ],
+},
+
+'ẹ' => Char {
+    data: [
+        "...",
+        "xxx",
+        "x..",
+        "xx.",
+        "x..",
+        "xxx",
+        ".x.",
+    ],
+},
+
+'Ẽ' => Char {
+    data: [
+        "xx.",
+        "xxx",
+        "x..",
+        "xx.",
+        "x..",
+        "xxx",
+        "...",
+    ],
+},
+
+'ẽ' => Char {
+    data: [
+        "xx.",
+        "xxx",
+        "x..",
+        "xx.",
+        "x..",
+        "xxx",
+        "...",
+    ],
+},
+
+'Ị' => Char {
+    data: [
+        "...",
+        "xxx",
+        ".x.",
+        ".x.",
+        ".x.",
+        "xxx",
+        ".x.",
+    ],
+},
+
+'ị' => Char {
+    data: [
+        "...",
+        "xxx",
+        ".x.",
+        ".x.",
+        ".x.",
+        "xxx",
+        ".x.",
+    ],
+},
+
+'Ọ' => Char {
+    data: [
+        "...",
+        ".x.",
+        "x.x",
+        "x.x",
+        "x.x",
+        ".x.",
+        ".x.",
+    ],
+},
+
+'ọ' => Char {
+    data: [
+        "...",
+        ".x.",
+        "x.x",
+        "x.x",
+        "x.x",
+        ".x.",
+        ".x.",
+    ],
+},
+
+'Ụ' => Char {
+    data: [
+        "...",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "xxx",
+        ".x.",
+    ],
+},
+
+'ụ' => Char {
+    data: [
+        "...",
+        "x.x",
+        "x.x",
+        "x.x",
+        "x.x",
+        "xxx",
+        ".x.",
+    ],
+},
+
+'Ỳ' => Char {
+    data: [
+        "x..",
+        "x.x",
+        "x.x",
+        ".x.",
+        ".x.",
+        ".x.",
+        "...",
+    ],
+},
+
+'ỳ' => Char {
+    data: [
+        "x..",
+        "x.x",
+        "x.x",
+        ".x.",
+        ".x.",
+        ".x.",
+        "...",
+    ],
+},
+
+'Ỵ' => Char {
+    data: [
+        "...",
+        "x.x",
+        "x.x",
+        ".x.",
+        ".x.",
+        ".x.",
+        ".x.",
+    ],
+},
+
+'ỵ' => Char {
+    data: [
+        "...",
+        "x.x",
+        "x.x",
+        ".x.",
+        ".x.",
+        ".x.",
+        ".x.",
+    ],
+},
+
+'Ỹ' => Char {
+    data: [
+        "xx.",
+        "x.x",
+        "x.x",
+        ".x.",
+        ".x.",
+        ".x.",
+        "...",
+    ],
+},
+
+'ỹ' => Char {
+    data: [
+        "xx.",
+        "x.x",
+        "x.x",
+        ".x.",
+        ".x.",
+        ".x.",
+        "...",
+    ],
+},
+
+'\u{fffd}' => Char {
+    data: [
+        "...",
+        "xxx",
+        "x.x",
+        "x.x",
+        "x.x",
+        "xxx",
+        "...",
+    ],
+},
+};
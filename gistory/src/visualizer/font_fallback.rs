@@ -0,0 +1,1087 @@
+use phf::phf_map;
+
+/// Similar character to draw when a character is missing from a font,
+/// usually the same letter without accent
+pub const SIMILAR_CHARS: phf::Map<char, char> = phf_map! {
+    '\u{a0}' => ' ',
+    '¡' => '!',
+    '¢' => 'c',
+    '£' => 'L',
+    '¤' => 'o',
+    '¥' => 'Y',
+    '¦' => '|',
+    '§' => 'S',
+    '¨' => '"',
+    '©' => 'C',
+    'ª' => 'a',
+    '«' => '<',
+    '¬' => '-',
+    '\u{ad}' => '-',
+    '®' => 'R',
+    '¯' => '-',
+    '°' => 'o',
+    '±' => '+',
+    '²' => '2',
+    '³' => '3',
+    '´' => '\'',
+    'µ' => 'u',
+    '¶' => 'P',
+    '·' => '.',
+    '¸' => ',',
+    '¹' => '1',
+    'º' => 'o',
+    '»' => '>',
+    '¿' => '?',
+    'À' => 'A',
+    'Á' => 'A',
+    'Â' => 'A',
+    'Ã' => 'A',
+    'Ä' => 'A',
+    'Å' => 'A',
+    'Æ' => 'A',
+    'Ç' => 'C',
+    'È' => 'E',
+    'É' => 'E',
+    'Ê' => 'E',
+    'Ë' => 'E',
+    'Ì' => 'I',
+    'Í' => 'I',
+    'Î' => 'I',
+    'Ï' => 'I',
+    'Ð' => 'D',
+    'Ñ' => 'N',
+    'Ò' => 'O',
+    'Ó' => 'O',
+    'Ô' => 'O',
+    'Õ' => 'O',
+    'Ö' => 'O',
+    '×' => 'x',
+    'Ø' => 'O',
+    'Ù' => 'U',
+    'Ú' => 'U',
+    'Û' => 'U',
+    'Ü' => 'U',
+    'Ý' => 'Y',
+    'Þ' => 'P',
+    'ß' => 's',
+    'à' => 'a',
+    'á' => 'a',
+    'â' => 'a',
+    'ã' => 'a',
+    'ä' => 'a',
+    'å' => 'a',
+    'æ' => 'a',
+    'ç' => 'c',
+    'è' => 'e',
+    'é' => 'e',
+    'ê' => 'e',
+    'ë' => 'e',
+    'ì' => 'i',
+    'í' => 'i',
+    'î' => 'i',
+    'ï' => 'i',
+    'ð' => 'd',
+    'ñ' => 'n',
+    'ò' => 'o',
+    'ó' => 'o',
+    'ô' => 'o',
+    'õ' => 'o',
+    'ö' => 'o',
+    '÷' => '/',
+    'ø' => 'o',
+    'ù' => 'u',
+    'ú' => 'u',
+    'û' => 'u',
+    'ü' => 'u',
+    'ý' => 'y',
+    'þ' => 'p',
+    'ÿ' => 'y',
+    'Ā' => 'A',
+    'ā' => 'a',
+    'Ă' => 'A',
+    'ă' => 'a',
+    'Ą' => 'A',
+    'ą' => 'a',
+    'Ć' => 'C',
+    'ć' => 'c',
+    'Ĉ' => 'C',
+    'ĉ' => 'c',
+    'Ċ' => 'C',
+    'ċ' => 'c',
+    'Č' => 'C',
+    'č' => 'c',
+    'Ď' => 'D',
+    'ď' => 'd',
+    'Đ' => 'D',
+    'đ' => 'd',
+    'Ē' => 'E',
+    'ē' => 'e',
+    'Ĕ' => 'E',
+    'ĕ' => 'e',
+    'Ė' => 'E',
+    'ė' => 'e',
+    'Ę' => 'E',
+    'ę' => 'e',
+    'Ě' => 'E',
+    'ě' => 'e',
+    'Ĝ' => 'G',
+    'ĝ' => 'g',
+    'Ğ' => 'G',
+    'ğ' => 'g',
+    'Ġ' => 'G',
+    'ġ' => 'g',
+    'Ģ' => 'G',
+    'ģ' => 'g',
+    'Ĥ' => 'H',
+    'ĥ' => 'h',
+    'Ħ' => 'H',
+    'ħ' => 'h',
+    'Ĩ' => 'I',
+    'ĩ' => 'i',
+    'Ī' => 'I',
+    'ī' => 'i',
+    'Ĭ' => 'I',
+    'ĭ' => 'i',
+    'Į' => 'I',
+    'į' => 'i',
+    'İ' => 'I',
+    'ı' => 'i',
+    'Ĳ' => 'I',
+    'ĳ' => 'i',
+    'Ĵ' => 'J',
+    'ĵ' => 'j',
+    'Ķ' => 'K',
+    'ķ' => 'k',
+    'ĸ' => 'k',
+    'Ĺ' => 'L',
+    'ĺ' => 'l',
+    'Ļ' => 'L',
+    'ļ' => 'l',
+    'Ľ' => 'L',
+    'ľ' => 'l',
+    'Ŀ' => 'L',
+    'ŀ' => 'l',
+    'Ł' => 'L',
+    'ł' => 'l',
+    'Ń' => 'N',
+    'ń' => 'n',
+    'Ņ' => 'N',
+    'ņ' => 'n',
+    'Ň' => 'N',
+    'ň' => 'n',
+    'ŉ' => 'n',
+    'Ŋ' => 'N',
+    'ŋ' => 'n',
+    'Ō' => 'O',
+    'ō' => 'o',
+    'Ŏ' => 'O',
+    'ŏ' => 'o',
+    'Ő' => 'O',
+    'ő' => 'o',
+    'Œ' => 'O',
+    'œ' => 'o',
+    'Ŕ' => 'R',
+    'ŕ' => 'r',
+    'Ŗ' => 'R',
+    'ŗ' => 'r',
+    'Ř' => 'R',
+    'ř' => 'r',
+    'Ś' => 'S',
+    'ś' => 's',
+    'Ŝ' => 'S',
+    'ŝ' => 's',
+    'Ş' => 'S',
+    'ş' => 's',
+    'Š' => 'S',
+    'š' => 's',
+    'Ţ' => 'T',
+    'ţ' => 't',
+    'Ť' => 'T',
+    'ť' => 't',
+    'Ŧ' => 'T',
+    'ŧ' => 't',
+    'Ũ' => 'U',
+    'ũ' => 'u',
+    'Ū' => 'U',
+    'ū' => 'u',
+    'Ŭ' => 'U',
+    'ŭ' => 'u',
+    'Ů' => 'U',
+    'ů' => 'u',
+    'Ű' => 'U',
+    'ű' => 'u',
+    'Ų' => 'U',
+    'ų' => 'u',
+    'Ŵ' => 'W',
+    'ŵ' => 'w',
+    'Ŷ' => 'Y',
+    'ŷ' => 'y',
+    'Ÿ' => 'Y',
+    'Ź' => 'Z',
+    'ź' => 'z',
+    'Ż' => 'Z',
+    'ż' => 'z',
+    'Ž' => 'Z',
+    'ž' => 'z',
+    'ſ' => 's',
+    'Ơ' => 'O',
+    'ơ' => 'o',
+    'Ư' => 'U',
+    'ư' => 'u',
+    'Ǎ' => 'A',
+    'ǎ' => 'a',
+    'Ǐ' => 'I',
+    'ǐ' => 'i',
+    'Ǒ' => 'O',
+    'ǒ' => 'o',
+    'Ǔ' => 'U',
+    'ǔ' => 'u',
+    'Ǖ' => 'Ü',
+    'ǖ' => 'ü',
+    'Ǘ' => 'Ü',
+    'ǘ' => 'ü',
+    'Ǚ' => 'Ü',
+    'ǚ' => 'ü',
+    'Ǜ' => 'Ü',
+    'ǜ' => 'ü',
+    'Ǟ' => 'Ä',
+    'ǟ' => 'ä',
+    'Ǡ' => 'Ȧ',
+    'ǡ' => 'ȧ',
+    'Ǣ' => 'Æ',
+    'ǣ' => 'æ',
+    'Ǧ' => 'G',
+    'ǧ' => 'g',
+    'Ǩ' => 'K',
+    'ǩ' => 'k',
+    'Ǫ' => 'O',
+    'ǫ' => 'o',
+    'Ǭ' => 'Ǫ',
+    'ǭ' => 'ǫ',
+    'Ǯ' => 'Ʒ',
+    'ǯ' => 'ʒ',
+    'ǰ' => 'j',
+    'Ǵ' => 'G',
+    'ǵ' => 'g',
+    'Ǹ' => 'N',
+    'ǹ' => 'n',
+    'Ǻ' => 'Å',
+    'ǻ' => 'å',
+    'Ǽ' => 'Æ',
+    'ǽ' => 'æ',
+    'Ǿ' => 'Ø',
+    'ǿ' => 'ø',
+    'Ȁ' => 'A',
+    'ȁ' => 'a',
+    'Ȃ' => 'A',
+    'ȃ' => 'a',
+    'Ȅ' => 'E',
+    'ȅ' => 'e',
+    'Ȇ' => 'E',
+    'ȇ' => 'e',
+    'Ȉ' => 'I',
+    'ȉ' => 'i',
+    'Ȋ' => 'I',
+    'ȋ' => 'i',
+    'Ȍ' => 'O',
+    'ȍ' => 'o',
+    'Ȏ' => 'O',
+    'ȏ' => 'o',
+    'Ȑ' => 'R',
+    'ȑ' => 'r',
+    'Ȓ' => 'R',
+    'ȓ' => 'r',
+    'Ȕ' => 'U',
+    'ȕ' => 'u',
+    'Ȗ' => 'U',
+    'ȗ' => 'u',
+    'Ș' => 'S',
+    'ș' => 's',
+    'Ț' => 'T',
+    'ț' => 't',
+    'Ȟ' => 'H',
+    'ȟ' => 'h',
+    'Ȧ' => 'A',
+    'ȧ' => 'a',
+    'Ȩ' => 'E',
+    'ȩ' => 'e',
+    'Ȫ' => 'Ö',
+    'ȫ' => 'ö',
+    'Ȭ' => 'Õ',
+    'ȭ' => 'õ',
+    'Ȯ' => 'O',
+    'ȯ' => 'o',
+    'Ȱ' => 'Ȯ',
+    'ȱ' => 'ȯ',
+    'Ȳ' => 'Y',
+    'ȳ' => 'y',
+    'ȷ' => 'j',
+    'Ḁ' => 'A',
+    'ḁ' => 'a',
+    'Ḃ' => 'B',
+    'ḃ' => 'b',
+    'Ḅ' => 'B',
+    'ḅ' => 'b',
+    'Ḇ' => 'B',
+    'ḇ' => 'b',
+    'Ḉ' => 'Ç',
+    'ḉ' => 'ç',
+    'Ḋ' => 'D',
+    'ḋ' => 'd',
+    'Ḍ' => 'D',
+    'ḍ' => 'd',
+    'Ḏ' => 'D',
+    'ḏ' => 'd',
+    'Ḑ' => 'D',
+    'ḑ' => 'd',
+    'Ḓ' => 'D',
+    'ḓ' => 'd',
+    'Ḕ' => 'Ē',
+    'ḕ' => 'ē',
+    'Ḗ' => 'Ē',
+    'ḗ' => 'ē',
+    'Ḙ' => 'E',
+    'ḙ' => 'e',
+    'Ḛ' => 'E',
+    'ḛ' => 'e',
+    'Ḝ' => 'Ȩ',
+    'ḝ' => 'ȩ',
+    'Ḟ' => 'F',
+    'ḟ' => 'f',
+    'Ḡ' => 'G',
+    'ḡ' => 'g',
+    'Ḣ' => 'H',
+    'ḣ' => 'h',
+    'Ḥ' => 'H',
+    'ḥ' => 'h',
+    'Ḧ' => 'H',
+    'ḧ' => 'h',
+    'Ḩ' => 'H',
+    'ḩ' => 'h',
+    'Ḫ' => 'H',
+    'ḫ' => 'h',
+    'Ḭ' => 'I',
+    'ḭ' => 'i',
+    'Ḯ' => 'Ï',
+    'ḯ' => 'ï',
+    'Ḱ' => 'K',
+    'ḱ' => 'k',
+    'Ḳ' => 'K',
+    'ḳ' => 'k',
+    'Ḵ' => 'K',
+    'ḵ' => 'k',
+    'Ḷ' => 'L',
+    'ḷ' => 'l',
+    'Ḹ' => 'Ḷ',
+    'ḹ' => 'ḷ',
+    'Ḻ' => 'L',
+    'ḻ' => 'l',
+    'Ḽ' => 'L',
+    'ḽ' => 'l',
+    'Ḿ' => 'M',
+    'ḿ' => 'm',
+    'Ṁ' => 'M',
+    'ṁ' => 'm',
+    'Ṃ' => 'M',
+    'ṃ' => 'm',
+    'Ṅ' => 'N',
+    'ṅ' => 'n',
+    'Ṇ' => 'N',
+    'ṇ' => 'n',
+    'Ṉ' => 'N',
+    'ṉ' => 'n',
+    'Ṋ' => 'N',
+    'ṋ' => 'n',
+    'Ṍ' => 'Õ',
+    'ṍ' => 'õ',
+    'Ṏ' => 'Õ',
+    'ṏ' => 'õ',
+    'Ṑ' => 'Ō',
+    'ṑ' => 'ō',
+    'Ṓ' => 'Ō',
+    'ṓ' => 'ō',
+    'Ṕ' => 'P',
+    'ṕ' => 'p',
+    'Ṗ' => 'P',
+    'ṗ' => 'p',
+    'Ṙ' => 'R',
+    'ṙ' => 'r',
+    'Ṛ' => 'R',
+    'ṛ' => 'r',
+    'Ṝ' => 'Ṛ',
+    'ṝ' => 'ṛ',
+    'Ṟ' => 'R',
+    'ṟ' => 'r',
+    'Ṡ' => 'S',
+    'ṡ' => 's',
+    'Ṣ' => 'S',
+    'ṣ' => 's',
+    'Ṥ' => 'Ś',
+    'ṥ' => 'ś',
+    'Ṧ' => 'Š',
+    'ṧ' => 'š',
+    'Ṩ' => 'Ṣ',
+    'ṩ' => 'ṣ',
+    'Ṫ' => 'T',
+    'ṫ' => 't',
+    'Ṭ' => 'T',
+    'ṭ' => 't',
+    'Ṯ' => 'T',
+    'ṯ' => 't',
+    'Ṱ' => 'T',
+    'ṱ' => 't',
+    'Ṳ' => 'U',
+    'ṳ' => 'u',
+    'Ṵ' => 'U',
+    'ṵ' => 'u',
+    'Ṷ' => 'U',
+    'ṷ' => 'u',
+    'Ṹ' => 'Ũ',
+    'ṹ' => 'ũ',
+    'Ṻ' => 'Ū',
+    'ṻ' => 'ū',
+    'Ṽ' => 'V',
+    'ṽ' => 'v',
+    'Ṿ' => 'V',
+    'ṿ' => 'v',
+    'Ẁ' => 'W',
+    'ẁ' => 'w',
+    'Ẃ' => 'W',
+    'ẃ' => 'w',
+    'Ẅ' => 'W',
+    'ẅ' => 'w',
+    'Ẇ' => 'W',
+    'ẇ' => 'w',
+    'Ẉ' => 'W',
+    'ẉ' => 'w',
+    'Ẋ' => 'X',
+    'ẋ' => 'x',
+    'Ẍ' => 'X',
+    'ẍ' => 'x',
+    'Ẏ' => 'Y',
+    'ẏ' => 'y',
+    'Ẑ' => 'Z',
+    'ẑ' => 'z',
+    'Ẓ' => 'Z',
+    'ẓ' => 'z',
+    'Ẕ' => 'Z',
+    'ẕ' => 'z',
+    'ẖ' => 'h',
+    'ẗ' => 't',
+    'ẘ' => 'w',
+    'ẙ' => 'y',
+    'ẛ' => 'ſ',
+    'Ạ' => 'A',
+    'ạ' => 'a',
+    'Ả' => 'A',
+    'ả' => 'a',
+    'Ấ' => 'Â',
+    'ấ' => 'â',
+    'Ầ' => 'Â',
+    'ầ' => 'â',
+    'Ẩ' => 'Â',
+    'ẩ' => 'â',
+    'Ẫ' => 'Â',
+    'ẫ' => 'â',
+    'Ậ' => 'Ạ',
+    'ậ' => 'ạ',
+    'Ắ' => 'Ă',
+    'ắ' => 'ă',
+    'Ằ' => 'Ă',
+    'ằ' => 'ă',
+    'Ẳ' => 'Ă',
+    'ẳ' => 'ă',
+    'Ẵ' => 'Ă',
+    'ẵ' => 'ă',
+    'Ặ' => 'Ạ',
+    'ặ' => 'ạ',
+    'Ẹ' => 'E',
+    'ẹ' => 'e',
+    'Ẻ' => 'E',
+    'ẻ' => 'e',
+    'Ẽ' => 'E',
+    'ẽ' => 'e',
+    'Ế' => 'Ê',
+    'ế' => 'ê',
+    'Ề' => 'Ê',
+    'ề' => 'ê',
+    'Ể' => 'Ê',
+    'ể' => 'ê',
+    'Ễ' => 'Ê',
+    'ễ' => 'ê',
+    'Ệ' => 'Ẹ',
+    'ệ' => 'ẹ',
+    'Ỉ' => 'I',
+    'ỉ' => 'i',
+    'Ị' => 'I',
+    'ị' => 'i',
+    'Ọ' => 'O',
+    'ọ' => 'o',
+    'Ỏ' => 'O',
+    'ỏ' => 'o',
+    'Ố' => 'Ô',
+    'ố' => 'ô',
+    'Ồ' => 'Ô',
+    'ồ' => 'ô',
+    'Ổ' => 'Ô',
+    'ổ' => 'ô',
+    'Ỗ' => 'Ô',
+    'ỗ' => 'ô',
+    'Ộ' => 'Ọ',
+    'ộ' => 'ọ',
+    'Ớ' => 'Ơ',
+    'ớ' => 'ơ',
+    'Ờ' => 'Ơ',
+    'ờ' => 'ơ',
+    'Ở' => 'Ơ',
+    'ở' => 'ơ',
+    'Ỡ' => 'Ơ',
+    'ỡ' => 'ơ',
+    'Ợ' => 'Ơ',
+    'ợ' => 'ơ',
+    'Ụ' => 'U',
+    'ụ' => 'u',
+    'Ủ' => 'U',
+    'ủ' => 'u',
+    'Ứ' => 'Ư',
+    'ứ' => 'ư',
+    'Ừ' => 'Ư',
+    'ừ' => 'ư',
+    'Ử' => 'Ư',
+    'ử' => 'ư',
+    'Ữ' => 'Ư',
+    'ữ' => 'ư',
+    'Ự' => 'Ư',
+    'ự' => 'ư',
+    'Ỳ' => 'Y',
+    'ỳ' => 'y',
+    'Ỵ' => 'Y',
+    'ỵ' => 'y',
+    'Ỷ' => 'Y',
+    'ỷ' => 'y',
+    'Ỹ' => 'Y',
+    'ỹ' => 'y',
+    '‐' => '-',
+    '‑' => '-',
+    '‒' => '-',
+    '–' => '-',
+    '—' => '-',
+    '―' => '-',
+    '‘' => '\'',
+    '’' => '\'',
+    '‚' => ',',
+    '‛' => '\'',
+    '“' => '"',
+    '”' => '"',
+    '„' => '"',
+    '‟' => '"',
+    '•' => '·',
+    '…' => '.',
+    '′' => '\'',
+    '″' => '"',
+    '‹' => '<',
+    '›' => '>',
+    '€' => 'E',
+    '™' => 'T',
+};
+
+/// Characters made of a letter followed by a combining accent
+pub const COMPOSITIONS: phf::Map<&'static str, char> = phf_map! {
+    "A\u{300}" => 'À',
+    "A\u{301}" => 'Á',
+    "A\u{302}" => 'Â',
+    "A\u{303}" => 'Ã',
+    "A\u{308}" => 'Ä',
+    "A\u{30a}" => 'Å',
+    "C\u{327}" => 'Ç',
+    "E\u{300}" => 'È',
+    "E\u{301}" => 'É',
+    "E\u{302}" => 'Ê',
+    "E\u{308}" => 'Ë',
+    "I\u{300}" => 'Ì',
+    "I\u{301}" => 'Í',
+    "I\u{302}" => 'Î',
+    "I\u{308}" => 'Ï',
+    "N\u{303}" => 'Ñ',
+    "O\u{300}" => 'Ò',
+    "O\u{301}" => 'Ó',
+    "O\u{302}" => 'Ô',
+    "O\u{303}" => 'Õ',
+    "O\u{308}" => 'Ö',
+    "U\u{300}" => 'Ù',
+    "U\u{301}" => 'Ú',
+    "U\u{302}" => 'Û',
+    "U\u{308}" => 'Ü',
+    "Y\u{301}" => 'Ý',
+    "a\u{300}" => 'à',
+    "a\u{301}" => 'á',
+    "a\u{302}" => 'â',
+    "a\u{303}" => 'ã',
+    "a\u{308}" => 'ä',
+    "a\u{30a}" => 'å',
+    "c\u{327}" => 'ç',
+    "e\u{300}" => 'è',
+    "e\u{301}" => 'é',
+    "e\u{302}" => 'ê',
+    "e\u{308}" => 'ë',
+    "i\u{300}" => 'ì',
+    "i\u{301}" => 'í',
+    "i\u{302}" => 'î',
+    "i\u{308}" => 'ï',
+    "n\u{303}" => 'ñ',
+    "o\u{300}" => 'ò',
+    "o\u{301}" => 'ó',
+    "o\u{302}" => 'ô',
+    "o\u{303}" => 'õ',
+    "o\u{308}" => 'ö',
+    "u\u{300}" => 'ù',
+    "u\u{301}" => 'ú',
+    "u\u{302}" => 'û',
+    "u\u{308}" => 'ü',
+    "y\u{301}" => 'ý',
+    "y\u{308}" => 'ÿ',
+    "A\u{304}" => 'Ā',
+    "a\u{304}" => 'ā',
+    "A\u{306}" => 'Ă',
+    "a\u{306}" => 'ă',
+    "A\u{328}" => 'Ą',
+    "a\u{328}" => 'ą',
+    "C\u{301}" => 'Ć',
+    "c\u{301}" => 'ć',
+    "C\u{302}" => 'Ĉ',
+    "c\u{302}" => 'ĉ',
+    "C\u{307}" => 'Ċ',
+    "c\u{307}" => 'ċ',
+    "C\u{30c}" => 'Č',
+    "c\u{30c}" => 'č',
+    "D\u{30c}" => 'Ď',
+    "d\u{30c}" => 'ď',
+    "E\u{304}" => 'Ē',
+    "e\u{304}" => 'ē',
+    "E\u{306}" => 'Ĕ',
+    "e\u{306}" => 'ĕ',
+    "E\u{307}" => 'Ė',
+    "e\u{307}" => 'ė',
+    "E\u{328}" => 'Ę',
+    "e\u{328}" => 'ę',
+    "E\u{30c}" => 'Ě',
+    "e\u{30c}" => 'ě',
+    "G\u{302}" => 'Ĝ',
+    "g\u{302}" => 'ĝ',
+    "G\u{306}" => 'Ğ',
+    "g\u{306}" => 'ğ',
+    "G\u{307}" => 'Ġ',
+    "g\u{307}" => 'ġ',
+    "G\u{327}" => 'Ģ',
+    "g\u{327}" => 'ģ',
+    "H\u{302}" => 'Ĥ',
+    "h\u{302}" => 'ĥ',
+    "I\u{303}" => 'Ĩ',
+    "i\u{303}" => 'ĩ',
+    "I\u{304}" => 'Ī',
+    "i\u{304}" => 'ī',
+    "I\u{306}" => 'Ĭ',
+    "i\u{306}" => 'ĭ',
+    "I\u{328}" => 'Į',
+    "i\u{328}" => 'į',
+    "I\u{307}" => 'İ',
+    "J\u{302}" => 'Ĵ',
+    "j\u{302}" => 'ĵ',
+    "K\u{327}" => 'Ķ',
+    "k\u{327}" => 'ķ',
+    "L\u{301}" => 'Ĺ',
+    "l\u{301}" => 'ĺ',
+    "L\u{327}" => 'Ļ',
+    "l\u{327}" => 'ļ',
+    "L\u{30c}" => 'Ľ',
+    "l\u{30c}" => 'ľ',
+    "N\u{301}" => 'Ń',
+    "n\u{301}" => 'ń',
+    "N\u{327}" => 'Ņ',
+    "n\u{327}" => 'ņ',
+    "N\u{30c}" => 'Ň',
+    "n\u{30c}" => 'ň',
+    "O\u{304}" => 'Ō',
+    "o\u{304}" => 'ō',
+    "O\u{306}" => 'Ŏ',
+    "o\u{306}" => 'ŏ',
+    "O\u{30b}" => 'Ő',
+    "o\u{30b}" => 'ő',
+    "R\u{301}" => 'Ŕ',
+    "r\u{301}" => 'ŕ',
+    "R\u{327}" => 'Ŗ',
+    "r\u{327}" => 'ŗ',
+    "R\u{30c}" => 'Ř',
+    "r\u{30c}" => 'ř',
+    "S\u{301}" => 'Ś',
+    "s\u{301}" => 'ś',
+    "S\u{302}" => 'Ŝ',
+    "s\u{302}" => 'ŝ',
+    "S\u{327}" => 'Ş',
+    "s\u{327}" => 'ş',
+    "S\u{30c}" => 'Š',
+    "s\u{30c}" => 'š',
+    "T\u{327}" => 'Ţ',
+    "t\u{327}" => 'ţ',
+    "T\u{30c}" => 'Ť',
+    "t\u{30c}" => 'ť',
+    "U\u{303}" => 'Ũ',
+    "u\u{303}" => 'ũ',
+    "U\u{304}" => 'Ū',
+    "u\u{304}" => 'ū',
+    "U\u{306}" => 'Ŭ',
+    "u\u{306}" => 'ŭ',
+    "U\u{30a}" => 'Ů',
+    "u\u{30a}" => 'ů',
+    "U\u{30b}" => 'Ű',
+    "u\u{30b}" => 'ű',
+    "U\u{328}" => 'Ų',
+    "u\u{328}" => 'ų',
+    "W\u{302}" => 'Ŵ',
+    "w\u{302}" => 'ŵ',
+    "Y\u{302}" => 'Ŷ',
+    "y\u{302}" => 'ŷ',
+    "Y\u{308}" => 'Ÿ',
+    "Z\u{301}" => 'Ź',
+    "z\u{301}" => 'ź',
+    "Z\u{307}" => 'Ż',
+    "z\u{307}" => 'ż',
+    "Z\u{30c}" => 'Ž',
+    "z\u{30c}" => 'ž',
+    "O\u{31b}" => 'Ơ',
+    "o\u{31b}" => 'ơ',
+    "U\u{31b}" => 'Ư',
+    "u\u{31b}" => 'ư',
+    "A\u{30c}" => 'Ǎ',
+    "a\u{30c}" => 'ǎ',
+    "I\u{30c}" => 'Ǐ',
+    "i\u{30c}" => 'ǐ',
+    "O\u{30c}" => 'Ǒ',
+    "o\u{30c}" => 'ǒ',
+    "U\u{30c}" => 'Ǔ',
+    "u\u{30c}" => 'ǔ',
+    "Ü\u{304}" => 'Ǖ',
+    "ü\u{304}" => 'ǖ',
+    "Ü\u{301}" => 'Ǘ',
+    "ü\u{301}" => 'ǘ',
+    "Ü\u{30c}" => 'Ǚ',
+    "ü\u{30c}" => 'ǚ',
+    "Ü\u{300}" => 'Ǜ',
+    "ü\u{300}" => 'ǜ',
+    "Ä\u{304}" => 'Ǟ',
+    "ä\u{304}" => 'ǟ',
+    "Ȧ\u{304}" => 'Ǡ',
+    "ȧ\u{304}" => 'ǡ',
+    "Æ\u{304}" => 'Ǣ',
+    "æ\u{304}" => 'ǣ',
+    "G\u{30c}" => 'Ǧ',
+    "g\u{30c}" => 'ǧ',
+    "K\u{30c}" => 'Ǩ',
+    "k\u{30c}" => 'ǩ',
+    "O\u{328}" => 'Ǫ',
+    "o\u{328}" => 'ǫ',
+    "Ǫ\u{304}" => 'Ǭ',
+    "ǫ\u{304}" => 'ǭ',
+    "Ʒ\u{30c}" => 'Ǯ',
+    "ʒ\u{30c}" => 'ǯ',
+    "j\u{30c}" => 'ǰ',
+    "G\u{301}" => 'Ǵ',
+    "g\u{301}" => 'ǵ',
+    "N\u{300}" => 'Ǹ',
+    "n\u{300}" => 'ǹ',
+    "Å\u{301}" => 'Ǻ',
+    "å\u{301}" => 'ǻ',
+    "Æ\u{301}" => 'Ǽ',
+    "æ\u{301}" => 'ǽ',
+    "Ø\u{301}" => 'Ǿ',
+    "ø\u{301}" => 'ǿ',
+    "A\u{30f}" => 'Ȁ',
+    "a\u{30f}" => 'ȁ',
+    "A\u{311}" => 'Ȃ',
+    "a\u{311}" => 'ȃ',
+    "E\u{30f}" => 'Ȅ',
+    "e\u{30f}" => 'ȅ',
+    "E\u{311}" => 'Ȇ',
+    "e\u{311}" => 'ȇ',
+    "I\u{30f}" => 'Ȉ',
+    "i\u{30f}" => 'ȉ',
+    "I\u{311}" => 'Ȋ',
+    "i\u{311}" => 'ȋ',
+    "O\u{30f}" => 'Ȍ',
+    "o\u{30f}" => 'ȍ',
+    "O\u{311}" => 'Ȏ',
+    "o\u{311}" => 'ȏ',
+    "R\u{30f}" => 'Ȑ',
+    "r\u{30f}" => 'ȑ',
+    "R\u{311}" => 'Ȓ',
+    "r\u{311}" => 'ȓ',
+    "U\u{30f}" => 'Ȕ',
+    "u\u{30f}" => 'ȕ',
+    "U\u{311}" => 'Ȗ',
+    "u\u{311}" => 'ȗ',
+    "S\u{326}" => 'Ș',
+    "s\u{326}" => 'ș',
+    "T\u{326}" => 'Ț',
+    "t\u{326}" => 'ț',
+    "H\u{30c}" => 'Ȟ',
+    "h\u{30c}" => 'ȟ',
+    "A\u{307}" => 'Ȧ',
+    "a\u{307}" => 'ȧ',
+    "E\u{327}" => 'Ȩ',
+    "e\u{327}" => 'ȩ',
+    "Ö\u{304}" => 'Ȫ',
+    "ö\u{304}" => 'ȫ',
+    "Õ\u{304}" => 'Ȭ',
+    "õ\u{304}" => 'ȭ',
+    "O\u{307}" => 'Ȯ',
+    "o\u{307}" => 'ȯ',
+    "Ȯ\u{304}" => 'Ȱ',
+    "ȯ\u{304}" => 'ȱ',
+    "Y\u{304}" => 'Ȳ',
+    "y\u{304}" => 'ȳ',
+    "A\u{325}" => 'Ḁ',
+    "a\u{325}" => 'ḁ',
+    "B\u{307}" => 'Ḃ',
+    "b\u{307}" => 'ḃ',
+    "B\u{323}" => 'Ḅ',
+    "b\u{323}" => 'ḅ',
+    "B\u{331}" => 'Ḇ',
+    "b\u{331}" => 'ḇ',
+    "Ç\u{301}" => 'Ḉ',
+    "ç\u{301}" => 'ḉ',
+    "D\u{307}" => 'Ḋ',
+    "d\u{307}" => 'ḋ',
+    "D\u{323}" => 'Ḍ',
+    "d\u{323}" => 'ḍ',
+    "D\u{331}" => 'Ḏ',
+    "d\u{331}" => 'ḏ',
+    "D\u{327}" => 'Ḑ',
+    "d\u{327}" => 'ḑ',
+    "D\u{32d}" => 'Ḓ',
+    "d\u{32d}" => 'ḓ',
+    "Ē\u{300}" => 'Ḕ',
+    "ē\u{300}" => 'ḕ',
+    "Ē\u{301}" => 'Ḗ',
+    "ē\u{301}" => 'ḗ',
+    "E\u{32d}" => 'Ḙ',
+    "e\u{32d}" => 'ḙ',
+    "E\u{330}" => 'Ḛ',
+    "e\u{330}" => 'ḛ',
+    "Ȩ\u{306}" => 'Ḝ',
+    "ȩ\u{306}" => 'ḝ',
+    "F\u{307}" => 'Ḟ',
+    "f\u{307}" => 'ḟ',
+    "G\u{304}" => 'Ḡ',
+    "g\u{304}" => 'ḡ',
+    "H\u{307}" => 'Ḣ',
+    "h\u{307}" => 'ḣ',
+    "H\u{323}" => 'Ḥ',
+    "h\u{323}" => 'ḥ',
+    "H\u{308}" => 'Ḧ',
+    "h\u{308}" => 'ḧ',
+    "H\u{327}" => 'Ḩ',
+    "h\u{327}" => 'ḩ',
+    "H\u{32e}" => 'Ḫ',
+    "h\u{32e}" => 'ḫ',
+    "I\u{330}" => 'Ḭ',
+    "i\u{330}" => 'ḭ',
+    "Ï\u{301}" => 'Ḯ',
+    "ï\u{301}" => 'ḯ',
+    "K\u{301}" => 'Ḱ',
+    "k\u{301}" => 'ḱ',
+    "K\u{323}" => 'Ḳ',
+    "k\u{323}" => 'ḳ',
+    "K\u{331}" => 'Ḵ',
+    "k\u{331}" => 'ḵ',
+    "L\u{323}" => 'Ḷ',
+    "l\u{323}" => 'ḷ',
+    "Ḷ\u{304}" => 'Ḹ',
+    "ḷ\u{304}" => 'ḹ',
+    "L\u{331}" => 'Ḻ',
+    "l\u{331}" => 'ḻ',
+    "L\u{32d}" => 'Ḽ',
+    "l\u{32d}" => 'ḽ',
+    "M\u{301}" => 'Ḿ',
+    "m\u{301}" => 'ḿ',
+    "M\u{307}" => 'Ṁ',
+    "m\u{307}" => 'ṁ',
+    "M\u{323}" => 'Ṃ',
+    "m\u{323}" => 'ṃ',
+    "N\u{307}" => 'Ṅ',
+    "n\u{307}" => 'ṅ',
+    "N\u{323}" => 'Ṇ',
+    "n\u{323}" => 'ṇ',
+    "N\u{331}" => 'Ṉ',
+    "n\u{331}" => 'ṉ',
+    "N\u{32d}" => 'Ṋ',
+    "n\u{32d}" => 'ṋ',
+    "Õ\u{301}" => 'Ṍ',
+    "õ\u{301}" => 'ṍ',
+    "Õ\u{308}" => 'Ṏ',
+    "õ\u{308}" => 'ṏ',
+    "Ō\u{300}" => 'Ṑ',
+    "ō\u{300}" => 'ṑ',
+    "Ō\u{301}" => 'Ṓ',
+    "ō\u{301}" => 'ṓ',
+    "P\u{301}" => 'Ṕ',
+    "p\u{301}" => 'ṕ',
+    "P\u{307}" => 'Ṗ',
+    "p\u{307}" => 'ṗ',
+    "R\u{307}" => 'Ṙ',
+    "r\u{307}" => 'ṙ',
+    "R\u{323}" => 'Ṛ',
+    "r\u{323}" => 'ṛ',
+    "Ṛ\u{304}" => 'Ṝ',
+    "ṛ\u{304}" => 'ṝ',
+    "R\u{331}" => 'Ṟ',
+    "r\u{331}" => 'ṟ',
+    "S\u{307}" => 'Ṡ',
+    "s\u{307}" => 'ṡ',
+    "S\u{323}" => 'Ṣ',
+    "s\u{323}" => 'ṣ',
+    "Ś\u{307}" => 'Ṥ',
+    "ś\u{307}" => 'ṥ',
+    "Š\u{307}" => 'Ṧ',
+    "š\u{307}" => 'ṧ',
+    "Ṣ\u{307}" => 'Ṩ',
+    "ṣ\u{307}" => 'ṩ',
+    "T\u{307}" => 'Ṫ',
+    "t\u{307}" => 'ṫ',
+    "T\u{323}" => 'Ṭ',
+    "t\u{323}" => 'ṭ',
+    "T\u{331}" => 'Ṯ',
+    "t\u{331}" => 'ṯ',
+    "T\u{32d}" => 'Ṱ',
+    "t\u{32d}" => 'ṱ',
+    "U\u{324}" => 'Ṳ',
+    "u\u{324}" => 'ṳ',
+    "U\u{330}" => 'Ṵ',
+    "u\u{330}" => 'ṵ',
+    "U\u{32d}" => 'Ṷ',
+    "u\u{32d}" => 'ṷ',
+    "Ũ\u{301}" => 'Ṹ',
+    "ũ\u{301}" => 'ṹ',
+    "Ū\u{308}" => 'Ṻ',
+    "ū\u{308}" => 'ṻ',
+    "V\u{303}" => 'Ṽ',
+    "v\u{303}" => 'ṽ',
+    "V\u{323}" => 'Ṿ',
+    "v\u{323}" => 'ṿ',
+    "W\u{300}" => 'Ẁ',
+    "w\u{300}" => 'ẁ',
+    "W\u{301}" => 'Ẃ',
+    "w\u{301}" => 'ẃ',
+    "W\u{308}" => 'Ẅ',
+    "w\u{308}" => 'ẅ',
+    "W\u{307}" => 'Ẇ',
+    "w\u{307}" => 'ẇ',
+    "W\u{323}" => 'Ẉ',
+    "w\u{323}" => 'ẉ',
+    "X\u{307}" => 'Ẋ',
+    "x\u{307}" => 'ẋ',
+    "X\u{308}" => 'Ẍ',
+    "x\u{308}" => 'ẍ',
+    "Y\u{307}" => 'Ẏ',
+    "y\u{307}" => 'ẏ',
+    "Z\u{302}" => 'Ẑ',
+    "z\u{302}" => 'ẑ',
+    "Z\u{323}" => 'Ẓ',
+    "z\u{323}" => 'ẓ',
+    "Z\u{331}" => 'Ẕ',
+    "z\u{331}" => 'ẕ',
+    "h\u{331}" => 'ẖ',
+    "t\u{308}" => 'ẗ',
+    "w\u{30a}" => 'ẘ',
+    "y\u{30a}" => 'ẙ',
+    "ſ\u{307}" => 'ẛ',
+    "A\u{323}" => 'Ạ',
+    "a\u{323}" => 'ạ',
+    "A\u{309}" => 'Ả',
+    "a\u{309}" => 'ả',
+    "Â\u{301}" => 'Ấ',
+    "â\u{301}" => 'ấ',
+    "Â\u{300}" => 'Ầ',
+    "â\u{300}" => 'ầ',
+    "Â\u{309}" => 'Ẩ',
+    "â\u{309}" => 'ẩ',
+    "Â\u{303}" => 'Ẫ',
+    "â\u{303}" => 'ẫ',
+    "Ạ\u{302}" => 'Ậ',
+    "ạ\u{302}" => 'ậ',
+    "Ă\u{301}" => 'Ắ',
+    "ă\u{301}" => 'ắ',
+    "Ă\u{300}" => 'Ằ',
+    "ă\u{300}" => 'ằ',
+    "Ă\u{309}" => 'Ẳ',
+    "ă\u{309}" => 'ẳ',
+    "Ă\u{303}" => 'Ẵ',
+    "ă\u{303}" => 'ẵ',
+    "Ạ\u{306}" => 'Ặ',
+    "ạ\u{306}" => 'ặ',
+    "E\u{323}" => 'Ẹ',
+    "e\u{323}" => 'ẹ',
+    "E\u{309}" => 'Ẻ',
+    "e\u{309}" => 'ẻ',
+    "E\u{303}" => 'Ẽ',
+    "e\u{303}" => 'ẽ',
+    "Ê\u{301}" => 'Ế',
+    "ê\u{301}" => 'ế',
+    "Ê\u{300}" => 'Ề',
+    "ê\u{300}" => 'ề',
+    "Ê\u{309}" => 'Ể',
+    "ê\u{309}" => 'ể',
+    "Ê\u{303}" => 'Ễ',
+    "ê\u{303}" => 'ễ',
+    "Ẹ\u{302}" => 'Ệ',
+    "ẹ\u{302}" => 'ệ',
+    "I\u{309}" => 'Ỉ',
+    "i\u{309}" => 'ỉ',
+    "I\u{323}" => 'Ị',
+    "i\u{323}" => 'ị',
+    "O\u{323}" => 'Ọ',
+    "o\u{323}" => 'ọ',
+    "O\u{309}" => 'Ỏ',
+    "o\u{309}" => 'ỏ',
+    "Ô\u{301}" => 'Ố',
+    "ô\u{301}" => 'ố',
+    "Ô\u{300}" => 'Ồ',
+    "ô\u{300}" => 'ồ',
+    "Ô\u{309}" => 'Ổ',
+    "ô\u{309}" => 'ổ',
+    "Ô\u{303}" => 'Ỗ',
+    "ô\u{303}" => 'ỗ',
+    "Ọ\u{302}" => 'Ộ',
+    "ọ\u{302}" => 'ộ',
+    "Ơ\u{301}" => 'Ớ',
+    "ơ\u{301}" => 'ớ',
+    "Ơ\u{300}" => 'Ờ',
+    "ơ\u{300}" => 'ờ',
+    "Ơ\u{309}" => 'Ở',
+    "ơ\u{309}" => 'ở',
+    "Ơ\u{303}" => 'Ỡ',
+    "ơ\u{303}" => 'ỡ',
+    "Ơ\u{323}" => 'Ợ',
+    "ơ\u{323}" => 'ợ',
+    "U\u{323}" => 'Ụ',
+    "u\u{323}" => 'ụ',
+    "U\u{309}" => 'Ủ',
+    "u\u{309}" => 'ủ',
+    "Ư\u{301}" => 'Ứ',
+    "ư\u{301}" => 'ứ',
+    "Ư\u{300}" => 'Ừ',
+    "ư\u{300}" => 'ừ',
+    "Ư\u{309}" => 'Ử',
+    "ư\u{309}" => 'ử',
+    "Ư\u{303}" => 'Ữ',
+    "ư\u{303}" => 'ữ',
+    "Ư\u{323}" => 'Ự',
+    "ư\u{323}" => 'ự',
+    "Y\u{300}" => 'Ỳ',
+    "y\u{300}" => 'ỳ',
+    "Y\u{323}" => 'Ỵ',
+    "y\u{323}" => 'ỵ',
+    "Y\u{309}" => 'Ỷ',
+    "y\u{309}" => 'ỷ',
+    "Y\u{303}" => 'Ỹ',
+    "y\u{303}" => 'ỹ',
+};
@@ -0,0 +1,669 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use tokio::fs;
+
+use crate::error::{Error, Result};
+use crate::visualizer::font::{CHAR_HEIGHT, Fallback, Pixel, lookup_glyphs};
+use crate::visualizer::text_options::Glyph;
+
+const PSF1_MAGIC: [u8; 2] = [0x36, 0x04];
+const PSF2_MAGIC: [u8; 4] = [0x72, 0xb5, 0x4a, 0x86];
+const PSF2_HEADER_SIZE: usize = 32;
+
+/// Most glyphs a PSF font file can have, one per UCS-2 character
+const MAX_GLYPHS: usize = 0x10000;
+/// Largest width and height of a glyph, and largest offset of a BDF glyph, in pixels
+const MAX_GLYPH_SIZE: usize = 256;
+
+/// Format of a font file loaded at runtime
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum FontFormat {
+    /// Glyph Bitmap Distribution Format, a text format used by X11
+    Bdf,
+    /// PC Screen Font, version 1 or 2, used by the Linux console
+    Psf,
+    /// Same layout as the built-in fonts:
+    /// a `[c]` line followed by 7 lines of `x` (on) and `.` (off) for each character.
+    /// Empty lines and lines starting with `#` are ignored
+    Text,
+}
+
+impl FontFormat {
+    pub fn detect(content: &[u8]) -> Self {
+        if content.starts_with(&PSF1_MAGIC) || content.starts_with(&PSF2_MAGIC) {
+            FontFormat::Psf
+        } else if content.starts_with(b"STARTFONT") {
+            FontFormat::Bdf
+        } else {
+            FontFormat::Text
+        }
+    }
+}
+
+/// A glyph of any size, stored row by row from the top
+struct Bitmap {
+    width: usize,
+    rows: Vec<Vec<bool>>,
+}
+
+impl Bitmap {
+    fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn is_on(&self, x: usize, y: usize) -> bool {
+        self.rows
+            .get(y)
+            .and_then(|row| row.get(x))
+            .copied()
+            .unwrap_or(false)
+    }
+
+    /// Remove empty columns on both sides, keeping at least 1 column
+    fn trim(self) -> Self {
+        let used = |x: usize| (0..self.height()).any(|y| self.is_on(x, y));
+        let Some(first) = (0..self.width).find(|x| used(*x)) else {
+            return Bitmap {
+                width: 1,
+                rows: vec![vec![false]; self.height()],
+            };
+        };
+        let last = (0..self.width).rfind(|x| used(*x)).unwrap();
+        let rows = self
+            .rows
+            .iter()
+            .map(|row| (first..=last).map(|x| row.get(x) == Some(&true)).collect())
+            .collect();
+        Bitmap {
+            width: last - first + 1,
+            rows,
+        }
+    }
+
+    /// Convert to 7 rows, column by column.
+    /// Shorter glyphs are aligned to the bottom. Taller glyphs are shrunk in both directions:
+    /// each pixel lands on exactly one target pixel, which is on if any of its pixels is on
+    /// so that thin strokes are kept.
+    fn to_pixels(&self) -> Vec<Pixel> {
+        let height = self.height();
+        let scale = (height as f32 / CHAR_HEIGHT as f32).max(1.0);
+        let target = |i: usize| (i as f32 / scale) as usize;
+        let width = target(self.width.saturating_sub(1)) + 1;
+        let top_padding = CHAR_HEIGHT.saturating_sub(height);
+
+        let mut pixels = vec![Pixel::Off; width * CHAR_HEIGHT];
+        for y in 0..height {
+            for x in 0..self.width {
+                if self.is_on(x, y) {
+                    let row = (top_padding + target(y)).min(CHAR_HEIGHT - 1);
+                    pixels[target(x) * CHAR_HEIGHT + row] = Pixel::On;
+                }
+            }
+        }
+        pixels
+    }
+}
+
+/// A bitmap font loaded at runtime. Glyphs are converted to 7 rows like the built-in fonts.
+#[derive(Debug, Clone)]
+pub struct FontFile {
+    chars: BTreeMap<char, Vec<Pixel>>,
+}
+
+impl FontFile {
+    pub async fn read(path: impl AsRef<Path>) -> Result<Self> {
+        let content = fs::read(path).await?;
+        Self::parse(&content, FontFormat::detect(&content))
+    }
+
+    pub fn parse(content: &[u8], format: FontFormat) -> Result<Self> {
+        let bitmaps = match format {
+            FontFormat::Bdf => parse_bdf(std::str::from_utf8(content)?)?,
+            FontFormat::Psf => parse_psf(content)?,
+            FontFormat::Text => parse_text(std::str::from_utf8(content)?)?,
+        };
+        let chars: BTreeMap<char, Vec<Pixel>> = bitmaps
+            .into_iter()
+            .map(|(ch, bitmap)| (ch, bitmap.to_pixels()))
+            .collect();
+        if chars.is_empty() {
+            return Err(Error::InvalidData("Font file has no character".to_string()));
+        }
+        Ok(FontFile { chars })
+    }
+
+    /// Pixels of the character, column by column
+    pub fn get_char(&self, ch: char) -> Option<&[Pixel]> {
+        self.chars.get(&ch).map(Vec::as_slice)
+    }
+
+    pub fn supported_chars(&self) -> String {
+        let chars: String = self
+            .chars
+            .keys()
+            .filter(|c| !c.is_whitespace() && !c.is_control())
+            .collect();
+        format!("<space> {chars}")
+    }
+
+    /// Glyphs of the text, with missing characters handled by `fallback`
+    pub(crate) fn glyphs(&self, text: &str, fallback: Fallback) -> Result<Vec<Glyph>> {
+        lookup_glyphs(
+            text,
+            fallback,
+            |ch| {
+                self.get_char(ch).map(|pixels| Glyph {
+                    pixels: pixels.to_vec(),
+                    is_space: ch.is_whitespace(),
+                })
+            },
+            || self.supported_chars(),
+        )
+    }
+}
+
+fn parse_text(content: &str) -> Result<BTreeMap<char, Bitmap>> {
+    let mut chars = BTreeMap::new();
+    let mut current: Option<(char, Vec<Vec<bool>>)> = None;
+
+    let mut finish = |current: Option<(char, Vec<Vec<bool>>)>| -> Result<()> {
+        if let Some((ch, rows)) = current {
+            if rows.len() != CHAR_HEIGHT {
+                return Err(Error::InvalidData(format!(
+                    "Character {:?} must have {CHAR_HEIGHT} rows, got {}",
+                    ch,
+                    rows.len()
+                )));
+            }
+            let width = rows[0].len();
+            chars.insert(ch, Bitmap { width, rows });
+        }
+        Ok(())
+    };
+
+    for (i, line) in content.lines().enumerate() {
+        let line_number = i + 1;
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if line.starts_with('[') && line.ends_with(']') && line.chars().count() == 3 {
+            let ch = line.chars().nth(1).unwrap();
+            finish(current.take())?;
+            current = Some((ch, Vec::with_capacity(CHAR_HEIGHT)));
+            continue;
+        }
+
+        let Some((ch, rows)) = current.as_mut() else {
+            return Err(Error::InvalidData(format!(
+                "Line {line_number}: expected a character header like [A]"
+            )));
+        };
+        let row = line
+            .trim_end()
+            .chars()
+            .map(|c| match c {
+                'x' => Ok(true),
+                '.' => Ok(false),
+                _ => Err(Error::InvalidData(format!(
+                    "Line {line_number}: invalid pixel {c:?}, expected x or ."
+                ))),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        if rows.first().is_some_and(|first| first.len() != row.len()) {
+            return Err(Error::InvalidData(format!(
+                "Line {line_number}: rows of character {:?} must have the same width",
+                ch
+            )));
+        }
+        rows.push(row);
+    }
+    finish(current)?;
+
+    Ok(chars)
+}
+
+fn parse_bdf(content: &str) -> Result<BTreeMap<char, Bitmap>> {
+    let invalid = |line_number: usize, message: &str| {
+        Error::InvalidData(format!("Line {line_number}: {message}"))
+    };
+    // sizes and offsets are bounded, so that adding them can't overflow
+    let numbers = |values: &[&str], line_number: usize| -> Result<Vec<i32>> {
+        values
+            .iter()
+            .map(|v| {
+                let n = v
+                    .parse::<i32>()
+                    .map_err(|_| invalid(line_number, "invalid number"))?;
+                if n.unsigned_abs() as usize > MAX_GLYPH_SIZE {
+                    return Err(invalid(
+                        line_number,
+                        &format!("{n} is out of range, expected at most {MAX_GLYPH_SIZE} pixels"),
+                    ));
+                }
+                Ok(n)
+            })
+            .collect()
+    };
+
+    let mut chars = BTreeMap::new();
+    // top and bottom of the font bounding box, relative to the baseline
+    let mut font_top = 0;
+    let mut font_bottom = 0;
+
+    let mut encoding: Option<u32> = None;
+    let mut advance = 0;
+    let mut bbx = [0; 4];
+    let mut bitmap: Option<Vec<Vec<bool>>> = None;
+
+    for (i, line) in content.lines().enumerate() {
+        let line_number = i + 1;
+        let mut fields = line.split_whitespace();
+        let keyword = fields.next().unwrap_or_default();
+        let values: Vec<&str> = fields.collect();
+
+        if let Some(rows) = bitmap.as_mut()
+            && keyword != "ENDCHAR"
+        {
+            let width = bbx[0].max(0) as usize;
+            let row_bits = keyword.len() * 4;
+            if row_bits > 64 || width > row_bits {
+                return Err(invalid(line_number, "bitmap row is too wide"));
+            }
+            let bits = u64::from_str_radix(keyword, 16)
+                .map_err(|_| invalid(line_number, "invalid bitmap row"))?;
+            rows.push(
+                (0..width)
+                    .map(|x| bits & (1 << (row_bits - 1 - x)) != 0)
+                    .collect(),
+            );
+            continue;
+        }
+
+        match keyword {
+            "FONTBOUNDINGBOX" => {
+                let v = numbers(&values, line_number)?;
+                let [_, height, _, y_offset] = v[..] else {
+                    return Err(invalid(line_number, "FONTBOUNDINGBOX needs 4 values"));
+                };
+                font_bottom = y_offset;
+                font_top = y_offset + height;
+            }
+            "STARTCHAR" => {
+                encoding = None;
+                advance = 0;
+                bbx = [0; 4];
+            }
+            "ENCODING" => {
+                encoding = values.first().and_then(|v| v.parse::<u32>().ok());
+            }
+            "DWIDTH" => {
+                advance = numbers(&values, line_number)?.first().copied().unwrap_or(0);
+            }
+            "BBX" => {
+                let v = numbers(&values, line_number)?;
+                let [width, height, x_offset, y_offset] = v[..] else {
+                    return Err(invalid(line_number, "BBX needs 4 values"));
+                };
+                bbx = [width, height, x_offset, y_offset];
+            }
+            "BITMAP" => bitmap = Some(Vec::new()),
+            "ENDCHAR" => {
+                let rows = bitmap.take().unwrap_or_default();
+                let Some(ch) = encoding.and_then(char::from_u32) else {
+                    continue;
+                };
+                let [width, height, x_offset, y_offset] = bbx;
+                if rows.len() != height.max(0) as usize {
+                    return Err(invalid(line_number, "bitmap rows don't match BBX height"));
+                }
+                if width <= 0 || height <= 0 {
+                    // blank glyph like space: keep its advance width minus the space between chars
+                    let width = (advance - 1).max(1) as usize;
+                    let height = (font_top - font_bottom).max(1) as usize;
+                    chars.insert(
+                        ch,
+                        Bitmap {
+                            width,
+                            rows: vec![vec![false; width]; height],
+                        },
+                    );
+                    continue;
+                }
+
+                // place the glyph in the font bounding box, so that all glyphs share the baseline
+                let x_offset = x_offset.max(0) as usize;
+                let top = font_top.max(y_offset + height);
+                let bottom = font_bottom.min(y_offset);
+                let cell_width = x_offset + width as usize;
+                let mut cell = vec![vec![false; cell_width]; (top - bottom) as usize];
+                let first_row = (top - (y_offset + height)) as usize;
+                for (y, row) in rows.into_iter().enumerate() {
+                    for (x, on) in row.into_iter().enumerate() {
+                        cell[first_row + y][x_offset + x] = on;
+                    }
+                }
+                chars.insert(
+                    ch,
+                    Bitmap {
+                        width: cell_width,
+                        rows: cell,
+                    }
+                    .trim(),
+                );
+            }
+            _ => {}
+        }
+    }
+    if bitmap.is_some() {
+        return Err(Error::InvalidData("BDF font file is truncated".to_string()));
+    }
+
+    Ok(chars)
+}
+
+fn parse_psf(content: &[u8]) -> Result<BTreeMap<char, Bitmap>> {
+    let truncated = || Error::InvalidData("PSF font file is truncated".to_string());
+    let u32_at = |offset: usize| -> Result<u32> {
+        let bytes = content.get(offset..offset + 4).ok_or_else(truncated)?;
+        Ok(u32::from_le_bytes(bytes.try_into().unwrap()))
+    };
+
+    // glyph data offset, glyph count, bytes per glyph, height, width, unicode table offset
+    let (offset, count, glyph_size, height, width, has_table) = if content.starts_with(&PSF1_MAGIC)
+    {
+        let mode = *content.get(2).ok_or_else(truncated)?;
+        let height = *content.get(3).ok_or_else(truncated)? as usize;
+        let count = if mode & 0x01 != 0 { 512 } else { 256 };
+        (4, count, height, height, 8, mode & 0x02 != 0)
+    } else if content.starts_with(&PSF2_MAGIC) {
+        let header_size = u32_at(8)? as usize;
+        if header_size < PSF2_HEADER_SIZE || header_size > content.len() {
+            return Err(Error::InvalidData(format!(
+                "PSF header size {header_size} is invalid"
+            )));
+        }
+        let flags = u32_at(12)?;
+        let count = u32_at(16)? as usize;
+        let glyph_size = u32_at(20)? as usize;
+        let height = u32_at(24)? as usize;
+        let width = u32_at(28)? as usize;
+        (
+            header_size,
+            count,
+            glyph_size,
+            height,
+            width,
+            flags & 0x01 != 0,
+        )
+    } else {
+        return Err(Error::InvalidData("Not a PSF font file".to_string()));
+    };
+    if count > MAX_GLYPHS {
+        return Err(Error::InvalidData(format!(
+            "PSF font file has {count} glyphs, expected at most {MAX_GLYPHS}"
+        )));
+    }
+    if !(1..=MAX_GLYPH_SIZE).contains(&width) || !(1..=MAX_GLYPH_SIZE).contains(&height) {
+        return Err(Error::InvalidData(format!(
+            "PSF glyphs are {width}x{height} pixels, expected 1 to {MAX_GLYPH_SIZE} pixels each way"
+        )));
+    }
+    let row_size = width.div_ceil(8);
+    if row_size * height > glyph_size {
+        return Err(Error::InvalidData(
+            "PSF glyph size is smaller than its width and height".to_string(),
+        ));
+    }
+    let table_offset = count
+        .checked_mul(glyph_size)
+        .and_then(|glyphs_size| glyphs_size.checked_add(offset))
+        .filter(|table_offset| *table_offset <= content.len())
+        .ok_or_else(truncated)?;
+
+    // which characters each glyph draws
+    let mut codes: Vec<Vec<u32>> = if has_table {
+        vec![Vec::new(); count]
+    } else {
+        (0..count as u32).map(|i| vec![i]).collect()
+    };
+    if has_table {
+        let table = &content[table_offset..];
+        let mut glyph = 0;
+        if content.starts_with(&PSF1_MAGIC) {
+            // UCS-2 little endian values, 0xFFFE starts a sequence, 0xFFFF ends the glyph
+            let mut in_sequence = false;
+            for value in table
+                .chunks_exact(2)
+                .map(|b| u16::from_le_bytes([b[0], b[1]]))
+            {
+                match value {
+                    0xFFFF => {
+                        glyph += 1;
+                        in_sequence = false;
+                    }
+                    0xFFFE => in_sequence = true,
+                    _ if !in_sequence && glyph < count => codes[glyph].push(value as u32),
+                    _ => {}
+                }
+            }
+        } else {
+            // UTF-8 strings, 0xFE starts a sequence, 0xFF ends the glyph
+            for entry in table.split(|b| *b == 0xFF).take(count) {
+                let single = entry.split(|b| *b == 0xFE).next().unwrap_or_default();
+                let text = String::from_utf8_lossy(single);
+                codes[glyph].extend(text.chars().map(|c| c as u32));
+                glyph += 1;
+            }
+        }
+    }
+
+    let mut chars = BTreeMap::new();
+    for (glyph, glyph_codes) in codes.iter().enumerate() {
+        let data = &content[offset + glyph * glyph_size..][..glyph_size];
+        let rows: Vec<Vec<bool>> = (0..height)
+            .map(|y| {
+                (0..width)
+                    .map(|x| data[y * row_size + x / 8] & (0x80 >> (x % 8)) != 0)
+                    .collect()
+            })
+            .collect();
+        for ch in glyph_codes.iter().copied().filter_map(char::from_u32) {
+            let bitmap = Bitmap {
+                width,
+                rows: rows.clone(),
+            };
+            // console fonts are monospaced with blank columns, keep only the drawn part
+            let bitmap = if ch == ' ' {
+                Bitmap {
+                    width: width.div_ceil(2),
+                    rows: vec![Vec::new(); height],
+                }
+            } else {
+                bitmap.trim()
+            };
+            chars.entry(ch).or_insert(bitmap);
+        }
+    }
+
+    Ok(chars)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 'I' as a 3x5 glyph in the middle of an 8x7 cell, one byte per row
+    const GLYPH_I: [u8; CHAR_HEIGHT] = [0x00, 0x38, 0x10, 0x10, 0x10, 0x38, 0x00];
+    const I_ROWS: [&str; CHAR_HEIGHT] = ["...", "xxx", ".x.", ".x.", ".x.", "xxx", "..."];
+
+    /// Rows of the character, from the top
+    fn rows(font: &FontFile, ch: char) -> Vec<String> {
+        let pixels = font.get_char(ch).unwrap();
+        (0..CHAR_HEIGHT)
+            .map(|y| {
+                pixels
+                    .chunks(CHAR_HEIGHT)
+                    .map(|column| if column[y] == Pixel::On { 'x' } else { '.' })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn parse_error(content: &[u8], format: FontFormat) -> String {
+        match FontFile::parse(content, format) {
+            Err(Error::InvalidData(message)) => message,
+            other => panic!("expected an InvalidData error, got {other:?}"),
+        }
+    }
+
+    fn psf1() -> Vec<u8> {
+        let mut content = vec![PSF1_MAGIC[0], PSF1_MAGIC[1], 0, CHAR_HEIGHT as u8];
+        for glyph in 0..256 {
+            content.extend(if glyph == 'I' as usize {
+                GLYPH_I
+            } else {
+                [0; CHAR_HEIGHT]
+            });
+        }
+        content
+    }
+
+    /// A single glyph drawing `I`, with a unicode table
+    fn psf2(count: u32, glyph_size: u32) -> Vec<u8> {
+        let mut content = PSF2_MAGIC.to_vec();
+        for value in [
+            0,
+            PSF2_HEADER_SIZE as u32,
+            1,
+            count,
+            glyph_size,
+            CHAR_HEIGHT as u32,
+            8,
+        ] {
+            content.extend(value.to_le_bytes());
+        }
+        content.extend(GLYPH_I);
+        content.extend(b"I\xFF");
+        content
+    }
+
+    const BDF: &str = "STARTFONT 2.1
+FONTBOUNDINGBOX 4 6 0 -1
+STARTCHAR space
+ENCODING 32
+DWIDTH 3 0
+BBX 0 0 0 0
+BITMAP
+ENDCHAR
+STARTCHAR I
+ENCODING 73
+DWIDTH 4 0
+BBX 3 5 0 0
+BITMAP
+E0
+40
+40
+40
+E0
+ENDCHAR
+ENDFONT
+";
+
+    #[test]
+    fn parses_psf1() {
+        let content = psf1();
+        assert_eq!(FontFormat::detect(&content), FontFormat::Psf);
+        let font = FontFile::parse(&content, FontFormat::Psf).unwrap();
+        assert_eq!(rows(&font, 'I'), I_ROWS);
+    }
+
+    #[test]
+    fn parses_psf2_with_unicode_table() {
+        let font = FontFile::parse(&psf2(1, 7), FontFormat::Psf).unwrap();
+        assert_eq!(rows(&font, 'I'), I_ROWS);
+        assert!(font.get_char('\0').is_none());
+    }
+
+    #[test]
+    fn parses_bdf() {
+        let content = BDF.as_bytes();
+        assert_eq!(FontFormat::detect(content), FontFormat::Bdf);
+        let font = FontFile::parse(content, FontFormat::Bdf).unwrap();
+        // the last row is below the baseline
+        assert_eq!(rows(&font, 'I'), I_ROWS);
+        assert_eq!(font.get_char(' ').unwrap().len(), 2 * CHAR_HEIGHT);
+    }
+
+    #[test]
+    fn reports_invalid_psf_headers() {
+        assert_eq!(
+            parse_error(&psf2(u32::MAX, 7), FontFormat::Psf),
+            "PSF font file has 4294967295 glyphs, expected at most 65536"
+        );
+        assert_eq!(
+            parse_error(&psf2(1, 0), FontFormat::Psf),
+            "PSF glyph size is smaller than its width and height"
+        );
+        assert_eq!(
+            parse_error(&psf2(2, 7), FontFormat::Psf),
+            "PSF font file is truncated"
+        );
+
+        let mut content = psf2(1, 7);
+        content[8..12].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert_eq!(
+            parse_error(&content, FontFormat::Psf),
+            "PSF header size 4294967295 is invalid"
+        );
+
+        let mut content = psf2(1, 7);
+        content[28..32].copy_from_slice(&0u32.to_le_bytes());
+        assert_eq!(
+            parse_error(&content, FontFormat::Psf),
+            "PSF glyphs are 0x7 pixels, expected 1 to 256 pixels each way"
+        );
+    }
+
+    #[test]
+    fn reports_truncated_psf_files() {
+        let content = psf1();
+        for length in [3, 4, content.len() - 1] {
+            assert_eq!(
+                parse_error(&content[..length], FontFormat::Psf),
+                "PSF font file is truncated"
+            );
+        }
+        let content = psf2(1, 7);
+        for length in [12, 31, PSF2_HEADER_SIZE + 6] {
+            assert!(FontFile::parse(&content[..length], FontFormat::Psf).is_err());
+        }
+    }
+
+    #[test]
+    fn reports_invalid_bdf_files() {
+        let content = BDF.replace("BBX 3 5 0 0", "BBX 3 2147483647 0 2147483647");
+        assert_eq!(
+            parse_error(content.as_bytes(), FontFormat::Bdf),
+            "Line 12: 2147483647 is out of range, expected at most 256 pixels"
+        );
+        let content = BDF.replace(
+            "FONTBOUNDINGBOX 4 6 0 -1",
+            "FONTBOUNDINGBOX 4 6 0 -2147483648",
+        );
+        assert_eq!(
+            parse_error(content.as_bytes(), FontFormat::Bdf),
+            "Line 2: -2147483648 is out of range, expected at most 256 pixels"
+        );
+        let content = &BDF[..BDF.find("40\nE0").unwrap()];
+        assert_eq!(
+            parse_error(content.as_bytes(), FontFormat::Bdf),
+            "BDF font file is truncated"
+        );
+        let content = BDF.replace("40\nE0\n", "");
+        assert_eq!(
+            parse_error(content.as_bytes(), FontFormat::Bdf),
+            "Line 17: bitmap rows don't match BBX height"
+        );
+    }
+}
@@ -0,0 +1,270 @@
+use phf::phf_map;
+
+use crate::visualizer::font::Char;
+
+pub const CHARS_MAPPING: phf::Map<char, Char> = phf_map! {
+
+' ' => Char {
+    data: [
+        "...",
+        "...",
+        "...",
+        "...",
+        "...",
+        "...",
+        "...",
+    ],
+},
+
+'a' => Char {
+    data: [
+        "...x...",
+        "...xx..",
+        "xxxxxx.",
+        "xxxxxxx",
+        "xxxxxx.",
+        "...xx..",
+        "...x...",
+    ],
+},
+
+'b' => Char {
+    data: [
+        "...x...",
+        "..xx...",
+        ".xxxxxx",
+        "xxxxxxx",
+        ".xxxxxx",
+        "..xx...",
+        "...x...",
+    ],
+},
+
+'c' => Char {
+    data: [
+        ".x.x..",
+        "x.x...",
+        "......",
+        "xxxxx.",
+        "xxxx.x",
+        "xxxx.x",
+        ".xxx..",
+    ],
+},
+
+'d' => Char {
+    data: [
+        "...x...",
+        "..xxx..",
+        ".xxxxx.",
+        "xxxxxxx",
+        ".xxxxx.",
+        "..xxx..",
+        "...x...",
+    ],
+},
+
+'f' => Char {
+    data: [
+        ".xxxxx.",
+        "x.....x",
+        "x.x.x.x",
+        "x.....x",
+        "x..x..x",
+        "x.x.x.x",
+        ".xxxxx.",
+    ],
+},
+
+'g' => Char {
+    data: [
+        ".xxxxx.",
+        "xxxxxxx",
+        "x..x..x",
+        "xxxxxxx",
+        "xxxxxxx",
+        "xxxxxxx",
+        "x.x.x.x",
+    ],
+},
+
+'h' => Char {
+    data: [
+        ".......",
+        ".xx.xx.",
+        "xxxxxxx",
+        "xxxxxxx",
+        ".xxxxx.",
+        "..xxx..",
+        "...x...",
+    ],
+},
+
+'i' => Char {
+    data: [
+        "..x...x..",
+        "...x.x...",
+        "..xxxxx..",
+        ".xx.x.xx.",
+        "xxxxxxxxx",
+        "x.xxxxx.x",
+        "x.x...x.x",
+    ],
+},
+
+'k' => Char {
+    data: [
+        ".xxxxx.",
+        "xxxxxxx",
+        "x..x..x",
+        "xxxxxxx",
+        ".xx.xx.",
+        ".xxxxx.",
+        ".x.x.x.",
+    ],
+},
+
+'l' => Char {
+    data: [
+        "...xx",
+        "..xx.",
+        ".xx..",
+        "xxxxx",
+        "..xx.",
+        ".xx..",
+        "xx...",
+    ],
+},
+
+'m' => Char {
+    data: [
+        "x.....x",
+        "xx...xx",
+        "x.x.x.x",
+        "x..x..x",
+        "x.....x",
+        "x.....x",
+        "x.....x",
+    ],
+},
+
+'n' => Char {
+    data: [
+        "..xxxx",
+        "..x..x",
+        "..x..x",
+        "..x..x",
+        "xxx.xx",
+        "xxxxxx",
+        "xx..xx",
+    ],
+},
+
+'o' => Char {
+    data: [
+        "..xxx..",
+        ".x...x.",
+        "x.....x",
+        "x.....x",
+        "x.....x",
+        ".x...x.",
+        "..xxx..",
+    ],
+},
+
+'p' => Char {
+    data: [
+        ".xxxx.",
+        "xxxxxx",
+        "xxxx..",
+        "xxx...",
+        "xxxx..",
+        "xxxxxx",
+        ".xxxx.",
+    ],
+},
+
+'s' => Char {
+    data: [
+        "...x...",
+        "...x...",
+        "xxxxxxx",
+        ".xxxxx.",
+        "..xxx..",
+        ".xx.xx.",
+        "x.....x",
+    ],
+},
+
+'t' => Char {
+    data: [
+        "...x...",
+        "..xxx..",
+        ".xxxxx.",
+        "..xxx..",
+        ".xxxxx.",
+        "xxxxxxx",
+        "...x...",
+    ],
+},
+
+'u' => Char {
+    data: [
+        "...x...",
+        "..xxx..",
+        ".xxxxx.",
+        "xxxxxxx",
+        "..xxx..",
+        "..xxx..",
+        "..xxx..",
+    ],
+},
+
+'v' => Char {
+    data: [
+        "..xxx..",
+        "..xxx..",
+        "..xxx..",
+        "xxxxxxx",
+        ".xxxxx.",
+        "..xxx..",
+        "...x...",
+    ],
+},
+
+'x' => Char {
+    data: [
+        "x.....x",
+        ".x...x.",
+        "..x.x..",
+        "...x...",
+        "..x.x..",
+        ".x...x.",
+        "x.....x",
+    ],
+},
+
+'y' => Char {
+    data: [
+        ".xxxxx.",
+        "x.....x",
+        "x.x.x.x",
+        "x.....x",
+        "x.x.x.x",
+        "x..x..x",
+        ".xxxxx.",
+    ],
+},
+
+'\u{fffd}' => Char {
+    data: [
+        "xxxxx",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        "xxxxx",
+    ],
+},
+};
@@ -0,0 +1,5478 @@
+use phf::phf_map;
+
+use crate::visualizer::font::Char;
+
+pub const CHARS_MAPPING: phf::Map<char, Char> = phf_map! {
+
+' ' => Char {
+    data: [
+        ".....",
+        ".....",
+        ".....",
+        ".....",
+        ".....",
+        ".....",
+        ".....",
+    ],
+},
+
+'!' => Char {
+    data: [
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+        ".....",
+        "..x..",
+    ],
+},
+
+'"' => Char {
+    data: [
+        ".x.x.",
+        ".x.x.",
+        ".....",
+        ".....",
+        ".....",
+        ".....",
+        ".....",
+    ],
+},
+
+'#' => Char {
+    data: [
+        ".....",
+        ".x.x.",
+        "xxxxx",
+        ".x.x.",
+        "xxxxx",
+        ".x.x.",
+        ".....",
+    ],
+},
+
+'$' => Char {
+    data: [
+        ".xxx.",
+        "x.x.x",
+        "x.x..",
+        ".xxx.",
+        "..x.x",
+        "x.x.x",
+        ".xxx.",
+    ],
+},
+
+'%' => Char {
+    data: [
+        "xx...",
+        "xx..x",
+        "...x.",
+        "..x..",
+        ".x...",
+        "x..xx",
+        "...xx",
+    ],
+},
+
+'&' => Char {
+    data: [
+        ".xx..",
+        "x..x.",
+        "x.x..",
+        ".x...",
+        "x.x.x",
+        "x..x.",
+        ".xx.x",
+    ],
+},
+
+'\'' => Char {
+    data: [
+        "..x..",
+        "..x..",
+        ".....",
+        ".....",
+        ".....",
+        ".....",
+        ".....",
+    ],
+},
+
+'(' => Char {
+    data: [
+        "..x..",
+        ".x...",
+        ".x...",
+        ".x...",
+        ".x...",
+        ".x...",
+        "..x..",
+    ],
+},
+
+')' => Char {
+    data: [
+        ".x...",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+        ".x...",
+    ],
+},
+
+'*' => Char {
+    data: [
+        "..x..",
+        "x.x.x",
+        ".xxx.",
+        "..x..",
+        ".xxx.",
+        "x.x.x",
+        "..x..",
+    ],
+},
+
+'+' => Char {
+    data: [
+        ".....",
+        "..x..",
+        "..x..",
+        "xxxxx",
+        "..x..",
+        "..x..",
+        ".....",
+    ],
+},
+
+',' => Char {
+    data: [
+        ".....",
+        ".....",
+        ".....",
+        ".....",
+        ".....",
+        "..x..",
+        ".xx..",
+    ],
+},
+
+'-' => Char {
+    data: [
+        ".....",
+        ".....",
+        ".....",
+        ".xxx.",
+        ".....",
+        ".....",
+        ".....",
+    ],
+},
+
+'.' => Char {
+    data: [
+        ".....",
+        ".....",
+        ".....",
+        ".....",
+        ".....",
+        ".....",
+        "..x..",
+    ],
+},
+
+'/' => Char {
+    data: [
+        "....x",
+        "....x",
+        "...x.",
+        "..x..",
+        ".x...",
+        "x....",
+        "x....",
+    ],
+},
+
+'0' => Char {
+    data: [
+        ".xxx.",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'1' => Char {
+    data: [
+        "..x..",
+        "xxx..",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+        "xxxxx",
+    ],
+},
+
+'2' => Char {
+    data: [
+        ".xxx.",
+        "x...x",
+        "....x",
+        "...x.",
+        "..x..",
+        ".x...",
+        "xxxxx",
+    ],
+},
+
+'3' => Char {
+    data: [
+        ".xxx.",
+        "x...x",
+        "....x",
+        "..xx.",
+        "....x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'4' => Char {
+    data: [
+        "....x",
+        "...xx",
+        "..x.x",
+        ".x..x",
+        "xxxxx",
+        "....x",
+        "....x",
+    ],
+},
+
+'5' => Char {
+    data: [
+        "xxxxx",
+        "x....",
+        "x....",
+        "xxxx.",
+        "....x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'6' => Char {
+    data: [
+        ".xxx.",
+        "x...x",
+        "x....",
+        "xxxx.",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'7' => Char {
+    data: [
+        "xxxxx",
+        "....x",
+        "...x.",
+        "..x..",
+        ".x...",
+        ".x...",
+        ".x...",
+    ],
+},
+
+'8' => Char {
+    data: [
+        ".xxx.",
+        "x...x",
+        "x...x",
+        ".xxx.",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'9' => Char {
+    data: [
+        ".xxx.",
+        "x...x",
+        "x...x",
+        ".xxxx",
+        "....x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+':' => Char {
+    data: [
+        ".....",
+        ".....",
+        ".....",
+        "..x..",
+        ".....",
+        ".....",
+        "..x..",
+    ],
+},
+
+';' => Char {
+    data: [
+        ".....",
+        ".....",
+        ".....",
+        "..x..",
+        ".....",
+        "..x..",
+        ".xx..",
+    ],
+},
+
+'<' => Char {
+    data: [
+        "...x.",
+        "..x..",
+        ".x...",
+        "x....",
+        ".x...",
+        "..x..",
+        "...x.",
+    ],
+},
+
+'=' => Char {
+    data: [
+        ".....",
+        ".....",
+        "xxxxx",
+        ".....",
+        "xxxxx",
+        ".....",
+        ".....",
+    ],
+},
+
+'>' => Char {
+    data: [
+        "x....",
+        ".x...",
+        "..x..",
+        "...x.",
+        "..x..",
+        ".x...",
+        "x....",
+    ],
+},
+
+'?' => Char {
+    data: [
+        ".xxx.",
+        "x...x",
+        "....x",
+        "..xx.",
+        ".x...",
+        ".....",
+        ".x...",
+    ],
+},
+
+'@' => Char {
+    data: [
+        ".xxx.",
+        "x...x",
+        "x..xx",
+        "x.x.x",
+        "x.x.x",
+        "x..x.",
+        ".x...",
+    ],
+},
+
+'A' => Char {
+    data: [
+        ".xxx.",
+        "x...x",
+        "x...x",
+        "xxxxx",
+        "x...x",
+        "x...x",
+        "x...x",
+    ],
+},
+
+'B' => Char {
+    data: [
+        "xxxx.",
+        "x...x",
+        "x...x",
+        "xxxx.",
+        "x...x",
+        "x...x",
+        "xxxx.",
+    ],
+},
+
+'C' => Char {
+    data: [
+        ".xxx.",
+        "x...x",
+        "x....",
+        "x....",
+        "x....",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'D' => Char {
+    data: [
+        "xxxx.",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        "xxxx.",
+    ],
+},
+
+'E' => Char {
+    data: [
+        "xxxxx",
+        "x....",
+        "x....",
+        "xxxx.",
+        "x....",
+        "x....",
+        "xxxxx",
+    ],
+},
+
+'F' => Char {
+    data: [
+        "xxxxx",
+        "x....",
+        "x....",
+        "xxxx.",
+        "x....",
+        "x....",
+        "x....",
+    ],
+},
+
+'G' => Char {
+    data: [
+        ".xxx.",
+        "x...x",
+        "x....",
+        "x.xxx",
+        "x...x",
+        "x...x",
+        ".xxxx",
+    ],
+},
+
+'H' => Char {
+    data: [
+        "x...x",
+        "x...x",
+        "x...x",
+        "xxxxx",
+        "x...x",
+        "x...x",
+        "x...x",
+    ],
+},
+
+'I' => Char {
+    data: [
+        ".xxx.",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+        "xxxxx",
+    ],
+},
+
+'J' => Char {
+    data: [
+        ".xxxx",
+        "....x",
+        "....x",
+        "....x",
+        "....x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'K' => Char {
+    data: [
+        "x...x",
+        "x..x.",
+        "x.x..",
+        "xx...",
+        "x.x..",
+        "x..x.",
+        "x...x",
+    ],
+},
+
+'L' => Char {
+    data: [
+        "x....",
+        "x....",
+        "x....",
+        "x....",
+        "x....",
+        "x....",
+        "xxxxx",
+    ],
+},
+
+'M' => Char {
+    data: [
+        "xx.xx",
+        "x.x.x",
+        "x.x.x",
+        "x.x.x",
+        "x...x",
+        "x...x",
+        "x...x",
+    ],
+},
+
+'N' => Char {
+    data: [
+        "x...x",
+        "x...x",
+        "xx..x",
+        "x.x.x",
+        "x..xx",
+        "x...x",
+        "x...x",
+    ],
+},
+
+'O' => Char {
+    data: [
+        ".xxx.",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'P' => Char {
+    data: [
+        "xxxx.",
+        "x...x",
+        "x...x",
+        "xxxx.",
+        "x....",
+        "x....",
+        "x....",
+    ],
+},
+
+'Q' => Char {
+    data: [
+        ".xxx.",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x.x.x",
+        "x..x.",
+        ".xx.x",
+    ],
+},
+
+'R' => Char {
+    data: [
+        "xxxx.",
+        "x...x",
+        "x...x",
+        "xxxx.",
+        "x.x..",
+        "x..x.",
+        "x...x",
+    ],
+},
+
+'S' => Char {
+    data: [
+        ".xxx.",
+        "x...x",
+        "x....",
+        ".xxx.",
+        "....x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'T' => Char {
+    data: [
+        "xxxxx",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+    ],
+},
+
+'U' => Char {
+    data: [
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'V' => Char {
+    data: [
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".x.x.",
+        "..x..",
+    ],
+},
+
+'W' => Char {
+    data: [
+        "x...x",
+        "x...x",
+        "x.x.x",
+        "x.x.x",
+        "x.x.x",
+        "x.x.x",
+        ".x.x.",
+    ],
+},
+
+'X' => Char {
+    data: [
+        "x...x",
+        ".x.x.",
+        "..x..",
+        "..x..",
+        "..x..",
+        ".x.x.",
+        "x...x",
+    ],
+},
+
+'Y' => Char {
+    data: [
+        "x...x",
+        "x...x",
+        "x...x",
+        ".x.x.",
+        "..x..",
+        "..x..",
+        "..x..",
+    ],
+},
+
+'Z' => Char {
+    data: [
+        "xxxxx",
+        "....x",
+        "...x.",
+        "..x..",
+        ".x...",
+        "x....",
+        "xxxxx",
+    ],
+},
+
+'[' => Char {
+    data: [
+        ".xx..",
+        ".x...",
+        ".x...",
+        ".x...",
+        ".x...",
+        ".x...",
+        ".xx..",
+    ],
+},
+
+'\\' => Char {
+    data: [
+        "x....",
+        "x....",
+        ".x...",
+        "..x..",
+        "...x.",
+        "....x",
+        "....x",
+    ],
+},
+
+']' => Char {
+    data: [
+        ".xx..",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+        ".xx..",
+    ],
+},
+
+'^' => Char {
+    data: [
+        "..x..",
+        ".x.x.",
+        "x...x",
+        ".....",
+        ".....",
+        ".....",
+        ".....",
+    ],
+},
+
+'_' => Char {
+    data: [
+        ".....",
+        ".....",
+        ".....",
+        ".....",
+        ".....",
+        ".....",
+        "xxxx.",
+    ],
+},
+
+'`' => Char {
+    data: [
+        ".x...",
+        "..x..",
+        "...x.",
+        ".....",
+        ".....",
+        ".....",
+        ".....",
+    ],
+},
+
+'a' => Char {
+    data: [
+        ".....",
+        ".xx..",
+        "...x.",
+        ".xxx.",
+        "x..x.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'b' => Char {
+    data: [
+        "x....",
+        "x....",
+        "xxx..",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'c' => Char {
+    data: [
+        ".....",
+        ".xx..",
+        "x..x.",
+        "x....",
+        "x....",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'d' => Char {
+    data: [
+        "...x.",
+        "...x.",
+        ".xxx.",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'e' => Char {
+    data: [
+        ".....",
+        ".xx..",
+        "x..x.",
+        "x..x.",
+        "xxxx.",
+        "x....",
+        ".xx..",
+    ],
+},
+
+'f' => Char {
+    data: [
+        "..xx.",
+        ".x...",
+        ".x...",
+        ".xx..",
+        ".x...",
+        ".x...",
+        ".x...",
+    ],
+},
+
+'g' => Char {
+    data: [
+        ".....",
+        ".xx..",
+        "x..x.",
+        "x..x.",
+        ".xxx.",
+        "...x.",
+        ".xx..",
+    ],
+},
+
+'h' => Char {
+    data: [
+        "x....",
+        "x....",
+        "xxx..",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+    ],
+},
+
+'i' => Char {
+    data: [
+        ".x...",
+        ".....",
+        "xx...",
+        ".x...",
+        ".x...",
+        ".x...",
+        "..x..",
+    ],
+},
+
+'j' => Char {
+    data: [
+        "...x.",
+        ".....",
+        "..xx.",
+        "...x.",
+        "...x.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'k' => Char {
+    data: [
+        "x....",
+        "x....",
+        "x..x.",
+        "x.x..",
+        "xx...",
+        "x.x..",
+        "x..x.",
+    ],
+},
+
+'l' => Char {
+    data: [
+        ".xx..",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+        "...x.",
+    ],
+},
+
+'m' => Char {
+    data: [
+        ".....",
+        ".x.x.",
+        "x.x.x",
+        "x.x.x",
+        "x.x.x",
+        "x.x.x",
+        "x.x.x",
+    ],
+},
+
+'n' => Char {
+    data: [
+        ".....",
+        ".xx..",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+    ],
+},
+
+'o' => Char {
+    data: [
+        ".....",
+        ".xx..",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'p' => Char {
+    data: [
+        ".....",
+        ".xx..",
+        "x..x.",
+        "x..x.",
+        "xxx..",
+        "x....",
+        "x....",
+    ],
+},
+
+'q' => Char {
+    data: [
+        ".....",
+        ".xx..",
+        "x..x.",
+        "x..x.",
+        ".xxx.",
+        "...x.",
+        "...x.",
+    ],
+},
+
+'r' => Char {
+    data: [
+        ".....",
+        "..xx.",
+        ".x...",
+        ".x...",
+        ".x...",
+        ".x...",
+        ".x...",
+    ],
+},
+
+'s' => Char {
+    data: [
+        ".....",
+        ".xx..",
+        "x..x.",
+        ".x...",
+        "..x..",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'t' => Char {
+    data: [
+        ".....",
+        ".x...",
+        ".xxx.",
+        ".x...",
+        ".x...",
+        ".x...",
+        "..xx.",
+    ],
+},
+
+'u' => Char {
+    data: [
+        ".....",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'v' => Char {
+    data: [
+        ".....",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".x.x.",
+        "..x..",
+    ],
+},
+
+'w' => Char {
+    data: [
+        ".....",
+        "x...x",
+        "x.x.x",
+        "x.x.x",
+        "x.x.x",
+        "x.x.x",
+        ".x.x.",
+    ],
+},
+
+'x' => Char {
+    data: [
+        ".....",
+        "x...x",
+        ".x.x.",
+        "..x..",
+        "..x..",
+        ".x.x.",
+        "x...x",
+    ],
+},
+
+'y' => Char {
+    data: [
+        ".....",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        ".xxx.",
+        "...x.",
+        ".xx..",
+    ],
+},
+
+'z' => Char {
+    data: [
+        ".....",
+        "xxxx.",
+        "...x.",
+        "..x..",
+        ".x...",
+        "x....",
+        "xxxx.",
+    ],
+},
+
+'{' => Char {
+    data: [
+        "..xx.",
+        "..x..",
+        "..x..",
+        ".xx..",
+        "..x..",
+        "..x..",
+        "..xx.",
+    ],
+},
+
+'|' => Char {
+    data: [
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+    ],
+},
+
+'}' => Char {
+    data: [
+        ".xx..",
+        "..x..",
+        "..x..",
+        "..xx.",
+        "..x..",
+        "..x..",
+        ".xx..",
+    ],
+},
+
+'~' => Char {
+    data: [
+        ".....",
+        ".....",
+        ".x...",
+        "x.x.x",
+        "...x.",
+        ".....",
+        ".....",
+    ],
+},
+
+'\u{a0}' => Char {
+    data: [
+        ".....",
+        ".....",
+        ".....",
+        ".....",
+        ".....",
+        ".....",
+        ".....",
+    ],
+},
+
+'¡' => Char {
+    data: [
+        "..x..",
+        ".....",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+    ],
+},
+
+'¢' => Char {
+    data: [
+        "..x..",
+        ".xxx.",
+        "x.x..",
+        "x.x..",
+        "x.x..",
+        ".xxx.",
+        "..x..",
+    ],
+},
+
+'£' => Char {
+    data: [
+        "..xx.",
+        ".x..x",
+        ".x...",
+        "xxxx.",
+        ".x...",
+        ".x...",
+        "xxxxx",
+    ],
+},
+
+'¤' => Char {
+    data: [
+        ".....",
+        "x...x",
+        ".xxx.",
+        ".x.x.",
+        ".xxx.",
+        "x...x",
+        ".....",
+    ],
+},
+
+'¥' => Char {
+    data: [
+        "x...x",
+        ".x.x.",
+        "..x..",
+        "xxxxx",
+        "..x..",
+        "xxxxx",
+        "..x..",
+    ],
+},
+
+'¦' => Char {
+    data: [
+        "..x..",
+        "..x..",
+        "..x..",
+        ".....",
+        "..x..",
+        "..x..",
+        "..x..",
+    ],
+},
+
+'§' => Char {
+    data: [
+        ".xxx.",
+        "x....",
+        ".xx..",
+        "x..x.",
+        ".xx..",
+        "...x.",
+        "xxx..",
+    ],
+},
+
+'¨' => Char {
+    data: [
+        ".x.x.",
+        ".....",
+        ".....",
+        ".....",
+        ".....",
+        ".....",
+        ".....",
+    ],
+},
+
+'¬' => Char {
+    data: [
+        ".....",
+        ".....",
+        ".....",
+        "xxxxx",
+        "....x",
+        ".....",
+        ".....",
+    ],
+},
+
+'\u{ad}' => Char {
+    data: [
+        ".....",
+        ".....",
+        ".....",
+        ".xxx.",
+        ".....",
+        ".....",
+        ".....",
+    ],
+},
+
+'¯' => Char {
+    data: [
+        ".xxx.",
+        ".....",
+        ".....",
+        ".....",
+        ".....",
+        ".....",
+        ".....",
+    ],
+},
+
+'°' => Char {
+    data: [
+        "..x..",
+        ".x.x.",
+        "..x..",
+        ".....",
+        ".....",
+        ".....",
+        ".....",
+    ],
+},
+
+'±' => Char {
+    data: [
+        ".....",
+        "..x..",
+        "..x..",
+        "xxxxx",
+        "..x..",
+        ".....",
+        "xxxxx",
+    ],
+},
+
+'´' => Char {
+    data: [
+        "..x..",
+        ".x...",
+        ".....",
+        ".....",
+        ".....",
+        ".....",
+        ".....",
+    ],
+},
+
+'µ' => Char {
+    data: [
+        ".....",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        "xxx..",
+        "x....",
+        "x....",
+    ],
+},
+
+'¶' => Char {
+    data: [
+        ".xxxx",
+        "xxx.x",
+        "xxx.x",
+        ".xx.x",
+        "..x.x",
+        "..x.x",
+        "..x.x",
+    ],
+},
+
+'·' => Char {
+    data: [
+        ".....",
+        ".....",
+        ".....",
+        "..x..",
+        ".....",
+        ".....",
+        ".....",
+    ],
+},
+
+'¿' => Char {
+    data: [
+        ".x...",
+        ".....",
+        "...x.",
+        ".xx..",
+        "x....",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'À' => Char {
+    data: [
+        ".x...",
+        "..x..",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "xxxxx",
+        "x...x",
+    ],
+},
+
+'Á' => Char {
+    data: [
+        "..x..",
+        ".x...",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "xxxxx",
+        "x...x",
+    ],
+},
+
+'Â' => Char {
+    data: [
+        "..x..",
+        ".x.x.",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "xxxxx",
+        "x...x",
+    ],
+},
+
+'Ã' => Char {
+    data: [
+        ".x.x.",
+        "x.x..",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "xxxxx",
+        "x...x",
+    ],
+},
+
+'Ä' => Char {
+    data: [
+        ".x.x.",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "xxxxx",
+        "x...x",
+        "x...x",
+    ],
+},
+
+'Å' => Char {
+    data: [
+        ".xx..",
+        ".xx..",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "xxxxx",
+        "x...x",
+    ],
+},
+
+'Ç' => Char {
+    data: [
+        ".xxx.",
+        "x...x",
+        "x....",
+        "x...x",
+        ".xxx.",
+        "..x..",
+        ".x...",
+    ],
+},
+
+'È' => Char {
+    data: [
+        ".x...",
+        "..x..",
+        "xxxxx",
+        "x....",
+        "xxxx.",
+        "x....",
+        "xxxxx",
+    ],
+},
+
+'É' => Char {
+    data: [
+        "..x..",
+        ".x...",
+        "xxxxx",
+        "x....",
+        "xxxx.",
+        "x....",
+        "xxxxx",
+    ],
+},
+
+'Ê' => Char {
+    data: [
+        "..x..",
+        ".x.x.",
+        "xxxxx",
+        "x....",
+        "xxxx.",
+        "x....",
+        "xxxxx",
+    ],
+},
+
+'Ë' => Char {
+    data: [
+        ".x.x.",
+        ".....",
+        "xxxxx",
+        "x....",
+        "xxxx.",
+        "x....",
+        "xxxxx",
+    ],
+},
+
+'Ì' => Char {
+    data: [
+        ".x...",
+        "..x..",
+        ".....",
+        ".xxx.",
+        "..x..",
+        "..x..",
+        "xxxxx",
+    ],
+},
+
+'Í' => Char {
+    data: [
+        "..x..",
+        ".x...",
+        ".....",
+        ".xxx.",
+        "..x..",
+        "..x..",
+        "xxxxx",
+    ],
+},
+
+'Î' => Char {
+    data: [
+        "..x..",
+        ".x.x.",
+        ".....",
+        ".xxx.",
+        "..x..",
+        "..x..",
+        "xxxxx",
+    ],
+},
+
+'Ï' => Char {
+    data: [
+        ".x.x.",
+        ".....",
+        ".xxx.",
+        "..x..",
+        "..x..",
+        "..x..",
+        "xxxxx",
+    ],
+},
+
+'Ð' => Char {
+    data: [
+        "xxxx.",
+        ".x..x",
+        ".x..x",
+        "xxx.x",
+        ".x..x",
+        ".x..x",
+        "xxxx.",
+    ],
+},
+
+'Ñ' => Char {
+    data: [
+        ".x.x.",
+        "x.x..",
+        "x...x",
+        "xx..x",
+        "x.x.x",
+        "x..xx",
+        "x...x",
+    ],
+},
+
+'Ò' => Char {
+    data: [
+        ".x...",
+        "..x..",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'Ó' => Char {
+    data: [
+        "..x..",
+        ".x...",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'Ô' => Char {
+    data: [
+        "..x..",
+        ".x.x.",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'Õ' => Char {
+    data: [
+        ".x.x.",
+        "x.x..",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'Ö' => Char {
+    data: [
+        ".x.x.",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'×' => Char {
+    data: [
+        ".....",
+        ".....",
+        "x...x",
+        ".x.x.",
+        "..x..",
+        ".x.x.",
+        "x...x",
+    ],
+},
+
+'Ø' => Char {
+    data: [
+        ".xxx.",
+        "x...x",
+        "x..xx",
+        "x.x.x",
+        "xx..x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'Ù' => Char {
+    data: [
+        ".x...",
+        "..x..",
+        ".....",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'Ú' => Char {
+    data: [
+        "..x..",
+        ".x...",
+        ".....",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'Û' => Char {
+    data: [
+        "..x..",
+        ".x.x.",
+        ".....",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'Ü' => Char {
+    data: [
+        ".x.x.",
+        ".....",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'Ý' => Char {
+    data: [
+        "..x..",
+        ".x...",
+        ".....",
+        "x...x",
+        ".x.x.",
+        "..x..",
+        "..x..",
+    ],
+},
+
+'Þ' => Char {
+    data: [
+        "x....",
+        "xxxx.",
+        "x...x",
+        "x...x",
+        "xxxx.",
+        "x....",
+        "x....",
+    ],
+},
+
+'ß' => Char {
+    data: [
+        ".xx..",
+        "x..x.",
+        "x.x..",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        "x.x..",
+    ],
+},
+
+'à' => Char {
+    data: [
+        ".x...",
+        "..x..",
+        ".xx..",
+        "...x.",
+        ".xxx.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'á' => Char {
+    data: [
+        "..x..",
+        ".x...",
+        ".xx..",
+        "...x.",
+        ".xxx.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'â' => Char {
+    data: [
+        ".x...",
+        "x.x..",
+        ".xx..",
+        "...x.",
+        ".xxx.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'ã' => Char {
+    data: [
+        ".x.x.",
+        "x.x..",
+        ".xx..",
+        "...x.",
+        ".xxx.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'ä' => Char {
+    data: [
+        "x..x.",
+        ".....",
+        ".xx..",
+        "...x.",
+        ".xxx.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'å' => Char {
+    data: [
+        ".xx..",
+        ".xx..",
+        ".xx..",
+        "...x.",
+        ".xxx.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'ç' => Char {
+    data: [
+        ".xx..",
+        "x..x.",
+        "x....",
+        "x..x.",
+        ".xx..",
+        "..x..",
+        ".x...",
+    ],
+},
+
+'è' => Char {
+    data: [
+        ".x...",
+        "..x..",
+        ".xx..",
+        "x..x.",
+        "xxxx.",
+        "x....",
+        ".xx..",
+    ],
+},
+
+'é' => Char {
+    data: [
+        "..x..",
+        ".x...",
+        ".xx..",
+        "x..x.",
+        "xxxx.",
+        "x....",
+        ".xx..",
+    ],
+},
+
+'ê' => Char {
+    data: [
+        ".x...",
+        "x.x..",
+        ".xx..",
+        "x..x.",
+        "xxxx.",
+        "x....",
+        ".xx..",
+    ],
+},
+
+'ë' => Char {
+    data: [
+        "x..x.",
+        ".....",
+        ".xx..",
+        "x..x.",
+        "xxxx.",
+        "x....",
+        ".xx..",
+    ],
+},
+
+'ì' => Char {
+    data: [
+        ".x...",
+        "..x..",
+        ".....",
+        "xx...",
+        ".x...",
+        ".x...",
+        "..x..",
+    ],
+},
+
+'í' => Char {
+    data: [
+        "..x..",
+        ".x...",
+        ".....",
+        "xx...",
+        ".x...",
+        ".x...",
+        "..x..",
+    ],
+},
+
+'î' => Char {
+    data: [
+        ".x...",
+        "x.x..",
+        ".....",
+        "xx...",
+        ".x...",
+        ".x...",
+        "..x..",
+    ],
+},
+
+'ï' => Char {
+    data: [
+        "x..x.",
+        ".....",
+        "xx...",
+        ".x...",
+        ".x...",
+        ".x...",
+        "..x..",
+    ],
+},
+
+'ð' => Char {
+    data: [
+        ".x.x.",
+        "..x..",
+        ".x.x.",
+        ".xxx.",
+        "x..x.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'ñ' => Char {
+    data: [
+        ".x.x.",
+        "x.x..",
+        ".....",
+        ".xx..",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+    ],
+},
+
+'ò' => Char {
+    data: [
+        ".x...",
+        "..x..",
+        ".....",
+        ".xx..",
+        "x..x.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'ó' => Char {
+    data: [
+        "..x..",
+        ".x...",
+        ".....",
+        ".xx..",
+        "x..x.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'ô' => Char {
+    data: [
+        ".x...",
+        "x.x..",
+        ".....",
+        ".xx..",
+        "x..x.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'õ' => Char {
+    data: [
+        ".x.x.",
+        "x.x..",
+        ".....",
+        ".xx..",
+        "x..x.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'ö' => Char {
+    data: [
+        "x..x.",
+        ".....",
+        ".xx..",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'÷' => Char {
+    data: [
+        ".....",
+        "..x..",
+        ".....",
+        "xxxxx",
+        ".....",
+        "..x..",
+        ".....",
+    ],
+},
+
+'ø' => Char {
+    data: [
+        ".....",
+        "....x",
+        ".xxx.",
+        "x.x.x",
+        "xx..x",
+        ".xxx.",
+        "x....",
+    ],
+},
+
+'ù' => Char {
+    data: [
+        ".x...",
+        "..x..",
+        ".....",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'ú' => Char {
+    data: [
+        "..x..",
+        ".x...",
+        ".....",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'û' => Char {
+    data: [
+        ".x...",
+        "x.x..",
+        ".....",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'ü' => Char {
+    data: [
+        "x..x.",
+        ".....",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'ý' => Char {
+    data: [
+        "..x..",
+        ".x...",
+        ".....",
+        "x..x.",
+        ".xxx.",
+        "...x.",
+        ".xx..",
+    ],
+},
+
+'þ' => Char {
+    data: [
+        "x....",
+        "xxx..",
+        "x..x.",
+        "x..x.",
+        "xxx..",
+        "x....",
+        "x....",
+    ],
+},
+
+'ÿ' => Char {
+    data: [
+        "x..x.",
+        ".....",
+        "x..x.",
+        "x..x.",
+        ".xxx.",
+        "...x.",
+        ".xx..",
+    ],
+},
+
+'Ā' => Char {
+    data: [
+        ".xxx.",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "xxxxx",
+        "x...x",
+        "x...x",
+    ],
+},
+
+'ā' => Char {
+    data: [
+        "xxx..",
+        ".....",
+        ".xx..",
+        "...x.",
+        ".xxx.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'Ă' => Char {
+    data: [
+        "x..x.",
+        ".xx..",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "xxxxx",
+        "x...x",
+    ],
+},
+
+'ă' => Char {
+    data: [
+        "x..x.",
+        ".xx..",
+        ".xx..",
+        "...x.",
+        ".xxx.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'Ą' => Char {
+    data: [
+        ".xxx.",
+        "x...x",
+        "xxxxx",
+        "x...x",
+        "x...x",
+        "...x.",
+        "....x",
+    ],
+},
+
+'ą' => Char {
+    data: [
+        ".xx..",
+        "...x.",
+        ".xxx.",
+        "x..x.",
+        ".xx..",
+        "..x..",
+        "...x.",
+    ],
+},
+
+'Ć' => Char {
+    data: [
+        "..x..",
+        ".x...",
+        ".xxx.",
+        "x...x",
+        "x....",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ć' => Char {
+    data: [
+        "..x..",
+        ".x...",
+        ".xx..",
+        "x..x.",
+        "x....",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'Ĉ' => Char {
+    data: [
+        "..x..",
+        ".x.x.",
+        ".xxx.",
+        "x...x",
+        "x....",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ĉ' => Char {
+    data: [
+        ".x...",
+        "x.x..",
+        ".xx..",
+        "x..x.",
+        "x....",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'Ċ' => Char {
+    data: [
+        "..x..",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "x....",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ċ' => Char {
+    data: [
+        ".x...",
+        ".....",
+        ".xx..",
+        "x..x.",
+        "x....",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'Č' => Char {
+    data: [
+        ".x.x.",
+        "..x..",
+        ".xxx.",
+        "x...x",
+        "x....",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'č' => Char {
+    data: [
+        "x.x..",
+        ".x...",
+        ".xx..",
+        "x..x.",
+        "x....",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'Ď' => Char {
+    data: [
+        ".x.x.",
+        "..x..",
+        ".....",
+        "xxxx.",
+        "x...x",
+        "x...x",
+        "xxxx.",
+    ],
+},
+
+'ď' => Char {
+    data: [
+        "x.x..",
+        ".x...",
+        ".....",
+        "...x.",
+        ".xxx.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'Đ' => Char {
+    data: [
+        "xxxx.",
+        ".x..x",
+        ".x..x",
+        "xxx.x",
+        ".x..x",
+        ".x..x",
+        "xxxx.",
+    ],
+},
+
+'đ' => Char {
+    data: [
+        "...x.",
+        "..xxx",
+        ".xxx.",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        ".xxx.",
+    ],
+},
+
+'Ē' => Char {
+    data: [
+        ".xxx.",
+        ".....",
+        "xxxxx",
+        "x....",
+        "xxxx.",
+        "x....",
+        "xxxxx",
+    ],
+},
+
+'ē' => Char {
+    data: [
+        "xxx..",
+        ".....",
+        ".xx..",
+        "x..x.",
+        "xxxx.",
+        "x....",
+        ".xx..",
+    ],
+},
+
+'Ĕ' => Char {
+    data: [
+        "x..x.",
+        ".xx..",
+        "xxxxx",
+        "x....",
+        "xxxx.",
+        "x....",
+        "xxxxx",
+    ],
+},
+
+'ĕ' => Char {
+    data: [
+        "x..x.",
+        ".xx..",
+        ".xx..",
+        "x..x.",
+        "xxxx.",
+        "x....",
+        ".xx..",
+    ],
+},
+
+'Ė' => Char {
+    data: [
+        "..x..",
+        ".....",
+        "xxxxx",
+        "x....",
+        "xxxx.",
+        "x....",
+        "xxxxx",
+    ],
+},
+
+'ė' => Char {
+    data: [
+        ".x...",
+        ".....",
+        ".xx..",
+        "x..x.",
+        "xxxx.",
+        "x....",
+        ".xx..",
+    ],
+},
+
+'Ę' => Char {
+    data: [
+        "xxxxx",
+        "x....",
+        "xxxx.",
+        "x....",
+        "xxxxx",
+        "...x.",
+        "....x",
+    ],
+},
+
+'ę' => Char {
+    data: [
+        ".xx..",
+        "x..x.",
+        "xxxx.",
+        "x....",
+        ".xx..",
+        "..x..",
+        "...x.",
+    ],
+},
+
+'Ě' => Char {
+    data: [
+        ".x.x.",
+        "..x..",
+        "xxxxx",
+        "x....",
+        "xxxx.",
+        "x....",
+        "xxxxx",
+    ],
+},
+
+'ě' => Char {
+    data: [
+        "x.x..",
+        ".x...",
+        ".xx..",
+        "x..x.",
+        "xxxx.",
+        "x....",
+        ".xx..",
+    ],
+},
+
+'Ĝ' => Char {
+    data: [
+        ".x.x.",
+        ".xxx.",
+        "x...x",
+        "x....",
+        "x.xxx",
+        "x...x",
+        ".xxxx",
+    ],
+},
+
+'ĝ' => Char {
+    data: [
+        ".x...",
+        "x.x..",
+        ".xx..",
+        "x..x.",
+        ".xxx.",
+        "...x.",
+        ".xx..",
+    ],
+},
+
+'Ğ' => Char {
+    data: [
+        "x..x.",
+        ".xxx.",
+        "x...x",
+        "x....",
+        "x.xxx",
+        "x...x",
+        ".xxxx",
+    ],
+},
+
+'ğ' => Char {
+    data: [
+        "x..x.",
+        ".xx..",
+        ".xx..",
+        "x..x.",
+        ".xxx.",
+        "...x.",
+        ".xx..",
+    ],
+},
+
+'Ġ' => Char {
+    data: [
+        "..x..",
+        ".xxx.",
+        "x...x",
+        "x....",
+        "x.xxx",
+        "x...x",
+        ".xxxx",
+    ],
+},
+
+'ġ' => Char {
+    data: [
+        ".x...",
+        ".....",
+        ".xx..",
+        "x..x.",
+        ".xxx.",
+        "...x.",
+        ".xx..",
+    ],
+},
+
+'Ģ' => Char {
+    data: [
+        ".xxx.",
+        "x...x",
+        "x....",
+        "x.xxx",
+        "x...x",
+        ".xxxx",
+        "..x..",
+    ],
+},
+
+'ģ' => Char {
+    data: [
+        ".xx..",
+        "x..x.",
+        ".xxx.",
+        "...x.",
+        ".xx..",
+        "..x..",
+        ".x...",
+    ],
+},
+
+'Ĥ' => Char {
+    data: [
+        "..x..",
+        ".x.x.",
+        ".....",
+        "x...x",
+        "xxxxx",
+        "x...x",
+        "x...x",
+    ],
+},
+
+'ĥ' => Char {
+    data: [
+        ".x...",
+        "x.x..",
+        ".....",
+        "x....",
+        "xxx..",
+        "x..x.",
+        "x..x.",
+    ],
+},
+
+'Ħ' => Char {
+    data: [
+        "x...x",
+        "xxxxx",
+        "x...x",
+        "xxxxx",
+        "x...x",
+        "x...x",
+        "x...x",
+    ],
+},
+
+'ħ' => Char {
+    data: [
+        ".x...",
+        "xxx..",
+        ".x...",
+        ".xxx.",
+        ".x..x",
+        ".x..x",
+        ".x..x",
+    ],
+},
+
+'Ĩ' => Char {
+    data: [
+        ".x.x.",
+        "x.x..",
+        ".....",
+        ".xxx.",
+        "..x..",
+        "..x..",
+        "xxxxx",
+    ],
+},
+
+'ĩ' => Char {
+    data: [
+        ".x.x.",
+        "x.x..",
+        ".....",
+        "xx...",
+        ".x...",
+        ".x...",
+        "..x..",
+    ],
+},
+
+'Ī' => Char {
+    data: [
+        ".xxx.",
+        ".....",
+        ".xxx.",
+        "..x..",
+        "..x..",
+        "..x..",
+        "xxxxx",
+    ],
+},
+
+'ī' => Char {
+    data: [
+        "xxx..",
+        ".....",
+        "xx...",
+        ".x...",
+        ".x...",
+        ".x...",
+        "..x..",
+    ],
+},
+
+'Ĭ' => Char {
+    data: [
+        "x..x.",
+        ".xx..",
+        ".....",
+        ".xxx.",
+        "..x..",
+        "..x..",
+        "xxxxx",
+    ],
+},
+
+'ĭ' => Char {
+    data: [
+        "x..x.",
+        ".xx..",
+        ".....",
+        "xx...",
+        ".x...",
+        ".x...",
+        "..x..",
+    ],
+},
+
+'Į' => Char {
+    data: [
+        ".xxx.",
+        "..x..",
+        "..x..",
+        "..x..",
+        "xxxxx",
+        "...x.",
+        "....x",
+    ],
+},
+
+'į' => Char {
+    data: [
+        ".x...",
+        ".....",
+        "xx...",
+        ".x...",
+        "..x..",
+        "..x..",
+        "...x.",
+    ],
+},
+
+'İ' => Char {
+    data: [
+        "..x..",
+        ".....",
+        ".xxx.",
+        "..x..",
+        "..x..",
+        "..x..",
+        "xxxxx",
+    ],
+},
+
+'ı' => Char {
+    data: [
+        ".....",
+        ".....",
+        "xx...",
+        ".x...",
+        ".x...",
+        ".x...",
+        "..x..",
+    ],
+},
+
+'Ĳ' => Char {
+    data: [
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x.x.x",
+        "x..x.",
+    ],
+},
+
+'ĳ' => Char {
+    data: [
+        "x..x.",
+        ".....",
+        "x.xx.",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        "x.x..",
+    ],
+},
+
+'Ĵ' => Char {
+    data: [
+        "..x..",
+        ".x.x.",
+        ".....",
+        ".xxxx",
+        "....x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ĵ' => Char {
+    data: [
+        ".x...",
+        "x.x..",
+        ".....",
+        "..xx.",
+        "...x.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'Ķ' => Char {
+    data: [
+        "x...x",
+        "x.x..",
+        "xx...",
+        "x.x..",
+        "x..x.",
+        "x...x",
+        "..x..",
+    ],
+},
+
+'ķ' => Char {
+    data: [
+        "x....",
+        "x..x.",
+        "x.x..",
+        "xx...",
+        "x.x..",
+        "x..x.",
+        ".x...",
+    ],
+},
+
+'ĸ' => Char {
+    data: [
+        ".....",
+        ".....",
+        "x..x.",
+        "x.x..",
+        "xx...",
+        "x.x..",
+        "x..x.",
+    ],
+},
+
+'Ĺ' => Char {
+    data: [
+        "..x..",
+        ".x...",
+        ".....",
+        "x....",
+        "x....",
+        "x....",
+        "xxxxx",
+    ],
+},
+
+'ĺ' => Char {
+    data: [
+        "..x..",
+        ".x...",
+        ".....",
+        ".xx..",
+        "..x..",
+        "..x..",
+        "...x.",
+    ],
+},
+
+'Ļ' => Char {
+    data: [
+        "x....",
+        "x....",
+        "x....",
+        "x....",
+        "xxxxx",
+        "..x..",
+        ".x...",
+    ],
+},
+
+'ļ' => Char {
+    data: [
+        ".xx..",
+        "..x..",
+        "..x..",
+        "..x..",
+        "...x.",
+        "..x..",
+        ".x...",
+    ],
+},
+
+'Ľ' => Char {
+    data: [
+        ".x.x.",
+        "..x..",
+        ".....",
+        "x....",
+        "x....",
+        "x....",
+        "xxxxx",
+    ],
+},
+
+'ľ' => Char {
+    data: [
+        ".x.x.",
+        "..x..",
+        ".....",
+        ".xx..",
+        "..x..",
+        "..x..",
+        "...x.",
+    ],
+},
+
+'Ŀ' => Char {
+    data: [
+        "x....",
+        "x....",
+        "x....",
+        "x..x.",
+        "x....",
+        "x....",
+        "xxxxx",
+    ],
+},
+
+'ŀ' => Char {
+    data: [
+        "xx...",
+        ".x...",
+        ".x...",
+        ".x.x.",
+        ".x...",
+        ".x...",
+        "..x..",
+    ],
+},
+
+'Ł' => Char {
+    data: [
+        ".x...",
+        ".x...",
+        ".x.x.",
+        ".xx..",
+        "xx...",
+        ".x...",
+        ".xxxx",
+    ],
+},
+
+'ł' => Char {
+    data: [
+        ".x...",
+        ".x...",
+        ".xx..",
+        "xx...",
+        ".x...",
+        ".x...",
+        "..x..",
+    ],
+},
+
+'Ń' => Char {
+    data: [
+        "..x..",
+        ".x...",
+        "x...x",
+        "xx..x",
+        "x.x.x",
+        "x..xx",
+        "x...x",
+    ],
+},
+
+'ń' => Char {
+    data: [
+        "..x..",
+        ".x...",
+        ".....",
+        ".xx..",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+    ],
+},
+
+'Ņ' => Char {
+    data: [
+        "x...x",
+        "xx..x",
+        "x.x.x",
+        "x..xx",
+        "x...x",
+        "..x..",
+        ".x...",
+    ],
+},
+
+'ņ' => Char {
+    data: [
+        ".xx..",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        "..x..",
+        ".x...",
+    ],
+},
+
+'Ň' => Char {
+    data: [
+        ".x.x.",
+        "..x..",
+        "x...x",
+        "xx..x",
+        "x.x.x",
+        "x..xx",
+        "x...x",
+    ],
+},
+
+'ň' => Char {
+    data: [
+        "x.x..",
+        ".x...",
+        ".....",
+        ".xx..",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+    ],
+},
+
+'ŉ' => Char {
+    data: [
+        "x....",
+        "x....",
+        "..xx.",
+        ".x..x",
+        ".x..x",
+        ".x..x",
+        ".x..x",
+    ],
+},
+
+'Ŋ' => Char {
+    data: [
+        "x...x",
+        "xx..x",
+        "x.x.x",
+        "x..xx",
+        "x...x",
+        "x...x",
+        "x.xx.",
+    ],
+},
+
+'ŋ' => Char {
+    data: [
+        ".....",
+        ".xx..",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        "x.x..",
+    ],
+},
+
+'Ō' => Char {
+    data: [
+        ".xxx.",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ō' => Char {
+    data: [
+        "xxx..",
+        ".....",
+        ".xx..",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'Ŏ' => Char {
+    data: [
+        "x..x.",
+        ".xx..",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ŏ' => Char {
+    data: [
+        "x..x.",
+        ".xx..",
+        ".....",
+        ".xx..",
+        "x..x.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'Ő' => Char {
+    data: [
+        ".x.x.",
+        "x.x..",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ő' => Char {
+    data: [
+        ".x.x.",
+        "x.x..",
+        ".....",
+        ".xx..",
+        "x..x.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'Ŕ' => Char {
+    data: [
+        "..x..",
+        "xxxx.",
+        "x...x",
+        "xxxx.",
+        "x.x..",
+        "x..x.",
+        "x...x",
+    ],
+},
+
+'ŕ' => Char {
+    data: [
+        "..x..",
+        ".x...",
+        ".....",
+        "..xx.",
+        ".x...",
+        ".x...",
+        ".x...",
+    ],
+},
+
+'Ŗ' => Char {
+    data: [
+        "xxxx.",
+        "x...x",
+        "xxxx.",
+        "x.x..",
+        "x..x.",
+        "x...x",
+        "..x..",
+    ],
+},
+
+'ŗ' => Char {
+    data: [
+        "..xx.",
+        ".x...",
+        ".x...",
+        ".x...",
+        ".x...",
+        "..x..",
+        ".x...",
+    ],
+},
+
+'Ř' => Char {
+    data: [
+        ".x.x.",
+        "xxxx.",
+        "x...x",
+        "xxxx.",
+        "x.x..",
+        "x..x.",
+        "x...x",
+    ],
+},
+
+'ř' => Char {
+    data: [
+        ".x.x.",
+        "..x..",
+        ".....",
+        "..xx.",
+        ".x...",
+        ".x...",
+        ".x...",
+    ],
+},
+
+'Ś' => Char {
+    data: [
+        "..x..",
+        ".xxx.",
+        "x....",
+        ".xxx.",
+        "....x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ś' => Char {
+    data: [
+        "..x..",
+        ".xx..",
+        "x..x.",
+        ".x...",
+        "..x..",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'Ŝ' => Char {
+    data: [
+        ".x.x.",
+        ".xxx.",
+        "x....",
+        ".xxx.",
+        "....x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ŝ' => Char {
+    data: [
+        "x.x..",
+        ".xx..",
+        "x..x.",
+        ".x...",
+        "..x..",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'Ş' => Char {
+    data: [
+        ".xxx.",
+        "x....",
+        ".xxx.",
+        "....x",
+        "x...x",
+        ".xxx.",
+        "..x..",
+    ],
+},
+
+'ş' => Char {
+    data: [
+        ".xx..",
+        "x..x.",
+        ".x...",
+        "..x..",
+        "x..x.",
+        ".xx..",
+        ".x...",
+    ],
+},
+
+'Š' => Char {
+    data: [
+        ".x.x.",
+        ".xxx.",
+        "x....",
+        ".xxx.",
+        "....x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'š' => Char {
+    data: [
+        "x.x..",
+        ".xx..",
+        "x..x.",
+        ".x...",
+        "..x..",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'Ţ' => Char {
+    data: [
+        "xxxxx",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+        ".x...",
+    ],
+},
+
+'ţ' => Char {
+    data: [
+        ".x...",
+        ".xxx.",
+        ".x...",
+        ".x...",
+        "..xx.",
+        "..x..",
+        ".x...",
+    ],
+},
+
+'Ť' => Char {
+    data: [
+        ".x.x.",
+        "..x..",
+        ".....",
+        "xxxxx",
+        "..x..",
+        "..x..",
+        "..x..",
+    ],
+},
+
+'ť' => Char {
+    data: [
+        ".x.x.",
+        "..x..",
+        ".....",
+        ".x...",
+        ".xxx.",
+        ".x...",
+        "..xx.",
+    ],
+},
+
+'Ŧ' => Char {
+    data: [
+        "xxxxx",
+        "..x..",
+        "..x..",
+        ".xxx.",
+        "..x..",
+        "..x..",
+        "..x..",
+    ],
+},
+
+'ŧ' => Char {
+    data: [
+        ".....",
+        ".x...",
+        ".xxx.",
+        ".x...",
+        ".xxx.",
+        ".x...",
+        "..xx.",
+    ],
+},
+
+'Ũ' => Char {
+    data: [
+        ".x.x.",
+        "x.x..",
+        ".....",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ũ' => Char {
+    data: [
+        ".x.x.",
+        "x.x..",
+        ".....",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'Ū' => Char {
+    data: [
+        ".xxx.",
+        ".....",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ū' => Char {
+    data: [
+        "xxx..",
+        ".....",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'Ŭ' => Char {
+    data: [
+        "x..x.",
+        ".xx..",
+        ".....",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ŭ' => Char {
+    data: [
+        "x..x.",
+        ".xx..",
+        ".....",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'Ů' => Char {
+    data: [
+        ".xx..",
+        ".xx..",
+        ".....",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ů' => Char {
+    data: [
+        ".xx..",
+        ".xx..",
+        ".....",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'Ű' => Char {
+    data: [
+        ".x.x.",
+        "x.x..",
+        ".....",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ű' => Char {
+    data: [
+        ".x.x.",
+        "x.x..",
+        ".....",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'Ų' => Char {
+    data: [
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".xxx.",
+        "...x.",
+        "....x",
+    ],
+},
+
+'ų' => Char {
+    data: [
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        ".xx..",
+        "..x..",
+        "...x.",
+    ],
+},
+
+'Ŵ' => Char {
+    data: [
+        "..x..",
+        ".x.x.",
+        ".....",
+        "x...x",
+        "x.x.x",
+        "x.x.x",
+        ".x.x.",
+    ],
+},
+
+'ŵ' => Char {
+    data: [
+        "..x..",
+        ".x.x.",
+        ".....",
+        "x...x",
+        "x.x.x",
+        "x.x.x",
+        ".x.x.",
+    ],
+},
+
+'Ŷ' => Char {
+    data: [
+        "..x..",
+        ".x.x.",
+        ".....",
+        "x...x",
+        ".x.x.",
+        "..x..",
+        "..x..",
+    ],
+},
+
+'ŷ' => Char {
+    data: [
+        ".x...",
+        "x.x..",
+        ".....",
+        "x..x.",
+        ".xxx.",
+        "...x.",
+        ".xx..",
+    ],
+},
+
+'Ÿ' => Char {
+    data: [
+        ".x.x.",
+        ".....",
+        "x...x",
+        "x...x",
+        ".x.x.",
+        "..x..",
+        "..x..",
+    ],
+},
+
+'Ź' => Char {
+    data: [
+        "..x..",
+        "xxxxx",
+        "...x.",
+        "..x..",
+        ".x...",
+        "x....",
+        "xxxxx",
+    ],
+},
+
+'ź' => Char {
+    data: [
+        "..x..",
+        "xxxx.",
+        "...x.",
+        "..x..",
+        ".x...",
+        "x....",
+        "xxxx.",
+    ],
+},
+
+'Ż' => Char {
+    data: [
+        "..x..",
+        "xxxxx",
+        "...x.",
+        "..x..",
+        ".x...",
+        "x....",
+        "xxxxx",
+    ],
+},
+
+'ż' => Char {
+    data: [
+        ".x...",
+        "xxxx.",
+        "...x.",
+        "..x..",
+        ".x...",
+        "x....",
+        "xxxx.",
+    ],
+},
+
+'Ž' => Char {
+    data: [
+        ".x.x.",
+        "xxxxx",
+        "...x.",
+        "..x..",
+        ".x...",
+        "x....",
+        "xxxxx",
+    ],
+},
+
+'ž' => Char {
+    data: [
+        "x.x..",
+        "xxxx.",
+        "...x.",
+        "..x..",
+        ".x...",
+        "x....",
+        "xxxx.",
+    ],
+},
+
+'ſ' => Char {
+    data: [
+        "..xx.",
+        ".x...",
+        ".x...",
+        ".x...",
+        ".x...",
+        ".x...",
+        ".x...",
+    ],
+},
+
+'Ǎ' => Char {
+    data: [
+        ".x.x.",
+        "..x..",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "xxxxx",
+        "x...x",
+    ],
+},
+
+'ǎ' => Char {
+    data: [
+        "x.x..",
+        ".x...",
+        ".xx..",
+        "...x.",
+        ".xxx.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'Ǐ' => Char {
+    data: [
+        ".x.x.",
+        "..x..",
+        ".....",
+        ".xxx.",
+        "..x..",
+        "..x..",
+        "xxxxx",
+    ],
+},
+
+'ǐ' => Char {
+    data: [
+        "x.x..",
+        ".x...",
+        ".....",
+        "xx...",
+        ".x...",
+        ".x...",
+        "..x..",
+    ],
+},
+
+'Ǒ' => Char {
+    data: [
+        ".x.x.",
+        "..x..",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ǒ' => Char {
+    data: [
+        "x.x..",
+        ".x...",
+        ".....",
+        ".xx..",
+        "x..x.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'Ǔ' => Char {
+    data: [
+        ".x.x.",
+        "..x..",
+        ".....",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ǔ' => Char {
+    data: [
+        "x.x..",
+        ".x...",
+        ".....",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'Ǧ' => Char {
+    data: [
+        ".x.x.",
+        ".xxx.",
+        "x...x",
+        "x....",
+        "x.xxx",
+        "x...x",
+        ".xxxx",
+    ],
+},
+
+'ǧ' => Char {
+    data: [
+        "x.x..",
+        ".x...",
+        ".xx..",
+        "x..x.",
+        ".xxx.",
+        "...x.",
+        ".xx..",
+    ],
+},
+
+'Ǩ' => Char {
+    data: [
+        ".x.x.",
+        "x...x",
+        "x.x..",
+        "xx...",
+        "x.x..",
+        "x..x.",
+        "x...x",
+    ],
+},
+
+'ǩ' => Char {
+    data: [
+        "x.x..",
+        "x....",
+        "x..x.",
+        "x.x..",
+        "xx...",
+        "x.x..",
+        "x..x.",
+    ],
+},
+
+'Ǫ' => Char {
+    data: [
+        ".xxx.",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".xxx.",
+        "...x.",
+        "....x",
+    ],
+},
+
+'ǫ' => Char {
+    data: [
+        ".xx..",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        ".xx..",
+        "..x..",
+        "...x.",
+    ],
+},
+
+'ǰ' => Char {
+    data: [
+        "x.x..",
+        ".x...",
+        ".....",
+        "..xx.",
+        "...x.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'Ǵ' => Char {
+    data: [
+        "..x..",
+        ".xxx.",
+        "x...x",
+        "x....",
+        "x.xxx",
+        "x...x",
+        ".xxxx",
+    ],
+},
+
+'ǵ' => Char {
+    data: [
+        "..x..",
+        ".x...",
+        ".xx..",
+        "x..x.",
+        ".xxx.",
+        "...x.",
+        ".xx..",
+    ],
+},
+
+'Ǹ' => Char {
+    data: [
+        ".x...",
+        "..x..",
+        "x...x",
+        "xx..x",
+        "x.x.x",
+        "x..xx",
+        "x...x",
+    ],
+},
+
+'ǹ' => Char {
+    data: [
+        ".x...",
+        "..x..",
+        ".....",
+        ".xx..",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+    ],
+},
+
+'Ǿ' => Char {
+    data: [
+        "..x..",
+        ".xxx.",
+        "x..xx",
+        "x.x.x",
+        "xx..x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ǿ' => Char {
+    data: [
+        "..x..",
+        "....x",
+        ".xxx.",
+        "x.x.x",
+        "xx..x",
+        ".xxx.",
+        "x....",
+    ],
+},
+
+'Ș' => Char {
+    data: [
+        ".xxx.",
+        "x....",
+        ".xxx.",
+        "....x",
+        "x...x",
+        ".xxx.",
+        "..x..",
+    ],
+},
+
+'ș' => Char {
+    data: [
+        ".xx..",
+        "x..x.",
+        ".x...",
+        "..x..",
+        "x..x.",
+        ".xx..",
+        ".x...",
+    ],
+},
+
+'Ț' => Char {
+    data: [
+        "xxxxx",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+    ],
+},
+
+'ț' => Char {
+    data: [
+        ".x...",
+        ".xxx.",
+        ".x...",
+        ".x...",
+        ".x...",
+        "..xx.",
+        "..x..",
+    ],
+},
+
+'Ȟ' => Char {
+    data: [
+        ".x.x.",
+        "..x..",
+        ".....",
+        "x...x",
+        "xxxxx",
+        "x...x",
+        "x...x",
+    ],
+},
+
+'ȟ' => Char {
+    data: [
+        "x.x..",
+        ".x...",
+        ".....",
+        "x....",
+        "xxx..",
+        "x..x.",
+        "x..x.",
+    ],
+},
+
+'Ȧ' => Char {
+    data: [
+        "..x..",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "xxxxx",
+        "x...x",
+        "x...x",
+    ],
+},
+
+'ȧ' => Char {
+    data: [
+        ".x...",
+        ".....",
+        ".xx..",
+        "...x.",
+        ".xxx.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'Ȩ' => Char {
+    data: [
+        "xxxxx",
+        "x....",
+        "xxxx.",
+        "x....",
+        "xxxxx",
+        "..x..",
+        ".x...",
+    ],
+},
+
+'ȩ' => Char {
+    data: [
+        ".xx..",
+        "x..x.",
+        "xxxx.",
+        "x....",
+        ".xx..",
+        "..x..",
+        ".x...",
+    ],
+},
+
+'Ȯ' => Char {
+    data: [
+        "..x..",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ȯ' => Char {
+    data: [
+        ".x...",
+        ".....",
+        ".xx..",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'Ȳ' => Char {
+    data: [
+        ".xxx.",
+        ".....",
+        "x...x",
+        "x...x",
+        ".x.x.",
+        "..x..",
+        "..x..",
+    ],
+},
+
+'ȳ' => Char {
+    data: [
+        "xxx..",
+        ".....",
+        "x..x.",
+        "x..x.",
+        ".xxx.",
+        "...x.",
+        ".xx..",
+    ],
+},
+
+'ȷ' => Char {
+    data: [
+        ".....",
+        ".....",
+        "..xx.",
+        "...x.",
+        "...x.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'Ḃ' => Char {
+    data: [
+        "..x..",
+        ".....",
+        "xxxx.",
+        "x...x",
+        "xxxx.",
+        "x...x",
+        "xxxx.",
+    ],
+},
+
+'ḃ' => Char {
+    data: [
+        ".x...",
+        ".....",
+        "x....",
+        "xxx..",
+        "x..x.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'Ḅ' => Char {
+    data: [
+        "xxxx.",
+        "x...x",
+        "xxxx.",
+        "x...x",
+        "x...x",
+        "xxxx.",
+        "..x..",
+    ],
+},
+
+'ḅ' => Char {
+    data: [
+        "x....",
+        "x....",
+        "xxx..",
+        "x..x.",
+        "x..x.",
+        ".xx..",
+        ".x...",
+    ],
+},
+
+'Ḇ' => Char {
+    data: [
+        "xxxx.",
+        "x...x",
+        "xxxx.",
+        "x...x",
+        "x...x",
+        "xxxx.",
+        ".xxx.",
+    ],
+},
+
+'ḇ' => Char {
+    data: [
+        "x....",
+        "x....",
+        "xxx..",
+        "x..x.",
+        "x..x.",
+        ".xx..",
+        "xxx..",
+    ],
+},
+
+'Ḋ' => Char {
+    data: [
+        "..x..",
+        ".....",
+        "xxxx.",
+        "x...x",
+        "x...x",
+        "x...x",
+        "xxxx.",
+    ],
+},
+
+'ḋ' => Char {
+    data: [
+        ".x...",
+        ".....",
+        "...x.",
+        ".xxx.",
+        "x..x.",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'Ḍ' => Char {
+    data: [
+        "xxxx.",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        "xxxx.",
+        "..x..",
+    ],
+},
+
+'ḍ' => Char {
+    data: [
+        "...x.",
+        "...x.",
+        ".xxx.",
+        "x..x.",
+        "x..x.",
+        ".xx..",
+        ".x...",
+    ],
+},
+
+'Ḏ' => Char {
+    data: [
+        "xxxx.",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        "xxxx.",
+        ".xxx.",
+    ],
+},
+
+'ḏ' => Char {
+    data: [
+        "...x.",
+        "...x.",
+        ".xxx.",
+        "x..x.",
+        "x..x.",
+        ".xx..",
+        "xxx..",
+    ],
+},
+
+'Ḑ' => Char {
+    data: [
+        "xxxx.",
+        "x...x",
+        "x...x",
+        "x...x",
+        "xxxx.",
+        "..x..",
+        ".x...",
+    ],
+},
+
+'ḑ' => Char {
+    data: [
+        "...x.",
+        ".xxx.",
+        "x..x.",
+        "x..x.",
+        ".xx..",
+        "..x..",
+        ".x...",
+    ],
+},
+
+'Ḟ' => Char {
+    data: [
+        "..x..",
+        ".....",
+        "xxxxx",
+        "x....",
+        "xxxx.",
+        "x....",
+        "x....",
+    ],
+},
+
+'ḟ' => Char {
+    data: [
+        "..x..",
+        ".....",
+        "..xx.",
+        ".x...",
+        ".xx..",
+        ".x...",
+        ".x...",
+    ],
+},
+
+'Ḡ' => Char {
+    data: [
+        ".xxx.",
+        ".xxx.",
+        "x...x",
+        "x....",
+        "x.xxx",
+        "x...x",
+        ".xxxx",
+    ],
+},
+
+'ḡ' => Char {
+    data: [
+        "xxx..",
+        ".....",
+        ".xx..",
+        "x..x.",
+        ".xxx.",
+        "...x.",
+        ".xx..",
+    ],
+},
+
+'Ḣ' => Char {
+    data: [
+        "..x..",
+        ".....",
+        "x...x",
+        "xxxxx",
+        "x...x",
+        "x...x",
+        "x...x",
+    ],
+},
+
+'ḣ' => Char {
+    data: [
+        ".x...",
+        ".....",
+        "x....",
+        "x....",
+        "xxx..",
+        "x..x.",
+        "x..x.",
+    ],
+},
+
+'Ḥ' => Char {
+    data: [
+        "x...x",
+        "x...x",
+        "xxxxx",
+        "x...x",
+        "x...x",
+        "x...x",
+        "..x..",
+    ],
+},
+
+'ḥ' => Char {
+    data: [
+        "x....",
+        "x....",
+        "xxx..",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        ".x...",
+    ],
+},
+
+'Ḧ' => Char {
+    data: [
+        ".x.x.",
+        ".....",
+        "x...x",
+        "xxxxx",
+        "x...x",
+        "x...x",
+        "x...x",
+    ],
+},
+
+'ḧ' => Char {
+    data: [
+        "x..x.",
+        ".....",
+        "x....",
+        "x....",
+        "xxx..",
+        "x..x.",
+        "x..x.",
+    ],
+},
+
+'Ḩ' => Char {
+    data: [
+        "x...x",
+        "xxxxx",
+        "x...x",
+        "x...x",
+        "x...x",
+        "..x..",
+        ".x...",
+    ],
+},
+
+'ḩ' => Char {
+    data: [
+        "x....",
+        "x....",
+        "xxx..",
+        "x..x.",
+        "x..x.",
+        "..x..",
+        ".x...",
+    ],
+},
+
+'Ḱ' => Char {
+    data: [
+        "..x..",
+        "x...x",
+        "x.x..",
+        "xx...",
+        "x.x..",
+        "x..x.",
+        "x...x",
+    ],
+},
+
+'ḱ' => Char {
+    data: [
+        "..x..",
+        "x....",
+        "x..x.",
+        "x.x..",
+        "xx...",
+        "x.x..",
+        "x..x.",
+    ],
+},
+
+'Ḳ' => Char {
+    data: [
+        "x...x",
+        "x.x..",
+        "xx...",
+        "x.x..",
+        "x..x.",
+        "x...x",
+        "..x..",
+    ],
+},
+
+'ḳ' => Char {
+    data: [
+        "x....",
+        "x..x.",
+        "x.x..",
+        "xx...",
+        "x.x..",
+        "x..x.",
+        ".x...",
+    ],
+},
+
+'Ḵ' => Char {
+    data: [
+        "x...x",
+        "x.x..",
+        "xx...",
+        "x.x..",
+        "x..x.",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ḵ' => Char {
+    data: [
+        "x....",
+        "x..x.",
+        "x.x..",
+        "xx...",
+        "x.x..",
+        "x..x.",
+        "xxx..",
+    ],
+},
+
+'Ḷ' => Char {
+    data: [
+        "x....",
+        "x....",
+        "x....",
+        "x....",
+        "x....",
+        "xxxxx",
+        "..x..",
+    ],
+},
+
+'ḷ' => Char {
+    data: [
+        ".xx..",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+        "...x.",
+        "..x..",
+    ],
+},
+
+'Ḻ' => Char {
+    data: [
+        "x....",
+        "x....",
+        "x....",
+        "x....",
+        "x....",
+        "xxxxx",
+        ".xxx.",
+    ],
+},
+
+'ḻ' => Char {
+    data: [
+        ".xx..",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+        "...x.",
+        ".xxx.",
+    ],
+},
+
+'Ḿ' => Char {
+    data: [
+        "..x..",
+        ".x...",
+        ".....",
+        "xx.xx",
+        "x.x.x",
+        "x...x",
+        "x...x",
+    ],
+},
+
+'ḿ' => Char {
+    data: [
+        "..x..",
+        ".x...",
+        ".....",
+        ".x.x.",
+        "x.x.x",
+        "x.x.x",
+        "x.x.x",
+    ],
+},
+
+'Ṁ' => Char {
+    data: [
+        "..x..",
+        ".....",
+        "xx.xx",
+        "x.x.x",
+        "x.x.x",
+        "x...x",
+        "x...x",
+    ],
+},
+
+'ṁ' => Char {
+    data: [
+        "..x..",
+        ".....",
+        ".x.x.",
+        "x.x.x",
+        "x.x.x",
+        "x.x.x",
+        "x.x.x",
+    ],
+},
+
+'Ṃ' => Char {
+    data: [
+        "xx.xx",
+        "x.x.x",
+        "x.x.x",
+        "x...x",
+        "x...x",
+        "x...x",
+        "..x..",
+    ],
+},
+
+'ṃ' => Char {
+    data: [
+        ".x.x.",
+        "x.x.x",
+        "x.x.x",
+        "x.x.x",
+        "x.x.x",
+        "x.x.x",
+        "..x..",
+    ],
+},
+
+'Ṅ' => Char {
+    data: [
+        "..x..",
+        ".....",
+        "x...x",
+        "xx..x",
+        "x.x.x",
+        "x..xx",
+        "x...x",
+    ],
+},
+
+'ṅ' => Char {
+    data: [
+        ".x...",
+        ".....",
+        ".xx..",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+    ],
+},
+
+'Ṇ' => Char {
+    data: [
+        "x...x",
+        "xx..x",
+        "x.x.x",
+        "x..xx",
+        "x...x",
+        "x...x",
+        "..x..",
+    ],
+},
+
+'ṇ' => Char {
+    data: [
+        ".xx..",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        ".x...",
+    ],
+},
+
+'Ṉ' => Char {
+    data: [
+        "x...x",
+        "xx..x",
+        "x.x.x",
+        "x..xx",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ṉ' => Char {
+    data: [
+        ".xx..",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        "xxx..",
+    ],
+},
+
+'Ṕ' => Char {
+    data: [
+        "..x..",
+        ".x...",
+        ".....",
+        "xxxx.",
+        "x...x",
+        "xxxx.",
+        "x....",
+    ],
+},
+
+'ṕ' => Char {
+    data: [
+        "..x..",
+        ".x...",
+        ".....",
+        ".xx..",
+        "x..x.",
+        "xxx..",
+        "x....",
+    ],
+},
+
+'Ṗ' => Char {
+    data: [
+        "..x..",
+        ".....",
+        "xxxx.",
+        "x...x",
+        "xxxx.",
+        "x....",
+        "x....",
+    ],
+},
+
+'ṗ' => Char {
+    data: [
+        ".x...",
+        ".....",
+        ".xx..",
+        "x..x.",
+        "xxx..",
+        "x....",
+        "x....",
+    ],
+},
+
+'Ṙ' => Char {
+    data: [
+        "..x..",
+        "xxxx.",
+        "x...x",
+        "xxxx.",
+        "x.x..",
+        "x..x.",
+        "x...x",
+    ],
+},
+
+'ṙ' => Char {
+    data: [
+        "..x..",
+        ".....",
+        "..xx.",
+        ".x...",
+        ".x...",
+        ".x...",
+        ".x...",
+    ],
+},
+
+'Ṛ' => Char {
+    data: [
+        "xxxx.",
+        "x...x",
+        "xxxx.",
+        "x.x..",
+        "x..x.",
+        "x...x",
+        "..x..",
+    ],
+},
+
+'ṛ' => Char {
+    data: [
+        "..xx.",
+        ".x...",
+        ".x...",
+        ".x...",
+        ".x...",
+        ".x...",
+        "..x..",
+    ],
+},
+
+'Ṟ' => Char {
+    data: [
+        "xxxx.",
+        "x...x",
+        "xxxx.",
+        "x.x..",
+        "x..x.",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ṟ' => Char {
+    data: [
+        "..xx.",
+        ".x...",
+        ".x...",
+        ".x...",
+        ".x...",
+        ".x...",
+        ".xxx.",
+    ],
+},
+
+'Ṡ' => Char {
+    data: [
+        "..x..",
+        ".xxx.",
+        "x....",
+        ".xxx.",
+        "....x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ṡ' => Char {
+    data: [
+        ".x...",
+        ".xx..",
+        "x..x.",
+        ".x...",
+        "..x..",
+        "x..x.",
+        ".xx..",
+    ],
+},
+
+'Ṣ' => Char {
+    data: [
+        ".xxx.",
+        "x....",
+        ".xxx.",
+        "....x",
+        "x...x",
+        ".xxx.",
+        "..x..",
+    ],
+},
+
+'ṣ' => Char {
+    data: [
+        ".xx..",
+        "x..x.",
+        ".x...",
+        "..x..",
+        "x..x.",
+        ".xx..",
+        ".x...",
+    ],
+},
+
+'Ṫ' => Char {
+    data: [
+        "..x..",
+        ".....",
+        "xxxxx",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+    ],
+},
+
+'ṫ' => Char {
+    data: [
+        "..x..",
+        ".....",
+        ".x...",
+        ".xxx.",
+        ".x...",
+        ".x...",
+        "..xx.",
+    ],
+},
+
+'Ṭ' => Char {
+    data: [
+        "xxxxx",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+    ],
+},
+
+'ṭ' => Char {
+    data: [
+        ".x...",
+        ".xxx.",
+        ".x...",
+        ".x...",
+        ".x...",
+        "..xx.",
+        "..x..",
+    ],
+},
+
+'Ṯ' => Char {
+    data: [
+        "xxxxx",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+        ".xxx.",
+    ],
+},
+
+'ṯ' => Char {
+    data: [
+        ".x...",
+        ".xxx.",
+        ".x...",
+        ".x...",
+        ".x...",
+        "..xx.",
+        ".xxx.",
+    ],
+},
+
+'Ṽ' => Char {
+    data: [
+        ".x.x.",
+        "x.x..",
+        ".....",
+        "x...x",
+        "x...x",
+        ".x.x.",
+        "..x..",
+    ],
+},
+
+'ṽ' => Char {
+    data: [
+        ".x.x.",
+        "x.x..",
+        ".....",
+        "x...x",
+        "x...x",
+        ".x.x.",
+        "..x..",
+    ],
+},
+
+'Ṿ' => Char {
+    data: [
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".x.x.",
+        "..x..",
+        "..x..",
+    ],
+},
+
+'ṿ' => Char {
+    data: [
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".x.x.",
+        "..x..",
+        "..x..",
+    ],
+},
+
+'Ẁ' => Char {
+    data: [
+        ".x...",
+        "..x..",
+        ".....",
+        "x...x",
+        "x.x.x",
+        "x.x.x",
+        ".x.x.",
+    ],
+},
+
+'ẁ' => Char {
+    data: [
+        ".x...",
+        "..x..",
+        ".....",
+        "x...x",
+        "x.x.x",
+        "x.x.x",
+        ".x.x.",
+    ],
+},
+
+'Ẃ' => Char {
+    data: [
+        "..x..",
+        ".x...",
+        ".....",
+        "x...x",
+        "x.x.x",
+        "x.x.x",
+        ".x.x.",
+    ],
+},
+
+'ẃ' => Char {
+    data: [
+        "..x..",
+        ".x...",
+        ".....",
+        "x...x",
+        "x.x.x",
+        "x.x.x",
+        ".x.x.",
+    ],
+},
+
+'Ẅ' => Char {
+    data: [
+        ".x.x.",
+        ".....",
+        "x...x",
+        "x...x",
+        "x.x.x",
+        "x.x.x",
+        ".x.x.",
+    ],
+},
+
+'ẅ' => Char {
+    data: [
+        ".x.x.",
+        ".....",
+        "x...x",
+        "x.x.x",
+        "x.x.x",
+        "x.x.x",
+        ".x.x.",
+    ],
+},
+
+'Ẇ' => Char {
+    data: [
+        "..x..",
+        ".....",
+        "x...x",
+        "x...x",
+        "x.x.x",
+        "x.x.x",
+        ".x.x.",
+    ],
+},
+
+'ẇ' => Char {
+    data: [
+        "..x..",
+        ".....",
+        "x...x",
+        "x.x.x",
+        "x.x.x",
+        "x.x.x",
+        ".x.x.",
+    ],
+},
+
+'Ẉ' => Char {
+    data: [
+        "x...x",
+        "x...x",
+        "x.x.x",
+        "x.x.x",
+        "x.x.x",
+        ".x.x.",
+        "..x..",
+    ],
+},
+
+'ẉ' => Char {
+    data: [
+        "x...x",
+        "x.x.x",
+        "x.x.x",
+        "x.x.x",
+        "x.x.x",
+        ".x.x.",
+        "..x..",
+    ],
+},
+
+'Ẋ' => Char {
+    data: [
+        "..x..",
+        ".....",
+        "x...x",
+        ".x.x.",
+        "..x..",
+        ".x.x.",
+        "x...x",
+    ],
+},
+
+'ẋ' => Char {
+    data: [
+        "..x..",
+        ".....",
+        "x...x",
+        ".x.x.",
+        "..x..",
+        ".x.x.",
+        "x...x",
+    ],
+},
+
+'Ẍ' => Char {
+    data: [
+        ".x.x.",
+        ".....",
+        "x...x",
+        ".x.x.",
+        "..x..",
+        ".x.x.",
+        "x...x",
+    ],
+},
+
+'ẍ' => Char {
+    data: [
+        ".x.x.",
+        ".....",
+        "x...x",
+        ".x.x.",
+        "..x..",
+        ".x.x.",
+        "x...x",
+    ],
+},
+
+'Ẏ' => Char {
+    data: [
+        "..x..",
+        ".....",
+        "x...x",
+        "x...x",
+        ".x.x.",
+        "..x..",
+        "..x..",
+    ],
+},
+
+'ẏ' => Char {
+    data: [
+        ".x...",
+        ".....",
+        "x..x.",
+        "x..x.",
+        ".xxx.",
+        "...x.",
+        ".xx..",
+    ],
+},
+
+'Ẑ' => Char {
+    data: [
+        ".x.x.",
+        "xxxxx",
+        "...x.",
+        "..x..",
+        ".x...",
+        "x....",
+        "xxxxx",
+    ],
+},
+
+'ẑ' => Char {
+    data: [
+        "x.x..",
+        "xxxx.",
+        "...x.",
+        "..x..",
+        ".x...",
+        "x....",
+        "xxxx.",
+    ],
+},
+
+'Ẓ' => Char {
+    data: [
+        "xxxxx",
+        "...x.",
+        "..x..",
+        ".x...",
+        "x....",
+        "xxxxx",
+        "..x..",
+    ],
+},
+
+'ẓ' => Char {
+    data: [
+        "xxxx.",
+        "...x.",
+        "..x..",
+        ".x...",
+        "x....",
+        "xxxx.",
+        ".x...",
+    ],
+},
+
+'Ẕ' => Char {
+    data: [
+        "xxxxx",
+        "...x.",
+        "..x..",
+        ".x...",
+        "x....",
+        "xxxxx",
+        ".xxx.",
+    ],
+},
+
+'ẕ' => Char {
+    data: [
+        "xxxx.",
+        "...x.",
+        "..x..",
+        ".x...",
+        "x....",
+        "xxxx.",
+        "xxx..",
+    ],
+},
+
+'ẖ' => Char {
+    data: [
+        "x....",
+        "x....",
+        "xxx..",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        "xxx..",
+    ],
+},
+
+'ẗ' => Char {
+    data: [
+        ".x.x.",
+        ".....",
+        ".x...",
+        ".xxx.",
+        ".x...",
+        ".x...",
+        "..xx.",
+    ],
+},
+
+'ẘ' => Char {
+    data: [
+        ".xx..",
+        ".xx..",
+        ".....",
+        "x...x",
+        "x.x.x",
+        "x.x.x",
+        ".x.x.",
+    ],
+},
+
+'ẙ' => Char {
+    data: [
+        ".xx..",
+        ".xx..",
+        ".....",
+        "x..x.",
+        ".xxx.",
+        "...x.",
+        ".xx..",
+    ],
+},
+
+'ẛ' => Char {
+    data: [
+        "..x..",
+        ".....",
+        "..xx.",
+        ".x...",
+        ".x...",
+        ".x...",
+        ".x...",
+    ],
+},
+
+'Ạ' => Char {
+    data: [
+        ".xxx.",
+        "x...x",
+        "xxxxx",
+        "x...x",
+        "x...x",
+        "x...x",
+        "..x..",
+    ],
+},
+
+'ạ' => Char {
+    data: [
+        ".xx..",
+        "...x.",
+        ".xxx.",
+        "x..x.",
+        "x..x.",
+        ".xx..",
+        ".x...",
+    ],
+},
+
+'Ẹ' => Char {
+    data: [
+        "xxxxx",
+        "x....",
+        "xxxx.",
+        "x....",
+        "x....",
+        "xxxxx",
+        "..x..",
+    ],
+},
+
+'ẹ' => Char {
+    data: [
+        ".xx..",
+        "x..x.",
+        "x..x.",
+        "xxxx.",
+        "x....",
+        ".xx..",
+        ".x...",
+    ],
+},
+
+'Ẽ' => Char {
+    data: [
+        ".x.x.",
+        "x.x..",
+        "xxxxx",
+        "x....",
+        "xxxx.",
+        "x....",
+        "xxxxx",
+    ],
+},
+
+'ẽ' => Char {
+    data: [
+        ".x.x.",
+        "x.x..",
+        ".xx..",
+        "x..x.",
+        "xxxx.",
+        "x....",
+        ".xx..",
+    ],
+},
+
+'Ị' => Char {
+    data: [
+        ".xxx.",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+        "xxxxx",
+        "..x..",
+    ],
+},
+
+'ị' => Char {
+    data: [
+        ".x...",
+        ".....",
+        "xx...",
+        ".x...",
+        ".x...",
+        "..x..",
+        ".x...",
+    ],
+},
+
+'Ọ' => Char {
+    data: [
+        ".xxx.",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".xxx.",
+        "..x..",
+    ],
+},
+
+'ọ' => Char {
+    data: [
+        ".xx..",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        ".xx..",
+        ".x...",
+    ],
+},
+
+'Ụ' => Char {
+    data: [
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".xxx.",
+        "..x..",
+    ],
+},
+
+'ụ' => Char {
+    data: [
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        ".xx..",
+        ".x...",
+    ],
+},
+
+'Ỳ' => Char {
+    data: [
+        ".x...",
+        "..x..",
+        ".....",
+        "x...x",
+        ".x.x.",
+        "..x..",
+        "..x..",
+    ],
+},
+
+'ỳ' => Char {
+    data: [
+        ".x...",
+        "..x..",
+        ".....",
+        "x..x.",
+        ".xxx.",
+        "...x.",
+        ".xx..",
+    ],
+},
+
+'Ỵ' => Char {
+    data: [
+        "x...x",
+        "x...x",
+        ".x.x.",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+    ],
+},
+
+'ỵ' => Char {
+    data: [
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        ".xxx.",
+        "...x.",
+        ".xx..",
+        ".x...",
+    ],
+},
+
+'Ỹ' => Char {
+    data: [
+        ".x.x.",
+        "x.x..",
+        ".....",
+        "x...x",
+        ".x.x.",
+        "..x..",
+        "..x..",
+    ],
+},
+
+'ỹ' => Char {
+    data: [
+        ".x.x.",
+        "x.x..",
+        ".....",
+        "x..x.",
+        ".xxx.",
+        "...x.",
+        ".xx..",
+    ],
+},
+
+'\u{fffd}' => Char {
+    data: [
+        "xxxxx",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        "xxxxx",
+    ],
+},
+};
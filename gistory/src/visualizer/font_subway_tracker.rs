@@ -2,189 +2,201 @@ use phf::phf_map;
 
 use crate::visualizer::font::Char;
 
-pub const CHARS_MAPPING: phf::Map<u8, Char> = phf_map! {
+pub const CHARS_MAPPING: phf::Map<char, Char> = phf_map! {
 
-b' ' => Char {
-data: [
-    ".",
-    ".",
-    ".",
-    ".",
-    ".",
-    ".",
-    ".",
-],
+' ' => Char {
+    data: [
+        ".",
+        ".",
+        ".",
+        ".",
+        ".",
+        ".",
+        ".",
+    ],
 },
 
-b'A' => Char {
+'!' => Char {
     data: [
-        ".xxx.",
-        "x...x",
-        "x...x",
-        "xxxxx",
-        "x...x",
-        "x...x",
-        "x...x",
+        "x",
+        "x",
+        "x",
+        "x",
+        "x",
+        ".",
+        "x",
     ],
 },
 
-b'B' => Char {
+'"' => Char {
     data: [
-        "xxxx.",
-        "x...x",
-        "x...x",
-        "xxxx.",
-        "x...x",
-        "x...x",
-        "xxxx.",
+        "x.x",
+        "x.x",
+        "...",
+        "...",
+        "...",
+        "...",
+        "...",
+    ],
+},
+
+'#' => Char {
+    data: [
+        ".....",
+        ".x.x.",
+        "xxxxx",
+        ".x.x.",
+        "xxxxx",
+        ".x.x.",
+        ".....",
     ],
 },
 
-b'C' => Char {
+'$' => Char {
     data: [
         ".xxx.",
-        "x...x",
-        "x....",
-        "x....",
-        "x....",
-        "x...x",
+        "x.x.x",
+        "x.x..",
+        ".xxx.",
+        "..x.x",
+        "x.x.x",
         ".xxx.",
     ],
 },
 
-b'D' => Char {
+'%' => Char {
     data: [
-        "xxxx.",
-        "x...x",
-        "x...x",
-        "x...x",
-        "x...x",
-        "x...x",
-        "xxxx.",
+        ".x...x.",
+        "x.x..x.",
+        ".x..x..",
+        "...x...",
+        "..x..x.",
+        ".x..x.x",
+        ".x...x.",
     ],
 },
 
-b'E' => Char {
+'&' => Char {
     data: [
-        "xxxxx",
-        "x....",
-        "x....",
-        "xxxx.",
-        "x....",
-        "x....",
-        "xxxxx",
+        ".xx..",
+        "x..x.",
+        "x.x..",
+        ".x...",
+        "x.x.x",
+        "x..x.",
+        ".xx.x",
     ],
 },
 
-b'F' => Char {
+'\'' => Char {
     data: [
-        "xxxxx",
-        "x....",
-        "x....",
-        "xxxx.",
-        "x....",
-        "x....",
-        "x....",
+        "x",
+        "x",
+        ".",
+        ".",
+        ".",
+        ".",
+        ".",
     ],
 },
 
-b'G' => Char {
+'(' => Char {
     data: [
-        ".xxx.",
-        "x...x",
-        "x....",
-        "x.xxx",
-        "x...x",
-        "x...x",
-        ".xxxx",
+        ".x",
+        "x.",
+        "x.",
+        "x.",
+        "x.",
+        "x.",
+        ".x",
     ],
 },
 
-b'H' => Char {
+')' => Char {
     data: [
-        "x...x",
-        "x...x",
-        "x...x",
-        "xxxxx",
-        "x...x",
-        "x...x",
-        "x...x",
+        "x.",
+        ".x",
+        ".x",
+        ".x",
+        ".x",
+        ".x",
+        "x.",
     ],
 },
 
-b'I' => Char {
+'*' => Char {
     data: [
-        ".xxx.",
-        "..x..",
-        "..x..",
         "..x..",
+        "x.x.x",
+        ".xxx.",
         "..x..",
+        ".xxx.",
+        "x.x.x",
         "..x..",
-        "xxxxx",
     ],
 },
 
-b'J' => Char {
+'+' => Char {
     data: [
-        ".xxxx",
-        "....x",
-        "....x",
-        "....x",
-        "....x",
-        "x...x",
-        ".xxx.",
+        ".....",
+        "..x..",
+        "..x..",
+        "xxxxx",
+        "..x..",
+        "..x..",
+        ".....",
     ],
 },
 
-b'K' => Char {
+',' => Char {
     data: [
-        "x...x",
-        "x..x.",
-        "x.x..",
-        "xx...",
-        "x.x..",
-        "x..x.",
-        "x...x",
+        "..",
+        "..",
+        "..",
+        "..",
+        "..",
+        ".x",
+        "xx",
     ],
 },
 
-b'L' => Char {
+'-' => Char {
     data: [
-        "x....",
-        "x....",
-        "x....",
-        "x....",
-        "x....",
-        "x....",
-        "xxxxx",
+        "...",
+        "...",
+        "...",
+        "xxx",
+        "...",
+        "...",
+        "...",
     ],
 },
 
-b'M' => Char {
+'.' => Char {
     data: [
-        "xx.xx",
-        "x.x.x",
-        "x.x.x",
-        "x.x.x",
-        "x...x",
-        "x...x",
-        "x...x",
+        ".",
+        ".",
+        ".",
+        ".",
+        ".",
+        ".",
+        "x",
     ],
 },
 
-b'N' => Char {
+'/' => Char {
     data: [
-        "x...x",
-        "x...x",
-        "xx..x",
-        "x.x.x",
-        "x..xx",
-        "x...x",
-        "x...x",
+        "....x",
+        "....x",
+        "...x.",
+        "..x..",
+        ".x...",
+        "x....",
+        "x....",
     ],
 },
 
-b'O' => Char {
+'0' => Char {
     data: [
         ".xxx.",
         "x...x",
@@ -196,951 +208,5415 @@ b'O' => Char {
     ],
 },
 
-b'P' => Char {
+'1' => Char {
     data: [
-        "xxxx.",
-        "x...x",
-        "x...x",
-        "xxxx.",
-        "x....",
-        "x....",
-        "x....",
+        "..x..",
+        "xxx..",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+        "xxxxx",
     ],
 },
 
-b'Q' => Char {
+'2' => Char {
     data: [
         ".xxx.",
         "x...x",
-        "x...x",
-        "x...x",
-        "x.x.x",
-        "x..x.",
-        ".xx.x",
+        "....x",
+        "...x.",
+        "..x..",
+        ".x...",
+        "xxxxx",
     ],
 },
 
-b'R' => Char {
+'3' => Char {
     data: [
-        "xxxx.",
-        "x...x",
+        ".xxx.",
         "x...x",
-        "xxxx.",
-        "x.x..",
-        "x..x.",
+        "....x",
+        "..xx.",
+        "....x",
         "x...x",
+        ".xxx.",
     ],
 },
 
-b'S' => Char {
+'4' => Char {
     data: [
-        ".xxx.",
-        "x...x",
-        "x....",
-        ".xxx.",
         "....x",
-        "x...x",
-        ".xxx.",
+        "...xx",
+        "..x.x",
+        ".x..x",
+        "xxxxx",
+        "....x",
+        "....x",
     ],
 },
 
-b'T' => Char {
+'5' => Char {
     data: [
         "xxxxx",
-        "..x..",
-        "..x..",
-        "..x..",
-        "..x..",
-        "..x..",
-        "..x..",
+        "x....",
+        "x....",
+        "xxxx.",
+        "....x",
+        "x...x",
+        ".xxx.",
     ],
 },
 
-b'U' => Char {
+'6' => Char {
     data: [
+        ".xxx.",
         "x...x",
-        "x...x",
-        "x...x",
-        "x...x",
+        "x....",
+        "xxxx.",
         "x...x",
         "x...x",
         ".xxx.",
     ],
 },
 
-b'V' => Char {
+'7' => Char {
     data: [
-        "x...x",
-        "x...x",
-        "x...x",
-        "x...x",
-        "x...x",
-        ".x.x.",
+        "xxxxx",
+        "....x",
+        "...x.",
         "..x..",
+        ".x...",
+        ".x...",
+        ".x...",
     ],
 },
 
-b'W' => Char {
+'8' => Char {
     data: [
+        ".xxx.",
         "x...x",
         "x...x",
-        "x.x.x",
-        "x.x.x",
-        "x.x.x",
-        "x.x.x",
-        ".x.x.",
+        ".xxx.",
+        "x...x",
+        "x...x",
+        ".xxx.",
     ],
 },
 
-b'X' => Char {
+'9' => Char {
     data: [
+        ".xxx.",
         "x...x",
-        ".x.x.",
-        "..x..",
-        "..x..",
-        "..x..",
-        ".x.x.",
         "x...x",
+        ".xxxx",
+        "....x",
+        "x...x",
+        ".xxx.",
     ],
 },
 
-b'Y' => Char {
+':' => Char {
     data: [
-        "x...x",
-        "x...x",
-        "x...x",
-        ".x.x.",
-        "..x..",
-        "..x..",
-        "..x..",
+        ".",
+        ".",
+        ".",
+        "x",
+        ".",
+        ".",
+        "x",
     ],
 },
 
-b'Z' => Char {
+';' => Char {
     data: [
-        "xxxxx",
-        "....x",
-        "...x.",
-        "..x..",
-        ".x...",
-        "x....",
-        "xxxxx",
+        "..",
+        "..",
+        "..",
+        ".x",
+        "..",
+        ".x",
+        "xx",
     ],
 },
 
-b'a' => Char {
+'<' => Char {
     data: [
-        "....",
-        ".xx.",
         "...x",
-        ".xxx",
-        "x..x",
-        "x..x",
-        ".xx.",
+        "..x.",
+        ".x..",
+        "x...",
+        ".x..",
+        "..x.",
+        "...x",
     ],
 },
 
-b'b' => Char {
+'=' => Char {
     data: [
-        "x...",
-        "x...",
-        "xxx.",
-        "x..x",
-        "x..x",
-        "x..x",
-        ".xx.",
+        ".....",
+        ".....",
+        "xxxxx",
+        ".....",
+        "xxxxx",
+        ".....",
+        ".....",
     ],
 },
 
-b'c' => Char {
+'>' => Char {
     data: [
-        "....",
-        ".xx.",
-        "x..x",
         "x...",
+        ".x..",
+        "..x.",
+        "...x",
+        "..x.",
+        ".x..",
         "x...",
-        "x..x",
-        ".xx.",
     ],
 },
 
-b'd' => Char {
+'?' => Char {
     data: [
-        "...x",
-        "...x",
-        ".xxx",
-        "x..x",
-        "x..x",
-        "x..x",
-        ".xx.",
+        ".xxx.",
+        "x...x",
+        "....x",
+        "..xx.",
+        ".x...",
+        ".....",
+        ".x...",
     ],
 },
 
-b'e' => Char {
+'@' => Char {
     data: [
-        "....",
-        ".xx.",
-        "x..x",
-        "x..x",
-        "xxxx",
-        "x...",
-        ".xx.",
+        ".xxx.",
+        "x...x",
+        "x..xx",
+        "x.x.x",
+        "x.x.x",
+        "x..x.",
+        ".x...",
     ],
 },
 
-b'f' => Char {
+'A' => Char {
     data: [
-        ".xx",
-        "x..",
-        "x..",
-        "xx.",
-        "x..",
-        "x..",
-        "x..",
+        ".xxx.",
+        "x...x",
+        "x...x",
+        "xxxxx",
+        "x...x",
+        "x...x",
+        "x...x",
     ],
 },
 
-b'g' => Char {
+'B' => Char {
     data: [
-        "....",
-        ".xx.",
-        "x..x",
-        "x..x",
-        ".xxx",
-        "...x",
-        ".xx.",
+        "xxxx.",
+        "x...x",
+        "x...x",
+        "xxxx.",
+        "x...x",
+        "x...x",
+        "xxxx.",
     ],
 },
 
-b'h' => Char {
+'C' => Char {
     data: [
-        "x...",
-        "x...",
-        "xxx.",
-        "x..x",
-        "x..x",
-        "x..x",
-        "x..x",
+        ".xxx.",
+        "x...x",
+        "x....",
+        "x....",
+        "x....",
+        "x...x",
+        ".xxx.",
     ],
 },
 
-b'i' => Char {
+'D' => Char {
     data: [
-        ".x..",
-        "....",
-        "xx..",
-        ".x..",
-        ".x..",
-        ".x..",
-        "..x.",
+        "xxxx.",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        "xxxx.",
     ],
 },
 
-b'j' => Char {
+'E' => Char {
     data: [
-        "...x",
-        "....",
-        "..xx",
-        "...x",
-        "...x",
-        "x..x",
-        ".xx.",
+        "xxxxx",
+        "x....",
+        "x....",
+        "xxxx.",
+        "x....",
+        "x....",
+        "xxxxx",
     ],
 },
 
-b'k' => Char {
+'F' => Char {
     data: [
-        "x...",
-        "x...",
-        "x..x",
-        "x.x.",
-        "xx..",
-        "x.x.",
-        "x..x",
+        "xxxxx",
+        "x....",
+        "x....",
+        "xxxx.",
+        "x....",
+        "x....",
+        "x....",
     ],
 },
 
-b'l' => Char {
+'G' => Char {
     data: [
-        "xx.",
-        ".x.",
-        ".x.",
-        ".x.",
-        ".x.",
-        ".x.",
-        "..x",
+        ".xxx.",
+        "x...x",
+        "x....",
+        "x.xxx",
+        "x...x",
+        "x...x",
+        ".xxxx",
     ],
 },
 
-b'm' => Char {
+'H' => Char {
     data: [
-        ".....",
-        ".x.x.",
-        "x.x.x",
-        "x.x.x",
-        "x.x.x",
-        "x.x.x",
-        "x.x.x",
+        "x...x",
+        "x...x",
+        "x...x",
+        "xxxxx",
+        "x...x",
+        "x...x",
+        "x...x",
     ],
 },
 
-b'n' => Char {
+'I' => Char {
     data: [
-        "....",
-        ".xx.",
-        "x..x",
-        "x..x",
-        "x..x",
-        "x..x",
-        "x..x",
-    ],
-},
-
-b'o' => Char {
-    data: [
-        "....",
-        ".xx.",
-        "x..x",
-        "x..x",
-        "x..x",
-        "x..x",
-        ".xx.",
+        ".xxx.",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+        "xxxxx",
     ],
 },
 
-b'p' => Char {
+'J' => Char {
     data: [
-        "....",
-        ".xx.",
-        "x..x",
-        "x..x",
-        "xxx.",
-        "x...",
-        "x...",
+        ".xxxx",
+        "....x",
+        "....x",
+        "....x",
+        "....x",
+        "x...x",
+        ".xxx.",
     ],
 },
 
-b'q' => Char {
+'K' => Char {
     data: [
-        "....",
-        ".xx.",
-        "x..x",
-        "x..x",
-        ".xxx",
-        "...x",
-        "...x",
+        "x...x",
+        "x..x.",
+        "x.x..",
+        "xx...",
+        "x.x..",
+        "x..x.",
+        "x...x",
     ],
 },
 
-b'r' => Char {
+'L' => Char {
     data: [
-        "...",
-        ".xx",
-        "x..",
-        "x..",
-        "x..",
-        "x..",
-        "x..",
+        "x....",
+        "x....",
+        "x....",
+        "x....",
+        "x....",
+        "x....",
+        "xxxxx",
     ],
 },
 
-b's' => Char {
+'M' => Char {
     data: [
-        "....",
-        ".xx.",
-        "x..x",
-        ".x..",
-        "..x.",
-        "x..x",
-        ".xx.",
+        "xx.xx",
+        "x.x.x",
+        "x.x.x",
+        "x.x.x",
+        "x...x",
+        "x...x",
+        "x...x",
     ],
 },
 
-b't' => Char {
+'N' => Char {
     data: [
-        "...",
-        "x..",
-        "xxx",
-        "x..",
-        "x..",
-        "x..",
-        ".xx",
+        "x...x",
+        "x...x",
+        "xx..x",
+        "x.x.x",
+        "x..xx",
+        "x...x",
+        "x...x",
     ],
 },
 
-b'u' => Char {
+'O' => Char {
     data: [
-        "....",
-        "x..x",
-        "x..x",
-        "x..x",
-        "x..x",
-        "x..x",
-        ".xx.",
+        ".xxx.",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".xxx.",
     ],
 },
 
-b'v' => Char {
+'P' => Char {
     data: [
-        "....",
-        "x..x",
-        "x..x",
-        "x..x",
-        "x..x",
-        ".x.x.",
-        "..x..",
+        "xxxx.",
+        "x...x",
+        "x...x",
+        "xxxx.",
+        "x....",
+        "x....",
+        "x....",
     ],
 },
 
-b'w' => Char {
+'Q' => Char {
     data: [
-        ".....",
+        ".xxx.",
+        "x...x",
+        "x...x",
         "x...x",
         "x.x.x",
-        "x.x.x",
-        "x.x.x",
-        "x.x.x",
-        ".x.x.",
+        "x..x.",
+        ".xx.x",
     ],
 },
 
-b'x' => Char {
+'R' => Char {
     data: [
-        ".....",
+        "xxxx.",
         "x...x",
-        ".x.x.",
-        "..x..",
-        "..x..",
-        ".x.x.",
+        "x...x",
+        "xxxx.",
+        "x.x..",
+        "x..x.",
         "x...x",
     ],
 },
 
-b'y' => Char {
-    data: [
-        "....",
-        "x..x",
-        "x..x",
-        "x..x",
-        ".xxx",
-        "...x",
-        ".xx.",
-    ],
-},
-
-b'z' => Char {
-    data: [
-        "....",
-        "xxxx",
-        "...x",
-        "..x.",
-        ".x..",
-        "x...",
-        "xxxx",
-    ],
-},
-
-b'0' => Char {
+'S' => Char {
     data: [
         ".xxx.",
         "x...x",
-        "x...x",
-        "x...x",
-        "x...x",
+        "x....",
+        ".xxx.",
+        "....x",
         "x...x",
         ".xxx.",
     ],
 },
 
-b'1' => Char {
+'T' => Char {
     data: [
+        "xxxxx",
+        "..x..",
         "..x..",
-        "xxx..",
         "..x..",
         "..x..",
         "..x..",
         "..x..",
-        "xxxxx",
     ],
 },
 
-b'2' => Char {
+'U' => Char {
     data: [
-        ".xxx.",
         "x...x",
-        "....x",
-        "...x.",
-        "..x..",
-        ".x...",
-        "xxxxx",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".xxx.",
     ],
 },
 
-b'3' => Char {
+'V' => Char {
     data: [
-        ".xxx.",
         "x...x",
-        "....x",
-        "..xx.",
-        "....x",
         "x...x",
-        ".xxx.",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".x.x.",
+        "..x..",
     ],
 },
 
-b'4' => Char {
+'W' => Char {
     data: [
-        "....x",
-        "...xx",
-        "..x.x",
-        ".x..x",
-        "xxxxx",
-        "....x",
-        "....x",
+        "x...x",
+        "x...x",
+        "x.x.x",
+        "x.x.x",
+        "x.x.x",
+        "x.x.x",
+        ".x.x.",
     ],
 },
 
-b'5' => Char {
+'X' => Char {
     data: [
-        "xxxxx",
-        "x....",
-        "x....",
-        "xxxx.",
-        "....x",
         "x...x",
-        ".xxx.",
+        ".x.x.",
+        "..x..",
+        "..x..",
+        "..x..",
+        ".x.x.",
+        "x...x",
     ],
 },
 
-b'6' => Char {
+'Y' => Char {
     data: [
-        ".xxx.",
         "x...x",
-        "x....",
-        "xxxx.",
         "x...x",
         "x...x",
-        ".xxx.",
+        ".x.x.",
+        "..x..",
+        "..x..",
+        "..x..",
     ],
 },
 
-b'7' => Char {
+'Z' => Char {
     data: [
         "xxxxx",
         "....x",
         "...x.",
         "..x..",
         ".x...",
-        ".x...",
-        ".x...",
+        "x....",
+        "xxxxx",
     ],
 },
 
-b'8' => Char {
+'[' => Char {
     data: [
-        ".xxx.",
-        "x...x",
-        "x...x",
-        ".xxx.",
-        "x...x",
-        "x...x",
-        ".xxx.",
+        "xx",
+        "x.",
+        "x.",
+        "x.",
+        "x.",
+        "x.",
+        "xx",
     ],
 },
 
-b'9' => Char {
+'\\' => Char {
     data: [
-        ".xxx.",
-        "x...x",
-        "x...x",
-        ".xxxx",
+        "x....",
+        "x....",
+        ".x...",
+        "..x..",
+        "...x.",
+        "....x",
         "....x",
-        "x...x",
-        ".xxx.",
     ],
 },
 
-b'!' => Char {
+']' => Char {
     data: [
+        "xx",
+        ".x",
+        ".x",
+        ".x",
+        ".x",
+        ".x",
+        "xx",
+    ],
+},
+
+'^' => Char {
+    data: [
+        "..x..",
+        ".x.x.",
+        "x...x",
+        ".....",
+        ".....",
+        ".....",
+        ".....",
+    ],
+},
+
+'_' => Char {
+    data: [
+        "....",
+        "....",
+        "....",
+        "....",
+        "....",
+        "....",
+        "xxxx",
+    ],
+},
+
+'`' => Char {
+    data: [
+        "x..",
+        ".x.",
+        "..x",
+        "...",
+        "...",
+        "...",
+        "...",
+    ],
+},
+
+'a' => Char {
+    data: [
+        "....",
+        ".xx.",
+        "...x",
+        ".xxx",
+        "x..x",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'b' => Char {
+    data: [
+        "x...",
+        "x...",
+        "xxx.",
+        "x..x",
+        "x..x",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'c' => Char {
+    data: [
+        "....",
+        ".xx.",
+        "x..x",
+        "x...",
+        "x...",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'d' => Char {
+    data: [
+        "...x",
+        "...x",
+        ".xxx",
+        "x..x",
+        "x..x",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'e' => Char {
+    data: [
+        "....",
+        ".xx.",
+        "x..x",
+        "x..x",
+        "xxxx",
+        "x...",
+        ".xx.",
+    ],
+},
+
+'f' => Char {
+    data: [
+        ".xx",
+        "x..",
+        "x..",
+        "xx.",
+        "x..",
+        "x..",
+        "x..",
+    ],
+},
+
+'g' => Char {
+    data: [
+        "....",
+        ".xx.",
+        "x..x",
+        "x..x",
+        ".xxx",
+        "...x",
+        ".xx.",
+    ],
+},
+
+'h' => Char {
+    data: [
+        "x...",
+        "x...",
+        "xxx.",
+        "x..x",
+        "x..x",
+        "x..x",
+        "x..x",
+    ],
+},
+
+'i' => Char {
+    data: [
+        ".x..",
+        "....",
+        "xx..",
+        ".x..",
+        ".x..",
+        ".x..",
+        "..x.",
+    ],
+},
+
+'j' => Char {
+    data: [
+        "...x",
+        "....",
+        "..xx",
+        "...x",
+        "...x",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'k' => Char {
+    data: [
+        "x...",
+        "x...",
+        "x..x",
+        "x.x.",
+        "xx..",
+        "x.x.",
+        "x..x",
+    ],
+},
+
+'l' => Char {
+    data: [
+        "xx.",
+        ".x.",
+        ".x.",
+        ".x.",
+        ".x.",
+        ".x.",
+        "..x",
+    ],
+},
+
+'m' => Char {
+    data: [
+        ".....",
+        ".x.x.",
+        "x.x.x",
+        "x.x.x",
+        "x.x.x",
+        "x.x.x",
+        "x.x.x",
+    ],
+},
+
+'n' => Char {
+    data: [
+        "....",
+        ".xx.",
+        "x..x",
+        "x..x",
+        "x..x",
+        "x..x",
+        "x..x",
+    ],
+},
+
+'o' => Char {
+    data: [
+        "....",
+        ".xx.",
+        "x..x",
+        "x..x",
+        "x..x",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'p' => Char {
+    data: [
+        "....",
+        ".xx.",
+        "x..x",
+        "x..x",
+        "xxx.",
+        "x...",
+        "x...",
+    ],
+},
+
+'q' => Char {
+    data: [
+        "....",
+        ".xx.",
+        "x..x",
+        "x..x",
+        ".xxx",
+        "...x",
+        "...x",
+    ],
+},
+
+'r' => Char {
+    data: [
+        "...",
+        ".xx",
+        "x..",
+        "x..",
+        "x..",
+        "x..",
+        "x..",
+    ],
+},
+
+'s' => Char {
+    data: [
+        "....",
+        ".xx.",
+        "x..x",
+        ".x..",
+        "..x.",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'t' => Char {
+    data: [
+        "...",
+        "x..",
+        "xxx",
+        "x..",
+        "x..",
+        "x..",
+        ".xx",
+    ],
+},
+
+'u' => Char {
+    data: [
+        "....",
+        "x..x",
+        "x..x",
+        "x..x",
+        "x..x",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'v' => Char {
+    data: [
+        ".....",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".x.x.",
+        "..x..",
+    ],
+},
+
+'w' => Char {
+    data: [
+        ".....",
+        "x...x",
+        "x.x.x",
+        "x.x.x",
+        "x.x.x",
+        "x.x.x",
+        ".x.x.",
+    ],
+},
+
+'x' => Char {
+    data: [
+        ".....",
+        "x...x",
+        ".x.x.",
+        "..x..",
+        "..x..",
+        ".x.x.",
+        "x...x",
+    ],
+},
+
+'y' => Char {
+    data: [
+        "....",
+        "x..x",
+        "x..x",
+        "x..x",
+        ".xxx",
+        "...x",
+        ".xx.",
+    ],
+},
+
+'z' => Char {
+    data: [
+        "....",
+        "xxxx",
+        "...x",
+        "..x.",
+        ".x..",
+        "x...",
+        "xxxx",
+    ],
+},
+
+'{' => Char {
+    data: [
+        ".xx",
+        ".x.",
+        ".x.",
+        "xx.",
+        ".x.",
+        ".x.",
+        ".xx",
+    ],
+},
+
+'|' => Char {
+    data: [
+        "x",
+        "x",
+        "x",
+        "x",
+        "x",
+        "x",
+        "x",
+    ],
+},
+
+'}' => Char {
+    data: [
+        "xx.",
+        ".x.",
+        ".x.",
+        ".xx",
+        ".x.",
+        ".x.",
+        "xx.",
+    ],
+},
+
+'~' => Char {
+    data: [
+        ".......",
+        ".......",
+        ".xx....",
+        "x..x..x",
+        "....xx.",
+        ".......",
+        ".......",
+    ],
+},
+
+'\u{a0}' => Char {
+    data: [
+        ".",
+        ".",
+        ".",
+        ".",
+        ".",
+        ".",
+        ".",
+    ],
+},
+
+'¡' => Char {
+    data: [
+        "x",
+        ".",
+        "x",
+        "x",
+        "x",
+        "x",
+        "x",
+    ],
+},
+
+'¢' => Char {
+    data: [
+        "..x.",
+        ".xxx",
+        "x.x.",
+        "x.x.",
+        "x.x.",
+        ".xxx",
+        "..x.",
+    ],
+},
+
+'£' => Char {
+    data: [
+        "..xx.",
+        ".x..x",
+        ".x...",
+        "xxxx.",
+        ".x...",
+        ".x...",
+        "xxxxx",
+    ],
+},
+
+'¤' => Char {
+    data: [
+        ".....",
+        "x...x",
+        ".xxx.",
+        ".x.x.",
+        ".xxx.",
+        "x...x",
+        ".....",
+    ],
+},
+
+'¥' => Char {
+    data: [
+        "x...x",
+        ".x.x.",
+        "..x..",
+        "xxxxx",
+        "..x..",
+        "xxxxx",
+        "..x..",
+    ],
+},
+
+'¦' => Char {
+    data: [
+        "x",
         "x",
         "x",
+        ".",
+        "x",
         "x",
         "x",
-        "x",
+    ],
+},
+
+'§' => Char {
+    data: [
+        ".xxx",
+        "x...",
+        ".xx.",
+        "x..x",
+        ".xx.",
+        "...x",
+        "xxx.",
+    ],
+},
+
+'¨' => Char {
+    data: [
+        "x.x",
+        "...",
+        "...",
+        "...",
+        "...",
+        "...",
+        "...",
+    ],
+},
+
+'©' => Char {
+    data: [
+        ".xxxxx.",
+        "x.....x",
+        "x..xx.x",
+        "x.x...x",
+        "x..xx.x",
+        "x.....x",
+        ".xxxxx.",
+    ],
+},
+
+'«' => Char {
+    data: [
+        "......",
+        "......",
+        "..x..x",
+        ".x..x.",
+        "x..x..",
+        ".x..x.",
+        "..x..x",
+    ],
+},
+
+'¬' => Char {
+    data: [
+        ".....",
+        ".....",
+        ".....",
+        "xxxxx",
+        "....x",
+        ".....",
+        ".....",
+    ],
+},
+
+'\u{ad}' => Char {
+    data: [
+        "...",
+        "...",
+        "...",
+        "xxx",
+        "...",
+        "...",
+        "...",
+    ],
+},
+
+'®' => Char {
+    data: [
+        ".xxxxx.",
+        "x.xx..x",
+        "x.x.x.x",
+        "x.xx..x",
+        "x.x.x.x",
+        "x.....x",
+        ".xxxxx.",
+    ],
+},
+
+'¯' => Char {
+    data: [
+        "xxx",
+        "...",
+        "...",
+        "...",
+        "...",
+        "...",
+        "...",
+    ],
+},
+
+'°' => Char {
+    data: [
+        ".x.",
+        "x.x",
+        ".x.",
+        "...",
+        "...",
+        "...",
+        "...",
+    ],
+},
+
+'±' => Char {
+    data: [
+        ".....",
+        "..x..",
+        "..x..",
+        "xxxxx",
+        "..x..",
+        ".....",
+        "xxxxx",
+    ],
+},
+
+'´' => Char {
+    data: [
+        ".x",
+        "x.",
+        "..",
+        "..",
+        "..",
+        "..",
+        "..",
+    ],
+},
+
+'µ' => Char {
+    data: [
+        "....",
+        "x..x",
+        "x..x",
+        "x..x",
+        "xxx.",
+        "x...",
+        "x...",
+    ],
+},
+
+'¶' => Char {
+    data: [
+        ".xxxx",
+        "xxx.x",
+        "xxx.x",
+        ".xx.x",
+        "..x.x",
+        "..x.x",
+        "..x.x",
+    ],
+},
+
+'·' => Char {
+    data: [
+        ".",
+        ".",
         ".",
         "x",
+        ".",
+        ".",
+        ".",
+    ],
+},
+
+'»' => Char {
+    data: [
+        "......",
+        "......",
+        "x..x..",
+        ".x..x.",
+        "..x..x",
+        ".x..x.",
+        "x..x..",
+    ],
+},
+
+'¿' => Char {
+    data: [
+        ".x...",
+        ".....",
+        "...x.",
+        ".xx..",
+        "x....",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'À' => Char {
+    data: [
+        ".x...",
+        "..x..",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "xxxxx",
+        "x...x",
+    ],
+},
+
+'Á' => Char {
+    data: [
+        "..x..",
+        ".x...",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "xxxxx",
+        "x...x",
+    ],
+},
+
+'Â' => Char {
+    data: [
+        "..x..",
+        ".x.x.",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "xxxxx",
+        "x...x",
+    ],
+},
+
+'Ã' => Char {
+    data: [
+        ".x.x.",
+        "x.x..",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "xxxxx",
+        "x...x",
+    ],
+},
+
+'Ä' => Char {
+    data: [
+        ".x.x.",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "xxxxx",
+        "x...x",
+        "x...x",
+    ],
+},
+
+'Å' => Char {
+    data: [
+        ".xx..",
+        ".xx..",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "xxxxx",
+        "x...x",
+    ],
+},
+
+'Æ' => Char {
+    data: [
+        ".xxxxxx",
+        "x..x...",
+        "x..x...",
+        "xxxxxx.",
+        "x..x...",
+        "x..x...",
+        "x..xxxx",
+    ],
+},
+
+'Ç' => Char {
+    data: [
+        ".xxx.",
+        "x...x",
+        "x....",
+        "x...x",
+        ".xxx.",
+        "..x..",
+        ".x...",
+    ],
+},
+
+'È' => Char {
+    data: [
+        ".x...",
+        "..x..",
+        "xxxxx",
+        "x....",
+        "xxxx.",
+        "x....",
+        "xxxxx",
+    ],
+},
+
+'É' => Char {
+    data: [
+        "..x..",
+        ".x...",
+        "xxxxx",
+        "x....",
+        "xxxx.",
+        "x....",
+        "xxxxx",
+    ],
+},
+
+'Ê' => Char {
+    data: [
+        "..x..",
+        ".x.x.",
+        "xxxxx",
+        "x....",
+        "xxxx.",
+        "x....",
+        "xxxxx",
+    ],
+},
+
+'Ë' => Char {
+    data: [
+        ".x.x.",
+        ".....",
+        "xxxxx",
+        "x....",
+        "xxxx.",
+        "x....",
+        "xxxxx",
+    ],
+},
+
+'Ì' => Char {
+    data: [
+        ".x...",
+        "..x..",
+        ".....",
+        ".xxx.",
+        "..x..",
+        "..x..",
+        "xxxxx",
+    ],
+},
+
+'Í' => Char {
+    data: [
+        "..x..",
+        ".x...",
+        ".....",
+        ".xxx.",
+        "..x..",
+        "..x..",
+        "xxxxx",
+    ],
+},
+
+'Î' => Char {
+    data: [
+        "..x..",
+        ".x.x.",
+        ".....",
+        ".xxx.",
+        "..x..",
+        "..x..",
+        "xxxxx",
+    ],
+},
+
+'Ï' => Char {
+    data: [
+        ".x.x.",
+        ".....",
+        ".xxx.",
+        "..x..",
+        "..x..",
+        "..x..",
+        "xxxxx",
+    ],
+},
+
+'Ð' => Char {
+    data: [
+        "xxxx.",
+        ".x..x",
+        ".x..x",
+        "xxx.x",
+        ".x..x",
+        ".x..x",
+        "xxxx.",
+    ],
+},
+
+'Ñ' => Char {
+    data: [
+        ".x.x.",
+        "x.x..",
+        "x...x",
+        "xx..x",
+        "x.x.x",
+        "x..xx",
+        "x...x",
+    ],
+},
+
+'Ò' => Char {
+    data: [
+        ".x...",
+        "..x..",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'Ó' => Char {
+    data: [
+        "..x..",
+        ".x...",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'Ô' => Char {
+    data: [
+        "..x..",
+        ".x.x.",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'Õ' => Char {
+    data: [
+        ".x.x.",
+        "x.x..",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'Ö' => Char {
+    data: [
+        ".x.x.",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'×' => Char {
+    data: [
+        ".....",
+        ".....",
+        "x...x",
+        ".x.x.",
+        "..x..",
+        ".x.x.",
+        "x...x",
+    ],
+},
+
+'Ø' => Char {
+    data: [
+        ".xxx.",
+        "x...x",
+        "x..xx",
+        "x.x.x",
+        "xx..x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'Ù' => Char {
+    data: [
+        ".x...",
+        "..x..",
+        ".....",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'Ú' => Char {
+    data: [
+        "..x..",
+        ".x...",
+        ".....",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'Û' => Char {
+    data: [
+        "..x..",
+        ".x.x.",
+        ".....",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'Ü' => Char {
+    data: [
+        ".x.x.",
+        ".....",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'Ý' => Char {
+    data: [
+        "..x..",
+        ".x...",
+        ".....",
+        "x...x",
+        ".x.x.",
+        "..x..",
+        "..x..",
+    ],
+},
+
+'Þ' => Char {
+    data: [
+        "x....",
+        "xxxx.",
+        "x...x",
+        "x...x",
+        "xxxx.",
+        "x....",
+        "x....",
+    ],
+},
+
+'ß' => Char {
+    data: [
+        ".xx.",
+        "x..x",
+        "x.x.",
+        "x..x",
+        "x..x",
+        "x..x",
+        "x.x.",
+    ],
+},
+
+'à' => Char {
+    data: [
+        ".x..",
+        "..x.",
+        ".xx.",
+        "...x",
+        ".xxx",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'á' => Char {
+    data: [
+        "..x.",
+        ".x..",
+        ".xx.",
+        "...x",
+        ".xxx",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'â' => Char {
+    data: [
+        ".x..",
+        "x.x.",
+        ".xx.",
+        "...x",
+        ".xxx",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'ã' => Char {
+    data: [
+        ".x.x",
+        "x.x.",
+        ".xx.",
+        "...x",
+        ".xxx",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'ä' => Char {
+    data: [
+        "x..x",
+        "....",
+        ".xx.",
+        "...x",
+        ".xxx",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'å' => Char {
+    data: [
+        ".xx.",
+        ".xx.",
+        ".xx.",
+        "...x",
+        ".xxx",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'æ' => Char {
+    data: [
+        ".......",
+        ".xx.xx.",
+        "...x..x",
+        ".xxxxxx",
+        "x..x...",
+        "x..x..x",
+        ".xx.xx.",
+    ],
+},
+
+'ç' => Char {
+    data: [
+        ".xx.",
+        "x..x",
+        "x...",
+        "x..x",
+        ".xx.",
+        "..x.",
+        ".x..",
+    ],
+},
+
+'è' => Char {
+    data: [
+        ".x..",
+        "..x.",
+        ".xx.",
+        "x..x",
+        "xxxx",
+        "x...",
+        ".xx.",
+    ],
+},
+
+'é' => Char {
+    data: [
+        "..x.",
+        ".x..",
+        ".xx.",
+        "x..x",
+        "xxxx",
+        "x...",
+        ".xx.",
+    ],
+},
+
+'ê' => Char {
+    data: [
+        ".x..",
+        "x.x.",
+        ".xx.",
+        "x..x",
+        "xxxx",
+        "x...",
+        ".xx.",
+    ],
+},
+
+'ë' => Char {
+    data: [
+        "x..x",
+        "....",
+        ".xx.",
+        "x..x",
+        "xxxx",
+        "x...",
+        ".xx.",
+    ],
+},
+
+'ì' => Char {
+    data: [
+        ".x..",
+        "..x.",
+        "....",
+        "xx..",
+        ".x..",
+        ".x..",
+        "..x.",
+    ],
+},
+
+'í' => Char {
+    data: [
+        "..x.",
+        ".x..",
+        "....",
+        "xx..",
+        ".x..",
+        ".x..",
+        "..x.",
+    ],
+},
+
+'î' => Char {
+    data: [
+        ".x..",
+        "x.x.",
+        "....",
+        "xx..",
+        ".x..",
+        ".x..",
+        "..x.",
+    ],
+},
+
+'ï' => Char {
+    data: [
+        "x..x",
+        "....",
+        "xx..",
+        ".x..",
+        ".x..",
+        ".x..",
+        "..x.",
+    ],
+},
+
+'ð' => Char {
+    data: [
+        ".x.x",
+        "..x.",
+        ".x.x",
+        ".xxx",
+        "x..x",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'ñ' => Char {
+    data: [
+        ".x.x",
+        "x.x.",
+        "....",
+        ".xx.",
+        "x..x",
+        "x..x",
+        "x..x",
+    ],
+},
+
+'ò' => Char {
+    data: [
+        ".x..",
+        "..x.",
+        "....",
+        ".xx.",
+        "x..x",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'ó' => Char {
+    data: [
+        "..x.",
+        ".x..",
+        "....",
+        ".xx.",
+        "x..x",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'ô' => Char {
+    data: [
+        ".x..",
+        "x.x.",
+        "....",
+        ".xx.",
+        "x..x",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'õ' => Char {
+    data: [
+        ".x.x",
+        "x.x.",
+        "....",
+        ".xx.",
+        "x..x",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'ö' => Char {
+    data: [
+        "x..x",
+        "....",
+        ".xx.",
+        "x..x",
+        "x..x",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'÷' => Char {
+    data: [
+        ".....",
+        "..x..",
+        ".....",
+        "xxxxx",
+        ".....",
+        "..x..",
+        ".....",
+    ],
+},
+
+'ø' => Char {
+    data: [
+        ".....",
+        "....x",
+        ".xxx.",
+        "x.x.x",
+        "xx..x",
+        ".xxx.",
+        "x....",
+    ],
+},
+
+'ù' => Char {
+    data: [
+        ".x..",
+        "..x.",
+        "....",
+        "x..x",
+        "x..x",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'ú' => Char {
+    data: [
+        "..x.",
+        ".x..",
+        "....",
+        "x..x",
+        "x..x",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'û' => Char {
+    data: [
+        ".x..",
+        "x.x.",
+        "....",
+        "x..x",
+        "x..x",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'ü' => Char {
+    data: [
+        "x..x",
+        "....",
+        "x..x",
+        "x..x",
+        "x..x",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'ý' => Char {
+    data: [
+        "..x.",
+        ".x..",
+        "....",
+        "x..x",
+        ".xxx",
+        "...x",
+        ".xx.",
+    ],
+},
+
+'þ' => Char {
+    data: [
+        "x...",
+        "xxx.",
+        "x..x",
+        "x..x",
+        "xxx.",
+        "x...",
+        "x...",
+    ],
+},
+
+'ÿ' => Char {
+    data: [
+        "x..x",
+        "....",
+        "x..x",
+        "x..x",
+        ".xxx",
+        "...x",
+        ".xx.",
+    ],
+},
+
+'Ā' => Char {
+    data: [
+        ".xxx.",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "xxxxx",
+        "x...x",
+        "x...x",
+    ],
+},
+
+'ā' => Char {
+    data: [
+        "xxx.",
+        "....",
+        ".xx.",
+        "...x",
+        ".xxx",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'Ă' => Char {
+    data: [
+        "x..x.",
+        ".xx..",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "xxxxx",
+        "x...x",
+    ],
+},
+
+'ă' => Char {
+    data: [
+        "x..x",
+        ".xx.",
+        ".xx.",
+        "...x",
+        ".xxx",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'Ą' => Char {
+    data: [
+        ".xxx.",
+        "x...x",
+        "xxxxx",
+        "x...x",
+        "x...x",
+        "...x.",
+        "....x",
+    ],
+},
+
+'ą' => Char {
+    data: [
+        ".xx.",
+        "...x",
+        ".xxx",
+        "x..x",
+        ".xx.",
+        "..x.",
+        "...x",
+    ],
+},
+
+'Ć' => Char {
+    data: [
+        "..x..",
+        ".x...",
+        ".xxx.",
+        "x...x",
+        "x....",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ć' => Char {
+    data: [
+        "..x.",
+        ".x..",
+        ".xx.",
+        "x..x",
+        "x...",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'Ĉ' => Char {
+    data: [
+        "..x..",
+        ".x.x.",
+        ".xxx.",
+        "x...x",
+        "x....",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ĉ' => Char {
+    data: [
+        ".x..",
+        "x.x.",
+        ".xx.",
+        "x..x",
+        "x...",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'Ċ' => Char {
+    data: [
+        "..x..",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "x....",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ċ' => Char {
+    data: [
+        ".x..",
+        "....",
+        ".xx.",
+        "x..x",
+        "x...",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'Č' => Char {
+    data: [
+        ".x.x.",
+        "..x..",
+        ".xxx.",
+        "x...x",
+        "x....",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'č' => Char {
+    data: [
+        "x.x.",
+        ".x..",
+        ".xx.",
+        "x..x",
+        "x...",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'Ď' => Char {
+    data: [
+        ".x.x.",
+        "..x..",
+        ".....",
+        "xxxx.",
+        "x...x",
+        "x...x",
+        "xxxx.",
+    ],
+},
+
+'ď' => Char {
+    data: [
+        "x.x.",
+        ".x..",
+        "....",
+        "...x",
+        ".xxx",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'Đ' => Char {
+    data: [
+        "xxxx.",
+        ".x..x",
+        ".x..x",
+        "xxx.x",
+        ".x..x",
+        ".x..x",
+        "xxxx.",
+    ],
+},
+
+'đ' => Char {
+    data: [
+        "...x.",
+        "..xxx",
+        ".xxx.",
+        "x..x.",
+        "x..x.",
+        "x..x.",
+        ".xxx.",
+    ],
+},
+
+'Ē' => Char {
+    data: [
+        ".xxx.",
+        ".....",
+        "xxxxx",
+        "x....",
+        "xxxx.",
+        "x....",
+        "xxxxx",
+    ],
+},
+
+'ē' => Char {
+    data: [
+        "xxx.",
+        "....",
+        ".xx.",
+        "x..x",
+        "xxxx",
+        "x...",
+        ".xx.",
+    ],
+},
+
+'Ĕ' => Char {
+    data: [
+        "x..x.",
+        ".xx..",
+        "xxxxx",
+        "x....",
+        "xxxx.",
+        "x....",
+        "xxxxx",
+    ],
+},
+
+'ĕ' => Char {
+    data: [
+        "x..x",
+        ".xx.",
+        ".xx.",
+        "x..x",
+        "xxxx",
+        "x...",
+        ".xx.",
+    ],
+},
+
+'Ė' => Char {
+    data: [
+        "..x..",
+        ".....",
+        "xxxxx",
+        "x....",
+        "xxxx.",
+        "x....",
+        "xxxxx",
+    ],
+},
+
+'ė' => Char {
+    data: [
+        ".x..",
+        "....",
+        ".xx.",
+        "x..x",
+        "xxxx",
+        "x...",
+        ".xx.",
+    ],
+},
+
+'Ę' => Char {
+    data: [
+        "xxxxx",
+        "x....",
+        "xxxx.",
+        "x....",
+        "xxxxx",
+        "...x.",
+        "....x",
+    ],
+},
+
+'ę' => Char {
+    data: [
+        ".xx.",
+        "x..x",
+        "xxxx",
+        "x...",
+        ".xx.",
+        "..x.",
+        "...x",
+    ],
+},
+
+'Ě' => Char {
+    data: [
+        ".x.x.",
+        "..x..",
+        "xxxxx",
+        "x....",
+        "xxxx.",
+        "x....",
+        "xxxxx",
+    ],
+},
+
+'ě' => Char {
+    data: [
+        "x.x.",
+        ".x..",
+        ".xx.",
+        "x..x",
+        "xxxx",
+        "x...",
+        ".xx.",
+    ],
+},
+
+'Ĝ' => Char {
+    data: [
+        ".x.x.",
+        ".xxx.",
+        "x...x",
+        "x....",
+        "x.xxx",
+        "x...x",
+        ".xxxx",
+    ],
+},
+
+'ĝ' => Char {
+    data: [
+        ".x..",
+        "x.x.",
+        ".xx.",
+        "x..x",
+        ".xxx",
+        "...x",
+        ".xx.",
+    ],
+},
+
+'Ğ' => Char {
+    data: [
+        "x..x.",
+        ".xxx.",
+        "x...x",
+        "x....",
+        "x.xxx",
+        "x...x",
+        ".xxxx",
+    ],
+},
+
+'ğ' => Char {
+    data: [
+        "x..x",
+        ".xx.",
+        ".xx.",
+        "x..x",
+        ".xxx",
+        "...x",
+        ".xx.",
+    ],
+},
+
+'Ġ' => Char {
+    data: [
+        "..x..",
+        ".xxx.",
+        "x...x",
+        "x....",
+        "x.xxx",
+        "x...x",
+        ".xxxx",
+    ],
+},
+
+'ġ' => Char {
+    data: [
+        ".x..",
+        "....",
+        ".xx.",
+        "x..x",
+        ".xxx",
+        "...x",
+        ".xx.",
+    ],
+},
+
+'Ģ' => Char {
+    data: [
+        ".xxx.",
+        "x...x",
+        "x....",
+        "x.xxx",
+        "x...x",
+        ".xxxx",
+        "..x..",
+    ],
+},
+
+'ģ' => Char {
+    data: [
+        ".xx.",
+        "x..x",
+        ".xxx",
+        "...x",
+        ".xx.",
+        "..x.",
+        ".x..",
+    ],
+},
+
+'Ĥ' => Char {
+    data: [
+        "..x..",
+        ".x.x.",
+        ".....",
+        "x...x",
+        "xxxxx",
+        "x...x",
+        "x...x",
+    ],
+},
+
+'ĥ' => Char {
+    data: [
+        ".x..",
+        "x.x.",
+        "....",
+        "x...",
+        "xxx.",
+        "x..x",
+        "x..x",
+    ],
+},
+
+'Ħ' => Char {
+    data: [
+        "x...x",
+        "xxxxx",
+        "x...x",
+        "xxxxx",
+        "x...x",
+        "x...x",
+        "x...x",
+    ],
+},
+
+'ħ' => Char {
+    data: [
+        ".x...",
+        "xxx..",
+        ".x...",
+        ".xxx.",
+        ".x..x",
+        ".x..x",
+        ".x..x",
+    ],
+},
+
+'Ĩ' => Char {
+    data: [
+        ".x.x.",
+        "x.x..",
+        ".....",
+        ".xxx.",
+        "..x..",
+        "..x..",
+        "xxxxx",
+    ],
+},
+
+'ĩ' => Char {
+    data: [
+        ".x.x",
+        "x.x.",
+        "....",
+        "xx..",
+        ".x..",
+        ".x..",
+        "..x.",
+    ],
+},
+
+'Ī' => Char {
+    data: [
+        ".xxx.",
+        ".....",
+        ".xxx.",
+        "..x..",
+        "..x..",
+        "..x..",
+        "xxxxx",
+    ],
+},
+
+'ī' => Char {
+    data: [
+        "xxx.",
+        "....",
+        "xx..",
+        ".x..",
+        ".x..",
+        ".x..",
+        "..x.",
+    ],
+},
+
+'Ĭ' => Char {
+    data: [
+        "x..x.",
+        ".xx..",
+        ".....",
+        ".xxx.",
+        "..x..",
+        "..x..",
+        "xxxxx",
+    ],
+},
+
+'ĭ' => Char {
+    data: [
+        "x..x",
+        ".xx.",
+        "....",
+        "xx..",
+        ".x..",
+        ".x..",
+        "..x.",
+    ],
+},
+
+'Į' => Char {
+    data: [
+        ".xxx.",
+        "..x..",
+        "..x..",
+        "..x..",
+        "xxxxx",
+        "...x.",
+        "....x",
+    ],
+},
+
+'į' => Char {
+    data: [
+        ".x..",
+        "....",
+        "xx..",
+        ".x..",
+        "..x.",
+        "..x.",
+        "...x",
+    ],
+},
+
+'İ' => Char {
+    data: [
+        "..x..",
+        ".....",
+        ".xxx.",
+        "..x..",
+        "..x..",
+        "..x..",
+        "xxxxx",
+    ],
+},
+
+'ı' => Char {
+    data: [
+        "....",
+        "....",
+        "xx..",
+        ".x..",
+        ".x..",
+        ".x..",
+        "..x.",
+    ],
+},
+
+'Ĳ' => Char {
+    data: [
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x.x.x",
+        "x..x.",
+    ],
+},
+
+'ĳ' => Char {
+    data: [
+        "x..x",
+        "....",
+        "x.xx",
+        "x..x",
+        "x..x",
+        "x..x",
+        "x.x.",
+    ],
+},
+
+'Ĵ' => Char {
+    data: [
+        "..x..",
+        ".x.x.",
+        ".....",
+        ".xxxx",
+        "....x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ĵ' => Char {
+    data: [
+        ".x..",
+        "x.x.",
+        "....",
+        "..xx",
+        "...x",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'Ķ' => Char {
+    data: [
+        "x...x",
+        "x.x..",
+        "xx...",
+        "x.x..",
+        "x..x.",
+        "x...x",
+        "..x..",
+    ],
+},
+
+'ķ' => Char {
+    data: [
+        "x...",
+        "x..x",
+        "x.x.",
+        "xx..",
+        "x.x.",
+        "x..x",
+        ".x..",
+    ],
+},
+
+'ĸ' => Char {
+    data: [
+        "....",
+        "....",
+        "x..x",
+        "x.x.",
+        "xx..",
+        "x.x.",
+        "x..x",
+    ],
+},
+
+'Ĺ' => Char {
+    data: [
+        "..x..",
+        ".x...",
+        ".....",
+        "x....",
+        "x....",
+        "x....",
+        "xxxxx",
+    ],
+},
+
+'ĺ' => Char {
+    data: [
+        ".x.",
+        "x..",
+        "...",
+        "xx.",
+        ".x.",
+        ".x.",
+        "..x",
+    ],
+},
+
+'Ļ' => Char {
+    data: [
+        "x....",
+        "x....",
+        "x....",
+        "x....",
+        "xxxxx",
+        "..x..",
+        ".x...",
+    ],
+},
+
+'ļ' => Char {
+    data: [
+        "xx.",
+        ".x.",
+        ".x.",
+        ".x.",
+        "..x",
+        ".x.",
+        "x..",
+    ],
+},
+
+'Ľ' => Char {
+    data: [
+        ".x.x.",
+        "..x..",
+        ".....",
+        "x....",
+        "x....",
+        "x....",
+        "xxxxx",
+    ],
+},
+
+'ľ' => Char {
+    data: [
+        "x.x",
+        ".x.",
+        "...",
+        "xx.",
+        ".x.",
+        ".x.",
+        "..x",
+    ],
+},
+
+'Ŀ' => Char {
+    data: [
+        "x....",
+        "x....",
+        "x....",
+        "x..x.",
+        "x....",
+        "x....",
+        "xxxxx",
+    ],
+},
+
+'ŀ' => Char {
+    data: [
+        "xx...",
+        ".x...",
+        ".x...",
+        ".x.x.",
+        ".x...",
+        ".x...",
+        "..x..",
+    ],
+},
+
+'Ł' => Char {
+    data: [
+        ".x...",
+        ".x...",
+        ".x.x.",
+        ".xx..",
+        "xx...",
+        ".x...",
+        ".xxxx",
+    ],
+},
+
+'ł' => Char {
+    data: [
+        ".x..",
+        ".x..",
+        ".xx.",
+        "xx..",
+        ".x..",
+        ".x..",
+        "..x.",
+    ],
+},
+
+'Ń' => Char {
+    data: [
+        "..x..",
+        ".x...",
+        "x...x",
+        "xx..x",
+        "x.x.x",
+        "x..xx",
+        "x...x",
+    ],
+},
+
+'ń' => Char {
+    data: [
+        "..x.",
+        ".x..",
+        "....",
+        ".xx.",
+        "x..x",
+        "x..x",
+        "x..x",
+    ],
+},
+
+'Ņ' => Char {
+    data: [
+        "x...x",
+        "xx..x",
+        "x.x.x",
+        "x..xx",
+        "x...x",
+        "..x..",
+        ".x...",
+    ],
+},
+
+'ņ' => Char {
+    data: [
+        ".xx.",
+        "x..x",
+        "x..x",
+        "x..x",
+        "x..x",
+        "..x.",
+        ".x..",
+    ],
+},
+
+'Ň' => Char {
+    data: [
+        ".x.x.",
+        "..x..",
+        "x...x",
+        "xx..x",
+        "x.x.x",
+        "x..xx",
+        "x...x",
+    ],
+},
+
+'ň' => Char {
+    data: [
+        "x.x.",
+        ".x..",
+        "....",
+        ".xx.",
+        "x..x",
+        "x..x",
+        "x..x",
+    ],
+},
+
+'ŉ' => Char {
+    data: [
+        "x....",
+        "x....",
+        "..xx.",
+        ".x..x",
+        ".x..x",
+        ".x..x",
+        ".x..x",
+    ],
+},
+
+'Ŋ' => Char {
+    data: [
+        "x...x",
+        "xx..x",
+        "x.x.x",
+        "x..xx",
+        "x...x",
+        "x...x",
+        "x.xx.",
+    ],
+},
+
+'ŋ' => Char {
+    data: [
+        "....",
+        ".xx.",
+        "x..x",
+        "x..x",
+        "x..x",
+        "x..x",
+        "x.x.",
+    ],
+},
+
+'Ō' => Char {
+    data: [
+        ".xxx.",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ō' => Char {
+    data: [
+        "xxx.",
+        "....",
+        ".xx.",
+        "x..x",
+        "x..x",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'Ŏ' => Char {
+    data: [
+        "x..x.",
+        ".xx..",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ŏ' => Char {
+    data: [
+        "x..x",
+        ".xx.",
+        "....",
+        ".xx.",
+        "x..x",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'Ő' => Char {
+    data: [
+        ".x.x.",
+        "x.x..",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ő' => Char {
+    data: [
+        ".x.x",
+        "x.x.",
+        "....",
+        ".xx.",
+        "x..x",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'Œ' => Char {
+    data: [
+        ".xxxxxx",
+        "x..x...",
+        "x..x...",
+        "x..xxx.",
+        "x..x...",
+        "x..x...",
+        ".xxxxxx",
+    ],
+},
+
+'œ' => Char {
+    data: [
+        ".......",
+        ".xx.xx.",
+        "x..x..x",
+        "x..xxxx",
+        "x..x...",
+        "x..x..x",
+        ".xx.xx.",
+    ],
+},
+
+'Ŕ' => Char {
+    data: [
+        "..x..",
+        "xxxx.",
+        "x...x",
+        "xxxx.",
+        "x.x..",
+        "x..x.",
+        "x...x",
+    ],
+},
+
+'ŕ' => Char {
+    data: [
+        ".x.",
+        "x..",
+        "...",
+        ".xx",
+        "x..",
+        "x..",
+        "x..",
+    ],
+},
+
+'Ŗ' => Char {
+    data: [
+        "xxxx.",
+        "x...x",
+        "xxxx.",
+        "x.x..",
+        "x..x.",
+        "x...x",
+        "..x..",
+    ],
+},
+
+'ŗ' => Char {
+    data: [
+        ".xx",
+        "x..",
+        "x..",
+        "x..",
+        "x..",
+        ".x.",
+        "x..",
+    ],
+},
+
+'Ř' => Char {
+    data: [
+        ".x.x.",
+        "xxxx.",
+        "x...x",
+        "xxxx.",
+        "x.x..",
+        "x..x.",
+        "x...x",
+    ],
+},
+
+'ř' => Char {
+    data: [
+        "x.x",
+        ".x.",
+        "...",
+        ".xx",
+        "x..",
+        "x..",
+        "x..",
+    ],
+},
+
+'Ś' => Char {
+    data: [
+        "..x..",
+        ".xxx.",
+        "x....",
+        ".xxx.",
+        "....x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ś' => Char {
+    data: [
+        "..x.",
+        ".xx.",
+        "x..x",
+        ".x..",
+        "..x.",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'Ŝ' => Char {
+    data: [
+        ".x.x.",
+        ".xxx.",
+        "x....",
+        ".xxx.",
+        "....x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ŝ' => Char {
+    data: [
+        "x.x.",
+        ".xx.",
+        "x..x",
+        ".x..",
+        "..x.",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'Ş' => Char {
+    data: [
+        ".xxx.",
+        "x....",
+        ".xxx.",
+        "....x",
+        "x...x",
+        ".xxx.",
+        "..x..",
+    ],
+},
+
+'ş' => Char {
+    data: [
+        ".xx.",
+        "x..x",
+        ".x..",
+        "..x.",
+        "x..x",
+        ".xx.",
+        ".x..",
+    ],
+},
+
+'Š' => Char {
+    data: [
+        ".x.x.",
+        ".xxx.",
+        "x....",
+        ".xxx.",
+        "....x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'š' => Char {
+    data: [
+        "x.x.",
+        ".xx.",
+        "x..x",
+        ".x..",
+        "..x.",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'Ţ' => Char {
+    data: [
+        "xxxxx",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+        ".x...",
+    ],
+},
+
+'ţ' => Char {
+    data: [
+        "x..",
+        "xxx",
+        "x..",
+        "x..",
+        ".xx",
+        ".x.",
+        "x..",
+    ],
+},
+
+'Ť' => Char {
+    data: [
+        ".x.x.",
+        "..x..",
+        ".....",
+        "xxxxx",
+        "..x..",
+        "..x..",
+        "..x..",
+    ],
+},
+
+'ť' => Char {
+    data: [
+        "x.x",
+        ".x.",
+        "...",
+        "x..",
+        "xxx",
+        "x..",
+        ".xx",
+    ],
+},
+
+'Ŧ' => Char {
+    data: [
+        "xxxxx",
+        "..x..",
+        "..x..",
+        ".xxx.",
+        "..x..",
+        "..x..",
+        "..x..",
+    ],
+},
+
+'ŧ' => Char {
+    data: [
+        "...",
+        "x..",
+        "xxx",
+        "x..",
+        "xxx",
+        "x..",
+        ".xx",
+    ],
+},
+
+'Ũ' => Char {
+    data: [
+        ".x.x.",
+        "x.x..",
+        ".....",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ũ' => Char {
+    data: [
+        ".x.x",
+        "x.x.",
+        "....",
+        "x..x",
+        "x..x",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'Ū' => Char {
+    data: [
+        ".xxx.",
+        ".....",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ū' => Char {
+    data: [
+        "xxx.",
+        "....",
+        "x..x",
+        "x..x",
+        "x..x",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'Ŭ' => Char {
+    data: [
+        "x..x.",
+        ".xx..",
+        ".....",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ŭ' => Char {
+    data: [
+        "x..x",
+        ".xx.",
+        "....",
+        "x..x",
+        "x..x",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'Ů' => Char {
+    data: [
+        ".xx..",
+        ".xx..",
+        ".....",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ů' => Char {
+    data: [
+        ".xx.",
+        ".xx.",
+        "....",
+        "x..x",
+        "x..x",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'Ű' => Char {
+    data: [
+        ".x.x.",
+        "x.x..",
+        ".....",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ű' => Char {
+    data: [
+        ".x.x",
+        "x.x.",
+        "....",
+        "x..x",
+        "x..x",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'Ų' => Char {
+    data: [
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".xxx.",
+        "...x.",
+        "....x",
+    ],
+},
+
+'ų' => Char {
+    data: [
+        "x..x",
+        "x..x",
+        "x..x",
+        "x..x",
+        ".xx.",
+        "..x.",
+        "...x",
+    ],
+},
+
+'Ŵ' => Char {
+    data: [
+        "..x..",
+        ".x.x.",
+        ".....",
+        "x...x",
+        "x.x.x",
+        "x.x.x",
+        ".x.x.",
+    ],
+},
+
+'ŵ' => Char {
+    data: [
+        "..x..",
+        ".x.x.",
+        ".....",
+        "x...x",
+        "x.x.x",
+        "x.x.x",
+        ".x.x.",
+    ],
+},
+
+'Ŷ' => Char {
+    data: [
+        "..x..",
+        ".x.x.",
+        ".....",
+        "x...x",
+        ".x.x.",
+        "..x..",
+        "..x..",
+    ],
+},
+
+'ŷ' => Char {
+    data: [
+        ".x..",
+        "x.x.",
+        "....",
+        "x..x",
+        ".xxx",
+        "...x",
+        ".xx.",
+    ],
+},
+
+'Ÿ' => Char {
+    data: [
+        ".x.x.",
+        ".....",
+        "x...x",
+        "x...x",
+        ".x.x.",
+        "..x..",
+        "..x..",
+    ],
+},
+
+'Ź' => Char {
+    data: [
+        "..x..",
+        "xxxxx",
+        "...x.",
+        "..x..",
+        ".x...",
+        "x....",
+        "xxxxx",
+    ],
+},
+
+'ź' => Char {
+    data: [
+        "..x.",
+        "xxxx",
+        "...x",
+        "..x.",
+        ".x..",
+        "x...",
+        "xxxx",
+    ],
+},
+
+'Ż' => Char {
+    data: [
+        "..x..",
+        "xxxxx",
+        "...x.",
+        "..x..",
+        ".x...",
+        "x....",
+        "xxxxx",
+    ],
+},
+
+'ż' => Char {
+    data: [
+        ".x..",
+        "xxxx",
+        "...x",
+        "..x.",
+        ".x..",
+        "x...",
+        "xxxx",
+    ],
+},
+
+'Ž' => Char {
+    data: [
+        ".x.x.",
+        "xxxxx",
+        "...x.",
+        "..x..",
+        ".x...",
+        "x....",
+        "xxxxx",
+    ],
+},
+
+'ž' => Char {
+    data: [
+        "x.x.",
+        "xxxx",
+        "...x",
+        "..x.",
+        ".x..",
+        "x...",
+        "xxxx",
+    ],
+},
+
+'ſ' => Char {
+    data: [
+        ".xx",
+        "x..",
+        "x..",
+        "x..",
+        "x..",
+        "x..",
+        "x..",
+    ],
+},
+
+'Ǎ' => Char {
+    data: [
+        ".x.x.",
+        "..x..",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "xxxxx",
+        "x...x",
+    ],
+},
+
+'ǎ' => Char {
+    data: [
+        "x.x.",
+        ".x..",
+        ".xx.",
+        "...x",
+        ".xxx",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'Ǐ' => Char {
+    data: [
+        ".x.x.",
+        "..x..",
+        ".....",
+        ".xxx.",
+        "..x..",
+        "..x..",
+        "xxxxx",
+    ],
+},
+
+'ǐ' => Char {
+    data: [
+        "x.x.",
+        ".x..",
+        "....",
+        "xx..",
+        ".x..",
+        ".x..",
+        "..x.",
+    ],
+},
+
+'Ǒ' => Char {
+    data: [
+        ".x.x.",
+        "..x..",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ǒ' => Char {
+    data: [
+        "x.x.",
+        ".x..",
+        "....",
+        ".xx.",
+        "x..x",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'Ǔ' => Char {
+    data: [
+        ".x.x.",
+        "..x..",
+        ".....",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ǔ' => Char {
+    data: [
+        "x.x.",
+        ".x..",
+        "....",
+        "x..x",
+        "x..x",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'Ǣ' => Char {
+    data: [
+        "..xxx..",
+        ".......",
+        ".xxxxxx",
+        "x..x...",
+        "xxxxxx.",
+        "x..x...",
+        "x..xxxx",
+    ],
+},
+
+'ǣ' => Char {
+    data: [
+        "..xxx..",
+        ".xx.xx.",
+        "...x..x",
+        ".xxxxxx",
+        "x..x...",
+        "x..x..x",
+        ".xx.xx.",
+    ],
+},
+
+'Ǧ' => Char {
+    data: [
+        ".x.x.",
+        ".xxx.",
+        "x...x",
+        "x....",
+        "x.xxx",
+        "x...x",
+        ".xxxx",
+    ],
+},
+
+'ǧ' => Char {
+    data: [
+        "x.x.",
+        ".x..",
+        ".xx.",
+        "x..x",
+        ".xxx",
+        "...x",
+        ".xx.",
+    ],
+},
+
+'Ǩ' => Char {
+    data: [
+        ".x.x.",
+        "x...x",
+        "x.x..",
+        "xx...",
+        "x.x..",
+        "x..x.",
+        "x...x",
+    ],
+},
+
+'ǩ' => Char {
+    data: [
+        "x.x.",
+        "x...",
+        "x..x",
+        "x.x.",
+        "xx..",
+        "x.x.",
+        "x..x",
+    ],
+},
+
+'Ǫ' => Char {
+    data: [
+        ".xxx.",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".xxx.",
+        "...x.",
+        "....x",
+    ],
+},
+
+'ǫ' => Char {
+    data: [
+        ".xx.",
+        "x..x",
+        "x..x",
+        "x..x",
+        ".xx.",
+        "..x.",
+        "...x",
+    ],
+},
+
+'ǰ' => Char {
+    data: [
+        "x.x.",
+        ".x..",
+        "....",
+        "..xx",
+        "...x",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'Ǵ' => Char {
+    data: [
+        "..x..",
+        ".xxx.",
+        "x...x",
+        "x....",
+        "x.xxx",
+        "x...x",
+        ".xxxx",
+    ],
+},
+
+'ǵ' => Char {
+    data: [
+        "..x.",
+        ".x..",
+        ".xx.",
+        "x..x",
+        ".xxx",
+        "...x",
+        ".xx.",
+    ],
+},
+
+'Ǹ' => Char {
+    data: [
+        ".x...",
+        "..x..",
+        "x...x",
+        "xx..x",
+        "x.x.x",
+        "x..xx",
+        "x...x",
+    ],
+},
+
+'ǹ' => Char {
+    data: [
+        ".x..",
+        "..x.",
+        "....",
+        ".xx.",
+        "x..x",
+        "x..x",
+        "x..x",
+    ],
+},
+
+'Ǽ' => Char {
+    data: [
+        "...x...",
+        "..x....",
+        ".xxxxxx",
+        "x..x...",
+        "xxxxxx.",
+        "x..x...",
+        "x..xxxx",
+    ],
+},
+
+'ǽ' => Char {
+    data: [
+        "...x...",
+        ".xx.xx.",
+        "...x..x",
+        ".xxxxxx",
+        "x..x...",
+        "x..x..x",
+        ".xx.xx.",
+    ],
+},
+
+'Ǿ' => Char {
+    data: [
+        "..x..",
+        ".xxx.",
+        "x..xx",
+        "x.x.x",
+        "xx..x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ǿ' => Char {
+    data: [
+        "..x..",
+        "....x",
+        ".xxx.",
+        "x.x.x",
+        "xx..x",
+        ".xxx.",
+        "x....",
+    ],
+},
+
+'Ș' => Char {
+    data: [
+        ".xxx.",
+        "x....",
+        ".xxx.",
+        "....x",
+        "x...x",
+        ".xxx.",
+        "..x..",
+    ],
+},
+
+'ș' => Char {
+    data: [
+        ".xx.",
+        "x..x",
+        ".x..",
+        "..x.",
+        "x..x",
+        ".xx.",
+        ".x..",
+    ],
+},
+
+'Ț' => Char {
+    data: [
+        "xxxxx",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+    ],
+},
+
+'ț' => Char {
+    data: [
+        "x..",
+        "xxx",
+        "x..",
+        "x..",
+        "x..",
+        ".xx",
+        ".x.",
+    ],
+},
+
+'Ȟ' => Char {
+    data: [
+        ".x.x.",
+        "..x..",
+        ".....",
+        "x...x",
+        "xxxxx",
+        "x...x",
+        "x...x",
+    ],
+},
+
+'ȟ' => Char {
+    data: [
+        "x.x.",
+        ".x..",
+        "....",
+        "x...",
+        "xxx.",
+        "x..x",
+        "x..x",
+    ],
+},
+
+'Ȧ' => Char {
+    data: [
+        "..x..",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "xxxxx",
+        "x...x",
+        "x...x",
+    ],
+},
+
+'ȧ' => Char {
+    data: [
+        ".x..",
+        "....",
+        ".xx.",
+        "...x",
+        ".xxx",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'Ȩ' => Char {
+    data: [
+        "xxxxx",
+        "x....",
+        "xxxx.",
+        "x....",
+        "xxxxx",
+        "..x..",
+        ".x...",
+    ],
+},
+
+'ȩ' => Char {
+    data: [
+        ".xx.",
+        "x..x",
+        "xxxx",
+        "x...",
+        ".xx.",
+        "..x.",
+        ".x..",
+    ],
+},
+
+'Ȯ' => Char {
+    data: [
+        "..x..",
+        ".....",
+        ".xxx.",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ȯ' => Char {
+    data: [
+        ".x..",
+        "....",
+        ".xx.",
+        "x..x",
+        "x..x",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'Ȳ' => Char {
+    data: [
+        ".xxx.",
+        ".....",
+        "x...x",
+        "x...x",
+        ".x.x.",
+        "..x..",
+        "..x..",
+    ],
+},
+
+'ȳ' => Char {
+    data: [
+        "xxx.",
+        "....",
+        "x..x",
+        "x..x",
+        ".xxx",
+        "...x",
+        ".xx.",
+    ],
+},
+
+'ȷ' => Char {
+    data: [
+        "....",
+        "....",
+        "..xx",
+        "...x",
+        "...x",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'Ḃ' => Char {
+    data: [
+        "..x..",
+        ".....",
+        "xxxx.",
+        "x...x",
+        "xxxx.",
+        "x...x",
+        "xxxx.",
+    ],
+},
+
+'ḃ' => Char {
+    data: [
+        ".x..",
+        "....",
+        "x...",
+        "xxx.",
+        "x..x",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'Ḅ' => Char {
+    data: [
+        "xxxx.",
+        "x...x",
+        "xxxx.",
+        "x...x",
+        "x...x",
+        "xxxx.",
+        "..x..",
+    ],
+},
+
+'ḅ' => Char {
+    data: [
+        "x...",
+        "x...",
+        "xxx.",
+        "x..x",
+        "x..x",
+        ".xx.",
+        ".x..",
+    ],
+},
+
+'Ḇ' => Char {
+    data: [
+        "xxxx.",
+        "x...x",
+        "xxxx.",
+        "x...x",
+        "x...x",
+        "xxxx.",
+        ".xxx.",
+    ],
+},
+
+'ḇ' => Char {
+    data: [
+        "x...",
+        "x...",
+        "xxx.",
+        "x..x",
+        "x..x",
+        ".xx.",
+        "xxx.",
+    ],
+},
+
+'Ḋ' => Char {
+    data: [
+        "..x..",
+        ".....",
+        "xxxx.",
+        "x...x",
+        "x...x",
+        "x...x",
+        "xxxx.",
+    ],
+},
+
+'ḋ' => Char {
+    data: [
+        ".x..",
+        "....",
+        "...x",
+        ".xxx",
+        "x..x",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'Ḍ' => Char {
+    data: [
+        "xxxx.",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        "xxxx.",
+        "..x..",
+    ],
+},
+
+'ḍ' => Char {
+    data: [
+        "...x",
+        "...x",
+        ".xxx",
+        "x..x",
+        "x..x",
+        ".xx.",
+        ".x..",
+    ],
+},
+
+'Ḏ' => Char {
+    data: [
+        "xxxx.",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        "xxxx.",
+        ".xxx.",
+    ],
+},
+
+'ḏ' => Char {
+    data: [
+        "...x",
+        "...x",
+        ".xxx",
+        "x..x",
+        "x..x",
+        ".xx.",
+        "xxx.",
+    ],
+},
+
+'Ḑ' => Char {
+    data: [
+        "xxxx.",
+        "x...x",
+        "x...x",
+        "x...x",
+        "xxxx.",
+        "..x..",
+        ".x...",
+    ],
+},
+
+'ḑ' => Char {
+    data: [
+        "...x",
+        ".xxx",
+        "x..x",
+        "x..x",
+        ".xx.",
+        "..x.",
+        ".x..",
+    ],
+},
+
+'Ḟ' => Char {
+    data: [
+        "..x..",
+        ".....",
+        "xxxxx",
+        "x....",
+        "xxxx.",
+        "x....",
+        "x....",
+    ],
+},
+
+'ḟ' => Char {
+    data: [
+        ".x.",
+        "...",
+        ".xx",
+        "x..",
+        "xx.",
+        "x..",
+        "x..",
+    ],
+},
+
+'Ḡ' => Char {
+    data: [
+        ".xxx.",
+        ".xxx.",
+        "x...x",
+        "x....",
+        "x.xxx",
+        "x...x",
+        ".xxxx",
+    ],
+},
+
+'ḡ' => Char {
+    data: [
+        "xxx.",
+        "....",
+        ".xx.",
+        "x..x",
+        ".xxx",
+        "...x",
+        ".xx.",
+    ],
+},
+
+'Ḣ' => Char {
+    data: [
+        "..x..",
+        ".....",
+        "x...x",
+        "xxxxx",
+        "x...x",
+        "x...x",
+        "x...x",
+    ],
+},
+
+'ḣ' => Char {
+    data: [
+        ".x..",
+        "....",
+        "x...",
+        "x...",
+        "xxx.",
+        "x..x",
+        "x..x",
+    ],
+},
+
+'Ḥ' => Char {
+    data: [
+        "x...x",
+        "x...x",
+        "xxxxx",
+        "x...x",
+        "x...x",
+        "x...x",
+        "..x..",
+    ],
+},
+
+'ḥ' => Char {
+    data: [
+        "x...",
+        "x...",
+        "xxx.",
+        "x..x",
+        "x..x",
+        "x..x",
+        ".x..",
+    ],
+},
+
+'Ḧ' => Char {
+    data: [
+        ".x.x.",
+        ".....",
+        "x...x",
+        "xxxxx",
+        "x...x",
+        "x...x",
+        "x...x",
+    ],
+},
+
+'ḧ' => Char {
+    data: [
+        "x..x",
+        "....",
+        "x...",
+        "x...",
+        "xxx.",
+        "x..x",
+        "x..x",
+    ],
+},
+
+'Ḩ' => Char {
+    data: [
+        "x...x",
+        "xxxxx",
+        "x...x",
+        "x...x",
+        "x...x",
+        "..x..",
+        ".x...",
+    ],
+},
+
+'ḩ' => Char {
+    data: [
+        "x...",
+        "x...",
+        "xxx.",
+        "x..x",
+        "x..x",
+        "..x.",
+        ".x..",
+    ],
+},
+
+'Ḱ' => Char {
+    data: [
+        "..x..",
+        "x...x",
+        "x.x..",
+        "xx...",
+        "x.x..",
+        "x..x.",
+        "x...x",
+    ],
+},
+
+'ḱ' => Char {
+    data: [
+        "..x.",
+        "x...",
+        "x..x",
+        "x.x.",
+        "xx..",
+        "x.x.",
+        "x..x",
+    ],
+},
+
+'Ḳ' => Char {
+    data: [
+        "x...x",
+        "x.x..",
+        "xx...",
+        "x.x..",
+        "x..x.",
+        "x...x",
+        "..x..",
+    ],
+},
+
+'ḳ' => Char {
+    data: [
+        "x...",
+        "x..x",
+        "x.x.",
+        "xx..",
+        "x.x.",
+        "x..x",
+        ".x..",
+    ],
+},
+
+'Ḵ' => Char {
+    data: [
+        "x...x",
+        "x.x..",
+        "xx...",
+        "x.x..",
+        "x..x.",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ḵ' => Char {
+    data: [
+        "x...",
+        "x..x",
+        "x.x.",
+        "xx..",
+        "x.x.",
+        "x..x",
+        "xxx.",
+    ],
+},
+
+'Ḷ' => Char {
+    data: [
+        "x....",
+        "x....",
+        "x....",
+        "x....",
+        "x....",
+        "xxxxx",
+        "..x..",
+    ],
+},
+
+'ḷ' => Char {
+    data: [
+        "xx.",
+        ".x.",
+        ".x.",
+        ".x.",
+        ".x.",
+        "..x",
+        ".x.",
+    ],
+},
+
+'Ḻ' => Char {
+    data: [
+        "x....",
+        "x....",
+        "x....",
+        "x....",
+        "x....",
+        "xxxxx",
+        ".xxx.",
+    ],
+},
+
+'ḻ' => Char {
+    data: [
+        "xx.",
+        ".x.",
+        ".x.",
+        ".x.",
+        ".x.",
+        "..x",
+        "xxx",
+    ],
+},
+
+'Ḿ' => Char {
+    data: [
+        "..x..",
+        ".x...",
+        ".....",
+        "xx.xx",
+        "x.x.x",
+        "x...x",
+        "x...x",
+    ],
+},
+
+'ḿ' => Char {
+    data: [
+        "..x..",
+        ".x...",
+        ".....",
+        ".x.x.",
+        "x.x.x",
+        "x.x.x",
+        "x.x.x",
+    ],
+},
+
+'Ṁ' => Char {
+    data: [
+        "..x..",
+        ".....",
+        "xx.xx",
+        "x.x.x",
+        "x.x.x",
+        "x...x",
+        "x...x",
+    ],
+},
+
+'ṁ' => Char {
+    data: [
+        "..x..",
+        ".....",
+        ".x.x.",
+        "x.x.x",
+        "x.x.x",
+        "x.x.x",
+        "x.x.x",
+    ],
+},
+
+'Ṃ' => Char {
+    data: [
+        "xx.xx",
+        "x.x.x",
+        "x.x.x",
+        "x...x",
+        "x...x",
+        "x...x",
+        "..x..",
+    ],
+},
+
+'ṃ' => Char {
+    data: [
+        ".x.x.",
+        "x.x.x",
+        "x.x.x",
+        "x.x.x",
+        "x.x.x",
+        "x.x.x",
+        "..x..",
+    ],
+},
+
+'Ṅ' => Char {
+    data: [
+        "..x..",
+        ".....",
+        "x...x",
+        "xx..x",
+        "x.x.x",
+        "x..xx",
+        "x...x",
+    ],
+},
+
+'ṅ' => Char {
+    data: [
+        ".x..",
+        "....",
+        ".xx.",
+        "x..x",
+        "x..x",
+        "x..x",
+        "x..x",
+    ],
+},
+
+'Ṇ' => Char {
+    data: [
+        "x...x",
+        "xx..x",
+        "x.x.x",
+        "x..xx",
+        "x...x",
+        "x...x",
+        "..x..",
+    ],
+},
+
+'ṇ' => Char {
+    data: [
+        ".xx.",
+        "x..x",
+        "x..x",
+        "x..x",
+        "x..x",
+        "x..x",
+        ".x..",
+    ],
+},
+
+'Ṉ' => Char {
+    data: [
+        "x...x",
+        "xx..x",
+        "x.x.x",
+        "x..xx",
+        "x...x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ṉ' => Char {
+    data: [
+        ".xx.",
+        "x..x",
+        "x..x",
+        "x..x",
+        "x..x",
+        "x..x",
+        "xxx.",
+    ],
+},
+
+'Ṕ' => Char {
+    data: [
+        "..x..",
+        ".x...",
+        ".....",
+        "xxxx.",
+        "x...x",
+        "xxxx.",
+        "x....",
+    ],
+},
+
+'ṕ' => Char {
+    data: [
+        "..x.",
+        ".x..",
+        "....",
+        ".xx.",
+        "x..x",
+        "xxx.",
+        "x...",
+    ],
+},
+
+'Ṗ' => Char {
+    data: [
+        "..x..",
+        ".....",
+        "xxxx.",
+        "x...x",
+        "xxxx.",
+        "x....",
+        "x....",
+    ],
+},
+
+'ṗ' => Char {
+    data: [
+        ".x..",
+        "....",
+        ".xx.",
+        "x..x",
+        "xxx.",
+        "x...",
+        "x...",
+    ],
+},
+
+'Ṙ' => Char {
+    data: [
+        "..x..",
+        "xxxx.",
+        "x...x",
+        "xxxx.",
+        "x.x..",
+        "x..x.",
+        "x...x",
+    ],
+},
+
+'ṙ' => Char {
+    data: [
+        ".x.",
+        "...",
+        ".xx",
+        "x..",
+        "x..",
+        "x..",
+        "x..",
+    ],
+},
+
+'Ṛ' => Char {
+    data: [
+        "xxxx.",
+        "x...x",
+        "xxxx.",
+        "x.x..",
+        "x..x.",
+        "x...x",
+        "..x..",
+    ],
+},
+
+'ṛ' => Char {
+    data: [
+        ".xx",
+        "x..",
+        "x..",
+        "x..",
+        "x..",
+        "x..",
+        ".x.",
+    ],
+},
+
+'Ṟ' => Char {
+    data: [
+        "xxxx.",
+        "x...x",
+        "xxxx.",
+        "x.x..",
+        "x..x.",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ṟ' => Char {
+    data: [
+        ".xx",
+        "x..",
+        "x..",
+        "x..",
+        "x..",
+        "x..",
+        "xxx",
+    ],
+},
+
+'Ṡ' => Char {
+    data: [
+        "..x..",
+        ".xxx.",
+        "x....",
+        ".xxx.",
+        "....x",
+        "x...x",
+        ".xxx.",
+    ],
+},
+
+'ṡ' => Char {
+    data: [
+        ".x..",
+        ".xx.",
+        "x..x",
+        ".x..",
+        "..x.",
+        "x..x",
+        ".xx.",
+    ],
+},
+
+'Ṣ' => Char {
+    data: [
+        ".xxx.",
+        "x....",
+        ".xxx.",
+        "....x",
+        "x...x",
+        ".xxx.",
+        "..x..",
+    ],
+},
+
+'ṣ' => Char {
+    data: [
+        ".xx.",
+        "x..x",
+        ".x..",
+        "..x.",
+        "x..x",
+        ".xx.",
+        ".x..",
+    ],
+},
+
+'Ṫ' => Char {
+    data: [
+        "..x..",
+        ".....",
+        "xxxxx",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+    ],
+},
+
+'ṫ' => Char {
+    data: [
+        ".x.",
+        "...",
+        "x..",
+        "xxx",
+        "x..",
+        "x..",
+        ".xx",
+    ],
+},
+
+'Ṭ' => Char {
+    data: [
+        "xxxxx",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+    ],
+},
+
+'ṭ' => Char {
+    data: [
+        "x..",
+        "xxx",
+        "x..",
+        "x..",
+        "x..",
+        ".xx",
+        ".x.",
+    ],
+},
+
+'Ṯ' => Char {
+    data: [
+        "xxxxx",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+        ".xxx.",
+    ],
+},
+
+'ṯ' => Char {
+    data: [
+        "x..",
+        "xxx",
+        "x..",
+        "x..",
+        "x..",
+        ".xx",
+        "xxx",
+    ],
+},
+
+'Ṽ' => Char {
+    data: [
+        ".x.x.",
+        "x.x..",
+        ".....",
+        "x...x",
+        "x...x",
+        ".x.x.",
+        "..x..",
+    ],
+},
+
+'ṽ' => Char {
+    data: [
+        ".x.x.",
+        "x.x..",
+        ".....",
+        "x...x",
+        "x...x",
+        ".x.x.",
+        "..x..",
+    ],
+},
+
+'Ṿ' => Char {
+    data: [
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".x.x.",
+        "..x..",
+        "..x..",
+    ],
+},
+
+'ṿ' => Char {
+    data: [
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".x.x.",
+        "..x..",
+        "..x..",
+    ],
+},
+
+'Ẁ' => Char {
+    data: [
+        ".x...",
+        "..x..",
+        ".....",
+        "x...x",
+        "x.x.x",
+        "x.x.x",
+        ".x.x.",
+    ],
+},
+
+'ẁ' => Char {
+    data: [
+        ".x...",
+        "..x..",
+        ".....",
+        "x...x",
+        "x.x.x",
+        "x.x.x",
+        ".x.x.",
     ],
 },
 
-b'@' => Char {
+'Ẃ' => Char {
     data: [
-        ".xxx.",
+        "..x..",
+        ".x...",
+        ".....",
         "x...x",
-        "x..xx",
         "x.x.x",
         "x.x.x",
-        "x..x.",
+        ".x.x.",
+    ],
+},
+
+'ẃ' => Char {
+    data: [
+        "..x..",
         ".x...",
+        ".....",
+        "x...x",
+        "x.x.x",
+        "x.x.x",
+        ".x.x.",
     ],
 },
 
-b'#' => Char {
+'Ẅ' => Char {
     data: [
+        ".x.x.",
         ".....",
+        "x...x",
+        "x...x",
+        "x.x.x",
+        "x.x.x",
         ".x.x.",
-        "xxxxx",
+    ],
+},
+
+'ẅ' => Char {
+    data: [
         ".x.x.",
-        "xxxxx",
+        ".....",
+        "x...x",
+        "x.x.x",
+        "x.x.x",
+        "x.x.x",
         ".x.x.",
+    ],
+},
+
+'Ẇ' => Char {
+    data: [
+        "..x..",
         ".....",
+        "x...x",
+        "x...x",
+        "x.x.x",
+        "x.x.x",
+        ".x.x.",
     ],
 },
 
-b'$' => Char {
+'ẇ' => Char {
     data: [
-        ".xxx.",
+        "..x..",
+        ".....",
+        "x...x",
         "x.x.x",
-        "x.x..",
-        ".xxx.",
-        "..x.x",
         "x.x.x",
-        ".xxx.",
+        "x.x.x",
+        ".x.x.",
     ],
 },
 
-b'%' => Char {
+'Ẉ' => Char {
     data: [
-        ".x...x.",
-        "x.x..x.",
-        ".x..x..",
-        "...x...",
-        "..x..x.",
-        ".x..x.x",
-        ".x...x.",
+        "x...x",
+        "x...x",
+        "x.x.x",
+        "x.x.x",
+        "x.x.x",
+        ".x.x.",
+        "..x..",
+    ],
+},
+
+'ẉ' => Char {
+    data: [
+        "x...x",
+        "x.x.x",
+        "x.x.x",
+        "x.x.x",
+        "x.x.x",
+        ".x.x.",
+        "..x..",
     ],
 },
 
-b'^' => Char {
+'Ẋ' => Char {
     data: [
+        "..x..",
+        ".....",
+        "x...x",
+        ".x.x.",
         "..x..",
         ".x.x.",
         "x...x",
+    ],
+},
+
+'ẋ' => Char {
+    data: [
+        "..x..",
         ".....",
+        "x...x",
+        ".x.x.",
+        "..x..",
+        ".x.x.",
+        "x...x",
+    ],
+},
+
+'Ẍ' => Char {
+    data: [
+        ".x.x.",
         ".....",
+        "x...x",
+        ".x.x.",
+        "..x..",
+        ".x.x.",
+        "x...x",
+    ],
+},
+
+'ẍ' => Char {
+    data: [
+        ".x.x.",
         ".....",
+        "x...x",
+        ".x.x.",
+        "..x..",
+        ".x.x.",
+        "x...x",
+    ],
+},
+
+'Ẏ' => Char {
+    data: [
+        "..x..",
         ".....",
+        "x...x",
+        "x...x",
+        ".x.x.",
+        "..x..",
+        "..x..",
     ],
 },
 
-b'&' => Char {
+'ẏ' => Char {
     data: [
-        ".xx..",
-        "x..x.",
-        "x.x..",
-        ".x...",
-        "x.x.x",
-        "x..x.",
-        ".xx.x",
+        ".x..",
+        "....",
+        "x..x",
+        "x..x",
+        ".xxx",
+        "...x",
+        ".xx.",
     ],
 },
 
-b'*' => Char {
+'Ẑ' => Char {
     data: [
+        ".x.x.",
+        "xxxxx",
+        "...x.",
         "..x..",
-        "x.x.x",
-        ".xxx.",
+        ".x...",
+        "x....",
+        "xxxxx",
+    ],
+},
+
+'ẑ' => Char {
+    data: [
+        "x.x.",
+        "xxxx",
+        "...x",
+        "..x.",
+        ".x..",
+        "x...",
+        "xxxx",
+    ],
+},
+
+'Ẓ' => Char {
+    data: [
+        "xxxxx",
+        "...x.",
         "..x..",
-        ".xxx.",
-        "x.x.x",
+        ".x...",
+        "x....",
+        "xxxxx",
         "..x..",
     ],
 },
 
-b'(' => Char {
+'ẓ' => Char {
     data: [
-        ".x",
-        "x.",
-        "x.",
-        "x.",
-        "x.",
-        "x.",
-        ".x",
+        "xxxx",
+        "...x",
+        "..x.",
+        ".x..",
+        "x...",
+        "xxxx",
+        ".x..",
     ],
 },
 
-b')' => Char {
+'Ẕ' => Char {
     data: [
-        "x.",
-        ".x",
-        ".x",
-        ".x",
-        ".x",
-        ".x",
-        "x.",
+        "xxxxx",
+        "...x.",
+        "..x..",
+        ".x...",
+        "x....",
+        "xxxxx",
+        ".xxx.",
     ],
 },
 
-b'[' => Char {
+'ẕ' => Char {
     data: [
-        "xx",
-        "x.",
-        "x.",
-        "x.",
-        "x.",
-        "x.",
-        "xx",
+        "xxxx",
+        "...x",
+        "..x.",
+        ".x..",
+        "x...",
+        "xxxx",
+        "xxx.",
     ],
 },
 
-b']' => Char {
+'ẖ' => Char {
     data: [
-        "xx",
-        ".x",
-        ".x",
-        ".x",
-        ".x",
-        ".x",
-        "xx",
+        "x...",
+        "x...",
+        "xxx.",
+        "x..x",
+        "x..x",
+        "x..x",
+        "xxx.",
     ],
 },
 
-b'{' => Char {
+'ẗ' => Char {
     data: [
+        "x.x",
+        "...",
+        "x..",
+        "xxx",
+        "x..",
+        "x..",
         ".xx",
-        ".x.",
-        ".x.",
-        "xx.",
-        ".x.",
-        ".x.",
-        ".xx",
     ],
 },
 
-b'}' => Char {
+'ẘ' => Char {
+    data: [
+        ".xx..",
+        ".xx..",
+        ".....",
+        "x...x",
+        "x.x.x",
+        "x.x.x",
+        ".x.x.",
+    ],
+},
+
+'ẙ' => Char {
+    data: [
+        ".xx.",
+        ".xx.",
+        "....",
+        "x..x",
+        ".xxx",
+        "...x",
+        ".xx.",
+    ],
+},
+
+'ẛ' => Char {
     data: [
-        "xx.",
-        ".x.",
         ".x.",
+        "...",
         ".xx",
-        ".x",
-        ".x.",
-        "xx.",
+        "x..",
+        "x..",
+        "x..",
+        "x..",
     ],
 },
 
-b'+' => Char {
+'Ạ' => Char {
     data: [
-        ".....",
-        "..x..",
-        "..x..",
+        ".xxx.",
+        "x...x",
         "xxxxx",
+        "x...x",
+        "x...x",
+        "x...x",
         "..x..",
-        "..x..",
-        ".....",
     ],
 },
 
-b'-' => Char {
+'ạ' => Char {
     data: [
-        "...",
-        "...",
-        "...",
-        "xxx",
-        "...",
-        "...",
-        "...",
+        ".xx.",
+        "...x",
+        ".xxx",
+        "x..x",
+        "x..x",
+        ".xx.",
+        ".x..",
     ],
 },
 
-b'_' => Char {
+'Ẹ' => Char {
     data: [
-        "....",
-        "....",
-        "....",
-        "....",
-        "....",
-        "....",
-        "xxxx",
+        "xxxxx",
+        "x....",
+        "xxxx.",
+        "x....",
+        "x....",
+        "xxxxx",
+        "..x..",
     ],
 },
 
-b'=' => Char {
+'ẹ' => Char {
     data: [
-        ".....",
-        ".....",
-        "xxxxx..........................................................................",
-        ".....",
-        "xxxxx",
-        ".....",
-        ".....",
+        ".xx.",
+        "x..x",
+        "x..x",
+        "xxxx",
+        "x...",
+        ".xx.",
+        ".x..",
     ],
 },
 
-b':' => Char {
+'Ẽ' => Char {
     data: [
-        ".",
-        ".",
-        ".",
-        "x",
-        ".",
-        ".",
-        "x",
+        ".x.x.",
+        "x.x..",
+        "xxxxx",
+        "x....",
+        "xxxx.",
+        "x....",
+        "xxxxx",
     ],
 },
 
-b';' => Char {
+'ẽ' => Char {
     data: [
-        "..",
-        "..",
-        "..",
-        ".x",
-        "..",
-        ".x",
-        "xx",
+        ".x.x",
+        "x.x.",
+        ".xx.",
+        "x..x",
+        "xxxx",
+        "x...",
+        ".xx.",
     ],
 },
 
-b',' => Char {
+'Ị' => Char {
     data: [
-        "..",
-        "..",
-        "..",
-        "..",
-        "..",
-        ".x",
-        "xx",
+        ".xxx.",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+        "xxxxx",
+        "..x..",
     ],
 },
 
-b'.' => Char {
+'ị' => Char {
     data: [
-        ".",
-        ".",
-        ".",
-        ".",
-        ".",
-        ".",
-        "x",
+        ".x..",
+        "....",
+        "xx..",
+        ".x..",
+        ".x..",
+        "..x.",
+        ".x..",
     ],
 },
 
-b'\'' => Char {
+'Ọ' => Char {
     data: [
-        "x",
-        "x",
-        ".",
-        ".",
-        ".",
-        ".",
-        ".",
+        ".xxx.",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".xxx.",
+        "..x..",
     ],
 },
 
-b'"' => Char {
+'ọ' => Char {
     data: [
-        "x.x",
-        "x.x",
-        "...",
-        "...",
-        "...",
-        "...",
-        "...",
+        ".xx.",
+        "x..x",
+        "x..x",
+        "x..x",
+        "x..x",
+        ".xx.",
+        ".x..",
     ],
 },
 
-b'/' => Char {
+'Ụ' => Char {
     data: [
-        "....x",
-        "....x",
-        "...x.",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        ".xxx.",
         "..x..",
-        ".x...",
-        "x....",
-        "x....",
     ],
 },
 
-b'\\' => Char {
+'ụ' => Char {
     data: [
-        "x....",
-        "x....",
-        ".x...",
-        "..x..",
-        "...x.",
-        "....x",
-        "....x",
+        "x..x",
+        "x..x",
+        "x..x",
+        "x..x",
+        "x..x",
+        ".xx.",
+        ".x..",
     ],
 },
 
-b'|' => Char {
+'Ỳ' => Char {
     data: [
-        "x",
-        "x",
-        "x",
-        "x",
-        "x",
-        "x",
-        "x",
+        ".x...",
+        "..x..",
+        ".....",
+        "x...x",
+        ".x.x.",
+        "..x..",
+        "..x..",
     ],
 },
 
-b'<' => Char {
+'ỳ' => Char {
     data: [
-        "...x",
-        "..x.",
-        ".x..",
-        "x...",
         ".x..",
         "..x.",
+        "....",
+        "x..x",
+        ".xxx",
         "...x",
+        ".xx.",
     ],
 },
 
-b'>' => Char {
+'Ỵ' => Char {
     data: [
-        "x...",
-        ".x..",
-        "..x.",
+        "x...x",
+        "x...x",
+        ".x.x.",
+        "..x..",
+        "..x..",
+        "..x..",
+        "..x..",
+    ],
+},
+
+'ỵ' => Char {
+    data: [
+        "x..x",
+        "x..x",
+        "x..x",
+        ".xxx",
         "...x",
-        "..x.",
+        ".xx.",
         ".x..",
-        "x...",
     ],
 },
 
-b'?' => Char {
+'Ỹ' => Char {
     data: [
-        ".xxx.",
-        "x...x",
-        "....x",
-        "..xx.",
-        ".x...",
+        ".x.x.",
+        "x.x..",
         ".....",
-        ".x...",
+        "x...x",
+        ".x.x.",
+        "..x..",
+        "..x..",
     ],
 },
 
-b'~' => Char {
+'ỹ' => Char {
     data: [
-        ".......",
-        ".......",
-        ".xx....",
-        "x..x..x",
-        "....xx.",
-        ".......",
-        ".......",
+        ".x.x",
+        "x.x.",
+        "....",
+        "x..x",
+        ".xxx",
+        "...x",
+        ".xx.",
     ],
 },
 
-b'`' => Char {
+'\u{fffd}' => Char {
     data: [
-        "x..",
-        ".x.",
-        "..x",
-        "...",
-        "...",
-        "...",
-        "...",
+        "xxxxx",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        "x...x",
+        "xxxxx",
     ],
 },
 };
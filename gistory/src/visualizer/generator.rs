@@ -0,0 +1,305 @@
+use std::f32::consts::TAU;
+
+use chrono::NaiveDate;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Error, Result};
+use crate::visualizer::calendar::DAYS_PER_WEEK;
+use crate::visualizer::{CommitCount, CommitGrid};
+
+/// Procedural patterns filling the days from the start date to an end date
+#[derive(Debug, PartialEq, Eq, Clone, Copy, clap::ValueEnum, Deserialize, Serialize)]
+pub enum Generator {
+    /// Perlin noise, smooth hills and valleys of commits
+    Noise,
+    /// Conway's Game of Life on a board of 7 days by the weeks, wrapping at the edges.
+    /// A day gets one commit level for each of the last 4 generations it was alive in
+    Life,
+    /// Elementary cellular automaton grown from a single cell, each week being the next generation
+    /// of the previous one
+    Automaton,
+    /// A sine wave running through the weeks
+    Sine,
+    /// Commit counts rising from zero to a lot over and over
+    Gradient,
+}
+
+/// Parameters of the generators, each one using only some of them
+#[derive(Debug, PartialEq, Clone, Copy, Deserialize, Serialize)]
+#[serde(default)]
+pub struct GeneratorOptions {
+    /// Size of the features in weeks: noise scale, wave period and gradient length.
+    /// Default to 8 weeks for noise and sine, and to all the weeks for the gradient
+    pub scale: Option<f32>,
+    /// Number of noise layers, each one twice as detailed as the previous one
+    pub octaves: usize,
+    /// Share of days alive in the first generation of the Game of Life
+    pub density: f32,
+    /// Number of generations of the Game of Life
+    pub steps: usize,
+    /// Wolfram code of the cellular automaton, like 30 or 110
+    pub rule: u8,
+    /// Height of the sine wave in days, from its middle
+    pub amplitude: f32,
+}
+
+impl Default for GeneratorOptions {
+    fn default() -> Self {
+        Self {
+            scale: None,
+            octaves: 3,
+            density: 0.35,
+            steps: 10,
+            rule: 30,
+            amplitude: 3.0,
+        }
+    }
+}
+
+const LEVELS: usize = CommitCount::ALL.len();
+
+impl GeneratorOptions {
+    pub fn validate(&self) -> Result<()> {
+        if self
+            .scale
+            .is_some_and(|scale| scale.is_nan() || scale <= 0.0)
+        {
+            return Err(Error::InvalidArg("scale must be > 0".to_string()));
+        }
+        if self.octaves == 0 {
+            return Err(Error::InvalidArg("octaves must be > 0".to_string()));
+        }
+        if !(0.0..=1.0).contains(&self.density) {
+            return Err(Error::InvalidArg(
+                "density must be between 0 and 1".to_string(),
+            ));
+        }
+        if self.amplitude.is_nan() || self.amplitude < 0.0 {
+            return Err(Error::InvalidArg("amplitude must be >= 0".to_string()));
+        }
+        Ok(())
+    }
+}
+
+/// 2D gradient noise from Ken Perlin's improved noise
+struct Perlin {
+    permutation: [u8; 512],
+}
+
+impl Perlin {
+    fn new(rng: &mut impl Rng) -> Self {
+        let mut values: Vec<u8> = (0..=255).collect();
+        values.shuffle(rng);
+        let mut permutation = [0; 512];
+        for (i, value) in permutation.iter_mut().enumerate() {
+            *value = values[i % 256];
+        }
+        Perlin { permutation }
+    }
+
+    fn fade(t: f32) -> f32 {
+        t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+    }
+
+    fn gradient(hash: u8, x: f32, y: f32) -> f32 {
+        match hash & 7 {
+            0 => x + y,
+            1 => -x + y,
+            2 => x - y,
+            3 => -x - y,
+            4 => x,
+            5 => -x,
+            6 => y,
+            _ => -y,
+        }
+    }
+
+    /// Noise at the given point, roughly between -1 and 1
+    fn noise(&self, x: f32, y: f32) -> f32 {
+        let (xi, yi) = (x.floor() as usize & 255, y.floor() as usize & 255);
+        let (xf, yf) = (x - x.floor(), y - y.floor());
+        let (u, v) = (Self::fade(xf), Self::fade(yf));
+        let p = &self.permutation;
+        let hash = |dx: usize, dy: usize| p[p[xi + dx] as usize + yi + dy];
+
+        let lerp = |a: f32, b: f32, t: f32| a + t * (b - a);
+        let top = lerp(
+            Self::gradient(hash(0, 0), xf, yf),
+            Self::gradient(hash(1, 0), xf - 1.0, yf),
+            u,
+        );
+        let bottom = lerp(
+            Self::gradient(hash(0, 1), xf, yf - 1.0),
+            Self::gradient(hash(1, 1), xf - 1.0, yf - 1.0),
+            u,
+        );
+        lerp(top, bottom, v)
+    }
+}
+
+/// Split values into commit levels of equal width between the lowest and the highest value
+fn quantize(values: &[f32]) -> Vec<CommitCount> {
+    let min = values.iter().copied().fold(f32::INFINITY, f32::min);
+    let max = values.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    let range = (max - min).max(f32::EPSILON);
+    values
+        .iter()
+        .map(|value| {
+            let level = ((value - min) / range * LEVELS as f32) as usize;
+            CommitCount::ALL[level.min(LEVELS - 1)]
+        })
+        .collect()
+}
+
+fn noise(weeks: usize, options: &GeneratorOptions, rng: &mut impl Rng) -> Vec<CommitCount> {
+    let perlin = Perlin::new(rng);
+    let scale = options.scale.unwrap_or(8.0);
+    let mut values = Vec::with_capacity(weeks * DAYS_PER_WEEK);
+    for week in 0..weeks {
+        for day in 0..DAYS_PER_WEEK {
+            let (mut value, mut frequency, mut amplitude) = (0.0, 1.0 / scale, 1.0);
+            for _ in 0..options.octaves {
+                value += amplitude * perlin.noise(week as f32 * frequency, day as f32 * frequency);
+                frequency *= 2.0;
+                amplitude /= 2.0;
+            }
+            values.push(value);
+        }
+    }
+    quantize(&values)
+}
+
+fn life(weeks: usize, options: &GeneratorOptions, rng: &mut impl Rng) -> Vec<CommitCount> {
+    let cells = weeks * DAYS_PER_WEEK;
+    let mut board: Vec<bool> = (0..cells)
+        .map(|_| rng.random_bool(options.density as f64))
+        .collect();
+    let mut ages = vec![0usize; cells];
+
+    let wrap = |value: usize, delta: isize, size: usize| {
+        (value as isize + delta).rem_euclid(size as isize) as usize
+    };
+    for step in 0..options.steps.max(1) {
+        if step > 0 {
+            board = (0..cells)
+                .map(|i| {
+                    let (week, day) = (i / DAYS_PER_WEEK, i % DAYS_PER_WEEK);
+                    let mut neighbours = 0;
+                    for dw in -1..=1 {
+                        for dd in -1..=1 {
+                            if (dw, dd) == (0, 0) {
+                                continue;
+                            }
+                            let week = wrap(week, dw, weeks);
+                            let day = wrap(day, dd, DAYS_PER_WEEK);
+                            neighbours += board[week * DAYS_PER_WEEK + day] as usize;
+                        }
+                    }
+                    neighbours == 3 || board[i] && neighbours == 2
+                })
+                .collect();
+        }
+        // only the last generations are shown
+        if step + LEVELS > options.steps.max(1) {
+            for (age, alive) in ages.iter_mut().zip(&board) {
+                *age += *alive as usize;
+            }
+        }
+    }
+    ages.into_iter()
+        .map(|age| CommitCount::ALL[age.min(LEVELS - 1)])
+        .collect()
+}
+
+fn automaton(weeks: usize, options: &GeneratorOptions) -> Vec<CommitCount> {
+    // the 7 days are the middle of a tape wide enough for its edges to never reach them
+    let width = DAYS_PER_WEEK + 2 * weeks;
+    let first_day = weeks;
+    let mut generation = vec![false; width];
+    generation[width / 2] = true;
+    let mut data = Vec::with_capacity(weeks * DAYS_PER_WEEK);
+    for _ in 0..weeks {
+        data.extend(
+            generation[first_day..first_day + DAYS_PER_WEEK]
+                .iter()
+                .map(|alive| {
+                    if *alive {
+                        CommitCount::ALot
+                    } else {
+                        CommitCount::Zero
+                    }
+                }),
+        );
+        generation = (0..width)
+            .map(|cell| {
+                let left = generation[(cell + width - 1) % width] as u8;
+                let center = generation[cell] as u8;
+                let right = generation[(cell + 1) % width] as u8;
+                options.rule >> (left << 2 | center << 1 | right) & 1 == 1
+            })
+            .collect();
+    }
+    data
+}
+
+fn sine(weeks: usize, options: &GeneratorOptions) -> Vec<CommitCount> {
+    let period = options.scale.unwrap_or(8.0);
+    let middle = (DAYS_PER_WEEK - 1) as f32 / 2.0;
+    let mut data = Vec::with_capacity(weeks * DAYS_PER_WEEK);
+    for week in 0..weeks {
+        let wave = middle + options.amplitude * (TAU * week as f32 / period).sin();
+        for day in 0..DAYS_PER_WEEK {
+            // a lot of commits on the wave, fading away from it
+            let distance = ((day as f32 - wave).abs() * 2.0).round() as usize;
+            data.push(CommitCount::ALL[(LEVELS - 1).saturating_sub(distance)]);
+        }
+    }
+    data
+}
+
+fn gradient(weeks: usize, options: &GeneratorOptions) -> Vec<CommitCount> {
+    let length = options.scale.unwrap_or(weeks as f32);
+    let mut data = Vec::with_capacity(weeks * DAYS_PER_WEEK);
+    for week in 0..weeks {
+        let progress = (week as f32 % length) / length;
+        let level = ((progress * LEVELS as f32) as usize).min(LEVELS - 1);
+        data.extend([CommitCount::ALL[level]; DAYS_PER_WEEK]);
+    }
+    data
+}
+
+impl CommitGrid {
+    /// Fill the days from the start date to `end_date` with a procedural pattern.
+    /// The same seed always generates the same pattern.
+    pub fn generate(
+        &mut self,
+        generator: Generator,
+        options: &GeneratorOptions,
+        end_date: NaiveDate,
+        seed: u64,
+    ) -> Result<()> {
+        if self.get_start_date().gt(&end_date) {
+            return Err(Error::InvalidArg(
+                "end_date must be >= start_date".to_string(),
+            ));
+        }
+        options.validate()?;
+
+        let days = (end_date - self.get_start_date()).num_days() as usize + 1;
+        let weeks = days.div_ceil(DAYS_PER_WEEK);
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut data = match generator {
+            Generator::Noise => noise(weeks, options, &mut rng),
+            Generator::Life => life(weeks, options, &mut rng),
+            Generator::Automaton => automaton(weeks, options),
+            Generator::Sine => sine(weeks, options),
+            Generator::Gradient => gradient(weeks, options),
+        };
+        data.truncate(days);
+        self.set_data(data);
+        Ok(())
+    }
+}
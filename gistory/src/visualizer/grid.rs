@@ -1,19 +1,25 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use chrono::{Datelike, NaiveDate, Weekday};
-use image::{GenericImageView, imageops};
 use log::{info, warn};
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 use tokio::fs;
 use tokio::fs::File;
 use tokio::io::AsyncReadExt;
-use tokio::io::{AsyncBufReadExt, BufReader};
 
 use crate::error::{Error, Result};
 use crate::git::repo::Repo;
 use crate::utils::DateRangeIter;
-use crate::visualizer::font::{CHAR_HEIGHT, Char, Font, Pixel};
+use crate::visualizer::calendar::DAYS_PER_WEEK;
+use crate::visualizer::calibration::CommitLevels;
+use crate::visualizer::composition::MAX_DESIGN_DEPTH;
+use crate::visualizer::design::{Design, DesignMetadata};
+use crate::visualizer::fill::FillOptions;
+use crate::visualizer::font::Font;
+use crate::visualizer::font_file::FontFile;
+use crate::visualizer::image_options::ImageOptions;
+use crate::visualizer::text_options::TextOptions;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, clap::ValueEnum, Deserialize, Serialize)]
 pub enum CommitCount {
@@ -38,6 +44,35 @@ impl From<char> for CommitCount {
 }
 
 impl CommitCount {
+    pub const ALL: [CommitCount; 5] = [
+        CommitCount::Zero,
+        CommitCount::Few,
+        CommitCount::Some,
+        CommitCount::Many,
+        CommitCount::ALot,
+    ];
+
+    pub fn from_digit(value: char) -> Option<Self> {
+        match value {
+            '0' => Some(CommitCount::Zero),
+            '1' => Some(CommitCount::Few),
+            '2' => Some(CommitCount::Some),
+            '3' => Some(CommitCount::Many),
+            '4' => Some(CommitCount::ALot),
+            _ => None,
+        }
+    }
+
+    pub fn to_digit(self) -> char {
+        match self {
+            CommitCount::Zero => '0',
+            CommitCount::Few => '1',
+            CommitCount::Some => '2',
+            CommitCount::Many => '3',
+            CommitCount::ALot => '4',
+        }
+    }
+
     pub fn value(self) -> usize {
         match self {
             CommitCount::Zero => 0,
@@ -49,6 +84,14 @@ impl CommitCount {
     }
 }
 
+/// Largest seed, so that seeds survive JSON numbers read as doubles
+pub const MAX_SEED: u64 = (1 << 53) - 1;
+
+/// A new seed for the random patterns
+pub fn random_seed() -> u64 {
+    rand::rng().random_range(0..=MAX_SEED)
+}
+
 #[derive(Debug)]
 pub struct CommitGrid {
     start_date: NaiveDate,
@@ -56,6 +99,10 @@ pub struct CommitGrid {
 }
 
 impl CommitGrid {
+    pub fn get_start_date(&self) -> NaiveDate {
+        self.start_date
+    }
+
     pub fn get_data(&self) -> &[CommitCount] {
         self.data.as_slice()
     }
@@ -65,13 +112,22 @@ impl CommitGrid {
     }
 
     pub async fn populate_repo(&self, repo: &mut Repo) -> Result<()> {
-        info!("Populating repo at {:?}", repo.path);
+        self.populate_repo_with_levels(repo, &CommitLevels::default())
+            .await
+    }
+
+    pub async fn populate_repo_with_levels(
+        &self,
+        repo: &mut Repo,
+        levels: &CommitLevels,
+    ) -> Result<()> {
+        info!("Populating repo at {:?} with levels {levels:?}", repo.path);
         let date_range = DateRangeIter::new(self.start_date, self.data.len());
 
         for (i, date) in date_range.enumerate() {
             let commit_count = self.data[i];
             info!("Creating {commit_count:?} commits for date {date:?}");
-            for _ in 0..commit_count.value() {
+            for _ in 0..levels.commits_for(commit_count) {
                 repo.add_commit(date).await?
             }
         }
@@ -103,93 +159,90 @@ impl CommitGrid {
     }
 
     pub fn random(&mut self, end_date: NaiveDate) -> Result<()> {
-        // randomly pick a CommitCount for each day from start_date to end_date,
-        // and populate self.data accordingly
-        if self.start_date.gt(&end_date) {
-            return Err(Error::InvalidArg(
-                "end_date must be >= start_date".to_string(),
-            ));
-        }
-
-        let days = (end_date - self.start_date).num_days() as usize;
-        let mut rng = rand::rng();
-
-        self.data = (0..=days)
-            .map(|_| {
-                let random_value = rng.random_range(0..=4);
-                match random_value {
-                    0 => CommitCount::Zero,
-                    1 => CommitCount::Few,
-                    2 => CommitCount::Some,
-                    3 => CommitCount::Many,
-                    4 => CommitCount::ALot,
-                    _ => unreachable!(),
-                }
-            })
-            .collect();
-
-        Ok(())
+        self.random_with_seed(end_date, random_seed())
     }
 
-    pub async fn read_pattern_file(&mut self, path: impl AsRef<Path>) -> Result<()> {
-        // read a file and populate self.data accordingly
-        // file format: a grid consist of 0 1 2 3 4 represent CommitCount
-        // there must be <= 7 rows (represent 7 days in week).
-        // if rows < 7, fill the remaining rows with 0
-
-        let file = fs::File::open(path).await?;
-        let reader = BufReader::new(file);
-        let mut lines = reader.lines();
-
-        let mut grid: Vec<Vec<CommitCount>> = Vec::new();
-
-        // Read up to 7 rows
-        while let Some(line) = lines.next_line().await? {
-            if grid.len() >= 7 {
-                break;
-            }
+    /// Same as `random`, always picking the same commit counts for the same seed
+    pub fn random_with_seed(&mut self, end_date: NaiveDate, seed: u64) -> Result<()> {
+        self.random_with_options(end_date, &FillOptions::default(), seed)
+    }
 
-            let row: Vec<CommitCount> = line
-                .chars()
-                .map(|num| match num {
-                    '0' => CommitCount::Zero,
-                    '1' => CommitCount::Few,
-                    '2' => CommitCount::Some,
-                    '3' => CommitCount::Many,
-                    '4' => CommitCount::ALot,
-                    _ => CommitCount::Zero, // Default to Zero for invalid values
-                })
-                .collect();
-
-            if !row.is_empty() {
-                grid.push(row);
+    pub async fn write_pattern_file(&self, path: impl AsRef<Path>) -> Result<()> {
+        // inverse of read_pattern_file: write 7 rows of 0 1 2 3 4, one column per 7 days.
+        // no start_date header, so the file can also be read in lenient mode. Use save to keep it
+
+        let columns = self.data.len().div_ceil(DAYS_PER_WEEK);
+        let mut content = String::with_capacity((columns + 1) * DAYS_PER_WEEK);
+
+        for r in 0..DAYS_PER_WEEK {
+            for c in 0..columns {
+                let commit_count = self
+                    .data
+                    .get(c * DAYS_PER_WEEK + r)
+                    .copied()
+                    .unwrap_or(CommitCount::Zero);
+                content.push(commit_count.to_digit());
             }
+            content.push('\n');
         }
 
-        let Some(max_len) = grid.iter().map(|x| x.len()).max() else {
-            return Err(Error::InvalidData("Empty file".to_string()));
-        };
+        fs::write(path, content).await?;
+        Ok(())
+    }
 
-        grid.iter_mut()
-            .for_each(|row| row.resize(max_len, CommitCount::Zero));
+    pub async fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        Design::from_grid(self, DesignMetadata::default())
+            .write(path)
+            .await
+    }
 
-        // Fill remaining rows with zeros if needed
-        while grid.len() < 7 {
-            grid.push(vec![CommitCount::Zero; max_len]);
-        }
+    pub async fn load(path: impl AsRef<Path>) -> Result<Self> {
+        Self::load_with_seed(path, random_seed()).await
+    }
 
-        for c in 0..max_len {
-            for row in grid.iter() {
-                self.data.push(row[c])
-            }
-        }
+    /// Same as `load`, with `seed` used by the random layers of the design
+    pub async fn load_with_seed(path: impl AsRef<Path>, seed: u64) -> Result<Self> {
+        Self::load_nested(path.as_ref(), seed, &[]).await
+    }
 
-        Ok(())
+    /// Load a design file included by the designs of `parents`, their canonical paths from the outermost one.
+    /// Fails when the design includes itself, or when designs are nested too deep
+    pub(crate) async fn load_nested(path: &Path, seed: u64, parents: &[PathBuf]) -> Result<Self> {
+        let canonical_path = fs::canonicalize(path).await?;
+        if parents.contains(&canonical_path) {
+            return Err(Error::InvalidArg(format!(
+                "Design {} includes itself",
+                path.display()
+            )));
+        }
+        if parents.len() >= MAX_DESIGN_DEPTH {
+            return Err(Error::InvalidArg(format!(
+                "Design {} is nested more than {MAX_DESIGN_DEPTH} designs deep",
+                path.display()
+            )));
+        }
+        let mut parents = parents.to_vec();
+        parents.push(canonical_path);
+
+        let base_dir = path.parent().unwrap_or(Path::new("."));
+        Design::read(path)
+            .await?
+            .to_grid(Some(base_dir), seed, &parents)
+            .await
     }
 
     pub async fn read_image_file(&mut self, path: impl AsRef<Path>) -> Result<()> {
-        // read an image (jpeg, png, etc) -> convert to black and white -> resize to 7 pixel rows (keep the aspect ratio)
-        // -> convert each pixel to CommitCount using the pixel brightness
+        self.read_image_file_with_options(path, &ImageOptions::default())
+            .await
+    }
+
+    pub async fn read_image_file_with_options(
+        &mut self,
+        path: impl AsRef<Path>,
+        options: &ImageOptions,
+    ) -> Result<()> {
+        // read an image (jpeg, png, etc) -> convert to grayscale with alpha -> fit to 7 pixel rows
+        // -> convert each pixel to CommitCount using the pixel brightness, transparent pixels get no commit
 
         // Read the image file
         let mut file = File::open(path).await?;
@@ -200,76 +253,37 @@ impl CommitGrid {
         let img = image::load_from_memory(&buffer)
             .map_err(|e| Error::InvalidData(format!("Failed to load image: {e}")))?;
 
-        // Convert to grayscale
-        let grayscale = img.grayscale();
-
-        // Resize to 7 rows, keeping aspect ratio
-        let (width, height) = grayscale.dimensions();
-        let new_height = 7;
-        let new_width = (width as f32 * (new_height as f32 / height as f32)).round() as u32;
-
-        let resized = imageops::resize(
-            &grayscale,
-            new_width,
-            new_height,
-            imageops::FilterType::Lanczos3,
-        );
-
-        // Convert each pixel to CommitCount based on brightness
-        let mut commit_counts = Vec::with_capacity(new_width as usize * new_height as usize);
-        for x in 0..new_width {
-            for y in 0..new_height {
-                let pixel = resized.get_pixel(x, y);
-                let brightness = pixel[0] as f32 / 255.0; // Normalize to 0.0-1.0
-
-                // Map brightness to CommitCount
-                let commit_count = match brightness {
-                    b if b < 0.2 => CommitCount::ALot,
-                    b if b < 0.4 => CommitCount::Many,
-                    b if b < 0.6 => CommitCount::Some,
-                    b if b < 0.8 => CommitCount::Few,
-                    _ => CommitCount::Zero,
-                };
-
-                commit_counts.push(commit_count);
-            }
-        }
-
-        self.data = commit_counts;
+        self.data = options.convert(&img)?;
 
         Ok(())
     }
 
     pub fn show_text(&mut self, text: String, font: Font, commit_count: CommitCount) -> Result<()> {
-        let mut chars: Vec<&Char> = Vec::with_capacity(text.len());
-        for byte in text.as_bytes() {
-            if let Some(ch) = font.get_char(*byte) {
-                chars.push(ch);
-            } else {
-                return Err(Error::InvalidData(format!(
-                    "Text only accepts {}",
-                    font.supported_chars()
-                )));
-            }
-        }
+        self.show_text_with_options(&text, font, commit_count, &TextOptions::default())
+    }
 
-        let mut data = Vec::with_capacity(5 * chars.len() * CHAR_HEIGHT); // estimate length
+    pub fn show_text_with_options(
+        &mut self,
+        text: &str,
+        font: Font,
+        commit_count: CommitCount,
+        options: &TextOptions,
+    ) -> Result<()> {
+        let glyphs = font.glyphs(text, options.fallback)?;
+        self.data = options.layout(&glyphs, commit_count)?;
 
-        for ch in chars.iter() {
-            // render char
-            for pixel in ch.pixels_by_column() {
-                data.push(match pixel {
-                    Pixel::On => commit_count,
-                    Pixel::Off => CommitCount::Zero,
-                });
-            }
-            // space between chars
-            for _ in 0..CHAR_HEIGHT {
-                data.push(CommitCount::Zero)
-            }
-        }
+        Ok(())
+    }
 
-        self.data = data;
+    pub fn show_text_with_font_file(
+        &mut self,
+        text: &str,
+        font: &FontFile,
+        commit_count: CommitCount,
+        options: &TextOptions,
+    ) -> Result<()> {
+        let glyphs = font.glyphs(text, options.fallback)?;
+        self.data = options.layout(&glyphs, commit_count)?;
 
         Ok(())
     }
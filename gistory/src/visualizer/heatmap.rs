@@ -0,0 +1,164 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use chrono::{Datelike, NaiveDate, TimeDelta};
+use serde::{Deserialize, Serialize};
+use tokio::fs;
+
+use crate::error::{Error, Result};
+use crate::visualizer::{CommitCount, CommitGrid};
+
+/// How values are turned into commit counts. A value of 0 always has no commit.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, clap::ValueEnum, Deserialize, Serialize)]
+pub enum Scale {
+    /// Proportional to the value, the largest value being ALot
+    #[default]
+    Linear,
+    /// Proportional to the logarithm of the value, so that a few large values don't hide the others
+    Log,
+    /// The quartiles of the values, like GitHub does with commits
+    Quantile,
+}
+
+/// The value of a day in a time series
+#[derive(Debug, PartialEq, Clone, Copy, Deserialize, Serialize)]
+pub struct DataPoint {
+    pub date: NaiveDate,
+    pub value: f64,
+}
+
+/// Parse a time series, either CSV lines of `date,value`, e.g. 2025-01-05,12.5,
+/// or a JSON array of `{"date": "2025-01-05", "value": 12.5}`.
+/// A first CSV line that isn't a data point is taken as the header and skipped.
+pub fn parse_series(content: &str) -> Result<Vec<DataPoint>> {
+    if content.trim_start().starts_with('[') {
+        return serde_json::from_str(content)
+            .map_err(|e| Error::InvalidData(format!("Invalid time series: {e}")));
+    }
+
+    let mut points = Vec::new();
+    for (i, line) in content.lines().enumerate() {
+        let fields: Vec<&str> = line
+            .split(|c: char| c == ',' || c == ';' || c.is_whitespace())
+            .filter(|f| !f.is_empty())
+            .collect();
+        let [date, value] = fields[..] else {
+            if fields.is_empty() {
+                continue;
+            }
+            return Err(Error::InvalidData(format!(
+                "Line {}: expected date,value, got {line:?}",
+                i + 1
+            )));
+        };
+        let Ok(date) = NaiveDate::parse_from_str(date, "%Y-%m-%d") else {
+            if i == 0 {
+                continue;
+            }
+            return Err(Error::InvalidData(format!(
+                "Line {}: invalid date {date:?}, expected YYYY-mm-dd",
+                i + 1
+            )));
+        };
+        let value = value
+            .parse::<f64>()
+            .map_err(|_| Error::InvalidData(format!("Line {}: invalid value {value:?}", i + 1)))?;
+        points.push(DataPoint { date, value });
+    }
+    Ok(points)
+}
+
+/// Split values into levels like GitHub does with commits: the non-zero values are divided into quartiles
+pub(crate) fn quartile_levels(values: &[f64]) -> Vec<CommitCount> {
+    let mut active: Vec<f64> = values.iter().copied().filter(|v| *v > 0.0).collect();
+    active.sort_unstable_by(f64::total_cmp);
+    let quartile = |q: usize| active[(active.len() - 1) * q / 4];
+
+    values
+        .iter()
+        .map(|value| match *value {
+            v if v <= 0.0 => CommitCount::Zero,
+            v if v <= quartile(1) => CommitCount::Few,
+            v if v <= quartile(2) => CommitCount::Some,
+            v if v <= quartile(3) => CommitCount::Many,
+            _ => CommitCount::ALot,
+        })
+        .collect()
+}
+
+/// Level of `value` when `max` is ALot, `value` being already scaled
+fn proportional_level(value: f64, max: f64) -> CommitCount {
+    if value <= 0.0 {
+        return CommitCount::Zero;
+    }
+    let level = (value / max * 4.0).ceil().clamp(1.0, 4.0) as usize;
+    CommitCount::ALL[level]
+}
+
+impl Scale {
+    fn levels(&self, values: &[f64]) -> Vec<CommitCount> {
+        match self {
+            Scale::Linear => {
+                let max = values.iter().copied().fold(0.0, f64::max);
+                values
+                    .iter()
+                    .map(|value| proportional_level(*value, max))
+                    .collect()
+            }
+            Scale::Log => {
+                let max = values.iter().copied().fold(0.0, f64::max).ln_1p();
+                values
+                    .iter()
+                    .map(|value| proportional_level(value.ln_1p(), max))
+                    .collect()
+            }
+            Scale::Quantile => quartile_levels(values),
+        }
+    }
+}
+
+impl CommitGrid {
+    /// A heatmap of a time series, starting on the Sunday of its earliest day.
+    /// Values of the same day are added up, and days without value have no commit.
+    pub fn from_series(points: &[DataPoint], scale: Scale) -> Result<Self> {
+        if let Some(point) = points
+            .iter()
+            .find(|point| !point.value.is_finite() || point.value < 0.0)
+        {
+            return Err(Error::InvalidData(format!(
+                "Value of {} must be a positive number, got {}",
+                point.date, point.value
+            )));
+        }
+        let mut daily_values: BTreeMap<NaiveDate, f64> = BTreeMap::new();
+        for point in points {
+            *daily_values.entry(point.date).or_default() += point.value;
+        }
+        let (Some((first_day, _)), Some((last_day, _))) = (
+            daily_values.first_key_value(),
+            daily_values.last_key_value(),
+        ) else {
+            return Err(Error::InvalidData("Time series is empty".to_string()));
+        };
+
+        let start_date =
+            *first_day - TimeDelta::days(first_day.weekday().num_days_from_sunday() as i64);
+        let days = (*last_day - start_date).num_days() + 1;
+        let values: Vec<f64> = (0..days)
+            .map(|i| {
+                let date = start_date + TimeDelta::days(i);
+                daily_values.get(&date).copied().unwrap_or(0.0)
+            })
+            .collect();
+
+        let mut grid = CommitGrid::new(start_date);
+        grid.set_data(scale.levels(&values));
+        Ok(grid)
+    }
+
+    /// Same as `from_series`, reading the time series from a CSV or JSON file, see `parse_series`
+    pub async fn from_series_file(path: impl AsRef<Path>, scale: Scale) -> Result<Self> {
+        let content = fs::read_to_string(path).await?;
+        Self::from_series(&parse_series(&content)?, scale)
+    }
+}
@@ -0,0 +1,350 @@
+use std::str::FromStr;
+
+use image::{DynamicImage, GrayAlphaImage, LumaA, imageops};
+use log::warn;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Error, Result};
+use crate::visualizer::CommitCount;
+use crate::visualizer::anchor::VISIBLE_WEEKS;
+use crate::visualizer::calendar::DAYS_PER_WEEK;
+
+const DEFAULT_THRESHOLDS: [f32; 4] = [0.2, 0.4, 0.6, 0.8];
+const BAYER_4X4: [[f32; 4]; 4] = [
+    [0.0, 8.0, 2.0, 10.0],
+    [12.0, 4.0, 14.0, 6.0],
+    [3.0, 11.0, 1.0, 9.0],
+    [15.0, 7.0, 13.0, 5.0],
+];
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, clap::ValueEnum, Deserialize, Serialize)]
+pub enum Dithering {
+    #[default]
+    None,
+    FloydSteinberg,
+    /// Ordered dithering with a 4x4 Bayer matrix
+    Ordered,
+}
+
+/// How the image is scaled to 7 rows
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, clap::ValueEnum, Deserialize, Serialize)]
+pub enum FitMode {
+    /// Scale to 7 rows, keeping the aspect ratio
+    #[default]
+    Height,
+    /// Stretch to exactly `weeks` columns and 7 rows
+    Width,
+    /// Keep the aspect ratio, fill `weeks` x 7 and cut the overflowing edges
+    Crop,
+    /// Keep the aspect ratio, fit inside `weeks` x 7 and leave the borders empty
+    Pad,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, clap::ValueEnum, Deserialize, Serialize)]
+pub enum Resampling {
+    /// Smooth, for photos
+    #[default]
+    Lanczos3,
+    /// Crisp, for pixel art
+    Nearest,
+}
+
+impl From<Resampling> for imageops::FilterType {
+    fn from(value: Resampling) -> Self {
+        match value {
+            Resampling::Lanczos3 => imageops::FilterType::Lanczos3,
+            Resampling::Nearest => imageops::FilterType::Nearest,
+        }
+    }
+}
+
+/// Intensity cut-offs between Zero|Few, Few|Some, Some|Many and Many|ALot.
+/// Intensity goes from 0.0 (white) to 1.0 (black), or the opposite when inverted.
+#[derive(Debug, PartialEq, Clone, Copy, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum Thresholds {
+    Fixed([f32; 4]),
+    /// Pick cut-offs so that each level covers the same number of pixels
+    Auto,
+}
+
+impl Default for Thresholds {
+    fn default() -> Self {
+        Thresholds::Fixed(DEFAULT_THRESHOLDS)
+    }
+}
+
+impl FromStr for Thresholds {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        // format: "auto" or T1,T2,T3,T4 e.g. "0.2,0.4,0.6,0.8"
+        if s.trim().eq_ignore_ascii_case("auto") {
+            return Ok(Thresholds::Auto);
+        }
+        let values = s
+            .split(',')
+            .map(|v| v.trim().parse::<f32>())
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|e| Error::InvalidArg(format!("Invalid thresholds {s:?}: {e}")))?;
+        let [t1, t2, t3, t4] = values[..] else {
+            return Err(Error::InvalidArg(format!(
+                "Thresholds must be \"auto\" or exactly 4 values, got {s:?}"
+            )));
+        };
+        let in_range = values.iter().all(|t| (0.0..=1.0).contains(t));
+        if !in_range || t1 > t2 || t2 > t3 || t3 > t4 {
+            return Err(Error::InvalidArg(format!(
+                "Thresholds must be increasing and between 0 and 1, got {s:?}"
+            )));
+        }
+        Ok(Thresholds::Fixed([t1, t2, t3, t4]))
+    }
+}
+
+impl TryFrom<String> for Thresholds {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self> {
+        value.parse()
+    }
+}
+
+impl From<Thresholds> for String {
+    fn from(value: Thresholds) -> Self {
+        match value {
+            Thresholds::Auto => "auto".to_string(),
+            Thresholds::Fixed(t) => format!("{},{},{},{}", t[0], t[1], t[2], t[3]),
+        }
+    }
+}
+
+impl Thresholds {
+    fn resolve(self, intensities: &[f32]) -> [f32; 4] {
+        match self {
+            Thresholds::Fixed(thresholds) => thresholds,
+            Thresholds::Auto => {
+                let mut sorted = intensities.to_vec();
+                sorted.sort_by(f32::total_cmp);
+                if sorted.is_empty() {
+                    return DEFAULT_THRESHOLDS;
+                }
+                let quantile = |i: usize| sorted[(sorted.len() - 1) * i / 5];
+                [quantile(1), quantile(2), quantile(3), quantile(4)]
+            }
+        }
+    }
+}
+
+/// How an image is converted to commit counts
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ImageOptions {
+    pub dithering: Dithering,
+    pub thresholds: Thresholds,
+    /// Applied after contrast, > 1.0 darkens mid-tones, < 1.0 lightens them
+    pub gamma: f32,
+    /// > 1.0 increases contrast around mid-gray, < 1.0 decreases it
+    pub contrast: f32,
+    /// By default dark pixels get more commits. When inverted, light pixels do
+    pub invert: bool,
+    pub fit: FitMode,
+    /// Number of columns for the Width, Crop and Pad fit modes. Default to 53
+    pub weeks: Option<usize>,
+    pub resampling: Resampling,
+}
+
+impl Default for ImageOptions {
+    fn default() -> Self {
+        Self {
+            dithering: Dithering::None,
+            thresholds: Thresholds::default(),
+            gamma: 1.0,
+            contrast: 1.0,
+            invert: false,
+            fit: FitMode::Height,
+            weeks: None,
+            resampling: Resampling::Lanczos3,
+        }
+    }
+}
+
+impl ImageOptions {
+    pub fn validate(&self) -> Result<()> {
+        if !(self.gamma > 0.0 && self.gamma.is_finite()) {
+            return Err(Error::InvalidArg(format!(
+                "gamma must be > 0, got {}",
+                self.gamma
+            )));
+        }
+        if !(self.contrast >= 0.0 && self.contrast.is_finite()) {
+            return Err(Error::InvalidArg(format!(
+                "contrast must be >= 0, got {}",
+                self.contrast
+            )));
+        }
+        if self.weeks == Some(0) {
+            return Err(Error::InvalidArg("weeks must be > 0".to_string()));
+        }
+        Ok(())
+    }
+
+    /// Scale the image to 7 rows according to the fit mode.
+    /// Transparent pixels are composited over white and keep their alpha.
+    pub fn fit(&self, img: &DynamicImage) -> Result<GrayAlphaImage> {
+        self.validate()?;
+
+        let mut img = img.to_luma_alpha8();
+        for pixel in img.pixels_mut() {
+            let LumaA([luma, alpha]) = *pixel;
+            let over_white = (luma as u32 * alpha as u32 + 255 * (255 - alpha as u32)) / 255;
+            *pixel = LumaA([over_white as u8, alpha]);
+        }
+
+        let (width, height) = img.dimensions();
+        if width == 0 || height == 0 {
+            return Err(Error::InvalidData("Image is empty".to_string()));
+        }
+        let rows = DAYS_PER_WEEK as u32;
+        let columns = self.weeks.unwrap_or(VISIBLE_WEEKS) as u32;
+        let filter = self.resampling.into();
+        // width of the image when scaled to 7 rows
+        let scaled_width = ((width as f32 * rows as f32 / height as f32).round() as u32).max(1);
+
+        let fitted = match self.fit {
+            FitMode::Height => imageops::resize(&img, scaled_width, rows, filter),
+            FitMode::Width => imageops::resize(&img, columns, rows, filter),
+            FitMode::Crop => {
+                let (new_width, new_height) = if scaled_width >= columns {
+                    (scaled_width, rows)
+                } else {
+                    let new_height = (height as f32 * columns as f32 / width as f32).round();
+                    (columns, (new_height as u32).max(rows))
+                };
+                let resized = imageops::resize(&img, new_width, new_height, filter);
+                let x = (new_width - columns) / 2;
+                let y = (new_height - rows) / 2;
+                imageops::crop_imm(&resized, x, y, columns, rows).to_image()
+            }
+            FitMode::Pad => {
+                let (new_width, new_height) = if scaled_width <= columns {
+                    (scaled_width, rows)
+                } else {
+                    let new_height = (height as f32 * columns as f32 / width as f32).round();
+                    (columns, (new_height as u32).clamp(1, rows))
+                };
+                let resized = imageops::resize(&img, new_width, new_height, filter);
+                let mut padded = GrayAlphaImage::from_pixel(columns, rows, LumaA([255, 0]));
+                let x = (columns - new_width) / 2;
+                let y = (rows - new_height) / 2;
+                imageops::overlay(&mut padded, &resized, x as i64, y as i64);
+                padded
+            }
+        };
+        Ok(fitted)
+    }
+
+    /// Fit the image to 7 rows and convert each pixel to CommitCount based on brightness
+    pub fn convert(&self, img: &DynamicImage) -> Result<Vec<CommitCount>> {
+        let fitted = self.fit(img)?;
+        let (width, height) = fitted.dimensions();
+        if width as usize > VISIBLE_WEEKS {
+            warn!(
+                "Image is {width} weeks wide, only {VISIBLE_WEEKS} weeks are visible on the profile at once"
+            );
+        }
+
+        let pixels: Vec<(f32, f32)> = fitted
+            .pixels()
+            .map(|pixel| (pixel[0] as f32 / 255.0, pixel[1] as f32 / 255.0)) // Normalize to 0.0-1.0
+            .collect();
+        self.to_commit_counts(&pixels, width as usize, height as usize)
+    }
+
+    fn intensity(&self, brightness: f32) -> f32 {
+        let adjusted = ((brightness - 0.5) * self.contrast + 0.5).clamp(0.0, 1.0);
+        let adjusted = adjusted.powf(self.gamma);
+        if self.invert {
+            adjusted
+        } else {
+            1.0 - adjusted
+        }
+    }
+
+    /// Convert pixels of (brightness, alpha) stored row by row to commit counts
+    /// stored column by column, like `CommitGrid` data. Brightness 0.0 is black, 1.0 is white.
+    /// Alpha 0.0 is transparent: mostly transparent pixels get no commit,
+    /// partly transparent pixels get fewer commits.
+    pub fn to_commit_counts(
+        &self,
+        pixels: &[(f32, f32)],
+        width: usize,
+        height: usize,
+    ) -> Result<Vec<CommitCount>> {
+        self.validate()?;
+
+        let opaque = |alpha: f32| alpha >= 0.5;
+        let mut intensities: Vec<f32> = pixels
+            .iter()
+            .map(|(brightness, alpha)| self.intensity(*brightness) * alpha)
+            .collect();
+        let opaque_intensities: Vec<f32> = pixels
+            .iter()
+            .zip(intensities.iter())
+            .filter(|((_, alpha), _)| opaque(*alpha))
+            .map(|(_, intensity)| *intensity)
+            .collect();
+        let thresholds = self.thresholds.resolve(&opaque_intensities);
+        let level = |intensity: f32| thresholds.iter().filter(|t| intensity > **t).count();
+        // the intensity each level stands for, used to compute the dithering error
+        let representative = [
+            0.0,
+            (thresholds[0] + thresholds[1]) / 2.0,
+            (thresholds[1] + thresholds[2]) / 2.0,
+            (thresholds[2] + thresholds[3]) / 2.0,
+            1.0,
+        ];
+
+        let mut levels = vec![0; width * height];
+        for y in 0..height {
+            for x in 0..width {
+                let i = y * width + x;
+                let intensity = intensities[i];
+                if !opaque(pixels[i].1) {
+                    continue;
+                }
+                levels[i] = match self.dithering {
+                    Dithering::None => level(intensity),
+                    Dithering::Ordered => {
+                        let offset = (BAYER_4X4[y % 4][x % 4] + 0.5) / 16.0 - 0.5;
+                        level(intensity + offset / 4.0)
+                    }
+                    Dithering::FloydSteinberg => {
+                        let quantized = level(intensity);
+                        let error = intensity - representative[quantized];
+                        let mut spread = |dx: isize, dy: usize, weight: f32| {
+                            let nx = x as isize + dx;
+                            let ny = y + dy;
+                            if nx >= 0 && (nx as usize) < width && ny < height {
+                                intensities[ny * width + nx as usize] += error * weight;
+                            }
+                        };
+                        spread(1, 0, 7.0 / 16.0);
+                        spread(-1, 1, 3.0 / 16.0);
+                        spread(0, 1, 5.0 / 16.0);
+                        spread(1, 1, 1.0 / 16.0);
+                        quantized
+                    }
+                };
+            }
+        }
+
+        let mut commit_counts = Vec::with_capacity(width * height);
+        for x in 0..width {
+            for y in 0..height {
+                commit_counts.push(CommitCount::ALL[levels[y * width + x]]);
+            }
+        }
+        Ok(commit_counts)
+    }
+}
@@ -0,0 +1,115 @@
+use chrono::Datelike;
+use log::warn;
+
+use crate::error::{Error, Result};
+use crate::visualizer::anchor::Anchor;
+use crate::visualizer::composition::BlendMode;
+use crate::visualizer::text_options::{Glyph, TextOptions};
+use crate::visualizer::{CommitCount, CommitGrid, Font, FontFile};
+
+/// Break the glyphs into lines fitting in the calendar years from `start_year`.
+/// Lines are broken between words, or within a word longer than a whole year.
+fn wrap(glyphs: &[Glyph], options: &TextOptions, start_year: i32) -> Result<Vec<Vec<Glyph>>> {
+    let space = glyphs.iter().find(|glyph| glyph.is_space).cloned();
+    let year_weeks = |line: usize| Anchor::Year(start_year + line as i32).full_weeks();
+    let fits = |line: &[Glyph], weeks: usize| options.layout_width(line) <= weeks;
+
+    let mut lines: Vec<Vec<Glyph>> = vec![Vec::new()];
+    for word in glyphs.split(|glyph| glyph.is_space) {
+        if word.is_empty() {
+            continue;
+        }
+
+        let weeks = year_weeks(lines.len() - 1)?;
+        let line = lines.last_mut().unwrap();
+        let mut candidate = line.clone();
+        if !candidate.is_empty() {
+            candidate.extend(space.clone());
+        }
+        candidate.extend_from_slice(word);
+        if fits(&candidate, weeks) {
+            *line = candidate;
+            continue;
+        }
+
+        // the word starts the next year, and is split if it doesn't fit in it either
+        if !line.is_empty() {
+            lines.push(Vec::new());
+        }
+        let mut split = false;
+        for glyph in word {
+            let weeks = year_weeks(lines.len() - 1)?;
+            let line = lines.last_mut().unwrap();
+            line.push(glyph.clone());
+            if fits(line, weeks) {
+                continue;
+            }
+            if line.len() == 1 {
+                return Err(Error::InvalidData(format!(
+                    "A character is wider than the {weeks} weeks of a year"
+                )));
+            }
+            let glyph = line.pop().unwrap();
+            lines.push(vec![glyph]);
+            split = true;
+        }
+        if split {
+            warn!("A word is longer than a year and was split between years");
+        }
+    }
+    Ok(lines)
+}
+
+impl CommitGrid {
+    /// Write the text across consecutive calendar years, starting at the year of the start date,
+    /// without splitting words between years. Returns the number of years used.
+    pub fn show_marquee(
+        &mut self,
+        text: &str,
+        font: Font,
+        commit_count: CommitCount,
+        options: &TextOptions,
+    ) -> Result<usize> {
+        let glyphs = font.glyphs(text, options.fallback)?;
+        self.draw_marquee(&glyphs, commit_count, options)
+    }
+
+    /// Same as `show_marquee`, with a font loaded from a file
+    pub fn show_marquee_with_font_file(
+        &mut self,
+        text: &str,
+        font: &FontFile,
+        commit_count: CommitCount,
+        options: &TextOptions,
+    ) -> Result<usize> {
+        let glyphs = font.glyphs(text, options.fallback)?;
+        self.draw_marquee(&glyphs, commit_count, options)
+    }
+
+    fn draw_marquee(
+        &mut self,
+        glyphs: &[Glyph],
+        commit_count: CommitCount,
+        options: &TextOptions,
+    ) -> Result<usize> {
+        let start_date = self.get_start_date();
+        let lines = wrap(glyphs, options, start_date.year())?;
+
+        let mut marquee: Option<CommitGrid> = None;
+        for (i, line) in lines.iter().enumerate() {
+            let mut year_grid = CommitGrid::new(start_date);
+            year_grid.set_data(options.layout(line, commit_count)?);
+            year_grid.place(Anchor::Year(start_date.year() + i as i32))?;
+
+            let marquee =
+                marquee.get_or_insert_with(|| CommitGrid::new(year_grid.get_start_date()));
+            let offset = (year_grid.get_start_date() - marquee.get_start_date()).num_days();
+            marquee.blend(&year_grid, offset as usize, BlendMode::Overwrite);
+        }
+
+        if let Some(marquee) = marquee {
+            *self = marquee;
+        }
+        Ok(lines.len())
+    }
+}
@@ -0,0 +1,259 @@
+use std::path::Path;
+
+use chrono::NaiveDate;
+use tokio::fs;
+
+use crate::error::{Error, Result};
+use crate::visualizer::calendar::DAYS_PER_WEEK;
+use crate::visualizer::{Anchor, CommitCount, CommitGrid};
+
+/// Characters of the commit counts in a pattern file, from Zero to ALot.
+/// `.` and space always mean Zero, unless the palette gives them another commit count.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Palette([char; 5]);
+
+impl Default for Palette {
+    fn default() -> Self {
+        Palette(['0', '1', '2', '3', '4'])
+    }
+}
+
+impl Palette {
+    fn parse(s: &str) -> std::result::Result<Self, String> {
+        let Ok(chars) = <[char; 5]>::try_from(s.chars().collect::<Vec<_>>()) else {
+            return Err(format!("palette must have exactly 5 characters, got {s:?}"));
+        };
+        if let Some(ch) = chars
+            .iter()
+            .find(|ch| matches!(ch, '#' | ':') || ch.is_whitespace())
+        {
+            return Err(format!("palette cannot contain {ch:?}, got {s:?}"));
+        }
+        if (1..chars.len()).any(|i| chars[..i].contains(&chars[i])) {
+            return Err(format!("palette characters must be different, got {s:?}"));
+        }
+        Ok(Palette(chars))
+    }
+
+    fn commit_count(&self, ch: char) -> Option<CommitCount> {
+        match self.0.iter().position(|c| *c == ch) {
+            Some(i) => Some(CommitCount::ALL[i]),
+            None if ch == '.' || ch == ' ' => Some(CommitCount::Zero),
+            None => None,
+        }
+    }
+
+    fn describe(&self) -> String {
+        self.0
+            .iter()
+            .map(|ch| ch.to_string())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// A pattern read from a text file.
+///
+/// Format version 2: up to 7 rows, one per day from Sunday to Saturday, one column per week.
+/// Lines starting with `#` are comments. Lines before the first row can be `key: value` headers:
+///
+/// ```text
+/// # a heart
+/// start_date: 2025-01-05
+/// palette: .-+*@
+/// .@@.@@.
+/// @@@@@@@
+/// .@@@@@.
+/// ..@@@..
+/// ...@...
+/// ```
+///
+/// Headers are `start_date`, `year`, `week` and `center`, which work like the CLI options of the same names,
+/// and `palette`, the 5 characters from Zero to ALot (default `01234`).
+/// Empty lines within the rows are rows without commits, and rows shorter than the others are padded with Zero.
+///
+/// The lenient mode reads the original format instead: up to 7 non-empty lines of 0 to 4,
+/// other characters being Zero and extra lines being ignored.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct PatternFile {
+    pub start_date: Option<NaiveDate>,
+    pub anchor: Option<Anchor>,
+    pub data: Vec<CommitCount>,
+}
+
+struct Header {
+    start_date: Option<NaiveDate>,
+    year: Option<i32>,
+    week: Option<usize>,
+    center: bool,
+    palette: Palette,
+}
+
+impl Header {
+    fn set(&mut self, key: &str, value: &str) -> std::result::Result<(), String> {
+        match key {
+            "start_date" => {
+                let date = NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                    .map_err(|e| format!("invalid start_date {value:?}: {e}"))?;
+                self.start_date = Some(date);
+            }
+            "year" => {
+                let year = value
+                    .parse()
+                    .map_err(|e| format!("invalid year {value:?}: {e}"))?;
+                self.year = Some(year);
+            }
+            "week" => {
+                let week = value
+                    .parse()
+                    .map_err(|e| format!("invalid week {value:?}: {e}"))?;
+                self.week = Some(week);
+            }
+            "center" => {
+                self.center = value
+                    .parse()
+                    .map_err(|_| format!("invalid center {value:?}, expected true or false"))?;
+            }
+            "palette" => {
+                self.palette = Palette::parse(value)?;
+            }
+            _ => return Err(format!("unknown header {key:?}")),
+        }
+        Ok(())
+    }
+
+    fn anchor(&self) -> Result<Option<Anchor>> {
+        match (self.year, self.week, self.center) {
+            (Some(_), _, true) => Err(Error::InvalidData(
+                "Headers year and center cannot be used together".to_string(),
+            )),
+            (None, Some(_), _) => Err(Error::InvalidData("Header week requires year".to_string())),
+            (Some(year), Some(week), _) => Ok(Some(Anchor::Week { year, week })),
+            (Some(year), None, _) => Ok(Some(Anchor::Year(year))),
+            (None, None, true) => Ok(Some(Anchor::Center {
+                today: chrono::Local::now().date_naive(),
+            })),
+            (None, None, false) => Ok(None),
+        }
+    }
+}
+
+/// Turn rows of days into the data of a grid, one column per week
+fn to_data(mut rows: Vec<Vec<CommitCount>>) -> Vec<CommitCount> {
+    let weeks = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+    rows.resize(DAYS_PER_WEEK, vec![]);
+    rows.iter_mut()
+        .for_each(|row| row.resize(weeks, CommitCount::Zero));
+    (0..weeks)
+        .flat_map(|week| rows.iter().map(move |row| row[week]))
+        .collect()
+}
+
+impl PatternFile {
+    pub fn parse(content: &str, lenient: bool) -> Result<Self> {
+        if lenient {
+            return Self::parse_lenient(content);
+        }
+
+        let mut header = Header {
+            start_date: None,
+            year: None,
+            week: None,
+            center: false,
+            palette: Palette::default(),
+        };
+        let mut rows: Vec<Vec<CommitCount>> = Vec::new();
+        let mut errors = Vec::new();
+        // empty lines are only rows when followed by another row
+        let mut empty_lines = 0;
+
+        for (i, line) in content.lines().enumerate() {
+            let line_number = i + 1;
+            if line.trim_start().starts_with('#') {
+                continue;
+            }
+            if rows.is_empty() {
+                if line.trim().is_empty() {
+                    continue;
+                }
+                if let Some((key, value)) = line.split_once(':') {
+                    if let Err(e) = header.set(key.trim(), value.trim()) {
+                        errors.push(format!("Line {line_number}: {e}"));
+                    }
+                    continue;
+                }
+            }
+            if line.trim().is_empty() {
+                empty_lines += 1;
+                continue;
+            }
+
+            rows.extend((0..empty_lines).map(|_| vec![]));
+            empty_lines = 0;
+            if rows.len() >= DAYS_PER_WEEK {
+                errors.push(format!(
+                    "Line {line_number}: more than {DAYS_PER_WEEK} rows"
+                ));
+                break;
+            }
+            let mut row = Vec::with_capacity(line.len());
+            for (j, ch) in line.chars().enumerate() {
+                match header.palette.commit_count(ch) {
+                    Some(commit_count) => row.push(commit_count),
+                    None => {
+                        errors.push(format!(
+                            "Line {line_number}, column {}: invalid character {ch:?}, expected one of {} . or space",
+                            j + 1,
+                            header.palette.describe()
+                        ));
+                        break;
+                    }
+                }
+            }
+            rows.push(row);
+        }
+
+        if !errors.is_empty() {
+            return Err(Error::InvalidData(errors.join("; ")));
+        }
+        if rows.is_empty() {
+            return Err(Error::InvalidData("Empty pattern".to_string()));
+        }
+        Ok(Self {
+            start_date: header.start_date,
+            anchor: header.anchor()?,
+            data: to_data(rows),
+        })
+    }
+
+    fn parse_lenient(content: &str) -> Result<Self> {
+        let rows: Vec<Vec<CommitCount>> = content
+            .lines()
+            .map(|line| line.chars().map(CommitCount::from).collect::<Vec<_>>())
+            .filter(|row| !row.is_empty())
+            .take(DAYS_PER_WEEK)
+            .collect();
+        if rows.is_empty() {
+            return Err(Error::InvalidData("Empty file".to_string()));
+        }
+        Ok(Self {
+            data: to_data(rows),
+            ..Default::default()
+        })
+    }
+
+    pub async fn read(path: impl AsRef<Path>, lenient: bool) -> Result<Self> {
+        let content = fs::read_to_string(path).await?;
+        Self::parse(&content, lenient)
+    }
+}
+
+impl CommitGrid {
+    /// Draw the pattern of a pattern file, ignoring its headers. See `PatternFile` for the format,
+    /// and for the original format read when `lenient` is true
+    pub async fn read_pattern_file(&mut self, path: impl AsRef<Path>, lenient: bool) -> Result<()> {
+        let pattern = PatternFile::read(path, lenient).await?;
+        self.set_data(pattern.data);
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+use std::env;
+use std::io::IsTerminal;
+
+use crate::visualizer::calendar::{Calendar, WEEKDAY_LABELS};
+use crate::visualizer::render::Theme;
+use crate::visualizer::{CommitCount, CommitGrid};
+
+const CELL_WIDTH: usize = 2;
+const LABEL_WIDTH: usize = 4;
+const RESET: &str = "\x1b[0m";
+
+const ANSI_256_COLORS: [u8; 5] = [236, 22, 28, 34, 46];
+const ASCII_CHARS: [char; 5] = ['.', '-', '+', '*', '#'];
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, clap::ValueEnum)]
+pub enum ColorMode {
+    Auto,
+    TrueColor,
+    Ansi256,
+    Ascii,
+}
+
+impl ColorMode {
+    pub fn detect() -> Self {
+        if env::var_os("NO_COLOR").is_some() || !std::io::stdout().is_terminal() {
+            return ColorMode::Ascii;
+        }
+        match env::var("COLORTERM").as_deref() {
+            Ok("truecolor") | Ok("24bit") => ColorMode::TrueColor,
+            _ => ColorMode::Ansi256,
+        }
+    }
+
+    fn resolve(self) -> Self {
+        match self {
+            ColorMode::Auto => Self::detect(),
+            mode => mode,
+        }
+    }
+
+    fn cell(self, commit_count: Option<CommitCount>) -> String {
+        let Some(commit_count) = commit_count else {
+            return " ".repeat(CELL_WIDTH);
+        };
+        let level = commit_count as usize;
+        match self {
+            ColorMode::TrueColor => {
+                let [r, g, b] = Theme::Dark.cell(commit_count);
+                format!("\x1b[38;2;{r};{g};{b}m■{RESET} ")
+            }
+            ColorMode::Ansi256 => format!("\x1b[38;5;{}m■{RESET} ", ANSI_256_COLORS[level]),
+            ColorMode::Ascii | ColorMode::Auto => format!("{} ", ASCII_CHARS[level]),
+        }
+    }
+}
+
+/// Render the grid as a GitHub-like calendar, ready to be printed to a terminal
+pub fn render(grid: &CommitGrid, color_mode: ColorMode) -> String {
+    let color_mode = color_mode.resolve();
+    let calendar = Calendar::from_grid(grid);
+    let mut output = String::new();
+
+    // month labels
+    let mut header = " ".repeat(LABEL_WIDTH);
+    for (week, label) in calendar.month_labels(2) {
+        let column = LABEL_WIDTH + week * CELL_WIDTH;
+        if header.len() < column {
+            header.push_str(&" ".repeat(column - header.len()));
+        }
+        header.push_str(label);
+    }
+    output.push_str(header.trim_end());
+    output.push('\n');
+
+    // one row per weekday, starting on Sunday
+    for (weekday, label) in WEEKDAY_LABELS.iter().enumerate() {
+        output.push_str(&format!("{label:<LABEL_WIDTH$}"));
+        for week in 0..calendar.weeks() {
+            output.push_str(&color_mode.cell(calendar.get(week, weekday)));
+        }
+        output.push('\n');
+    }
+
+    // legend
+    output.push_str(&" ".repeat(LABEL_WIDTH));
+    output.push_str("Less ");
+    for commit_count in CommitCount::ALL {
+        output.push_str(&color_mode.cell(Some(commit_count)));
+    }
+    output.push_str("More\n");
+
+    output
+}
@@ -0,0 +1,193 @@
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+use crate::error::{Error, Result};
+use crate::visualizer::CommitCount;
+use crate::visualizer::anchor::VISIBLE_WEEKS;
+use crate::visualizer::calendar::DAYS_PER_WEEK;
+
+/// Longest raw pattern, 10 years of days
+pub const MAX_RAW_PATTERN_DAYS: usize = 10 * VISIBLE_WEEKS * DAYS_PER_WEEK;
+
+/// Commit counts of consecutive days written with characters 0 to 4, e.g. 0123401234.
+/// `|` or whitespace end a week early, the missing days of the week have no commit: 0440|044|4
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize)]
+#[serde(try_from = "RawPatternInput")]
+pub struct RawPattern(Vec<CommitCount>);
+
+/// A raw pattern is either written as text, or given as a list of commit counts
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawPatternInput {
+    Text(String),
+    Days(Vec<CommitCount>),
+}
+
+impl TryFrom<RawPatternInput> for RawPattern {
+    type Error = Error;
+
+    fn try_from(value: RawPatternInput) -> Result<Self> {
+        match value {
+            RawPatternInput::Text(text) => text.parse(),
+            RawPatternInput::Days(days) => RawPattern::new(days),
+        }
+    }
+}
+
+impl FromStr for RawPattern {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let is_separator = |ch: char| ch == '|' || ch.is_whitespace();
+        // checked first, so a long pattern isn't reported as a long week
+        let day_count = s.chars().filter(|ch| !is_separator(*ch)).count();
+        if day_count > MAX_RAW_PATTERN_DAYS {
+            return Err(Error::InvalidArg(format!(
+                "Raw pattern has {day_count} days, expected at most {MAX_RAW_PATTERN_DAYS}"
+            )));
+        }
+
+        let mut days = Vec::with_capacity(day_count);
+        let mut invalid = Vec::new();
+        let mut week_start = 0;
+
+        for (i, ch) in s.chars().enumerate() {
+            if is_separator(ch) {
+                let week_days = days.len() - week_start;
+                if week_days > DAYS_PER_WEEK {
+                    return Err(Error::InvalidArg(format!(
+                        "Week ending at position {} has {week_days} days, expected at most {DAYS_PER_WEEK}",
+                        i + 1
+                    )));
+                }
+                // consecutive separators end a single week
+                if week_days > 0 {
+                    days.resize(week_start + DAYS_PER_WEEK, CommitCount::Zero);
+                    week_start = days.len();
+                }
+                continue;
+            }
+            match CommitCount::from_digit(ch) {
+                Some(commit_count) => days.push(commit_count),
+                None => invalid.push(format!("{ch:?} at position {}", i + 1)),
+            }
+        }
+
+        if !invalid.is_empty() {
+            return Err(Error::InvalidArg(format!(
+                "Invalid characters in raw pattern, expected 0 to 4: {}",
+                invalid.join(", ")
+            )));
+        }
+        // when weeks are separated, the last one can't be longer than the others either
+        if week_start > 0 && days.len() - week_start > DAYS_PER_WEEK {
+            return Err(Error::InvalidArg(format!(
+                "Last week has {} days, expected at most {DAYS_PER_WEEK}",
+                days.len() - week_start
+            )));
+        }
+        RawPattern::new(days)
+    }
+}
+
+impl RawPattern {
+    pub fn new(days: Vec<CommitCount>) -> Result<Self> {
+        if days.is_empty() {
+            return Err(Error::InvalidArg("Raw pattern is empty".to_string()));
+        }
+        if days.len() > MAX_RAW_PATTERN_DAYS {
+            return Err(Error::InvalidArg(format!(
+                "Raw pattern has {} days, expected at most {MAX_RAW_PATTERN_DAYS}",
+                days.len()
+            )));
+        }
+        Ok(RawPattern(days))
+    }
+
+    pub fn into_days(self) -> Vec<CommitCount> {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> Vec<usize> {
+        let pattern: RawPattern = s.parse().unwrap();
+        pattern
+            .into_days()
+            .into_iter()
+            .map(|cc| cc as usize)
+            .collect()
+    }
+
+    fn parse_error(s: &str) -> String {
+        match s.parse::<RawPattern>() {
+            Err(Error::InvalidArg(message)) => message,
+            other => panic!("expected an InvalidArg error for {s:?}, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parses_days_and_weeks() {
+        assert_eq!(parse("0123401234"), [0, 1, 2, 3, 4, 0, 1, 2, 3, 4]);
+        assert_eq!(
+            parse("0440|044|4"),
+            [0, 4, 4, 0, 0, 0, 0, 0, 4, 4, 0, 0, 0, 0, 4]
+        );
+        // consecutive separators end a single week, and spaces are separators too
+        assert_eq!(parse("44|| 3"), [4, 4, 0, 0, 0, 0, 0, 3]);
+        assert_eq!(parse("1234012|"), [1, 2, 3, 4, 0, 1, 2]);
+    }
+
+    #[test]
+    fn reports_invalid_characters_with_positions() {
+        assert_eq!(
+            parse_error("01a3b"),
+            "Invalid characters in raw pattern, expected 0 to 4: 'a' at position 3, 'b' at position 5"
+        );
+        assert_eq!(
+            parse_error("04|5"),
+            "Invalid characters in raw pattern, expected 0 to 4: '5' at position 4"
+        );
+    }
+
+    #[test]
+    fn reports_long_weeks_with_positions() {
+        assert_eq!(
+            parse_error("01234012|0"),
+            "Week ending at position 9 has 8 days, expected at most 7"
+        );
+        assert_eq!(
+            parse_error("012|01234012"),
+            "Last week has 8 days, expected at most 7"
+        );
+    }
+
+    #[test]
+    fn reports_empty_and_long_patterns() {
+        assert_eq!(parse_error(""), "Raw pattern is empty");
+        assert_eq!(parse_error(" | "), "Raw pattern is empty");
+
+        let days = MAX_RAW_PATTERN_DAYS + 1;
+        let expected =
+            format!("Raw pattern has {days} days, expected at most {MAX_RAW_PATTERN_DAYS}");
+        assert_eq!(parse_error(&"1".repeat(days)), expected);
+        assert_eq!(parse_error(&format!("{}|", "1".repeat(days))), expected);
+        assert_eq!(
+            parse_error(&format!("1|{}", "1".repeat(days - 1))),
+            expected
+        );
+    }
+
+    #[test]
+    fn deserializes_text_and_lists() {
+        let text: RawPattern = serde_json::from_str(r#""04|4""#).unwrap();
+        assert_eq!(text.into_days().len(), 8);
+        let list: RawPattern = serde_json::from_str(r#"["Zero", "ALot"]"#).unwrap();
+        assert_eq!(list.into_days(), [CommitCount::Zero, CommitCount::ALot]);
+        assert!(serde_json::from_str::<RawPattern>(r#""012x""#).is_err());
+    }
+}
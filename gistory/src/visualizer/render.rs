@@ -0,0 +1,378 @@
+use std::fmt::Write;
+use std::io::Cursor;
+use std::path::Path;
+
+use image::{ImageFormat, Rgba, RgbaImage};
+use serde::{Deserialize, Serialize};
+use tokio::fs;
+
+use crate::error::{Error, Result};
+use crate::visualizer::calendar::{Calendar, DAYS_PER_WEEK, WEEKDAY_LABELS};
+use crate::visualizer::font::CHAR_HEIGHT;
+use crate::visualizer::{CommitCount, CommitGrid, Font};
+
+// Geometry of GitHub's contribution graph, in SVG units. PNG output multiplies them by a scale.
+const CELL_SIZE: u32 = 10;
+const CELL_GAP: u32 = 3;
+const CELL_STEP: u32 = CELL_SIZE + CELL_GAP;
+const CELL_RADIUS: u32 = 2;
+const PADDING: u32 = 10;
+const LEFT_MARGIN: u32 = 30;
+const TOP_MARGIN: u32 = 20;
+const LEGEND_HEIGHT: u32 = 25;
+const LEGEND_LABEL_GAP: u32 = 5;
+// The legend needs about 10 weeks of width
+const MIN_WEEKS: usize = 10;
+const FONT_SIZE: u32 = 9;
+const FONT_FAMILY: &str =
+    "-apple-system,BlinkMacSystemFont,&quot;Segoe UI&quot;,Helvetica,Arial,sans-serif";
+// Glyphs of the bitmap font used for PNG labels are rendered 1 unit per pixel
+const LABEL_FONT: Font = Font::SubwayTracker;
+
+pub type Color = [u8; 3];
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, clap::ValueEnum, Deserialize, Serialize)]
+pub enum Theme {
+    Light,
+    Dark,
+}
+
+impl Theme {
+    pub fn background(self) -> Color {
+        match self {
+            Theme::Light => [0xff, 0xff, 0xff],
+            Theme::Dark => [0x0d, 0x11, 0x17],
+        }
+    }
+
+    pub fn text(self) -> Color {
+        match self {
+            Theme::Light => [0x65, 0x6d, 0x76],
+            Theme::Dark => [0x7d, 0x85, 0x90],
+        }
+    }
+
+    pub fn cell(self, commit_count: CommitCount) -> Color {
+        let palette = match self {
+            Theme::Light => [
+                [0xeb, 0xed, 0xf0],
+                [0x9b, 0xe9, 0xa8],
+                [0x40, 0xc4, 0x63],
+                [0x30, 0xa1, 0x4e],
+                [0x21, 0x6e, 0x39],
+            ],
+            Theme::Dark => [
+                [0x16, 0x1b, 0x22],
+                [0x0e, 0x44, 0x29],
+                [0x00, 0x6d, 0x32],
+                [0x26, 0xa6, 0x41],
+                [0x39, 0xd3, 0x53],
+            ],
+        };
+        palette[commit_count as usize]
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, clap::ValueEnum, Deserialize, Serialize)]
+pub enum RenderFormat {
+    Svg,
+    Png,
+}
+
+impl RenderFormat {
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self> {
+        let extension = path
+            .as_ref()
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext.to_ascii_lowercase());
+        match extension.as_deref() {
+            Some("svg") => Ok(RenderFormat::Svg),
+            Some("png") => Ok(RenderFormat::Png),
+            _ => Err(Error::InvalidArg(
+                "Output file must have extension .svg or .png".to_string(),
+            )),
+        }
+    }
+
+    pub fn content_type(self) -> &'static str {
+        match self {
+            RenderFormat::Svg => "image/svg+xml",
+            RenderFormat::Png => "image/png",
+        }
+    }
+}
+
+struct Layout {
+    calendar: Calendar,
+    width: u32,
+    height: u32,
+}
+
+impl Layout {
+    fn new(grid: &CommitGrid) -> Self {
+        let calendar = Calendar::from_grid(grid);
+        let weeks = calendar.weeks().max(MIN_WEEKS) as u32;
+        let width = 2 * PADDING + LEFT_MARGIN + weeks * CELL_STEP - CELL_GAP;
+        let height =
+            2 * PADDING + TOP_MARGIN + DAYS_PER_WEEK as u32 * CELL_STEP - CELL_GAP + LEGEND_HEIGHT;
+        Self {
+            calendar,
+            width,
+            height,
+        }
+    }
+
+    fn cell_position(&self, week: usize, weekday: usize) -> (u32, u32) {
+        (
+            PADDING + LEFT_MARGIN + week as u32 * CELL_STEP,
+            PADDING + TOP_MARGIN + weekday as u32 * CELL_STEP,
+        )
+    }
+
+    fn legend_top(&self) -> u32 {
+        self.height - PADDING - CELL_SIZE
+    }
+
+    fn legend_cell_x(&self, i: usize) -> u32 {
+        let legend_cells = CommitCount::ALL.len() as u32;
+        self.width - PADDING - LEGEND_LABEL_GAP - LEFT_MARGIN - legend_cells * CELL_STEP
+            + i as u32 * CELL_STEP
+    }
+}
+
+fn hex(color: Color) -> String {
+    format!("#{:02x}{:02x}{:02x}", color[0], color[1], color[2])
+}
+
+/// Render the grid like GitHub's contribution graph as an SVG document
+pub fn render_svg(grid: &CommitGrid, theme: Theme) -> String {
+    let layout = Layout::new(grid);
+    let calendar = &layout.calendar;
+    let mut svg = String::new();
+
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}">"#,
+        w = layout.width,
+        h = layout.height,
+    );
+    let _ = writeln!(
+        svg,
+        r#"<rect width="100%" height="100%" fill="{}"/>"#,
+        hex(theme.background())
+    );
+    let _ = writeln!(
+        svg,
+        r#"<g font-family="{FONT_FAMILY}" font-size="{FONT_SIZE}" fill="{}">"#,
+        hex(theme.text())
+    );
+    for (week, label) in calendar.month_labels(2) {
+        let (x, y) = layout.cell_position(week, 0);
+        let _ = writeln!(svg, r#"<text x="{x}" y="{}">{label}</text>"#, y - 7);
+    }
+    for (weekday, label) in WEEKDAY_LABELS.iter().enumerate() {
+        if label.is_empty() {
+            continue;
+        }
+        let (_, y) = layout.cell_position(0, weekday);
+        let _ = writeln!(
+            svg,
+            r#"<text x="{PADDING}" y="{}">{label}</text>"#,
+            y + FONT_SIZE
+        );
+    }
+    let legend_y = layout.legend_top() + FONT_SIZE;
+    let _ = writeln!(
+        svg,
+        r#"<text x="{}" y="{legend_y}" text-anchor="end">Less</text>"#,
+        layout.legend_cell_x(0) - LEGEND_LABEL_GAP
+    );
+    let _ = writeln!(
+        svg,
+        r#"<text x="{}" y="{legend_y}">More</text>"#,
+        layout.legend_cell_x(CommitCount::ALL.len()) - CELL_GAP + LEGEND_LABEL_GAP
+    );
+    let _ = writeln!(svg, "</g>");
+
+    let cell = |svg: &mut String, x: u32, y: u32, commit_count: CommitCount, title: String| {
+        let _ = writeln!(
+            svg,
+            r#"<rect x="{x}" y="{y}" width="{CELL_SIZE}" height="{CELL_SIZE}" rx="{CELL_RADIUS}" ry="{CELL_RADIUS}" fill="{}"><title>{title}</title></rect>"#,
+            hex(theme.cell(commit_count))
+        );
+    };
+
+    for week in 0..calendar.weeks() {
+        for weekday in 0..DAYS_PER_WEEK {
+            let Some(commit_count) = calendar.get(week, weekday) else {
+                continue;
+            };
+            let (x, y) = layout.cell_position(week, weekday);
+            let date = calendar.week_start(week) + chrono::TimeDelta::days(weekday as i64);
+            cell(
+                &mut svg,
+                x,
+                y,
+                commit_count,
+                format!("{date}: {commit_count:?}"),
+            );
+        }
+    }
+    for (i, commit_count) in CommitCount::ALL.into_iter().enumerate() {
+        let x = layout.legend_cell_x(i);
+        cell(
+            &mut svg,
+            x,
+            layout.legend_top(),
+            commit_count,
+            format!("{commit_count:?}"),
+        );
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Render the grid like GitHub's contribution graph as a PNG image.
+/// `scale` multiplies the size of the image, 1 means 1 pixel per SVG unit.
+pub fn render_png(grid: &CommitGrid, theme: Theme, scale: u32) -> Result<Vec<u8>> {
+    if scale == 0 {
+        return Err(Error::InvalidArg("scale must be > 0".to_string()));
+    }
+    let layout = Layout::new(grid);
+    let calendar = &layout.calendar;
+    let mut canvas = Canvas {
+        image: RgbaImage::from_pixel(
+            layout.width * scale,
+            layout.height * scale,
+            rgba(theme.background()),
+        ),
+        scale,
+    };
+    let text_color = theme.text();
+
+    for (week, label) in calendar.month_labels(2) {
+        let (x, y) = layout.cell_position(week, 0);
+        canvas.draw_text(x, y - 5 - CHAR_HEIGHT as u32, label, text_color);
+    }
+    for (weekday, label) in WEEKDAY_LABELS.iter().enumerate() {
+        let (_, y) = layout.cell_position(0, weekday);
+        canvas.draw_text(PADDING, y + 1, label, text_color);
+    }
+
+    for week in 0..calendar.weeks() {
+        for weekday in 0..DAYS_PER_WEEK {
+            if let Some(commit_count) = calendar.get(week, weekday) {
+                let (x, y) = layout.cell_position(week, weekday);
+                canvas.fill_cell(x, y, theme.cell(commit_count));
+            }
+        }
+    }
+
+    let legend_y = layout.legend_top();
+    let less_x = layout.legend_cell_x(0) - LEGEND_LABEL_GAP - text_width("Less");
+    canvas.draw_text(less_x, legend_y + 1, "Less", text_color);
+    for (i, commit_count) in CommitCount::ALL.into_iter().enumerate() {
+        canvas.fill_cell(layout.legend_cell_x(i), legend_y, theme.cell(commit_count));
+    }
+    let more_x = layout.legend_cell_x(CommitCount::ALL.len()) - CELL_GAP + LEGEND_LABEL_GAP;
+    canvas.draw_text(more_x, legend_y + 1, "More", text_color);
+
+    let mut buffer = Cursor::new(Vec::new());
+    canvas
+        .image
+        .write_to(&mut buffer, ImageFormat::Png)
+        .map_err(|e| Error::InvalidData(format!("Failed to encode image: {e}")))?;
+    Ok(buffer.into_inner())
+}
+
+/// Render the grid in the given format. `scale` only applies to PNG images.
+pub fn render(
+    grid: &CommitGrid,
+    theme: Theme,
+    format: RenderFormat,
+    scale: u32,
+) -> Result<Vec<u8>> {
+    match format {
+        RenderFormat::Svg => Ok(render_svg(grid, theme).into_bytes()),
+        RenderFormat::Png => render_png(grid, theme, scale),
+    }
+}
+
+pub async fn save(
+    grid: &CommitGrid,
+    theme: Theme,
+    scale: u32,
+    path: impl AsRef<Path>,
+) -> Result<()> {
+    let format = RenderFormat::from_path(&path)?;
+    let content = render(grid, theme, format, scale)?;
+    fs::write(path, content).await?;
+    Ok(())
+}
+
+fn rgba(color: Color) -> Rgba<u8> {
+    Rgba([color[0], color[1], color[2], 0xff])
+}
+
+fn text_width(text: &str) -> u32 {
+    text.chars()
+        .filter_map(|ch| LABEL_FONT.get_char(ch))
+        .map(|ch| ch.width() as u32 + 1)
+        .sum::<u32>()
+        .saturating_sub(1)
+}
+
+struct Canvas {
+    image: RgbaImage,
+    scale: u32,
+}
+
+impl Canvas {
+    fn fill_unit(&mut self, x: u32, y: u32, color: Color) {
+        for dx in 0..self.scale {
+            for dy in 0..self.scale {
+                self.image
+                    .put_pixel(x * self.scale + dx, y * self.scale + dy, rgba(color));
+            }
+        }
+    }
+
+    fn fill_cell(&mut self, x: u32, y: u32, color: Color) {
+        // a square with rounded corners, computed at pixel level so it stays smooth when scaled
+        let size = (CELL_SIZE * self.scale) as i64;
+        let radius = (CELL_RADIUS * self.scale) as i64;
+        for py in 0..size {
+            for px in 0..size {
+                let cx = px.clamp(radius, size - 1 - radius);
+                let cy = py.clamp(radius, size - 1 - radius);
+                if (px - cx).pow(2) + (py - cy).pow(2) > radius.pow(2) {
+                    continue;
+                }
+                self.image.put_pixel(
+                    x * self.scale + px as u32,
+                    y * self.scale + py as u32,
+                    rgba(color),
+                );
+            }
+        }
+    }
+
+    fn draw_text(&mut self, x: u32, y: u32, text: &str, color: Color) {
+        let mut cursor = x;
+        for ch in text.chars() {
+            let Some(ch) = LABEL_FONT.get_char(ch) else {
+                continue;
+            };
+            for (row, line) in ch.data.iter().enumerate() {
+                for (column, pixel) in line.bytes().enumerate() {
+                    if pixel == b'x' {
+                        self.fill_unit(cursor + column as u32, y + row as u32, color);
+                    }
+                }
+            }
+            cursor += ch.width() as u32 + 1;
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use super::sprite_library::SPRITES;
+use crate::error::{Error, Result};
+use crate::visualizer::calendar::DAYS_PER_WEEK;
+use crate::visualizer::{CommitCount, CommitGrid};
+
+/// A small drawing of up to 7 rows, `x` for a day with commits and `.` for a day without
+pub struct Sprite {
+    pub description: &'static str,
+    pub(crate) data: &'static [&'static str],
+}
+
+impl Sprite {
+    /// Number of weeks of the sprite
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.data.first().map_or(0, |row| row.len())
+    }
+
+    /// Number of days of the week of the sprite
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Days of the sprite, one column of 7 days per week.
+    /// Sprites of less than 7 rows are centered in the week.
+    pub fn days(&self, commit_count: CommitCount) -> Vec<CommitCount> {
+        let top = (DAYS_PER_WEEK - self.height()) / 2;
+        let mut res = vec![CommitCount::Zero; self.width() * DAYS_PER_WEEK];
+
+        for (j, row) in self.data.iter().enumerate() {
+            for (i, pixel) in row.bytes().enumerate() {
+                res[i * DAYS_PER_WEEK + top + j] = match pixel {
+                    b'x' => commit_count,
+                    b'.' => CommitCount::Zero,
+                    _ => panic!("Invalid char in sprite"),
+                };
+            }
+        }
+        res
+    }
+}
+
+/// The built-in sprite with the given name
+pub fn get(name: &str) -> Option<&'static Sprite> {
+    SPRITES.get(name)
+}
+
+/// Names of the built-in sprites, in alphabetical order
+pub fn names() -> Vec<&'static str> {
+    let mut names: Vec<&'static str> = SPRITES.keys().copied().collect();
+    names.sort();
+    names
+}
+
+impl CommitGrid {
+    /// Draw a built-in sprite from the start date
+    pub fn show_sprite(&mut self, name: &str, commit_count: CommitCount) -> Result<()> {
+        let Some(sprite) = get(name) else {
+            return Err(Error::InvalidArg(format!(
+                "Unknown sprite {name:?}. Sprites: {}",
+                names().join(", ")
+            )));
+        };
+        self.set_data(sprite.days(commit_count));
+        Ok(())
+    }
+}
@@ -0,0 +1,283 @@
+use phf::phf_map;
+
+use crate::visualizer::sprite::Sprite;
+
+pub const SPRITES: phf::Map<&'static str, Sprite> = phf_map! {
+
+"arrow-down" => Sprite {
+    description: "Arrow pointing down",
+    data: &[
+        "..xxx..",
+        "..xxx..",
+        "..xxx..",
+        "xxxxxxx",
+        ".xxxxx.",
+        "..xxx..",
+        "...x...",
+    ],
+},
+
+"arrow-left" => Sprite {
+    description: "Arrow pointing left",
+    data: &[
+        "...x...",
+        "..xx...",
+        ".xxxxxx",
+        "xxxxxxx",
+        ".xxxxxx",
+        "..xx...",
+        "...x...",
+    ],
+},
+
+"arrow-right" => Sprite {
+    description: "Arrow pointing right",
+    data: &[
+        "...x...",
+        "...xx..",
+        "xxxxxx.",
+        "xxxxxxx",
+        "xxxxxx.",
+        "...xx..",
+        "...x...",
+    ],
+},
+
+"arrow-up" => Sprite {
+    description: "Arrow pointing up",
+    data: &[
+        "...x...",
+        "..xxx..",
+        ".xxxxx.",
+        "xxxxxxx",
+        "..xxx..",
+        "..xxx..",
+        "..xxx..",
+    ],
+},
+
+"border" => Sprite {
+    description: "Rounded rectangle, to frame a short text",
+    data: &[
+        ".xxxxxxxxxxx.",
+        "x...........x",
+        "x...........x",
+        "x...........x",
+        "x...........x",
+        "x...........x",
+        ".xxxxxxxxxxx.",
+    ],
+},
+
+"cat" => Sprite {
+    description: "Cat face",
+    data: &[
+        "x.....x",
+        "xx...xx",
+        "xxxxxxx",
+        "x.xxx.x",
+        "xxxxxxx",
+        "xxx.xxx",
+        ".xxxxx.",
+    ],
+},
+
+"check" => Sprite {
+    description: "Check mark",
+    data: &[
+        "......x",
+        ".....xx",
+        "x...xx.",
+        "xx.xx..",
+        ".xxx...",
+        "..x....",
+    ],
+},
+
+"checker" => Sprite {
+    description: "Checkerboard border, repeat it to make a line",
+    data: &[
+        "x.",
+        ".x",
+        "x.",
+        ".x",
+        "x.",
+        ".x",
+        "x.",
+    ],
+},
+
+"cross" => Sprite {
+    description: "Diagonal cross",
+    data: &[
+        "x...x",
+        ".x.x.",
+        "..x..",
+        ".x.x.",
+        "x...x",
+    ],
+},
+
+"diamond" => Sprite {
+    description: "Diamond",
+    data: &[
+        "...x...",
+        "..xxx..",
+        ".xxxxx.",
+        "xxxxxxx",
+        ".xxxxx.",
+        "..xxx..",
+        "...x...",
+    ],
+},
+
+"ghost" => Sprite {
+    description: "Pac-Man ghost",
+    data: &[
+        "..xxx..",
+        ".xxxxx.",
+        "xx.x.xx",
+        "xxxxxxx",
+        "xxxxxxx",
+        "xxxxxxx",
+        "x.x.x.x",
+    ],
+},
+
+"heart" => Sprite {
+    description: "Heart",
+    data: &[
+        ".xx.xx.",
+        "xxxxxxx",
+        "xxxxxxx",
+        "xxxxxxx",
+        ".xxxxx.",
+        "..xxx..",
+        "...x...",
+    ],
+},
+
+"heart-small" => Sprite {
+    description: "Small heart",
+    data: &[
+        ".x.x.",
+        "xxxxx",
+        ".xxx.",
+        "..x..",
+    ],
+},
+
+"house" => Sprite {
+    description: "House",
+    data: &[
+        "...x...",
+        "..xxx..",
+        ".xxxxx.",
+        "xxxxxxx",
+        ".x...x.",
+        ".x.x.x.",
+        ".x.x.x.",
+    ],
+},
+
+"invader" => Sprite {
+    description: "Space Invaders crab",
+    data: &[
+        "..x.....x..",
+        "...x...x...",
+        "..xxxxxxx..",
+        ".xx.xxx.xx.",
+        "xxxxxxxxxxx",
+        "x.xxxxxxx.x",
+        "x.x.....x.x",
+    ],
+},
+
+"mushroom" => Sprite {
+    description: "Mushroom",
+    data: &[
+        "..xxx..",
+        ".xx.xx.",
+        "xxxxxxx",
+        "x.xxx.x",
+        "..x.x..",
+        "..x.x..",
+        "..xxx..",
+    ],
+},
+
+"pacman" => Sprite {
+    description: "Pac-Man",
+    data: &[
+        "..xxx..",
+        ".xxxxx.",
+        "xxxx...",
+        "xxx....",
+        "xxxx...",
+        ".xxxxx.",
+        "..xxx..",
+    ],
+},
+
+"skull" => Sprite {
+    description: "Skull",
+    data: &[
+        ".xxxxx.",
+        "xxxxxxx",
+        "x..x..x",
+        "xxxxxxx",
+        ".xx.xx.",
+        "..xxx..",
+        "..x.x..",
+    ],
+},
+
+"smiley" => Sprite {
+    description: "Smiling face",
+    data: &[
+        ".xxxxx.",
+        "x.....x",
+        "x.x.x.x",
+        "x.....x",
+        "x.xxx.x",
+        "x.....x",
+        ".xxxxx.",
+    ],
+},
+
+"star" => Sprite {
+    description: "Star",
+    data: &[
+        "...x...",
+        "...x...",
+        "xxxxxxx",
+        ".xxxxx.",
+        "..xxx..",
+        ".xx.xx.",
+        "x.....x",
+    ],
+},
+
+"tree" => Sprite {
+    description: "Pine tree",
+    data: &[
+        "...x...",
+        "..xxx..",
+        ".xxxxx.",
+        "..xxx..",
+        ".xxxxx.",
+        "xxxxxxx",
+        "...x...",
+    ],
+},
+
+"zigzag" => Sprite {
+    description: "Zigzag border, repeat it to make a line",
+    data: &[
+        "x.....",
+        ".x...x",
+        "..x.x.",
+        "...x..",
+    ],
+},
+};
@@ -0,0 +1,157 @@
+use std::fmt;
+
+use chrono::{Datelike, NaiveDate, TimeDelta};
+
+use crate::visualizer::calendar::Calendar;
+use crate::visualizer::{CommitCount, CommitGrid, CommitLevels};
+
+/// Number of commits above which creating the repo deserves a second thought
+pub const LARGE_COMMIT_COUNT: usize = 10_000;
+
+/// Average size of a zlib-compressed commit object written by `Repo::add_commit`
+const COMMIT_OBJECT_SIZE: u64 = 160;
+
+/// Smallest space a file takes on most file systems, which every loose object does
+const FILE_SYSTEM_BLOCK_SIZE: u64 = 4096;
+
+/// Figures about a grid and the repo it would create
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct GridStats {
+    /// Number of commits, given the commit levels
+    pub total_commits: usize,
+    /// Number of days of the grid, with or without commits
+    pub days: usize,
+    /// Number of days with commits
+    pub active_days: usize,
+    /// Number of columns of the contribution graph, weeks starting on Sunday
+    pub weeks: usize,
+    /// Calendar years of the days with commits
+    pub years: Vec<i32>,
+    /// Most consecutive days with commits
+    pub longest_streak: usize,
+    /// First day of the longest streak, the earliest one when several are as long
+    pub longest_streak_start: Option<NaiveDate>,
+    /// Number of git objects: one commit per commit, and the empty tree they all share
+    pub objects: usize,
+    /// Estimate of the size of the loose objects, in bytes
+    pub objects_size: u64,
+    /// Estimate of the disk space of the loose objects, each taking at least a file system block
+    pub disk_size: u64,
+}
+
+impl GridStats {
+    /// Whether creating the repo would create a surprisingly large number of commits
+    pub fn is_large(&self) -> bool {
+        self.total_commits > LARGE_COMMIT_COUNT
+    }
+}
+
+/// A number of bytes in B, KiB, MiB or GiB
+fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{size:.1} {}", UNITS[unit])
+    }
+}
+
+impl fmt::Display for GridStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let years = match (self.years.first(), self.years.last()) {
+            (Some(first), Some(last)) if first == last => format!("{first}"),
+            (Some(first), Some(last)) => format!("{first}-{last} ({})", self.years.len()),
+            _ => "none".to_string(),
+        };
+        let streak = match self.longest_streak_start {
+            Some(start) => format!("{} days from {start}", self.longest_streak),
+            None => "none".to_string(),
+        };
+
+        writeln!(f, "Commits:        {}", self.total_commits)?;
+        writeln!(
+            f,
+            "Days:           {} ({} with commits)",
+            self.days, self.active_days
+        )?;
+        writeln!(f, "Weeks:          {}", self.weeks)?;
+        writeln!(f, "Years:          {years}")?;
+        writeln!(f, "Longest streak: {streak}")?;
+        writeln!(f, "Git objects:    {}", self.objects)?;
+        writeln!(
+            f,
+            "Repo size:      ~{} of objects, ~{} on disk",
+            format_size(self.objects_size),
+            format_size(self.disk_size)
+        )?;
+        if self.is_large() {
+            writeln!(
+                f,
+                "Warning: more than {LARGE_COMMIT_COUNT} commits, check the commit levels"
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl CommitGrid {
+    /// Figures about the grid, and an estimate of the repo it would create with the given commit levels
+    pub fn stats(&self, levels: &CommitLevels) -> GridStats {
+        let start_date = self.get_start_date();
+        let data = self.get_data();
+
+        let total_commits: usize = data.iter().map(|cc| levels.commits_for(*cc)).sum();
+        let active_days = data.iter().filter(|cc| **cc != CommitCount::Zero).count();
+
+        let mut years: Vec<i32> = data
+            .iter()
+            .enumerate()
+            .filter(|(_, cc)| **cc != CommitCount::Zero)
+            .map(|(i, _)| (start_date + TimeDelta::days(i as i64)).year())
+            .collect();
+        years.dedup();
+
+        let mut longest_streak = 0;
+        let mut longest_streak_start = None;
+        let mut streak = 0;
+        for (i, commit_count) in data.iter().enumerate() {
+            if *commit_count == CommitCount::Zero {
+                streak = 0;
+                continue;
+            }
+            streak += 1;
+            if streak > longest_streak {
+                longest_streak = streak;
+                longest_streak_start = Some(start_date + TimeDelta::days((i + 1 - streak) as i64));
+            }
+        }
+
+        // commits all point to the same empty tree
+        let objects = total_commits + usize::from(total_commits > 0);
+        let objects_size = objects as u64 * COMMIT_OBJECT_SIZE;
+        let disk_size = objects as u64 * FILE_SYSTEM_BLOCK_SIZE;
+
+        GridStats {
+            total_commits,
+            days: data.len(),
+            active_days,
+            weeks: if data.is_empty() {
+                0
+            } else {
+                Calendar::from_grid(self).weeks()
+            },
+            years,
+            longest_streak,
+            longest_streak_start,
+            objects,
+            objects_size,
+            disk_size,
+        }
+    }
+}
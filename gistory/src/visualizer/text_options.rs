@@ -0,0 +1,225 @@
+use log::warn;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Error, Result};
+use crate::visualizer::CommitCount;
+use crate::visualizer::font::{CHAR_HEIGHT, Fallback, Pixel};
+
+/// Where the text goes when `TextOptions::width` is wider than the text
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, clap::ValueEnum, Deserialize, Serialize)]
+pub enum Alignment {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+/// How commit counts are given to the pixels of the text
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, clap::ValueEnum, Deserialize, Serialize)]
+pub enum Shading {
+    /// Every pixel of the text gets the same commit count
+    #[default]
+    Solid,
+    /// The text is surrounded by an outline with the `shade` commit count
+    Outline,
+    /// The commit count goes from `shade` at the first column to the text commit count at the last one
+    Gradient,
+}
+
+/// A glyph as found in a font: its pixels column by column and whether it separates words
+#[derive(Debug, Clone)]
+pub struct Glyph {
+    pub pixels: Vec<Pixel>,
+    pub is_space: bool,
+}
+
+/// How text is laid out on the grid
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Deserialize, Serialize)]
+#[serde(default)]
+pub struct TextOptions {
+    pub fallback: Fallback,
+    /// Blank columns between characters
+    pub letter_spacing: usize,
+    /// Blank columns between words, replacing the space character and the letter spacing around it
+    pub word_spacing: Option<usize>,
+    /// Remove the letter spacing between characters whose pixels don't touch, like T and o
+    pub kerning: bool,
+    /// Blank columns before the text
+    pub leading: usize,
+    /// Number of weeks to align the text in. Default to the width of the text
+    pub width: Option<usize>,
+    pub alignment: Alignment,
+    pub shading: Shading,
+    /// Commit count of the outline or of the start of the gradient
+    pub shade: CommitCount,
+}
+
+impl Default for TextOptions {
+    fn default() -> Self {
+        Self {
+            fallback: Fallback::Replace,
+            letter_spacing: 1,
+            word_spacing: None,
+            kerning: false,
+            leading: 0,
+            width: None,
+            alignment: Alignment::Left,
+            shading: Shading::Solid,
+            shade: CommitCount::Few,
+        }
+    }
+}
+
+type Column = [Pixel; CHAR_HEIGHT];
+
+fn columns(pixels: &[Pixel]) -> Vec<Column> {
+    pixels
+        .chunks(CHAR_HEIGHT)
+        .map(|chunk| {
+            let mut column = [Pixel::Off; CHAR_HEIGHT];
+            column[..chunk.len()].copy_from_slice(chunk);
+            column
+        })
+        .collect()
+}
+
+/// Whether two adjacent columns have pixels touching each other, including diagonally
+fn touching(left: &Column, right: &Column) -> bool {
+    (0..CHAR_HEIGHT).any(|y| {
+        left[y] == Pixel::On
+            && (y.saturating_sub(1)..=(y + 1).min(CHAR_HEIGHT - 1)).any(|y2| right[y2] == Pixel::On)
+    })
+}
+
+impl TextOptions {
+    pub fn validate(&self) -> Result<()> {
+        if self.width == Some(0) {
+            return Err(Error::InvalidArg("text width must be > 0".to_string()));
+        }
+        Ok(())
+    }
+
+    /// Number of weeks taken by the glyphs, without leading or alignment
+    pub fn text_width(&self, glyphs: &[Glyph]) -> usize {
+        self.arrange(glyphs).len()
+    }
+
+    /// Number of weeks of the data of `layout`: the leading, the aligned text,
+    /// and without width, the letter spacing after the text where its outline can be drawn
+    pub fn layout_width(&self, glyphs: &[Glyph]) -> usize {
+        let content_width = self.text_width(glyphs);
+        match self.width {
+            None => self.leading + content_width + self.letter_spacing,
+            Some(width) => self.leading + width.saturating_sub(self.leading).max(content_width),
+        }
+    }
+
+    /// Place the glyphs next to each other with letter and word spacing
+    fn arrange(&self, glyphs: &[Glyph]) -> Vec<Column> {
+        let blank = [Pixel::Off; CHAR_HEIGHT];
+        let mut text: Vec<Column> = Vec::new();
+        let mut after_word_space = true;
+        for glyph in glyphs {
+            if glyph.is_space
+                && let Some(word_spacing) = self.word_spacing
+            {
+                text.extend(std::iter::repeat_n(blank, word_spacing));
+                after_word_space = true;
+                continue;
+            }
+            let glyph_columns = columns(&glyph.pixels);
+            if !after_word_space {
+                let mut spacing = self.letter_spacing;
+                if self.kerning
+                    && let (Some(left), Some(right)) = (text.last(), glyph_columns.first())
+                    && !touching(left, right)
+                {
+                    spacing = 0;
+                }
+                text.extend(std::iter::repeat_n(blank, spacing));
+            }
+            text.extend(glyph_columns);
+            after_word_space = false;
+        }
+
+        text
+    }
+
+    /// Place the glyphs next to each other, then align and shade them.
+    /// Returns commit counts column by column, like `CommitGrid` data.
+    pub fn layout(&self, glyphs: &[Glyph], commit_count: CommitCount) -> Result<Vec<CommitCount>> {
+        self.validate()?;
+
+        let blank = [Pixel::Off; CHAR_HEIGHT];
+        let text = self.arrange(glyphs);
+        let content_width = text.len();
+        let (before, after) = match self.width {
+            None => (0, self.letter_spacing),
+            Some(width) => {
+                let width = width.saturating_sub(self.leading);
+                if content_width > width {
+                    warn!(
+                        "Text is {content_width} weeks wide, wider than the {width} weeks to align it in"
+                    );
+                }
+                let free = width.saturating_sub(content_width);
+                match self.alignment {
+                    Alignment::Left => (0, free),
+                    Alignment::Center => (free / 2, free - free / 2),
+                    Alignment::Right => (free, 0),
+                }
+            }
+        };
+
+        let mut placed = vec![blank; self.leading + before];
+        placed.extend(text);
+        placed.extend(std::iter::repeat_n(blank, after));
+        let start = self.leading + before;
+        let end = start + content_width;
+
+        let mut data = Vec::with_capacity(placed.len() * CHAR_HEIGHT);
+        for (x, column) in placed.iter().enumerate() {
+            for (y, pixel) in column.iter().enumerate() {
+                data.push(self.shade(&placed, x, y, *pixel, (start, end), commit_count));
+            }
+        }
+        Ok(data)
+    }
+
+    fn shade(
+        &self,
+        columns: &[Column],
+        x: usize,
+        y: usize,
+        pixel: Pixel,
+        (start, end): (usize, usize),
+        commit_count: CommitCount,
+    ) -> CommitCount {
+        match (self.shading, pixel) {
+            (Shading::Solid, Pixel::On) | (Shading::Outline, Pixel::On) => commit_count,
+            (Shading::Solid, Pixel::Off) | (Shading::Gradient, Pixel::Off) => CommitCount::Zero,
+            (Shading::Outline, Pixel::Off) => {
+                let neighbour_on = (x.saturating_sub(1)..=x + 1).any(|nx| {
+                    (y.saturating_sub(1)..=(y + 1).min(CHAR_HEIGHT - 1))
+                        .any(|ny| columns.get(nx).is_some_and(|c| c[ny] == Pixel::On))
+                });
+                if neighbour_on {
+                    self.shade
+                } else {
+                    CommitCount::Zero
+                }
+            }
+            (Shading::Gradient, Pixel::On) => {
+                let (from, to) = (self.shade as usize, commit_count as usize);
+                let steps = (end - start).saturating_sub(1).max(1);
+                let progress = x.saturating_sub(start).min(steps);
+                let level = if to >= from {
+                    from + (to - from) * progress / steps
+                } else {
+                    from - (from - to) * progress / steps
+                };
+                CommitCount::ALL[level]
+            }
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use chrono::{NaiveDate, TimeDelta};
+use log::warn;
+
+use crate::error::{Error, Result};
+use crate::visualizer::calendar::DAYS_PER_WEEK;
+use crate::visualizer::{CommitCount, CommitGrid};
+
+type Column = [CommitCount; DAYS_PER_WEEK];
+
+// Transforms work on columns of 7 days starting at start_date, like pattern files.
+// The last column is padded with Zero.
+fn to_columns(data: &[CommitCount]) -> Vec<Column> {
+    data.chunks(DAYS_PER_WEEK)
+        .map(|days| {
+            let mut column = [CommitCount::Zero; DAYS_PER_WEEK];
+            column[..days.len()].copy_from_slice(days);
+            column
+        })
+        .collect()
+}
+
+fn from_columns(columns: Vec<Column>) -> Vec<CommitCount> {
+    columns.into_iter().flatten().collect()
+}
+
+impl CommitGrid {
+    fn replace(&mut self, start_date: NaiveDate, data: Vec<CommitCount>) {
+        *self = CommitGrid::new(start_date);
+        self.set_data(data);
+    }
+
+    /// Move the whole grid in time. Negative values move it to the past.
+    pub fn shift(&mut self, weeks: i64, days: i64) -> Result<()> {
+        let data = self.get_data().to_vec();
+        let start_date = TimeDelta::try_weeks(weeks)
+            .zip(TimeDelta::try_days(days))
+            .and_then(|(weeks, days)| weeks.checked_add(&days))
+            .and_then(|delta| self.get_start_date().checked_add_signed(delta))
+            .filter(|start_date| {
+                start_date
+                    .checked_add_signed(TimeDelta::days(data.len() as i64))
+                    .is_some()
+            })
+            .ok_or_else(|| {
+                Error::InvalidArg(format!(
+                    "Shifting the grid by {weeks} weeks and {days} days is out of range"
+                ))
+            })?;
+        self.replace(start_date, data);
+        Ok(())
+    }
+
+    /// Reverse the order of the weeks, left becomes right
+    pub fn mirror(&mut self) {
+        let mut columns = to_columns(self.get_data());
+        columns.reverse();
+        self.set_data(from_columns(columns));
+    }
+
+    /// Reverse the days within each week, top becomes bottom
+    pub fn flip(&mut self) {
+        let mut columns = to_columns(self.get_data());
+        columns.iter_mut().for_each(|column| column.reverse());
+        self.set_data(from_columns(columns));
+    }
+
+    /// Swap commit counts: Zero <-> ALot, Few <-> Many
+    pub fn invert(&mut self) {
+        let data = self
+            .get_data()
+            .iter()
+            .map(|c| CommitCount::ALL[CommitCount::ALot as usize - *c as usize])
+            .collect();
+        self.set_data(data);
+    }
+
+    /// Keep only the days between `from` and `to`, inclusive
+    pub fn crop(&mut self, from: NaiveDate, to: NaiveDate) -> Result<()> {
+        if from > to {
+            return Err(Error::InvalidArg(
+                "crop end must be >= crop start".to_string(),
+            ));
+        }
+        let start_date = self.get_start_date().max(from);
+        let skip = (start_date - self.get_start_date()).num_days() as usize;
+        let take = ((to - start_date).num_days() + 1).max(0) as usize;
+        let data = self
+            .get_data()
+            .iter()
+            .copied()
+            .skip(skip)
+            .take(take)
+            .collect();
+        self.replace(start_date, data);
+        Ok(())
+    }
+
+    /// Repeat the pattern, padded to whole weeks, until `end_date`
+    pub fn tile(&mut self, end_date: NaiveDate) -> Result<()> {
+        if self.get_start_date() > end_date {
+            return Err(Error::InvalidArg(
+                "end_date must be >= start_date".to_string(),
+            ));
+        }
+        let pattern = from_columns(to_columns(self.get_data()));
+        if pattern.is_empty() {
+            return Ok(());
+        }
+        let days = (end_date - self.get_start_date()).num_days() as usize + 1;
+        let data = pattern.into_iter().cycle().take(days).collect();
+        self.set_data(data);
+        Ok(())
+    }
+
+    /// Enlarge the pattern by an integer factor in both directions.
+    /// Days going past the 7th row are dropped.
+    pub fn scale(&mut self, factor: usize) -> Result<()> {
+        if factor == 0 {
+            return Err(Error::InvalidArg("scale factor must be > 0".to_string()));
+        }
+        let columns = to_columns(self.get_data());
+        let mut scaled = Vec::with_capacity(columns.len() * factor);
+        let mut dropped = 0;
+
+        for column in columns.iter() {
+            let mut scaled_column = [CommitCount::Zero; DAYS_PER_WEEK];
+            for (row, commit_count) in column.iter().enumerate() {
+                for i in 0..factor {
+                    match scaled_column.get_mut(row * factor + i) {
+                        Some(cell) => *cell = *commit_count,
+                        None if *commit_count != CommitCount::Zero => dropped += 1,
+                        None => {}
+                    }
+                }
+            }
+            scaled.extend(std::iter::repeat_n(scaled_column, factor));
+        }
+
+        if dropped > 0 {
+            warn!("{dropped} cell(s) were scaled past the 7th day of the week and were dropped");
+        }
+        self.set_data(from_columns(scaled));
+        Ok(())
+    }
+}
@@ -1,7 +1,18 @@
 use std::sync::LazyLock;
 
 use chrono::{FixedOffset, NaiveDate};
-use gistory::visualizer::{CommitCount, Font};
+use gistory::visualizer::animation::FrameLayout;
+use gistory::visualizer::calibration::MAX_COMMITS_PER_DAY;
+use gistory::visualizer::fill::{Weekdays, Weights};
+use gistory::visualizer::font::Fallback;
+use gistory::visualizer::generator::Generator;
+use gistory::visualizer::grid::MAX_SEED;
+use gistory::visualizer::heatmap::Scale;
+use gistory::visualizer::image_options::{Dithering, FitMode, Resampling, Thresholds};
+use gistory::visualizer::raw_pattern::RawPattern;
+use gistory::visualizer::render::{RenderFormat, Theme};
+use gistory::visualizer::text_options::{Alignment, Shading};
+use gistory::visualizer::{CommitCount, CommitLevels, Font};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use validator::{Validate, ValidationError};
@@ -19,6 +30,11 @@ pub enum RepoVisualizeMethod {
     Image = 3,
     Text = 4,
     RawPattern = 5,
+    Design = 6,
+    Generate = 7,
+    Simulate = 8,
+    Encode = 9,
+    Heatmap = 10,
 }
 
 #[derive(Clone, Debug, Validate, Deserialize)]
@@ -35,6 +51,19 @@ pub struct CreateRepoDto {
     pub timezone: FixedOffset,
     #[validate(nested)]
     pub visualizer_method: VisualizerMethodDto,
+    #[validate(custom(function = "validate_commit_levels"))]
+    pub commit_levels: Option<CommitLevels>,
+}
+
+fn validate_commit_levels(levels: &CommitLevels) -> Result<(), ValidationError> {
+    levels.validate().map_err(|_| {
+        ValidationError::new("commit_levels").with_message(
+            format!(
+                "Commit levels must be strictly increasing, > 0 and at most {MAX_COMMITS_PER_DAY}"
+            )
+            .into(),
+        )
+    })
 }
 
 fn deserialize_fixed_offset<'de, D>(deserializer: D) -> Result<FixedOffset, D::Error>
@@ -58,11 +87,60 @@ pub struct VisualizerMethodDto {
     pub end_date: Option<NaiveDate>,
     pub commit_count: Option<CommitCount>,
     pub font: Option<Font>,
+    pub fallback: Option<Fallback>,
+    #[validate(range(max = 10))]
+    pub letter_spacing: Option<usize>,
+    #[validate(range(max = 10))]
+    pub word_spacing: Option<usize>,
+    pub kerning: Option<bool>,
+    #[validate(range(max = 52))]
+    pub leading: Option<usize>,
+    #[validate(range(min = 1, max = 53))]
+    pub text_width: Option<usize>,
+    pub align: Option<Alignment>,
+    pub shading: Option<Shading>,
+    pub shade: Option<CommitCount>,
+    pub marquee: Option<bool>,
     #[validate(length(equal = 36))]
     pub input_file: Option<String>,
-    #[validate(length(min = 1, max = 64))]
+    #[validate(length(min = 1, max = 512))]
     pub text: Option<String>,
-    pub raw_pattern: Option<Vec<CommitCount>>,
+    pub raw_pattern: Option<RawPattern>,
+    pub heatmap_scale: Option<Scale>,
+    pub lenient: Option<bool>,
+    pub dithering: Option<Dithering>,
+    pub thresholds: Option<Thresholds>,
+    #[validate(range(exclusive_min = 0.0, max = 10.0))]
+    pub gamma: Option<f32>,
+    #[validate(range(min = 0.0, max = 10.0))]
+    pub contrast: Option<f32>,
+    pub invert_image: Option<bool>,
+    pub fit: Option<FitMode>,
+    #[validate(range(min = 1, max = 53))]
+    pub fit_weeks: Option<usize>,
+    pub resampling: Option<Resampling>,
+    pub animation: Option<FrameLayout>,
+    pub weights: Option<Weights>,
+    pub weekdays: Option<Weekdays>,
+    pub random_background: Option<bool>,
+    pub generator: Option<Generator>,
+    #[validate(range(exclusive_min = 0.0, max = 1000.0))]
+    pub noise_scale: Option<f32>,
+    #[validate(range(min = 1, max = 8))]
+    pub octaves: Option<usize>,
+    #[validate(range(min = 0.0, max = 1.0))]
+    pub density: Option<f32>,
+    #[validate(range(max = 1000))]
+    pub steps: Option<usize>,
+    pub rule: Option<u8>,
+    #[validate(range(min = 0.0, max = 10.0))]
+    pub amplitude: Option<f32>,
+    #[validate(range(max = MAX_SEED))]
+    pub seed: Option<u64>,
+    pub year: Option<i32>,
+    #[validate(range(max = 52))]
+    pub week: Option<usize>,
+    pub center: Option<bool>,
 }
 
 pub fn validate_visualizer_method_dto(dto: &VisualizerMethodDto) -> Result<(), ValidationError> {
@@ -80,6 +158,22 @@ pub fn validate_visualizer_method_dto(dto: &VisualizerMethodDto) -> Result<(), V
                 return Err(ValidationError::new("Random method requires end_date"));
             }
         }
+        RepoVisualizeMethod::Generate => {
+            if dto.end_date.is_none() {
+                return Err(ValidationError::new("Generate method requires end_date"));
+            }
+            if dto.generator.is_none() {
+                return Err(ValidationError::new("Generate method requires generator"));
+            }
+        }
+        RepoVisualizeMethod::Simulate => {
+            if dto.end_date.is_none() {
+                return Err(ValidationError::new("Simulate method requires end_date"));
+            }
+            if dto.input_file.is_none() {
+                return Err(ValidationError::new("Simulate method requires input_file"));
+            }
+        }
         RepoVisualizeMethod::PatternFile => {
             if dto.input_file.is_none() {
                 return Err(ValidationError::new(
@@ -102,6 +196,34 @@ pub fn validate_visualizer_method_dto(dto: &VisualizerMethodDto) -> Result<(), V
             if dto.commit_count.is_none() {
                 return Err(ValidationError::new("Full method requires commit_count"));
             }
+            if dto.marquee != Some(true)
+                && dto
+                    .text
+                    .as_ref()
+                    .is_some_and(|text| text.chars().count() > 64)
+            {
+                return Err(ValidationError::new(
+                    "text longer than 64 characters requires marquee",
+                ));
+            }
+        }
+        RepoVisualizeMethod::Encode => {
+            if dto.text.is_none() {
+                return Err(ValidationError::new("Encode method requires text"));
+            }
+            if dto.commit_count.is_none() {
+                return Err(ValidationError::new("Encode method requires commit_count"));
+            }
+        }
+        RepoVisualizeMethod::Heatmap => {
+            if dto.input_file.is_none() {
+                return Err(ValidationError::new("Heatmap method requires input_file"));
+            }
+        }
+        RepoVisualizeMethod::Design => {
+            if dto.input_file.is_none() {
+                return Err(ValidationError::new("Design method requires input_file"));
+            }
         }
         RepoVisualizeMethod::RawPattern => {
             if dto.raw_pattern.is_none() {
@@ -112,11 +234,72 @@ pub fn validate_visualizer_method_dto(dto: &VisualizerMethodDto) -> Result<(), V
         }
     }
 
+    if dto.random_background == Some(true) {
+        if dto.end_date.is_none() {
+            return Err(ValidationError::new("random_background requires end_date"));
+        }
+        if matches!(
+            dto.method,
+            RepoVisualizeMethod::Random
+                | RepoVisualizeMethod::Generate
+                | RepoVisualizeMethod::Simulate
+        ) {
+            return Err(ValidationError::new(
+                "random_background cannot be used with Random, Generate or Simulate methods",
+            ));
+        }
+    }
+    if dto.week.is_some() && dto.year.is_none() {
+        return Err(ValidationError::new("week requires year"));
+    }
+    if dto.year.is_some() && dto.center == Some(true) {
+        return Err(ValidationError::new(
+            "year and center cannot be used together",
+        ));
+    }
+    if dto.animation.is_some() && (dto.year.is_some() || dto.center == Some(true)) {
+        return Err(ValidationError::new(
+            "animation cannot be used with year or center",
+        ));
+    }
+    if dto.marquee == Some(true) && (dto.year.is_some() || dto.center == Some(true)) {
+        return Err(ValidationError::new(
+            "marquee cannot be used with year or center",
+        ));
+    }
+
     Ok(())
 }
 
+#[derive(Clone, Debug, Deserialize)]
+pub struct RenderOptionsDto {
+    pub theme: Option<Theme>,
+    pub format: Option<RenderFormat>,
+    pub scale: Option<u32>,
+}
+
 #[derive(Serialize, Debug)]
 pub struct Preview {
+    pub start_date: NaiveDate,
+    pub data: Vec<CommitCount>,
+    /// Number of calendar years a marquee spans
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub years: Option<usize>,
+    /// Seed to create the repo with to get the same random patterns
+    pub seed: u64,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct SpriteOptionsDto {
+    pub commit_count: Option<CommitCount>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct SpriteDto {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub weeks: usize,
+    /// 7 days per week, like `Preview::data`
     pub data: Vec<CommitCount>,
 }
 
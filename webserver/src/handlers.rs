@@ -3,31 +3,91 @@ use std::sync::Arc;
 use axum::Extension;
 use axum::extract::Multipart;
 use axum::extract::{Json, Path, Query};
+use axum::http::header::CONTENT_TYPE;
+use axum::response::{IntoResponse, Response};
 use axum_valid::Valid;
+use chrono::{Datelike, TimeDelta};
 use diesel::{
     ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl, SelectableHelper, SqliteConnection,
 };
+use gistory::visualizer::grid::random_seed;
+use gistory::visualizer::render::{self, RenderFormat, Theme};
+use gistory::visualizer::sprite;
+use gistory::visualizer::{CommitCount, Design, DesignMetadata};
 use log::info;
 use tokio::fs;
 use tokio::sync::Mutex;
 
 use crate::constants::UPLOAD_DIR;
-use crate::dto::{CreateRepoDto, Preview, UploadResult, VisualizerMethodDto};
+use crate::dto::{
+    CreateRepoDto, Preview, RenderOptionsDto, SpriteDto, SpriteOptionsDto, UploadResult,
+    VisualizerMethodDto,
+};
 use crate::error::{Error, FieldErr, Result};
 use crate::models::*;
 use crate::schema::repo;
 use crate::tasks::{create_grid_from_dto, create_upload_file, generate_repo};
 
 pub async fn preview(
-    Valid(Query(dto)): Valid<Query<VisualizerMethodDto>>,
+    Valid(Query(mut dto)): Valid<Query<VisualizerMethodDto>>,
 ) -> Result<Json<Preview>> {
     info!("Preview dto: {dto:?}");
+    // the seed is sent back so the created repo can use the same one
+    let seed = *dto.seed.get_or_insert_with(random_seed);
+    let marquee = dto.marquee == Some(true);
     let grid = create_grid_from_dto(dto).await?;
+    let years = marquee.then(|| {
+        let last_day =
+            grid.get_start_date() + TimeDelta::days(grid.get_data().len().saturating_sub(1) as i64);
+        (last_day.year() - grid.get_start_date().year() + 1) as usize
+    });
     Ok(Json(Preview {
+        start_date: grid.get_start_date(),
         data: grid.get_data().to_vec(),
+        years,
+        seed,
     }))
 }
 
+pub async fn render_image(
+    Valid(Query(dto)): Valid<Query<VisualizerMethodDto>>,
+    Query(options): Query<RenderOptionsDto>,
+) -> Result<Response> {
+    info!("Render dto: {dto:?} {options:?}");
+    let grid = create_grid_from_dto(dto).await?;
+    let format = options.format.unwrap_or(RenderFormat::Svg);
+    let theme = options.theme.unwrap_or(Theme::Light);
+    let scale = options.scale.unwrap_or(2).clamp(1, 16);
+    let content = render::render(&grid, theme, format, scale)?;
+    Ok(([(CONTENT_TYPE, format.content_type())], content).into_response())
+}
+
+pub async fn export_design(
+    Valid(Query(dto)): Valid<Query<VisualizerMethodDto>>,
+    Query(metadata): Query<DesignMetadata>,
+) -> Result<Json<Design>> {
+    info!("Export design dto: {dto:?} {metadata:?}");
+    let grid = create_grid_from_dto(dto).await?;
+    Ok(Json(Design::from_grid(&grid, metadata)))
+}
+
+pub async fn list_sprites(Query(options): Query<SpriteOptionsDto>) -> Json<Vec<SpriteDto>> {
+    let commit_count = options.commit_count.unwrap_or(CommitCount::Many);
+    let sprites = sprite::names()
+        .into_iter()
+        .map(|name| {
+            let sprite = sprite::get(name).unwrap();
+            SpriteDto {
+                name,
+                description: sprite.description,
+                weeks: sprite.width(),
+                data: sprite.days(commit_count),
+            }
+        })
+        .collect();
+    Json(sprites)
+}
+
 pub async fn create_repo(
     Extension(conn_arc): Extension<Arc<Mutex<SqliteConnection>>>,
     Valid(Json(create_repo_dto)): Valid<Json<CreateRepoDto>>,
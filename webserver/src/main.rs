@@ -46,6 +46,9 @@ async fn main() {
         )
         .nest_service("/static", ServeDir::new("static"))
         .route("/api/preview", get(handlers::preview))
+        .route("/api/render", get(handlers::render_image))
+        .route("/api/design", get(handlers::export_design))
+        .route("/api/sprites", get(handlers::list_sprites))
         .route("/api/repo", post(handlers::create_repo))
         .route("/api/repo/{id}", get(handlers::get_repo))
         .route("/api/upload", post(handlers::upload_file))
@@ -5,7 +5,13 @@ use axum::body::Bytes;
 use axum::extract::multipart::Field;
 use diesel::{ExpressionMethods, RunQueryDsl};
 use gistory::git;
-use gistory::visualizer::CommitGrid;
+use gistory::visualizer::activity::ActivityProfile;
+use gistory::visualizer::design::{Design, DesignFormat};
+use gistory::visualizer::fill::FillOptions;
+use gistory::visualizer::generator::GeneratorOptions;
+use gistory::visualizer::grid::random_seed;
+use gistory::visualizer::pattern_file::PatternFile;
+use gistory::visualizer::{Anchor, CommitGrid, ImageOptions, TextOptions};
 use log::{debug, error, info};
 use tokio::fs;
 use tokio::io::{AsyncWriteExt, BufReader};
@@ -36,33 +42,144 @@ pub async fn create_upload_file(field: Field<'_>) -> Result<(String, Bytes)> {
     Ok((file_id, data))
 }
 
+fn get_anchor(dto: &VisualizerMethodDto) -> Option<Anchor> {
+    match (dto.year, dto.week, dto.center) {
+        (Some(year), Some(week), _) => Some(Anchor::Week { year, week }),
+        (Some(year), None, _) => Some(Anchor::Year(year)),
+        (None, _, Some(true)) => Some(Anchor::Center {
+            today: chrono::Utc::now().date_naive(),
+        }),
+        _ => None,
+    }
+}
+
 pub async fn create_grid_from_dto(dto: VisualizerMethodDto) -> Result<CommitGrid> {
+    let mut anchor = get_anchor(&dto);
+    let seed = dto.seed.unwrap_or_else(random_seed);
+    let defaults = FillOptions::default();
+    let fill_options = FillOptions {
+        weights: dto.weights.unwrap_or(defaults.weights),
+        weekdays: dto.weekdays.unwrap_or(defaults.weekdays),
+    };
     let mut grid = CommitGrid::new(dto.start_date);
     match dto.method {
         RepoVisualizeMethod::Full => {
-            grid.full(dto.commit_count.unwrap(), dto.end_date.unwrap())?;
+            grid.full_with_options(
+                dto.commit_count.unwrap(),
+                dto.end_date.unwrap(),
+                &fill_options,
+            )?;
+        }
+        RepoVisualizeMethod::Random => {
+            grid.random_with_options(dto.end_date.unwrap(), &fill_options, seed)?
+        }
+        RepoVisualizeMethod::Generate => {
+            let defaults = GeneratorOptions::default();
+            let options = GeneratorOptions {
+                scale: dto.noise_scale,
+                octaves: dto.octaves.unwrap_or(defaults.octaves),
+                density: dto.density.unwrap_or(defaults.density),
+                steps: dto.steps.unwrap_or(defaults.steps),
+                rule: dto.rule.unwrap_or(defaults.rule),
+                amplitude: dto.amplitude.unwrap_or(defaults.amplitude),
+            };
+            grid.generate(
+                dto.generator.unwrap(),
+                &options,
+                dto.end_date.unwrap(),
+                seed,
+            )?;
+        }
+        RepoVisualizeMethod::Simulate => {
+            let mut path_buf = PathBuf::from(UPLOAD_DIR);
+            path_buf.push(dto.input_file.unwrap());
+            let profile = ActivityProfile::read(&path_buf).await?;
+            grid.simulate(&profile, dto.end_date.unwrap(), seed)?;
         }
-        RepoVisualizeMethod::Random => grid.random(dto.end_date.unwrap())?,
         RepoVisualizeMethod::PatternFile => {
             let mut path_buf = PathBuf::from(UPLOAD_DIR);
             path_buf.push(dto.input_file.unwrap());
-            grid.read_pattern_file(&path_buf).await?;
+            let pattern = PatternFile::read(&path_buf, dto.lenient.unwrap_or(false)).await?;
+            // the start date is chosen by the user, but the pattern can still say where it goes
+            anchor = anchor.or(pattern.anchor);
+            grid.set_data(pattern.data);
         }
         RepoVisualizeMethod::Image => {
             let mut path_buf = PathBuf::from(UPLOAD_DIR);
             path_buf.push(dto.input_file.unwrap());
-            grid.read_image_file(&path_buf).await?;
+            let defaults = ImageOptions::default();
+            let options = ImageOptions {
+                dithering: dto.dithering.unwrap_or(defaults.dithering),
+                thresholds: dto.thresholds.unwrap_or(defaults.thresholds),
+                gamma: dto.gamma.unwrap_or(defaults.gamma),
+                contrast: dto.contrast.unwrap_or(defaults.contrast),
+                invert: dto.invert_image.unwrap_or(defaults.invert),
+                fit: dto.fit.unwrap_or(defaults.fit),
+                weeks: dto.fit_weeks,
+                resampling: dto.resampling.unwrap_or(defaults.resampling),
+            };
+            match dto.animation {
+                Some(layout) => {
+                    grid.read_animation_file(&path_buf, &options, layout)
+                        .await?
+                }
+                None => {
+                    grid.read_image_file_with_options(&path_buf, &options)
+                        .await?
+                }
+            }
         }
         RepoVisualizeMethod::Text => {
             let text = dto.text.unwrap();
             let font = dto.font.unwrap();
             let commit_count = dto.commit_count.unwrap();
-            grid.show_text(text.clone(), font, commit_count)?;
+            let defaults = TextOptions::default();
+            let options = TextOptions {
+                fallback: dto.fallback.unwrap_or(defaults.fallback),
+                letter_spacing: dto.letter_spacing.unwrap_or(defaults.letter_spacing),
+                word_spacing: dto.word_spacing,
+                kerning: dto.kerning.unwrap_or(defaults.kerning),
+                leading: dto.leading.unwrap_or(defaults.leading),
+                width: dto.text_width,
+                alignment: dto.align.unwrap_or(defaults.alignment),
+                shading: dto.shading.unwrap_or(defaults.shading),
+                shade: dto.shade.unwrap_or(defaults.shade),
+            };
+            if dto.marquee == Some(true) {
+                let years = grid.show_marquee(&text, font, commit_count, &options)?;
+                info!("Marquee spans {years} year(s)");
+            } else {
+                grid.show_text_with_options(&text, font, commit_count, &options)?;
+            }
+        }
+        RepoVisualizeMethod::Encode => {
+            grid.show_message(&dto.text.unwrap(), dto.commit_count.unwrap())?;
         }
         RepoVisualizeMethod::RawPattern => {
             let raw_pattern = dto.raw_pattern.unwrap();
-            grid.set_data(raw_pattern);
+            grid.set_data(raw_pattern.into_days());
         }
+        RepoVisualizeMethod::Heatmap => {
+            let mut path_buf = PathBuf::from(UPLOAD_DIR);
+            path_buf.push(dto.input_file.unwrap());
+            // unlike designs, the dates of the series are kept
+            grid = CommitGrid::from_series_file(&path_buf, dto.heatmap_scale.unwrap_or_default())
+                .await?;
+        }
+        RepoVisualizeMethod::Design => {
+            let mut path_buf = PathBuf::from(UPLOAD_DIR);
+            path_buf.push(dto.input_file.unwrap());
+            let content = fs::read_to_string(&path_buf).await?;
+            let design = Design::parse(&content, DesignFormat::detect(&content))?;
+            // the design's own start date is overridden by the one chosen by the user
+            grid.set_data(design.to_grid(None, seed, &[]).await?.get_data().to_vec());
+        }
+    }
+    if let Some(anchor) = anchor {
+        grid.place(anchor)?;
+    }
+    if dto.random_background == Some(true) {
+        grid.fill_background(dto.end_date.unwrap(), &fill_options, seed)?;
     }
     Ok(grid)
 }
@@ -139,7 +256,9 @@ async fn do_generate_repo(repo_dto: CreateRepoDto, db_repo: &Repo) -> Result<()>
     git_repo.init().await.unwrap();
     debug!("Git repo: {git_repo:?}");
 
-    grid.populate_repo(&mut git_repo).await?;
+    let commit_levels = repo_dto.commit_levels.unwrap_or_default();
+    grid.populate_repo_with_levels(&mut git_repo, &commit_levels)
+        .await?;
     debug!("Repo populated {}", db_repo.uuid);
 
     compress_directory(&repo_path).await?;